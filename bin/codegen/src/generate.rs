@@ -0,0 +1,774 @@
+use std::{
+    collections::HashSet,
+    fmt::Write as _,
+    io::Write as _,
+    process::{Command, Stdio},
+};
+
+use anyhow::{bail, Context, Result};
+use idl::{
+    to_pascal_case, to_snake_case, Idl, IdlEnumFields, IdlField, IdlInstruction, IdlType,
+    IdlTypeDefinition, IdlTypeDefinitionTy,
+};
+
+const DERIVE: &str = "#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]";
+const ARBITRARY: &str =
+    "#[cfg_attr(any(test, feature = \"arbitrary\"), derive(arbitrary::Arbitrary))]";
+const KEYWORDS: &[&str] = &[
+    "type", "match", "ref", "mod", "fn", "struct", "enum", "impl",
+];
+
+/// Renders the decoder module of a program: instructions, instruction accounts, accounts, types,
+/// events and errors, with their discriminators.
+pub fn generate(idl: &Idl, prefix: &str, command: &str) -> Result<String> {
+    check_names(idl)?;
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// Generated from the {} v{} IDL by `{}`, do not edit.",
+        idl.name, idl.version, command
+    )?;
+    writeln!(out)?;
+    writeln!(out, "use borsh::{{BorshDeserialize, BorshSerialize}};")?;
+    writeln!(
+        out,
+        "use solana_program::{{instruction::AccountMeta, program_error::ProgramError, pubkey::Pubkey}};"
+    )?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "use crate::{{impl_discriminator, Discriminator, InstructionArgs, ToAccountMetas}};"
+    )?;
+    writeln!(out)?;
+    if let Some(address) = idl.metadata.as_ref().and_then(|m| m.address.as_ref()) {
+        writeln!(out, "solana_program::declare_id!({:?});", address)?;
+        writeln!(out)?;
+    }
+
+    instructions(&mut out, idl, prefix)?;
+    instruction_accounts(&mut out, idl)?;
+    accounts(&mut out, idl)?;
+    types(&mut out, idl)?;
+    events(&mut out, idl, prefix)?;
+    errors(&mut out, idl, prefix)?;
+
+    Ok(out)
+}
+
+/// Formats a generated module with rustfmt.
+pub fn format(module: &str) -> Result<String> {
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2021", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .context("Failed to run rustfmt")?;
+    rustfmt
+        .stdin
+        .take()
+        .context("rustfmt stdin")?
+        .write_all(module.as_bytes())?;
+
+    let output = rustfmt.wait_with_output()?;
+    if !output.status.success() {
+        bail!("rustfmt failed on the generated module");
+    }
+
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// Instruction argument, account, type and event definitions share one namespace.
+fn check_names(idl: &Idl) -> Result<()> {
+    let mut names = HashSet::new();
+    let all = idl
+        .instructions
+        .iter()
+        .flat_map(|instruction| {
+            let name = to_pascal_case(&instruction.name);
+            [format!("{}Accounts", name), name]
+        })
+        .chain(idl.accounts.iter().map(|account| account.name.clone()))
+        .chain(idl.types.iter().map(|ty| ty.name.clone()))
+        .chain(idl.events.iter().map(|event| event.name.clone()));
+
+    for name in all {
+        if !names.insert(name.clone()) {
+            bail!("{} is defined twice", name);
+        }
+    }
+
+    Ok(())
+}
+
+fn instructions(out: &mut String, idl: &Idl, prefix: &str) -> Result<()> {
+    writeln!(out, "// Instructions")?;
+    writeln!(out)?;
+    writeln!(out, "#[allow(clippy::large_enum_variant)]")?;
+    writeln!(out, "{}", DERIVE)?;
+    writeln!(out, "{}", ARBITRARY)?;
+    writeln!(out, "pub enum {}Instruction {{", prefix)?;
+    for instruction in &idl.instructions {
+        let name = to_pascal_case(&instruction.name);
+        match instruction.args.is_empty() {
+            true => writeln!(out, "    {},", name)?,
+            false => writeln!(out, "    {}({}),", name, name)?,
+        }
+    }
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "/// Names of the `{}Instruction` variants in declaration order, the index of a name is the",
+        prefix
+    )?;
+    writeln!(out, "/// borsh tag of its variant.")?;
+    writeln!(
+        out,
+        "pub const INSTRUCTION_NAMES: [&str; {}] = [",
+        idl.instructions.len()
+    )?;
+    for instruction in &idl.instructions {
+        writeln!(out, "    {:?},", to_pascal_case(&instruction.name))?;
+    }
+    writeln!(out, "];")?;
+    writeln!(out)?;
+
+    writeln!(out, "impl {}Instruction {{", prefix)?;
+    writeln!(
+        out,
+        "    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {{"
+    )?;
+    unpack_prelude(out)?;
+    for instruction in &idl.instructions {
+        let name = to_pascal_case(&instruction.name);
+        match instruction.args.is_empty() {
+            true => writeln!(
+                out,
+                "                {}::DISCRIMINATOR => Self::{},",
+                name, name
+            )?,
+            false => writeln!(
+                out,
+                "                {}::DISCRIMINATOR => Self::{}(borsh::from_slice(rest)?),",
+                name, name
+            )?,
+        }
+    }
+    unpack_epilogue(out)?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "    /// Serializes the instruction back into its discriminator followed by the borsh encoded"
+    )?;
+    writeln!(out, "    /// arguments.")?;
+    writeln!(
+        out,
+        "    pub fn pack(&self) -> Result<Vec<u8>, ProgramError> {{"
+    )?;
+    writeln!(out, "        match self {{")?;
+    for instruction in &idl.instructions {
+        let name = to_pascal_case(&instruction.name);
+        match instruction.args.is_empty() {
+            true => writeln!(
+                out,
+                "            Self::{} => Ok({}::DISCRIMINATOR.to_vec()),",
+                name, name
+            )?,
+            false => writeln!(
+                out,
+                "            Self::{}(args) => pack_instruction(args),",
+                name
+            )?,
+        }
+    }
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "fn pack_instruction<T: BorshSerialize + Discriminator>(args: &T) -> Result<Vec<u8>, ProgramError> {{"
+    )?;
+    writeln!(out, "    let mut data = T::DISCRIMINATOR.to_vec();")?;
+    writeln!(out, "    args.serialize(&mut data)?;")?;
+    writeln!(out, "    Ok(data)")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+
+    for instruction in &idl.instructions {
+        let name = to_pascal_case(&instruction.name);
+        docs(out, &instruction.docs, "")?;
+        writeln!(out, "{}", DERIVE)?;
+        writeln!(out, "{}", ARBITRARY)?;
+        write_struct(out, &name, &instruction.args)?;
+        writeln!(out, "impl_discriminator!(instruction {});", name)?;
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+fn instruction_accounts(out: &mut String, idl: &Idl) -> Result<()> {
+    writeln!(out, "// Instruction accounts")?;
+    writeln!(out)?;
+    for instruction in &idl.instructions {
+        writeln!(out, "{}", DERIVE)?;
+        writeln!(
+            out,
+            "pub struct {}Accounts {{",
+            to_pascal_case(&instruction.name)
+        )?;
+        for account in instruction.flat_accounts() {
+            docs(out, &account.docs, "    ")?;
+            let ty = match account.is_optional {
+                true => "Option<Pubkey>",
+                false => "Pubkey",
+            };
+            writeln!(out, "    pub {}: {},", field_name(&account.name), ty)?;
+        }
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+        account_metas(out, idl, instruction)?;
+    }
+
+    Ok(())
+}
+
+/// `ToAccountMetas` of the accounts of an instruction, with the IDL's writable and signer flags,
+/// and the `InstructionArgs` linking its arguments to them. Optional accounts that are `None` are
+/// passed as the program id, like anchor does.
+fn account_metas(out: &mut String, idl: &Idl, instruction: &IdlInstruction) -> Result<()> {
+    let name = to_pascal_case(&instruction.name);
+    writeln!(out, "impl ToAccountMetas for {}Accounts {{", name)?;
+    writeln!(out, "    fn to_account_metas(&self) -> Vec<AccountMeta> {{")?;
+    writeln!(out, "        vec![")?;
+    for account in instruction.flat_accounts() {
+        let constructor = match account.is_mut {
+            true => "AccountMeta::new",
+            false => "AccountMeta::new_readonly",
+        };
+        let field = field_name(&account.name);
+        match account.is_optional {
+            true if !has_address(idl) => {
+                bail!("Optional account {} without a program id", account.name)
+            }
+            true => writeln!(
+                out,
+                "            match self.{} {{ Some(key) => {}(key, {}), None => AccountMeta::new_readonly(ID, false) }},",
+                field, constructor, account.is_signer
+            )?,
+            false => writeln!(
+                out,
+                "            {}(self.{}, {}),",
+                constructor, field, account.is_signer
+            )?,
+        }
+    }
+    writeln!(out, "        ]")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+
+    writeln!(out, "impl InstructionArgs for {} {{", name)?;
+    writeln!(out, "    type Accounts = {}Accounts;", name)?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+
+    Ok(())
+}
+
+fn has_address(idl: &Idl) -> bool {
+    idl.metadata
+        .as_ref()
+        .is_some_and(|metadata| metadata.address.is_some())
+}
+
+fn accounts(out: &mut String, idl: &Idl) -> Result<()> {
+    writeln!(out, "// Accounts")?;
+    writeln!(out)?;
+    for account in &idl.accounts {
+        type_definition(out, account)?;
+        writeln!(out, "impl_discriminator!(account {});", account.name)?;
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+fn types(out: &mut String, idl: &Idl) -> Result<()> {
+    writeln!(out, "// Types definition")?;
+    writeln!(out)?;
+    for ty in &idl.types {
+        type_definition(out, ty)?;
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+fn events(out: &mut String, idl: &Idl, prefix: &str) -> Result<()> {
+    writeln!(out, "// Events")?;
+    writeln!(out)?;
+    writeln!(out, "#[allow(clippy::large_enum_variant)]")?;
+    writeln!(out, "{}", DERIVE)?;
+    writeln!(out, "{}", ARBITRARY)?;
+    writeln!(out, "pub enum {}Event {{", prefix)?;
+    for event in &idl.events {
+        writeln!(out, "    {}({}),", event.name, event.name)?;
+    }
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+
+    writeln!(out, "impl {}Event {{", prefix)?;
+    writeln!(
+        out,
+        "    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {{"
+    )?;
+    unpack_prelude(out)?;
+    for event in &idl.events {
+        writeln!(
+            out,
+            "                {}::DISCRIMINATOR => Self::{}(borsh::from_slice(rest)?),",
+            event.name, event.name
+        )?;
+    }
+    unpack_epilogue(out)?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+
+    for event in &idl.events {
+        let fields = event
+            .fields
+            .iter()
+            .map(|field| IdlField {
+                name: field.name.clone(),
+                docs: Vec::new(),
+                ty: field.ty.clone(),
+            })
+            .collect::<Vec<_>>();
+        writeln!(out, "{}", DERIVE)?;
+        writeln!(out, "{}", ARBITRARY)?;
+        write_struct(out, &event.name, &fields)?;
+        writeln!(out, "impl_discriminator!(event {});", event.name)?;
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+fn errors(out: &mut String, idl: &Idl, prefix: &str) -> Result<()> {
+    if idl.errors.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(out, "// Errors")?;
+    writeln!(out)?;
+    writeln!(out, "#[derive(Clone, Copy, Debug, PartialEq)]")?;
+    writeln!(out, "pub enum {}Error {{", prefix)?;
+    for error in &idl.errors {
+        writeln!(out, "    {} = {},", error.name, error.code)?;
+    }
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+
+    writeln!(out, "impl {}Error {{", prefix)?;
+    writeln!(out, "    pub fn from_code(code: u32) -> Option<Self> {{")?;
+    writeln!(out, "        match code {{")?;
+    for error in &idl.errors {
+        writeln!(
+            out,
+            "            {} => Some(Self::{}),",
+            error.code, error.name
+        )?;
+    }
+    writeln!(out, "            _ => None,")?;
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out)?;
+    writeln!(out, "    pub fn message(&self) -> &'static str {{")?;
+    writeln!(out, "        match self {{")?;
+    for error in &idl.errors {
+        let message = error.msg.clone().unwrap_or_else(|| error.name.clone());
+        writeln!(out, "            Self::{} => {:?},", error.name, message)?;
+    }
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+
+    Ok(())
+}
+
+fn unpack_prelude(out: &mut String) -> Result<()> {
+    writeln!(out, "        if input.len() < 8 {{")?;
+    writeln!(
+        out,
+        "            return Err(ProgramError::InvalidInstructionData);"
+    )?;
+    writeln!(out, "        }}")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "        let (discriminator, rest) = input.split_at(8);"
+    )?;
+    writeln!(
+        out,
+        "        if let Ok(discriminator) = discriminator.try_into() {{"
+    )?;
+    writeln!(out, "            return Ok(match discriminator {{")?;
+    Ok(())
+}
+
+fn unpack_epilogue(out: &mut String) -> Result<()> {
+    writeln!(
+        out,
+        "                _ => return Err(ProgramError::InvalidInstructionData),"
+    )?;
+    writeln!(out, "            }});")?;
+    writeln!(out, "        }}")?;
+    writeln!(out)?;
+    writeln!(out, "        Err(ProgramError::InvalidInstructionData)")?;
+    writeln!(out, "    }}")?;
+    Ok(())
+}
+
+fn type_definition(out: &mut String, definition: &IdlTypeDefinition) -> Result<()> {
+    docs(out, &definition.docs, "")?;
+    writeln!(out, "{}", DERIVE)?;
+    writeln!(out, "{}", ARBITRARY)?;
+    match &definition.ty {
+        IdlTypeDefinitionTy::Struct { fields } => write_struct(out, &definition.name, fields)?,
+        IdlTypeDefinitionTy::Enum { variants } => {
+            writeln!(out, "pub enum {} {{", definition.name)?;
+            for variant in variants {
+                match &variant.fields {
+                    None => writeln!(out, "    {},", variant.name)?,
+                    Some(IdlEnumFields::Tuple(types)) => {
+                        let attributes = types
+                            .iter()
+                            .map(|ty| arbitrary_with(ty, "        "))
+                            .collect::<Result<Vec<_>>>()?;
+                        let types = types.iter().map(rust_type).collect::<Result<Vec<_>>>()?;
+                        // rustfmt leaves attributes alone, fields carrying one go on their own
+                        // lines.
+                        match attributes.iter().all(String::is_empty) {
+                            true => writeln!(out, "    {}({}),", variant.name, types.join(", "))?,
+                            false => {
+                                writeln!(out, "    {}(", variant.name)?;
+                                for (attribute, ty) in attributes.iter().zip(types) {
+                                    writeln!(out, "        {}{},", attribute, ty)?;
+                                }
+                                writeln!(out, "    ),")?;
+                            }
+                        }
+                    }
+                    Some(IdlEnumFields::Named(fields)) => {
+                        writeln!(out, "    {} {{", variant.name)?;
+                        for field in fields {
+                            docs(out, &field.docs, "        ")?;
+                            write!(out, "        {}", arbitrary_with(&field.ty, "        ")?)?;
+                            writeln!(
+                                out,
+                                "{}: {},",
+                                field_name(&field.name),
+                                rust_type(&field.ty)?
+                            )?;
+                        }
+                        writeln!(out, "    }},")?;
+                    }
+                }
+            }
+            writeln!(out, "}}")?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_struct(out: &mut String, name: &str, fields: &[IdlField]) -> Result<()> {
+    if fields.is_empty() {
+        writeln!(out, "pub struct {} {{}}", name)?;
+        writeln!(out)?;
+        return Ok(());
+    }
+
+    writeln!(out, "pub struct {} {{", name)?;
+    for field in fields {
+        docs(out, &field.docs, "    ")?;
+        write!(out, "    {}", arbitrary_with(&field.ty, "    ")?)?;
+        writeln!(
+            out,
+            "pub {}: {},",
+            field_name(&field.name),
+            rust_type(&field.ty)?
+        )?;
+    }
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+
+    Ok(())
+}
+
+/// `Pubkey` has no `Arbitrary` implementation, fields holding keys generate them through the
+/// helpers of `arbitrary_support`.
+fn arbitrary_with(ty: &IdlType, indent: &str) -> Result<String> {
+    let with = match ty {
+        IdlType::PublicKey => "pubkey",
+        IdlType::Array(inner, _) if **inner == IdlType::PublicKey => "pubkeys",
+        IdlType::Option(inner) => match inner.as_ref() {
+            IdlType::PublicKey => "option_pubkey",
+            IdlType::Array(inner, _) if **inner == IdlType::PublicKey => "option_pubkeys",
+            inner if has_public_key(inner) => bail!("Unsupported public key type {:?}", ty),
+            _ => return Ok(String::new()),
+        },
+        ty if has_public_key(ty) => bail!("Unsupported public key type {:?}", ty),
+        _ => return Ok(String::new()),
+    };
+
+    Ok(format!(
+        "#[cfg_attr(\n{indent}    any(test, feature = \"arbitrary\"),\n{indent}    arbitrary(with = crate::arbitrary_support::{with})\n{indent})]\n{indent}",
+    ))
+}
+
+fn has_public_key(ty: &IdlType) -> bool {
+    match ty {
+        IdlType::PublicKey => true,
+        IdlType::Option(inner) | IdlType::Vec(inner) | IdlType::Array(inner, _) => {
+            has_public_key(inner)
+        }
+        _ => false,
+    }
+}
+
+fn docs(out: &mut String, docs: &[String], indent: &str) -> Result<()> {
+    for line in docs {
+        writeln!(out, "{}/// {}", indent, line)?;
+    }
+    Ok(())
+}
+
+fn field_name(name: &str) -> String {
+    let name = to_snake_case(name);
+    match KEYWORDS.contains(&name.as_str()) {
+        true => format!("r#{}", name),
+        false => name,
+    }
+}
+
+fn rust_type(ty: &IdlType) -> Result<String> {
+    Ok(match ty {
+        IdlType::Bool => "bool".to_string(),
+        IdlType::U8 => "u8".to_string(),
+        IdlType::I8 => "i8".to_string(),
+        IdlType::U16 => "u16".to_string(),
+        IdlType::I16 => "i16".to_string(),
+        IdlType::U32 => "u32".to_string(),
+        IdlType::I32 => "i32".to_string(),
+        IdlType::F32 => "f32".to_string(),
+        IdlType::U64 => "u64".to_string(),
+        IdlType::I64 => "i64".to_string(),
+        IdlType::F64 => "f64".to_string(),
+        IdlType::U128 => "u128".to_string(),
+        IdlType::I128 => "i128".to_string(),
+        IdlType::U256 | IdlType::I256 => bail!("256 bit integers are not supported"),
+        IdlType::Bytes => "Vec<u8>".to_string(),
+        IdlType::String => "String".to_string(),
+        IdlType::PublicKey => "Pubkey".to_string(),
+        IdlType::Defined(name) => name.clone(),
+        IdlType::Option(inner) => format!("Option<{}>", rust_type(inner)?),
+        IdlType::Vec(inner) => format!("Vec<{}>", rust_type(inner)?),
+        IdlType::Array(inner, len) => format!("[{}; {}]", rust_type(inner)?, len),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use super::*;
+
+    const IDL: &str = r#"{
+        "version": "0.1.0",
+        "name": "example",
+        "instructions": [
+            {
+                "name": "initialize",
+                "accounts": [
+                    { "name": "authority", "isMut": false, "isSigner": true },
+                    { "name": "vault", "isOptional": true }
+                ],
+                "args": []
+            },
+            {
+                "name": "setOwner",
+                "accounts": [],
+                "args": [
+                    { "name": "owner", "type": "publicKey" },
+                    { "name": "delegates", "type": { "option": { "array": ["publicKey", 2] } } }
+                ]
+            }
+        ],
+        "types": [
+            {
+                "name": "Side",
+                "type": { "kind": "enum", "variants": [{ "name": "Bid" }, { "name": "Ask" }] }
+            }
+        ],
+        "events": [
+            { "name": "OwnerSet", "fields": [{ "name": "owner", "type": "publicKey", "index": false }] }
+        ],
+        "errors": [
+            { "code": 6000, "name": "Unauthorized", "msg": "Signer is not the owner" },
+            { "code": 6001, "name": "Paused" }
+        ],
+        "metadata": { "address": "11111111111111111111111111111111" }
+    }"#;
+
+    fn example() -> String {
+        generate(&Idl::from_json(IDL).unwrap(), "Example", "codegen").unwrap()
+    }
+
+    #[test]
+    fn instructions_without_args_are_unit_variants() {
+        let module = example();
+
+        assert!(module.contains("    Initialize,\n    SetOwner(SetOwner),\n"));
+        assert!(module.contains("Initialize::DISCRIMINATOR => Self::Initialize,"));
+        assert!(module.contains("Self::Initialize => Ok(Initialize::DISCRIMINATOR.to_vec()),"));
+        assert!(module.contains("Self::SetOwner(args) => pack_instruction(args),"));
+        assert!(module.contains("pub const INSTRUCTION_NAMES: [&str; 2] = [\n    \"Initialize\",\n    \"SetOwner\",\n];"));
+    }
+
+    #[test]
+    fn discriminators_and_program_id() {
+        let module = example();
+
+        assert!(
+            module.contains("solana_program::declare_id!(\"11111111111111111111111111111111\");")
+        );
+        assert!(module.contains("impl_discriminator!(instruction SetOwner);"));
+        assert!(module.contains("impl_discriminator!(event OwnerSet);"));
+    }
+
+    #[test]
+    fn errors_map_codes_and_messages() {
+        let module = example();
+
+        assert!(module.contains(
+            "// Errors\n\n#[derive(Clone, Copy, Debug, PartialEq)]\npub enum ExampleError {\n    Unauthorized = 6000,\n    Paused = 6001,\n}"
+        ));
+        assert!(module.contains("            6000 => Some(Self::Unauthorized),"));
+        assert!(module.contains("            Self::Unauthorized => \"Signer is not the owner\","));
+        // Errors without a message fall back to their name.
+        assert!(module.contains("            Self::Paused => \"Paused\","));
+    }
+
+    #[test]
+    fn optional_accounts_are_options() {
+        assert!(example().contains(
+            "pub struct InitializeAccounts {\n    pub authority: Pubkey,\n    pub vault: Option<Pubkey>,\n}"
+        ));
+    }
+
+    #[test]
+    fn account_metas_carry_the_idl_flags() {
+        let module = example();
+
+        assert!(module.contains(
+            "impl ToAccountMetas for InitializeAccounts {\n    fn to_account_metas(&self) -> Vec<AccountMeta> {\n        vec![\n            AccountMeta::new_readonly(self.authority, true),\n            match self.vault { Some(key) => AccountMeta::new_readonly(key, false), None => AccountMeta::new_readonly(ID, false) },\n        ]\n"
+        ));
+        assert!(module.contains(
+            "impl InstructionArgs for SetOwner {\n    type Accounts = SetOwnerAccounts;\n}"
+        ));
+
+        let json = IDL.replace(
+            r#""metadata": { "address": "11111111111111111111111111111111" }"#,
+            "\"metadata\": {}",
+        );
+        assert!(generate(&Idl::from_json(&json).unwrap(), "Example", "codegen").is_err());
+    }
+
+    #[test]
+    fn public_keys_have_arbitrary_helpers() {
+        let module = example();
+
+        assert!(module.contains(
+            "        arbitrary(with = crate::arbitrary_support::pubkey)\n    )]\n    pub owner: Pubkey,"
+        ));
+        assert!(module.contains(
+            "        arbitrary(with = crate::arbitrary_support::option_pubkeys)\n    )]\n    pub delegates: Option<[Pubkey; 2]>,"
+        ));
+    }
+
+    #[test]
+    fn rejects_unsupported_public_key_containers() {
+        let json = IDL.replace(
+            r#"{ "option": { "array": ["publicKey", 2] } }"#,
+            r#"{ "vec": "publicKey" }"#,
+        );
+        assert!(generate(&Idl::from_json(&json).unwrap(), "Example", "codegen").is_err());
+    }
+
+    #[test]
+    fn rejects_duplicate_names() {
+        let json = IDL.replace(r#""name": "Side""#, r#""name": "SetOwner""#);
+        assert!(generate(&Idl::from_json(&json).unwrap(), "Example", "codegen").is_err());
+    }
+
+    /// The checked in Drift decoder is the formatted output of the checked in IDL, regenerate it
+    /// with the command in its header after changing either the IDL or the generator.
+    #[test]
+    fn drift_v2_is_up_to_date() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let idl = Idl::from_file(root.join("idl/drift.json")).unwrap();
+        let command = "cargo run -p codegen -- idl/drift.json DriftV2";
+        let module = format(&generate(&idl, "DriftV2", command).unwrap()).unwrap();
+
+        let checked_in =
+            fs::read_to_string(root.join("crates/decoders/src/drift_v2/generated.rs")).unwrap();
+        assert!(module == checked_in, "generated.rs is out of date");
+    }
+
+    /// Every account of every Drift instruction gets the writable and signer flags of the IDL.
+    #[test]
+    fn drift_v2_account_metas_match_the_idl() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let idl = Idl::from_file(root.join("idl/drift.json")).unwrap();
+        let module =
+            fs::read_to_string(root.join("crates/decoders/src/drift_v2/generated.rs")).unwrap();
+
+        for instruction in &idl.instructions {
+            let header = format!(
+                "impl ToAccountMetas for {}Accounts {{",
+                to_pascal_case(&instruction.name)
+            );
+            let start = module.find(&header).unwrap() + header.len();
+            let metas = &module[start..start + module[start..].find("\n}\n").unwrap()];
+            let metas = metas
+                .lines()
+                .filter(|line| line.contains("AccountMeta::new"))
+                .map(str::trim)
+                .collect::<Vec<_>>();
+
+            let expected = instruction
+                .flat_accounts()
+                .iter()
+                .map(|account| {
+                    let constructor = match account.is_mut {
+                        true => "new",
+                        false => "new_readonly",
+                    };
+                    format!(
+                        "AccountMeta::{}(self.{}, {}),",
+                        constructor,
+                        field_name(&account.name),
+                        account.is_signer
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(metas, expected, "{}", instruction.name);
+        }
+    }
+}
@@ -0,0 +1,29 @@
+mod generate;
+
+use std::{env, fs};
+
+use anyhow::{bail, Result};
+use idl::Idl;
+
+/// Generates a decoder module from an Anchor IDL.
+///
+/// Usage: `codegen <idl.json> <prefix> [output.rs]`, e.g.
+/// `cargo run -p codegen -- idl/drift.json DriftV2 crates/decoders/src/drift_v2/generated.rs`.
+/// Without an output path the module is printed to stdout.
+fn main() -> Result<()> {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if args.len() < 2 || args.len() > 3 {
+        bail!("Usage: codegen <idl.json> <prefix> [output.rs]");
+    }
+
+    let idl = Idl::from_file(&args[0])?;
+    let command = format!("cargo run -p codegen -- {} {}", args[0], args[1]);
+    let module = generate::format(&generate::generate(&idl, &args[1], &command)?)?;
+
+    match args.get(2) {
+        Some(output) => fs::write(output, module)?,
+        None => print!("{}", module),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,284 @@
+use analytics::{
+    candles::CandleAggregator,
+    costs::TransactionCosts,
+    funding::FundingTracker,
+    liquidations::{LiquidationMonitor, LiquidationReport},
+    markets::{event_markets, instruction_markets, MarketRegistry},
+    orders::OrderTracker,
+    positions::PositionTracker,
+};
+use anyhow::Result;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use decoders::{
+    compute_budget::{self, ComputeBudgetInstruction},
+    drift_v2::{
+        self,
+        pda::{self, event_users, PdaIndex},
+        versions::VersionedDecoder,
+        DriftV2Event, DriftV2Instruction, OrderActionRecord,
+    },
+    system_program::{self, SystemInstruction},
+};
+use models::{Candle, MarketKind, ReadOnlyTransaction, SinkRecord};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::broadcast;
+use tracing::{info, warn};
+use utils::{find_all_instructions_by_program_id, find_all_program_data_by_program_id};
+
+/// Most accounts `getMultipleAccounts` returns per request.
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// Bundled markets, replaced by the `PerpMarket`/`SpotMarket` accounts fetched from `rpc_url`.
+/// The bundled markets are kept when the RPC fails.
+pub async fn load_markets(rpc_url: &str) -> Result<MarketRegistry> {
+    let mut markets = MarketRegistry::bundled()?;
+    let market_addresses = markets
+        .markets()
+        .map(|market| match market.market_kind {
+            MarketKind::Perp => pda::perp_market(market.market_index),
+            MarketKind::Spot => pda::spot_market(market.market_index),
+        })
+        .collect::<Vec<_>>();
+    let rpc_client = RpcClient::new(rpc_url.to_string());
+    for addresses in market_addresses.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        match rpc_client.get_multiple_accounts(addresses).await {
+            Ok(accounts) => {
+                for account in accounts.into_iter().flatten() {
+                    if let Err(err) = markets.insert_account(&account.data) {
+                        warn!("Invalid market account: {}", err);
+                    }
+                }
+            }
+            Err(err) => warn!("Failed to fetch market accounts: {}", err),
+        }
+    }
+
+    Ok(markets)
+}
+
+/// Decodes Drift events and instructions and feeds them to the analytics.
+pub struct Drift {
+    candles: CandleAggregator,
+    funding: FundingTracker,
+    liquidations: LiquidationMonitor,
+    orders: OrderTracker,
+    positions: PositionTracker,
+    pda_index: PdaIndex,
+    markets: MarketRegistry,
+    decoder: VersionedDecoder,
+}
+
+impl Drift {
+    pub fn new(
+        mut candles: CandleAggregator,
+        markets: MarketRegistry,
+        decoder: VersionedDecoder,
+    ) -> Self {
+        let mut liquidations = LiquidationMonitor::new();
+        let mut pda_index = PdaIndex::new();
+        let market_count = |market_kind| {
+            markets
+                .markets()
+                .filter(|market| market.market_kind == market_kind)
+                .map(|market| market.market_index + 1)
+                .max()
+                .unwrap_or_default()
+        };
+        pda_index.index_markets(
+            market_count(MarketKind::Perp),
+            market_count(MarketKind::Spot),
+        );
+        for market in markets.markets() {
+            if market.market_kind == MarketKind::Spot {
+                candles.set_spot_decimals(market.market_index, market.decimals);
+                liquidations.set_spot_decimals(market.market_index, market.decimals);
+            }
+            liquidations.set_market_metadata(
+                market.market_kind,
+                market.market_index,
+                market.metadata(),
+            );
+        }
+
+        Self {
+            candles,
+            funding: FundingTracker::new(),
+            liquidations,
+            orders: OrderTracker::new(),
+            positions: PositionTracker::new(),
+            pda_index,
+            markets,
+            decoder,
+        }
+    }
+
+    pub fn subscribe_liquidations(&self) -> broadcast::Receiver<LiquidationReport> {
+        self.liquidations.subscribe()
+    }
+
+    /// Handles the instructions and events of a transaction, returning its `OrderActionRecord`s.
+    /// Instructions go first so cancels and modifies reach their orders before the actions that
+    /// close them.
+    pub fn handle(&mut self, tx: &ReadOnlyTransaction) -> Vec<OrderActionRecord> {
+        self.handle_instructions(tx);
+        self.handle_events(tx)
+    }
+
+    fn handle_events(&mut self, tx: &ReadOnlyTransaction) -> Vec<OrderActionRecord> {
+        let mut order_actions = Vec::new();
+        for event in
+            find_all_program_data_by_program_id(&tx.log_messages, &drift_v2::ID.to_string())
+        {
+            let data = STANDARD.decode(&event).unwrap_or_default();
+            let event = match DriftV2Event::unpack(&data) {
+                Ok(event) => event,
+                Err(_) => {
+                    warn!("Unknown event data: {:?}: {:?}", tx.signature, event);
+                    continue;
+                }
+            };
+            info!(
+                "Signature {:?} has event: {:?} on markets: {:?} for users: {:?}",
+                tx.signature,
+                event,
+                self.markets.describe(&event_markets(&event)),
+                self.pda_index.describe(&event_users(&event))
+            );
+            self.liquidations.apply(tx.slot, &event);
+            self.orders.apply_event(tx.slot, &event);
+            self.positions.apply(&event);
+            match &event {
+                DriftV2Event::OrderActionRecord(record) => {
+                    self.candles.ingest(tx.slot, record);
+                    order_actions.push(record.clone());
+                }
+                DriftV2Event::FundingRateRecord(record) => self.funding.ingest_rate(record),
+                DriftV2Event::FundingPaymentRecord(record) => self.funding.ingest_payment(record),
+                _ => {}
+            }
+        }
+
+        order_actions
+    }
+
+    fn handle_instructions(&mut self, tx: &ReadOnlyTransaction) {
+        let (costs, instruction_count) = transaction_costs(tx);
+        for instruction in
+            find_all_instructions_by_program_id(tx.instructions.clone(), &drift_v2::ID.to_string())
+        {
+            let decoded = match bs58::decode(&instruction.data)
+                .into_vec()
+                .map(|data| self.decoder.unpack_instruction(tx.slot, &data))
+            {
+                Ok(Ok(decoded)) => decoded,
+                _ => {
+                    warn!(
+                        "Unknown instruction data: {:?}: {:?}",
+                        tx.signature, instruction.data
+                    );
+                    continue;
+                }
+            };
+            let accounts = instruction
+                .accounts
+                .iter()
+                .map(|account| account.parse().ok())
+                .collect::<Option<Vec<Pubkey>>>()
+                .unwrap_or_else(|| {
+                    warn!(
+                        "Invalid instruction accounts: {:?}: {:?}",
+                        tx.signature, instruction.accounts
+                    );
+                    Vec::new()
+                });
+            self.pda_index.observe(&decoded, &accounts);
+            info!(
+                "Signature {:?} has instruction: {:?} on markets: {:?} with accounts: {:?}",
+                tx.signature,
+                decoded,
+                self.markets.describe(&instruction_markets(&decoded)),
+                self.pda_index.describe(&accounts)
+            );
+            // Cancel and modify instructions take the user account second.
+            if let Some(user) = accounts.get(1) {
+                self.orders.apply_instruction(*user, &decoded);
+            }
+            if let DriftV2Instruction::InitializeUser(_) = decoded {
+                info!(
+                    "Signature {:?} has InitializeUser costs: {:?} with priority fee: {}",
+                    tx.signature,
+                    costs,
+                    costs.priority_fee(instruction_count)
+                );
+            }
+        }
+    }
+
+    /// Sink records of the bars, funding and orders that are final.
+    pub fn drain_records(&mut self) -> Vec<SinkRecord> {
+        let candles = self.candles.drain_finalized();
+        self.enrich(candles)
+    }
+
+    /// Sink records of every bar, closed or not, and of the remaining funding and closed orders.
+    pub fn flush(&mut self) -> Vec<SinkRecord> {
+        let candles = self.candles.flush();
+        self.enrich(candles)
+    }
+
+    fn enrich(&mut self, candles: Vec<Candle>) -> Vec<SinkRecord> {
+        candles
+            .into_iter()
+            .map(SinkRecord::Candle)
+            .chain(self.funding.drain_records())
+            .chain(self.orders.drain_records())
+            .map(|mut record| {
+                self.markets.enrich(&mut record);
+                record
+            })
+            .collect()
+    }
+}
+
+/// Costs of a transaction and the number of its top-level instructions that are not compute
+/// budget instructions. System instructions the node parsed are read from their JSON.
+fn transaction_costs(tx: &ReadOnlyTransaction) -> (TransactionCosts, u32) {
+    let mut costs = TransactionCosts::new();
+    let mut instruction_count = 0;
+    let compute_budget = compute_budget::ID.to_string();
+    for instruction in &tx.instructions {
+        if instruction.program_id != compute_budget {
+            instruction_count += 1;
+            continue;
+        }
+        let data = bs58::decode(&instruction.data)
+            .into_vec()
+            .unwrap_or_default();
+        match ComputeBudgetInstruction::unpack(&data) {
+            Ok(decoded) => costs.ingest_compute_budget(&decoded),
+            Err(_) => warn!(
+                "Unknown instruction data: {:?}: {:?}",
+                tx.signature, instruction.data
+            ),
+        }
+    }
+    for instruction in find_all_instructions_by_program_id(
+        tx.instructions.clone(),
+        &system_program::ID.to_string(),
+    ) {
+        let Ok(data) = bs58::decode(&instruction.data).into_vec() else {
+            costs.ingest_parsed_system(&instruction.data);
+            continue;
+        };
+        match SystemInstruction::unpack(&data) {
+            Ok(decoded) => costs.ingest_system(&decoded),
+            Err(_) => warn!(
+                "Unknown instruction data: {:?}: {:?}",
+                tx.signature, instruction.data
+            ),
+        }
+    }
+
+    (costs, instruction_count)
+}
@@ -0,0 +1,379 @@
+use std::{collections::HashMap, fmt::Debug};
+
+use analytics::phoenix::match_phoenix_fills;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use decoders::{
+    associated_token::{self, AssociatedTokenInstruction},
+    drift_v2::OrderActionRecord,
+    jupiter_v6::{self, JupiterV6Event, JupiterV6Instruction},
+    kamino_lending::{self, KaminoLendingInstruction},
+    marginfi_v2::{self, MarginfiV2Event, MarginfiV2Instruction},
+    openbook_v2::{self, OpenbookV2Event, OpenbookV2Instruction},
+    phoenix_v1::{self, PhoenixLog, PhoenixV1Instruction},
+    raydium_amm_v4::{self, RayLog, RaydiumAmmV4Instruction, RAY_LOG_PREFIX},
+    raydium_clmm::{self, RaydiumClmmEvent, RaydiumClmmInstruction},
+    serum_v3::{self, SerumV3Instruction},
+    spl_token::{self, SplTokenInstruction},
+    whirlpool::{self, SwapAmounts, WhirlpoolInstruction},
+};
+use idl::decoder::IdlDecoder;
+use models::{ReadOnlyInstruction, ReadOnlyTransaction};
+use solana_sdk::{program_error::ProgramError, pubkey::Pubkey};
+use tracing::{info, warn};
+use utils::{
+    find_all_instructions_by_program_id, find_all_program_data_by_program_id,
+    find_all_program_logs_by_program_id, find_all_token_transfer_amounts,
+};
+
+type Unpack<T> = fn(&[u8]) -> Result<T, ProgramError>;
+
+/// Decodes and logs what one program did in a transaction.
+pub trait ProgramHandler {
+    fn program_id(&self) -> Pubkey;
+
+    /// `order_actions` are the Drift `OrderActionRecord`s of the same transaction.
+    fn handle(&mut self, tx: &ReadOnlyTransaction, order_actions: &[OrderActionRecord]);
+}
+
+/// Handlers of every program the scraper decodes besides Drift, keyed on their program id.
+pub struct Handlers {
+    handlers: HashMap<Pubkey, Box<dyn ProgramHandler>>,
+}
+
+impl Handlers {
+    pub fn new(idl_decoders: Vec<IdlDecoder>) -> anyhow::Result<Self> {
+        let mut handlers: Vec<Box<dyn ProgramHandler>> = vec![
+            Box::new(Jupiter),
+            Box::new(RaydiumAmm),
+            Box::new(Logged::with_events(
+                raydium_clmm::ID,
+                RaydiumClmmInstruction::unpack,
+                RaydiumClmmEvent::unpack,
+            )),
+            Box::new(Whirlpool),
+            Box::new(Phoenix),
+            Box::new(Logged::new(serum_v3::ID, SerumV3Instruction::unpack)),
+            // OpenBook v1 is a fork of Serum v3 with the same instructions.
+            Box::new(Logged::new(
+                serum_v3::OPENBOOK_V1_ID,
+                SerumV3Instruction::unpack,
+            )),
+            Box::new(Logged::with_events(
+                openbook_v2::ID,
+                OpenbookV2Instruction::unpack,
+                OpenbookV2Event::unpack,
+            )),
+            Box::new(Logged::new(spl_token::ID, SplTokenInstruction::unpack)),
+            Box::new(Logged::new(
+                spl_token::TOKEN_2022_ID,
+                SplTokenInstruction::unpack,
+            )),
+            Box::new(Logged::with_events(
+                marginfi_v2::ID,
+                MarginfiV2Instruction::unpack,
+                MarginfiV2Event::unpack,
+            )),
+            Box::new(Logged::new(
+                kamino_lending::ID,
+                KaminoLendingInstruction::unpack,
+            )),
+            Box::new(Logged::new(
+                associated_token::ID,
+                AssociatedTokenInstruction::unpack,
+            )),
+        ];
+        for idl_decoder in idl_decoders {
+            handlers.push(Box::new(Idl {
+                program_id: idl_decoder.program_id().parse()?,
+                decoder: idl_decoder,
+            }));
+        }
+
+        Ok(Self {
+            handlers: handlers
+                .into_iter()
+                .map(|handler| (handler.program_id(), handler))
+                .collect(),
+        })
+    }
+
+    /// Runs the handler of every program the transaction references, in account order.
+    pub fn handle(&mut self, tx: &ReadOnlyTransaction, order_actions: &[OrderActionRecord]) {
+        let mut handled = Vec::new();
+        for account in &tx.accounts {
+            let Ok(program_id) = account.parse::<Pubkey>() else {
+                continue;
+            };
+            if handled.contains(&program_id) {
+                continue;
+            }
+            if let Some(handler) = self.handlers.get_mut(&program_id) {
+                handler.handle(tx, order_actions);
+                handled.push(program_id);
+            }
+        }
+    }
+}
+
+/// Decodes every instruction of `program_id`, returning those that decoded along with their
+/// decoded form. Instructions the node parsed are JSON rather than base58 and skipped.
+fn decode_instructions<T: Debug>(
+    tx: &ReadOnlyTransaction,
+    program_id: &Pubkey,
+    unpack: Unpack<T>,
+) -> Vec<(ReadOnlyInstruction, T)> {
+    let mut decoded = Vec::new();
+    for instruction in
+        find_all_instructions_by_program_id(tx.instructions.clone(), &program_id.to_string())
+    {
+        let Ok(data) = bs58::decode(&instruction.data).into_vec() else {
+            continue;
+        };
+        match unpack(&data) {
+            Ok(value) => decoded.push((instruction, value)),
+            Err(_) => warn!(
+                "Unknown instruction data: {:?}: {:?}",
+                tx.signature, instruction.data
+            ),
+        }
+    }
+
+    decoded
+}
+
+/// Instructions of `program_id` the node parsed, whose data is the parsed JSON rather than base58.
+fn parsed_instructions(tx: &ReadOnlyTransaction, program_id: &Pubkey) -> Vec<ReadOnlyInstruction> {
+    find_all_instructions_by_program_id(tx.instructions.clone(), &program_id.to_string())
+        .into_iter()
+        .filter(|instruction| bs58::decode(&instruction.data).into_vec().is_err())
+        .collect()
+}
+
+/// Decodes and logs the `Program data:` events `program_id` emitted.
+fn log_events<T: Debug>(tx: &ReadOnlyTransaction, program_id: &Pubkey, unpack: Unpack<T>) {
+    for event in find_all_program_data_by_program_id(&tx.log_messages, &program_id.to_string()) {
+        let data = STANDARD.decode(&event).unwrap_or_default();
+        match unpack(&data) {
+            Ok(decoded) => info!("Signature {:?} has event: {:?}", tx.signature, decoded),
+            Err(_) => warn!("Unknown event data: {:?}: {:?}", tx.signature, event),
+        }
+    }
+}
+
+/// Logs the instructions and, for programs emitting any, the events of a program. Instructions the
+/// node parsed, such as those of the token programs, are logged as their parsed JSON.
+struct Logged<I, E> {
+    program_id: Pubkey,
+    unpack_instruction: Unpack<I>,
+    unpack_event: Option<Unpack<E>>,
+}
+
+impl<I> Logged<I, ()> {
+    fn new(program_id: Pubkey, unpack_instruction: Unpack<I>) -> Self {
+        Self {
+            program_id,
+            unpack_instruction,
+            unpack_event: None,
+        }
+    }
+}
+
+impl<I, E> Logged<I, E> {
+    fn with_events(
+        program_id: Pubkey,
+        unpack_instruction: Unpack<I>,
+        unpack_event: Unpack<E>,
+    ) -> Self {
+        Self {
+            program_id,
+            unpack_instruction,
+            unpack_event: Some(unpack_event),
+        }
+    }
+}
+
+impl<I: Debug, E: Debug> ProgramHandler for Logged<I, E> {
+    fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    fn handle(&mut self, tx: &ReadOnlyTransaction, _: &[OrderActionRecord]) {
+        for (_, decoded) in decode_instructions(tx, &self.program_id, self.unpack_instruction) {
+            info!(
+                "Signature {:?} has instruction: {:?}",
+                tx.signature, decoded
+            );
+        }
+        for instruction in parsed_instructions(tx, &self.program_id) {
+            info!(
+                "Signature {:?} has parsed instruction: {}",
+                tx.signature, instruction.data
+            );
+        }
+        if let Some(unpack_event) = self.unpack_event {
+            log_events(tx, &self.program_id, unpack_event);
+        }
+    }
+}
+
+/// Logs Jupiter routes with the `SwapEvent` each hop emits through a self-CPI.
+struct Jupiter;
+
+impl ProgramHandler for Jupiter {
+    fn program_id(&self) -> Pubkey {
+        jupiter_v6::ID
+    }
+
+    fn handle(&mut self, tx: &ReadOnlyTransaction, _: &[OrderActionRecord]) {
+        for (instruction, decoded) in
+            decode_instructions(tx, &jupiter_v6::ID, JupiterV6Instruction::unpack)
+        {
+            info!(
+                "Signature {:?} has instruction: {:?}",
+                tx.signature, decoded
+            );
+            for event in find_all_instructions_by_program_id(
+                instruction.inner_instructions,
+                &jupiter_v6::ID.to_string(),
+            ) {
+                let data = bs58::decode(&event.data).into_vec().unwrap_or_default();
+                match JupiterV6Event::unpack_cpi(&data) {
+                    Ok(event) => info!("Signature {:?} has event: {:?}", tx.signature, event),
+                    Err(_) => warn!("Unknown event data: {:?}: {:?}", tx.signature, event.data),
+                }
+            }
+        }
+    }
+}
+
+/// Logs Raydium AMM v4 instructions with the `ray_log` holding their amounts.
+struct RaydiumAmm;
+
+impl ProgramHandler for RaydiumAmm {
+    fn program_id(&self) -> Pubkey {
+        raydium_amm_v4::ID
+    }
+
+    fn handle(&mut self, tx: &ReadOnlyTransaction, _: &[OrderActionRecord]) {
+        // Raydium writes one `ray_log` per swap, deposit or withdrawal in execution order.
+        let mut ray_logs =
+            find_all_program_logs_by_program_id(&tx.log_messages, &raydium_amm_v4::ID.to_string())
+                .into_iter()
+                .filter_map(|log| log.strip_prefix(RAY_LOG_PREFIX).map(|log| log.to_string()));
+        for (_, decoded) in
+            decode_instructions(tx, &raydium_amm_v4::ID, RaydiumAmmV4Instruction::unpack)
+        {
+            let ray_log = match decoded.writes_ray_log() {
+                true => ray_logs.next().and_then(|log| {
+                    let data = STANDARD.decode(log).unwrap_or_default();
+                    RayLog::unpack(&data).ok()
+                }),
+                false => None,
+            };
+            info!(
+                "Signature {:?} has instruction: {:?} with log: {:?}",
+                tx.signature, decoded, ray_log
+            );
+        }
+    }
+}
+
+/// Logs Whirlpool instructions with the amounts of their swaps.
+struct Whirlpool;
+
+impl ProgramHandler for Whirlpool {
+    fn program_id(&self) -> Pubkey {
+        whirlpool::ID
+    }
+
+    fn handle(&mut self, tx: &ReadOnlyTransaction, _: &[OrderActionRecord]) {
+        for (instruction, decoded) in
+            decode_instructions(tx, &whirlpool::ID, WhirlpoolInstruction::unpack)
+        {
+            // Whirlpool emits no events, swapped amounts come from the token transfers of the
+            // swap.
+            let amounts = match decoded.is_swap() {
+                true => SwapAmounts::from_transfers(&find_all_token_transfer_amounts(&instruction)),
+                false => None,
+            };
+            info!(
+                "Signature {:?} has instruction: {:?} with amounts: {:?}",
+                tx.signature, decoded, amounts
+            );
+        }
+    }
+}
+
+/// Logs Phoenix instructions with the market events of their `Log` self-CPIs, and matches Drift
+/// fills routed to Phoenix with them.
+struct Phoenix;
+
+impl ProgramHandler for Phoenix {
+    fn program_id(&self) -> Pubkey {
+        phoenix_v1::ID
+    }
+
+    fn handle(&mut self, tx: &ReadOnlyTransaction, order_actions: &[OrderActionRecord]) {
+        let mut phoenix_logs = Vec::new();
+        for (instruction, decoded) in
+            decode_instructions(tx, &phoenix_v1::ID, PhoenixV1Instruction::unpack)
+        {
+            info!(
+                "Signature {:?} has instruction: {:?}",
+                tx.signature, decoded
+            );
+            for log in find_all_instructions_by_program_id(
+                instruction.inner_instructions,
+                &phoenix_v1::ID.to_string(),
+            ) {
+                let data = bs58::decode(&log.data).into_vec().unwrap_or_default();
+                match PhoenixLog::unpack(&data) {
+                    Ok(log) => {
+                        info!("Signature {:?} has event: {:?}", tx.signature, log);
+                        phoenix_logs.push(log);
+                    }
+                    Err(_) => warn!("Unknown event data: {:?}: {:?}", tx.signature, log.data),
+                }
+            }
+        }
+        for fill in match_phoenix_fills(order_actions, &phoenix_logs) {
+            info!("Signature {:?} has Phoenix fill: {:?}", tx.signature, fill);
+        }
+    }
+}
+
+/// Logs the instructions and events of a program configured with its IDL.
+struct Idl {
+    program_id: Pubkey,
+    decoder: IdlDecoder,
+}
+
+impl ProgramHandler for Idl {
+    fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    fn handle(&mut self, tx: &ReadOnlyTransaction, _: &[OrderActionRecord]) {
+        let program_id = self.decoder.program_id();
+        for event in find_all_program_data_by_program_id(&tx.log_messages, program_id) {
+            let data = STANDARD.decode(&event).unwrap_or_default();
+            match self.decoder.decode_event(&data) {
+                Ok(event) => info!("Signature {:?} has event: {}", tx.signature, event),
+                Err(err) => warn!(
+                    "Unknown event data: {:?}: {:?}: {}",
+                    tx.signature, event, err
+                ),
+            }
+        }
+        for instruction in find_all_instructions_by_program_id(tx.instructions.clone(), program_id)
+        {
+            match self.decoder.decode_instruction(&instruction) {
+                Ok(decoded) => info!("Signature {:?} has instruction: {}", tx.signature, decoded),
+                Err(err) => warn!(
+                    "Unknown instruction data: {:?}: {:?}: {}",
+                    tx.signature, instruction.data, err
+                ),
+            }
+        }
+    }
+}
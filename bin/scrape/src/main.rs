@@ -1,7 +1,17 @@
+mod drift;
+mod handlers;
+
+use analytics::candles::CandleAggregator;
 use anyhow::Result;
-use decoders::drift_v2::DriftV2Instruction;
+use decoders::drift_v2::{
+    self,
+    versions::{Upgrade, VersionedDecoder},
+};
+use drift::Drift;
 use extractors::EncodedTransactionExtractor;
 use futures::StreamExt;
+use handlers::Handlers;
+use idl::decoder::IdlDecoder;
 use settings::Settings;
 use solana_client::{
     nonblocking::pubsub_client::PubsubClient,
@@ -9,11 +19,8 @@ use solana_client::{
 };
 use solana_sdk::commitment_config::CommitmentConfig;
 use solana_transaction_status::{TransactionDetails, UiTransactionEncoding};
-use tracing::{info, warn};
+use tracing::warn;
 use tracing_subscriber::EnvFilter;
-use utils::find_all_instructions_by_program_id;
-
-pub const DRIFT_V2: &str = "dRiftyHA39MWEi3m9aunc5MzRF1JYuBsbn6VPcn33UH";
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -33,10 +40,48 @@ async fn main() -> Result<()> {
         .with_writer(non_blocking)
         .init();
 
+    let mut sink = sinks::from_settings(&settings.sink)?;
+    let candles = CandleAggregator::new(
+        &settings.candles.resolutions,
+        settings.candles.allowed_lateness,
+    );
+    let upgrades = settings
+        .drift
+        .upgrades
+        .iter()
+        .map(|upgrade| {
+            Ok(Upgrade {
+                slot: upgrade.slot,
+                revision: upgrade.revision.parse()?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let markets = drift::load_markets(&settings.solana.rpc).await?;
+    let mut drift = Drift::new(candles, markets, VersionedDecoder::new(upgrades));
+    let mut liquidation_reports = drift.subscribe_liquidations();
+    tokio::spawn(async move {
+        while let Ok(report) = liquidation_reports.recv().await {
+            warn!("Liquidation: {:?}", report);
+        }
+    });
+
+    let idl_decoders = settings
+        .idl
+        .programs
+        .iter()
+        .map(|program| IdlDecoder::from_file(&program.program_id, &program.path))
+        .collect::<Result<Vec<_>>>()?;
+    // Blocks only mentioning the IDL programs would be missed by the Drift filter.
+    let filter = match idl_decoders.is_empty() {
+        true => RpcBlockSubscribeFilter::MentionsAccountOrProgram(drift_v2::ID.to_string()),
+        false => RpcBlockSubscribeFilter::All,
+    };
+    let mut handlers = Handlers::new(idl_decoders)?;
+
     let pubsub_client = PubsubClient::new(&settings.solana.wss.clone()).await?;
     if let Ok((mut stream, _)) = pubsub_client
         .block_subscribe(
-            RpcBlockSubscribeFilter::MentionsAccountOrProgram(DRIFT_V2.to_string()),
+            filter,
             Some(RpcBlockSubscribeConfig {
                 commitment: Some(CommitmentConfig::confirmed()),
                 encoding: Some(UiTransactionEncoding::JsonParsed),
@@ -65,38 +110,29 @@ async fn main() -> Result<()> {
 
                         let mut extractor = EncodedTransactionExtractor::new(
                             &settings.solana.rpc.clone(),
+                            res.value.slot,
                             block_time,
                             &transaction,
                         );
-                        if let Some(signature) = extractor.extract_signature() {
-                            if let Some(tx) = extractor.parse_readonly_transaction().await {
-                                let instructions =
-                                    find_all_instructions_by_program_id(tx.instructions, DRIFT_V2);
-                                for instruction in instructions {
-                                    let data =
-                                        bs58::decode(instruction.data.clone()).into_vec().unwrap();
-                                    match DriftV2Instruction::unpack(&data) {
-                                        Ok(instruction) => {
-                                            info!(
-                                                "Signature {:?} has instruction: {:?}",
-                                                signature, instruction
-                                            );
-                                        }
-                                        Err(_) => {
-                                            warn!(
-                                                "Unknown instruction data: {:?}: {:?}",
-                                                signature, instruction.data
-                                            );
-                                        }
-                                    }
-                                }
-                            }
+                        if let Some(tx) = extractor.parse_readonly_transaction().await {
+                            let order_actions = drift.handle(&tx);
+                            handlers.handle(&tx, &order_actions);
                         }
                     }
                 }
+
+                for record in drift.drain_records() {
+                    sink.write(&record)?;
+                }
+                sink.flush()?;
             }
         }
     }
 
+    for record in drift.flush() {
+        sink.write(&record)?;
+    }
+    sink.flush()?;
+
     Ok(())
 }
@@ -0,0 +1,432 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use decoders::drift_v2::{OrderAction, OrderActionRecord};
+use models::{Candle, MarketKind, Resolution};
+
+use crate::{market_kind, BASE_PRECISION, DEFAULT_SPOT_DECIMALS, PRICE_PRECISION, QUOTE_PRECISION};
+
+/// A single fill taken from an `OrderActionRecord`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fill {
+    pub slot: u64,
+    pub ts: i64,
+    pub market_kind: MarketKind,
+    pub market_index: u16,
+    pub fill_record_id: u64,
+    pub base_asset_amount: u64,
+    pub quote_asset_amount: u64,
+}
+
+impl Fill {
+    /// Returns `None` for every action other than a fill with non zero amounts and a fill record
+    /// id, which bars dedupe fills by.
+    pub fn from_record(slot: u64, record: &OrderActionRecord) -> Option<Self> {
+        if record.action != OrderAction::Fill {
+            return None;
+        }
+
+        let base_asset_amount = record
+            .base_asset_amount_filled
+            .filter(|amount| *amount > 0)?;
+        let quote_asset_amount = record
+            .quote_asset_amount_filled
+            .filter(|amount| *amount > 0)?;
+
+        Some(Self {
+            slot,
+            ts: record.ts,
+            market_kind: market_kind(&record.market_type),
+            market_index: record.market_index,
+            fill_record_id: record.fill_record_id?,
+            base_asset_amount,
+            quote_asset_amount,
+        })
+    }
+}
+
+/// Bars are ordered by close time first so finalized bars can be drained from the front.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct BarKey {
+    close_time: i64,
+    market_kind: MarketKind,
+    market_index: u16,
+    resolution: Resolution,
+}
+
+#[derive(Clone, Debug)]
+struct Bar {
+    /// (slot, fill_record_id, price) of the earliest and latest fill seen so far
+    open: (u64, u64, u64),
+    close: (u64, u64, u64),
+    high: u64,
+    low: u64,
+    volume_base: u128,
+    volume_quote: u128,
+    fill_record_ids: HashSet<u64>,
+}
+
+/// Aggregates Drift fills into OHLCV bars per market and resolution.
+///
+/// Bars are keyed by block time. A bar is finalized once the highest block time seen is past its
+/// close time plus the allowed lateness, so fills arriving out of slot order within that window
+/// still land in the right bar. Fills for bars that were already finalized are dropped, and every
+/// fill dropped from at least one resolution is counted once in `late_fills`. Fills whose price
+/// doesn't fit in a `u64` are dropped and counted in `unpriced_fills`.
+pub struct CandleAggregator {
+    resolutions: Vec<Resolution>,
+    allowed_lateness: i64,
+    spot_decimals: HashMap<u16, u32>,
+    bars: BTreeMap<BarKey, Bar>,
+    watermark: i64,
+    late_fills: u64,
+    unpriced_fills: u64,
+}
+
+impl CandleAggregator {
+    pub fn new(resolutions: &[Resolution], allowed_lateness: i64) -> Self {
+        Self {
+            resolutions: resolutions.to_vec(),
+            allowed_lateness,
+            spot_decimals: HashMap::new(),
+            bars: BTreeMap::new(),
+            watermark: i64::MIN,
+            late_fills: 0,
+            unpriced_fills: 0,
+        }
+    }
+
+    /// Registers the token decimals of a spot market, used to price its fills.
+    pub fn set_spot_decimals(&mut self, market_index: u16, decimals: u32) {
+        self.spot_decimals.insert(market_index, decimals);
+    }
+
+    pub fn late_fills(&self) -> u64 {
+        self.late_fills
+    }
+
+    pub fn unpriced_fills(&self) -> u64 {
+        self.unpriced_fills
+    }
+
+    pub fn ingest(&mut self, slot: u64, record: &OrderActionRecord) {
+        if let Some(fill) = Fill::from_record(slot, record) {
+            self.ingest_fill(&fill);
+        }
+    }
+
+    pub fn ingest_fill(&mut self, fill: &Fill) {
+        self.watermark = self.watermark.max(fill.ts);
+
+        let base_precision = self.base_precision(fill.market_kind, fill.market_index);
+        let Some(price) = price(
+            fill.quote_asset_amount as u128,
+            fill.base_asset_amount as u128,
+            base_precision,
+        ) else {
+            self.unpriced_fills += 1;
+            return;
+        };
+        let position = (fill.slot, fill.fill_record_id, price);
+
+        let mut late = false;
+        for resolution in self.resolutions.clone() {
+            let close_time = resolution.open_time(fill.ts) + resolution.seconds();
+            if self.is_finalized(close_time) {
+                late = true;
+                continue;
+            }
+
+            let key = BarKey {
+                close_time,
+                market_kind: fill.market_kind,
+                market_index: fill.market_index,
+                resolution,
+            };
+            let bar = self.bars.entry(key).or_insert_with(|| Bar {
+                open: position,
+                close: position,
+                high: price,
+                low: price,
+                volume_base: 0,
+                volume_quote: 0,
+                fill_record_ids: HashSet::new(),
+            });
+
+            if !bar.fill_record_ids.insert(fill.fill_record_id) {
+                continue;
+            }
+
+            if position < bar.open {
+                bar.open = position;
+            }
+            if position > bar.close {
+                bar.close = position;
+            }
+            bar.high = bar.high.max(price);
+            bar.low = bar.low.min(price);
+            bar.volume_base += fill.base_asset_amount as u128;
+            bar.volume_quote += fill.quote_asset_amount as u128;
+        }
+
+        if late {
+            self.late_fills += 1;
+        }
+    }
+
+    /// Removes and returns every bar that can no longer receive fills.
+    pub fn drain_finalized(&mut self) -> Vec<Candle> {
+        let mut finalized = Vec::new();
+
+        while let Some((key, _)) = self.bars.first_key_value() {
+            if !self.is_finalized(key.close_time) {
+                break;
+            }
+            if let Some(entry) = self.bars.pop_first() {
+                finalized.push(entry);
+            }
+        }
+
+        finalized
+            .iter()
+            .map(|(key, bar)| self.to_candle(key, bar))
+            .collect()
+    }
+
+    /// Removes and returns every bar, finalized or not. Used on shutdown.
+    pub fn flush(&mut self) -> Vec<Candle> {
+        let bars = std::mem::take(&mut self.bars);
+
+        bars.iter()
+            .map(|(key, bar)| self.to_candle(key, bar))
+            .collect()
+    }
+
+    fn is_finalized(&self, close_time: i64) -> bool {
+        close_time.saturating_add(self.allowed_lateness) <= self.watermark
+    }
+
+    fn base_precision(&self, market_kind: MarketKind, market_index: u16) -> u128 {
+        match market_kind {
+            MarketKind::Perp => BASE_PRECISION,
+            MarketKind::Spot => {
+                let decimals = self
+                    .spot_decimals
+                    .get(&market_index)
+                    .copied()
+                    .unwrap_or(DEFAULT_SPOT_DECIMALS);
+                10u128.pow(decimals)
+            }
+        }
+    }
+
+    fn to_candle(&self, key: &BarKey, bar: &Bar) -> Candle {
+        let base_precision = self.base_precision(key.market_kind, key.market_index);
+
+        Candle {
+            market_kind: key.market_kind,
+            market_index: key.market_index,
+            market: None,
+            resolution: key.resolution,
+            open_time: key.close_time - key.resolution.seconds(),
+            close_time: key.close_time,
+            open: bar.open.2,
+            high: bar.high,
+            low: bar.low,
+            close: bar.close.2,
+            volume_base: bar.volume_base,
+            volume_quote: bar.volume_quote,
+            trade_count: bar.fill_record_ids.len() as u64,
+            vwap: price(bar.volume_quote, bar.volume_base, base_precision).unwrap_or_default(),
+            first_slot: bar.open.0,
+            last_slot: bar.close.0,
+        }
+    }
+}
+
+/// Price in PRICE_PRECISION of `quote` QUOTE_PRECISION units paid for `base` base units, `None`
+/// when it overflows.
+fn price(quote: u128, base: u128, base_precision: u128) -> Option<u64> {
+    if base == 0 {
+        return Some(0);
+    }
+
+    let numerator = quote
+        .checked_mul(PRICE_PRECISION)?
+        .checked_mul(base_precision)?;
+    u64::try_from(numerator / QUOTE_PRECISION.checked_mul(base)?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use decoders::drift_v2::{MarketType, OrderActionExplanation};
+
+    use super::*;
+
+    /// Perp fill of `base` whole units at `price` whole dollars.
+    fn fill(ts: i64, fill_record_id: u64, base: u64, price: u64) -> Fill {
+        Fill {
+            slot: ts as u64,
+            ts,
+            market_kind: MarketKind::Perp,
+            market_index: 0,
+            fill_record_id,
+            base_asset_amount: base * BASE_PRECISION as u64,
+            quote_asset_amount: base * price * QUOTE_PRECISION as u64,
+        }
+    }
+
+    fn record(fill_record_id: Option<u64>) -> OrderActionRecord {
+        OrderActionRecord {
+            ts: 0,
+            action: OrderAction::Fill,
+            action_explanation: OrderActionExplanation::None,
+            market_index: 0,
+            market_type: MarketType::Perp,
+            filler: None,
+            filler_reward: None,
+            fill_record_id,
+            base_asset_amount_filled: Some(BASE_PRECISION as u64),
+            quote_asset_amount_filled: Some(100 * QUOTE_PRECISION as u64),
+            taker_fee: None,
+            maker_fee: None,
+            referrer_reward: None,
+            quote_asset_amount_surplus: None,
+            spot_fulfillment_method_fee: None,
+            taker: None,
+            taker_order_id: None,
+            taker_order_direction: None,
+            taker_order_base_asset_amount: None,
+            taker_order_cumulative_base_asset_amount_filled: None,
+            taker_order_cumulative_quote_asset_amount_filled: None,
+            maker: None,
+            maker_order_id: None,
+            maker_order_direction: None,
+            maker_order_base_asset_amount: None,
+            maker_order_cumulative_base_asset_amount_filled: None,
+            maker_order_cumulative_quote_asset_amount_filled: None,
+            oracle_price: 0,
+        }
+    }
+
+    fn dollars(price: u64) -> u64 {
+        price * PRICE_PRECISION as u64
+    }
+
+    #[test]
+    fn fills_land_in_the_bar_of_each_resolution() {
+        let mut aggregator =
+            CandleAggregator::new(&[Resolution::OneMinute, Resolution::OneHour], 0);
+        aggregator.ingest_fill(&fill(0, 1, 1, 100));
+        aggregator.ingest_fill(&fill(30, 2, 1, 110));
+        aggregator.ingest_fill(&fill(61, 3, 1, 90));
+
+        let finalized = aggregator.drain_finalized();
+        assert_eq!(finalized.len(), 1);
+        let minute = &finalized[0];
+        assert_eq!(minute.resolution, Resolution::OneMinute);
+        assert_eq!((minute.open_time, minute.close_time), (0, 60));
+        assert_eq!(minute.open, dollars(100));
+        assert_eq!(minute.high, dollars(110));
+        assert_eq!(minute.low, dollars(100));
+        assert_eq!(minute.close, dollars(110));
+        assert_eq!((minute.first_slot, minute.last_slot), (0, 30));
+        assert_eq!(minute.trade_count, 2);
+
+        let rest = aggregator.flush();
+        assert_eq!(rest.len(), 2);
+        let hour = rest
+            .iter()
+            .find(|candle| candle.resolution == Resolution::OneHour)
+            .unwrap();
+        assert_eq!(
+            (hour.open, hour.close, hour.low),
+            (dollars(100), dollars(90), dollars(90))
+        );
+        assert_eq!(hour.trade_count, 3);
+    }
+
+    #[test]
+    fn vwap_weighs_prices_by_base_volume() {
+        let mut aggregator = CandleAggregator::new(&[Resolution::OneMinute], 0);
+        aggregator.ingest_fill(&fill(0, 1, 1, 100));
+        aggregator.ingest_fill(&fill(1, 2, 3, 120));
+
+        let candle = &aggregator.flush()[0];
+        assert_eq!(candle.vwap, dollars(115));
+        assert_eq!(candle.volume_base, 4 * BASE_PRECISION);
+        assert_eq!(candle.volume_quote, 460 * QUOTE_PRECISION);
+    }
+
+    #[test]
+    fn spot_prices_use_the_registered_decimals() {
+        let mut aggregator = CandleAggregator::new(&[Resolution::OneMinute], 0);
+        aggregator.set_spot_decimals(1, 6);
+        aggregator.ingest_fill(&Fill {
+            market_kind: MarketKind::Spot,
+            market_index: 1,
+            base_asset_amount: 2_000_000,
+            quote_asset_amount: 50 * QUOTE_PRECISION as u64,
+            ..fill(0, 1, 0, 0)
+        });
+
+        assert_eq!(aggregator.flush()[0].close, dollars(25));
+    }
+
+    #[test]
+    fn duplicate_fills_count_once() {
+        let mut aggregator = CandleAggregator::new(&[Resolution::OneMinute], 0);
+        aggregator.ingest_fill(&fill(0, 1, 1, 100));
+        aggregator.ingest_fill(&fill(0, 1, 1, 100));
+        aggregator.ingest_fill(&fill(1, 2, 1, 100));
+
+        let candle = &aggregator.flush()[0];
+        assert_eq!(candle.trade_count, 2);
+        assert_eq!(candle.volume_base, 2 * BASE_PRECISION);
+    }
+
+    #[test]
+    fn fills_without_a_record_id_are_skipped() {
+        assert_eq!(Fill::from_record(7, &record(None)), None);
+        assert_eq!(
+            Fill::from_record(7, &record(Some(4))).map(|fill| (fill.slot, fill.fill_record_id)),
+            Some((7, 4))
+        );
+    }
+
+    #[test]
+    fn fills_with_an_overflowing_price_are_skipped() {
+        let mut aggregator = CandleAggregator::new(&[Resolution::OneMinute], 0);
+        aggregator.ingest_fill(&Fill {
+            base_asset_amount: 1,
+            quote_asset_amount: u64::MAX,
+            ..fill(0, 1, 1, 100)
+        });
+        assert_eq!(aggregator.unpriced_fills(), 1);
+        assert!(aggregator.flush().is_empty());
+
+        assert_eq!(price(u128::MAX, 1, BASE_PRECISION), None);
+    }
+
+    #[test]
+    fn late_fills_are_dropped_and_counted_once() {
+        let mut aggregator =
+            CandleAggregator::new(&[Resolution::OneMinute, Resolution::FiveMinutes], 10);
+        aggregator.ingest_fill(&fill(0, 1, 1, 100));
+        // Within the allowed lateness of the first minute.
+        aggregator.ingest_fill(&fill(65, 2, 1, 100));
+        aggregator.ingest_fill(&fill(30, 3, 1, 100));
+        assert_eq!(aggregator.late_fills(), 0);
+
+        // Past the close of both bars of the fill at 20.
+        aggregator.ingest_fill(&fill(400, 4, 1, 100));
+        aggregator.ingest_fill(&fill(20, 5, 1, 100));
+        assert_eq!(aggregator.late_fills(), 1);
+
+        let finalized = aggregator.drain_finalized();
+        let minute = finalized
+            .iter()
+            .find(|candle| candle.resolution == Resolution::OneMinute && candle.open_time == 0)
+            .unwrap();
+        assert_eq!(minute.trade_count, 2);
+    }
+}
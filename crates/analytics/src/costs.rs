@@ -0,0 +1,144 @@
+use decoders::{
+    compute_budget::{
+        priority_fee, ComputeBudgetInstruction, DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT,
+        MAX_COMPUTE_UNIT_LIMIT,
+    },
+    system_program::SystemInstruction,
+};
+use serde_json::Value;
+
+/// What a transaction spends in SOL besides its base fee: the priority fee it bids, the rent of
+/// the accounts it creates and the lamports it transfers, used to attribute the cost of opening a
+/// Drift account with `InitializeUser`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransactionCosts {
+    pub compute_unit_limit: Option<u32>,
+    /// In micro-lamports per compute unit.
+    pub compute_unit_price: Option<u64>,
+    pub heap_frame: Option<u32>,
+    pub accounts_created: u32,
+    /// Rent exemption of the created accounts, whether created directly or through CPI.
+    pub rent_lamports: u64,
+    pub transferred_lamports: u64,
+}
+
+impl TransactionCosts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ingest_compute_budget(&mut self, instruction: &ComputeBudgetInstruction) {
+        match instruction {
+            ComputeBudgetInstruction::SetComputeUnitLimit(limit) => {
+                self.compute_unit_limit = Some(*limit)
+            }
+            ComputeBudgetInstruction::SetComputeUnitPrice(price) => {
+                self.compute_unit_price = Some(*price)
+            }
+            ComputeBudgetInstruction::RequestHeapFrame(bytes) => self.heap_frame = Some(*bytes),
+            _ => {}
+        }
+    }
+
+    pub fn ingest_system(&mut self, instruction: &SystemInstruction) {
+        match instruction {
+            SystemInstruction::CreateAccount(_) | SystemInstruction::CreateAccountWithSeed(_) => {
+                self.accounts_created += 1;
+                self.rent_lamports += instruction.lamports().unwrap_or_default();
+            }
+            SystemInstruction::Transfer { lamports }
+            | SystemInstruction::TransferWithSeed { lamports, .. } => {
+                self.transferred_lamports += lamports
+            }
+            _ => {}
+        }
+    }
+
+    /// Same as `ingest_system` for a System instruction the node returned parsed, given as its
+    /// `jsonParsed` JSON. Anything else is ignored.
+    pub fn ingest_parsed_system(&mut self, data: &str) {
+        let Ok(parsed) = serde_json::from_str::<Value>(data) else {
+            return;
+        };
+        let lamports = parsed["info"]["lamports"].as_u64().unwrap_or_default();
+        match parsed["type"].as_str() {
+            Some("createAccount" | "createAccountWithSeed") => {
+                self.accounts_created += 1;
+                self.rent_lamports += lamports;
+            }
+            Some("transfer" | "transferWithSeed") => self.transferred_lamports += lamports,
+            _ => {}
+        }
+    }
+
+    /// Priority fee in lamports. Without an explicit limit each of the `instruction_count`
+    /// top-level instructions that aren't compute budget ones gets the default limit.
+    pub fn priority_fee(&self, instruction_count: u32) -> u64 {
+        let limit = self.compute_unit_limit.unwrap_or_else(|| {
+            instruction_count.saturating_mul(DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT)
+        });
+        priority_fee(
+            limit.min(MAX_COMPUTE_UNIT_LIMIT),
+            self.compute_unit_price.unwrap_or_default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use decoders::system_program::CreateAccount;
+    use solana_program::pubkey::Pubkey;
+
+    #[test]
+    fn ingest_system_totals_rent_and_transfers() {
+        let mut costs = TransactionCosts::new();
+        costs.ingest_system(&SystemInstruction::CreateAccount(CreateAccount {
+            lamports: 2_039_280,
+            space: 165,
+            owner: Pubkey::new_unique(),
+        }));
+        costs.ingest_system(&SystemInstruction::Transfer { lamports: 5_000 });
+        costs.ingest_system(&SystemInstruction::Transfer { lamports: 1_000 });
+
+        assert_eq!(costs.accounts_created, 1);
+        assert_eq!(costs.rent_lamports, 2_039_280);
+        assert_eq!(costs.transferred_lamports, 6_000);
+    }
+
+    #[test]
+    fn ingest_parsed_system_matches_raw() {
+        let mut costs = TransactionCosts::new();
+        costs.ingest_parsed_system(
+            r#"{"type":"createAccount","info":{"lamports":2039280,"space":165}}"#,
+        );
+        costs.ingest_parsed_system(r#"{"type":"transfer","info":{"lamports":5000}}"#);
+        costs.ingest_parsed_system(r#"{"type":"allocate","info":{"space":165}}"#);
+        costs.ingest_parsed_system("3Bxs4h24hBtQy9rw");
+
+        assert_eq!(costs.accounts_created, 1);
+        assert_eq!(costs.rent_lamports, 2_039_280);
+        assert_eq!(costs.transferred_lamports, 5_000);
+    }
+
+    #[test]
+    fn compute_budget_sets_priority_fee() {
+        let mut costs = TransactionCosts::new();
+        costs.ingest_compute_budget(&ComputeBudgetInstruction::SetComputeUnitPrice(1_000_000));
+        // Two instructions at the default limit, at one lamport per compute unit.
+        assert_eq!(
+            costs.priority_fee(2),
+            2 * DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT as u64
+        );
+
+        costs.ingest_compute_budget(&ComputeBudgetInstruction::SetComputeUnitLimit(50_000));
+        assert_eq!(costs.priority_fee(2), 50_000);
+    }
+
+    #[test]
+    fn priority_fee_caps_the_default_limit() {
+        let mut costs = TransactionCosts::new();
+        costs.ingest_compute_budget(&ComputeBudgetInstruction::SetComputeUnitPrice(1_000_000));
+        assert_eq!(costs.priority_fee(100), MAX_COMPUTE_UNIT_LIMIT as u64);
+    }
+}
@@ -0,0 +1,288 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use decoders::drift_v2::{FundingPaymentRecord, FundingRateRecord};
+use models::{FundingPayments, FundingRate, Resolution, SinkRecord};
+use solana_program::pubkey::Pubkey;
+
+/// Builds the hourly funding series of every perp market from `FundingRateRecord`s and the per
+/// user funding totals from `FundingPaymentRecord`s.
+///
+/// Funding is updated at most once per funding period, so the series keeps one point per market
+/// and hour. A later record for the same hour replaces the earlier one.
+///
+/// Payment records have no record id. A payment settles the funding of a user position from its
+/// last cumulative funding up to the AMM's, so that interval identifies it and a record seen twice
+/// is only counted once. Intervals are remembered from the hour of the latest payment drained
+/// on, and payments from before that hour are taken to be replays and dropped.
+pub struct FundingTracker {
+    rates: BTreeMap<(u16, i64), FundingRate>,
+    payments: HashMap<(Pubkey, u16), FundingPayments>,
+    /// Settled intervals and the block time of their payment.
+    settled: HashMap<Settlement, i64>,
+    settled_since: i64,
+    last_payment_ts: i64,
+    pending_rates: BTreeMap<(u16, i64), FundingRate>,
+    updated_payments: BTreeSet<(Pubkey, u16)>,
+}
+
+/// User, market, user cumulative funding and AMM long and short cumulative funding of a payment.
+type Settlement = (Pubkey, u16, i64, i128, i128);
+
+impl Default for FundingTracker {
+    fn default() -> Self {
+        Self {
+            rates: BTreeMap::new(),
+            payments: HashMap::new(),
+            settled: HashMap::new(),
+            settled_since: i64::MIN,
+            last_payment_ts: i64::MIN,
+            pending_rates: BTreeMap::new(),
+            updated_payments: BTreeSet::new(),
+        }
+    }
+}
+
+impl FundingTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ingest_rate(&mut self, record: &FundingRateRecord) {
+        let hour = Resolution::OneHour.open_time(record.ts);
+        if let Some(existing) = self.rates.get(&(record.market_index, hour)) {
+            if existing.record_id >= record.record_id {
+                return;
+            }
+        }
+
+        let rate = FundingRate {
+            market_index: record.market_index,
+            market: None,
+            hour,
+            ts: record.ts,
+            record_id: record.record_id,
+            funding_rate: record.funding_rate,
+            funding_rate_long: record.funding_rate_long,
+            funding_rate_short: record.funding_rate_short,
+            cumulative_funding_rate_long: record.cumulative_funding_rate_long,
+            cumulative_funding_rate_short: record.cumulative_funding_rate_short,
+            oracle_price_twap: record.oracle_price_twap,
+            mark_price_twap: record.mark_price_twap,
+            mark_oracle_spread: record.mark_price_twap as i64 - record.oracle_price_twap,
+            period_revenue: record.period_revenue,
+        };
+        self.rates.insert((record.market_index, hour), rate.clone());
+        self.pending_rates.insert((record.market_index, hour), rate);
+    }
+
+    pub fn ingest_payment(&mut self, record: &FundingPaymentRecord) {
+        let settlement = (
+            record.user,
+            record.market_index,
+            record.user_last_cumulative_funding,
+            record.amm_cumulative_funding_long,
+            record.amm_cumulative_funding_short,
+        );
+        if record.ts < self.settled_since || self.settled.contains_key(&settlement) {
+            return;
+        }
+        self.settled.insert(settlement, record.ts);
+        self.last_payment_ts = self.last_payment_ts.max(record.ts);
+
+        let key = (record.user, record.market_index);
+        let payments = self.payments.entry(key).or_insert_with(|| FundingPayments {
+            user: record.user.to_string(),
+            user_authority: record.user_authority.to_string(),
+            market_index: record.market_index,
+            market: None,
+            total_funding_payment: 0,
+            payment_count: 0,
+            first_ts: record.ts,
+            last_ts: record.ts,
+        });
+
+        payments.total_funding_payment += record.funding_payment as i128;
+        payments.payment_count += 1;
+        payments.first_ts = payments.first_ts.min(record.ts);
+        payments.last_ts = payments.last_ts.max(record.ts);
+        self.updated_payments.insert(key);
+    }
+
+    /// Hourly funding series of a perp market, oldest first.
+    pub fn rates(&self, market_index: u16) -> impl Iterator<Item = &FundingRate> {
+        self.rates
+            .range((market_index, i64::MIN)..=(market_index, i64::MAX))
+            .map(|(_, rate)| rate)
+    }
+
+    /// Funding totals of every user and market seen so far.
+    pub fn payments(&self) -> impl Iterator<Item = &FundingPayments> {
+        self.payments.values()
+    }
+
+    /// Funding totals of a single user sub account across markets.
+    pub fn user_payments<'a>(
+        &'a self,
+        user: &'a Pubkey,
+    ) -> impl Iterator<Item = &'a FundingPayments> + 'a {
+        self.payments
+            .iter()
+            .filter(move |((payments_user, _), _)| payments_user.eq(user))
+            .map(|(_, payments)| payments)
+    }
+
+    /// New funding points and the totals that changed since the last call. Forgets the intervals
+    /// settled before the hour of the latest payment.
+    pub fn drain_records(&mut self) -> Vec<SinkRecord> {
+        let mut records = std::mem::take(&mut self.pending_rates)
+            .into_values()
+            .map(SinkRecord::FundingRate)
+            .collect::<Vec<_>>();
+
+        if self.last_payment_ts != i64::MIN {
+            self.settled_since = Resolution::OneHour.open_time(self.last_payment_ts);
+            let settled_since = self.settled_since;
+            self.settled.retain(|_, ts| *ts >= settled_since);
+        }
+
+        for key in std::mem::take(&mut self.updated_payments) {
+            if let Some(payments) = self.payments.get(&key) {
+                records.push(SinkRecord::FundingPayments(payments.clone()));
+            }
+        }
+
+        records
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate(ts: i64, record_id: u64, funding_rate: i64) -> FundingRateRecord {
+        FundingRateRecord {
+            ts,
+            record_id,
+            market_index: 0,
+            funding_rate,
+            funding_rate_long: funding_rate as i128,
+            funding_rate_short: funding_rate as i128,
+            cumulative_funding_rate_long: 0,
+            cumulative_funding_rate_short: 0,
+            oracle_price_twap: 100,
+            mark_price_twap: 101,
+            period_revenue: 0,
+            base_asset_amount_with_amm: 0,
+            base_asset_amount_with_unsettled_lp: 0,
+        }
+    }
+
+    fn payment(
+        user: Pubkey,
+        ts: i64,
+        from: i64,
+        to: i128,
+        funding_payment: i64,
+    ) -> FundingPaymentRecord {
+        FundingPaymentRecord {
+            ts,
+            user_authority: Pubkey::default(),
+            user,
+            market_index: 0,
+            funding_payment,
+            base_asset_amount: 1,
+            user_last_cumulative_funding: from,
+            amm_cumulative_funding_long: to,
+            amm_cumulative_funding_short: to,
+        }
+    }
+
+    #[test]
+    fn keeps_the_latest_rate_of_each_hour() {
+        let mut tracker = FundingTracker::new();
+        tracker.ingest_rate(&rate(10, 1, 5));
+        tracker.ingest_rate(&rate(20, 2, 6));
+        tracker.ingest_rate(&rate(15, 1, 7));
+        tracker.ingest_rate(&rate(3_600, 3, 8));
+
+        let rates = tracker.rates(0).collect::<Vec<_>>();
+        assert_eq!(rates.len(), 2);
+        assert_eq!((rates[0].hour, rates[0].funding_rate), (0, 6));
+        assert_eq!(rates[0].mark_oracle_spread, 1);
+        assert_eq!((rates[1].hour, rates[1].funding_rate), (3_600, 8));
+        assert_eq!(tracker.rates(1).count(), 0);
+    }
+
+    #[test]
+    fn sums_payments_per_user_and_market() {
+        let user = Pubkey::new_unique();
+        let mut tracker = FundingTracker::new();
+        tracker.ingest_payment(&payment(user, 20, 0, 10, -5));
+        tracker.ingest_payment(&payment(user, 10, 10, 30, 7));
+        tracker.ingest_payment(&payment(Pubkey::new_unique(), 10, 0, 30, 1));
+
+        let payments = tracker.user_payments(&user).collect::<Vec<_>>();
+        assert_eq!(payments.len(), 1);
+        assert_eq!(payments[0].total_funding_payment, 2);
+        assert_eq!(payments[0].payment_count, 2);
+        assert_eq!((payments[0].first_ts, payments[0].last_ts), (10, 20));
+        assert_eq!(tracker.payments().count(), 2);
+    }
+
+    #[test]
+    fn counts_a_payment_seen_twice_once() {
+        let user = Pubkey::new_unique();
+        let mut tracker = FundingTracker::new();
+        tracker.ingest_payment(&payment(user, 20, 0, 10, -5));
+        tracker.ingest_payment(&payment(user, 20, 0, 10, -5));
+
+        let payments = tracker.user_payments(&user).next().unwrap();
+        assert_eq!(payments.total_funding_payment, -5);
+        assert_eq!(payments.payment_count, 1);
+    }
+
+    #[test]
+    fn drains_new_rates_and_updated_payments() {
+        let user = Pubkey::new_unique();
+        let mut tracker = FundingTracker::new();
+        tracker.ingest_rate(&rate(10, 1, 5));
+        tracker.ingest_payment(&payment(user, 20, 0, 10, -5));
+
+        let records = tracker.drain_records();
+        assert_eq!(records.len(), 2);
+        assert!(matches!(records[0], SinkRecord::FundingRate(_)));
+        assert!(matches!(records[1], SinkRecord::FundingPayments(_)));
+        assert!(tracker.drain_records().is_empty());
+
+        tracker.ingest_payment(&payment(user, 20, 0, 10, -5));
+        assert!(tracker.drain_records().is_empty());
+    }
+
+    #[test]
+    fn drains_one_rate_per_market_and_hour() {
+        let mut tracker = FundingTracker::new();
+        tracker.ingest_rate(&rate(10, 1, 5));
+        tracker.ingest_rate(&rate(20, 2, 6));
+
+        let records = tracker.drain_records();
+        assert_eq!(records.len(), 1);
+        assert!(matches!(&records[0], SinkRecord::FundingRate(rate) if rate.funding_rate == 6));
+    }
+
+    #[test]
+    fn forgets_intervals_settled_before_the_drained_hour() {
+        let user = Pubkey::new_unique();
+        let mut tracker = FundingTracker::new();
+        tracker.ingest_payment(&payment(user, 20, 0, 10, -5));
+        tracker.ingest_payment(&payment(user, 3_700, 10, 30, 7));
+        tracker.drain_records();
+        assert_eq!(tracker.settled.len(), 1);
+
+        // Replays from before and within the drained hour are both dropped.
+        tracker.ingest_payment(&payment(user, 20, 0, 10, -5));
+        tracker.ingest_payment(&payment(user, 3_700, 10, 30, 7));
+        let payments = tracker.user_payments(&user).next().unwrap();
+        assert_eq!(payments.total_funding_payment, 2);
+        assert_eq!(payments.payment_count, 2);
+    }
+}
@@ -0,0 +1,34 @@
+pub mod candles;
+pub mod costs;
+pub mod funding;
+pub mod liquidations;
+pub mod markets;
+pub mod orders;
+pub mod phoenix;
+pub mod positions;
+
+use decoders::drift_v2::MarketType;
+use models::MarketKind;
+
+/// precision for prices: 1e6
+pub const PRICE_PRECISION: u128 = 1_000_000;
+/// precision for quote amounts: 1e6
+pub const QUOTE_PRECISION: u128 = 1_000_000;
+/// precision for perp base amounts: 1e9
+pub const BASE_PRECISION: u128 = 1_000_000_000;
+/// Spot markets whose decimals were never registered are assumed to use 9 decimals.
+pub const DEFAULT_SPOT_DECIMALS: u32 = 9;
+
+pub fn market_kind(market_type: &MarketType) -> MarketKind {
+    match market_type {
+        MarketType::Perp => MarketKind::Perp,
+        MarketType::Spot => MarketKind::Spot,
+    }
+}
+
+/// Decoded value with every field zero: default keys, first enum variants and `None`s. Tests
+/// override the fields they care about.
+#[cfg(test)]
+fn zeroed<T: borsh::BorshDeserialize>() -> T {
+    T::deserialize(&mut &[0u8; 16 * 1024][..]).unwrap()
+}
@@ -0,0 +1,411 @@
+use std::collections::HashMap;
+
+use decoders::drift_v2::{
+    DriftV2Event, LiquidateBorrowForPerpPnlRecord, LiquidatePerpPnlForDepositRecord,
+    LiquidatePerpRecord, LiquidateSpotRecord, LiquidationRecord, LiquidationType,
+    PerpBankruptcyRecord, SpotBankruptcyRecord, User,
+};
+use models::{MarketKind, MarketMetadata};
+use solana_program::pubkey::Pubkey;
+use tokio::sync::broadcast;
+
+use crate::DEFAULT_SPOT_DECIMALS;
+
+/// Number of reports a slow subscriber can lag behind before it starts missing them.
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// The meaningful part of a `LiquidationRecord`, picked by its `LiquidationType`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Liquidation {
+    Perp(LiquidatePerpRecord),
+    Spot(LiquidateSpotRecord),
+    BorrowForPerpPnl(LiquidateBorrowForPerpPnlRecord),
+    PerpPnlForDeposit(LiquidatePerpPnlForDepositRecord),
+    PerpBankruptcy(PerpBankruptcyRecord),
+    SpotBankruptcy(SpotBankruptcyRecord),
+}
+
+impl From<&LiquidationRecord> for Liquidation {
+    fn from(record: &LiquidationRecord) -> Self {
+        match record.liquidation_type {
+            LiquidationType::LiquidatePerp => Self::Perp(record.liquidate_perp.clone()),
+            LiquidationType::LiquidateSpot => Self::Spot(record.liquidate_spot.clone()),
+            LiquidationType::LiquidateBorrowForPerpPnl => {
+                Self::BorrowForPerpPnl(record.liquidate_borrow_for_perp_pnl.clone())
+            }
+            LiquidationType::LiquidatePerpPnlForDeposit => {
+                Self::PerpPnlForDeposit(record.liquidate_perp_pnl_for_deposit.clone())
+            }
+            LiquidationType::PerpBankruptcy => Self::PerpBankruptcy(record.perp_bankruptcy.clone()),
+            LiquidationType::SpotBankruptcy => Self::SpotBankruptcy(record.spot_bankruptcy.clone()),
+        }
+    }
+}
+
+impl Liquidation {
+    /// Markets the liquidation moved positions in, perp before spot and asset before liability.
+    pub fn markets(&self) -> Vec<(MarketKind, u16)> {
+        match self {
+            Self::Perp(record) => vec![(MarketKind::Perp, record.market_index)],
+            Self::Spot(record) => vec![
+                (MarketKind::Spot, record.asset_market_index),
+                (MarketKind::Spot, record.liability_market_index),
+            ],
+            Self::BorrowForPerpPnl(record) => vec![
+                (MarketKind::Perp, record.perp_market_index),
+                (MarketKind::Spot, record.liability_market_index),
+            ],
+            Self::PerpPnlForDeposit(record) => vec![
+                (MarketKind::Perp, record.perp_market_index),
+                (MarketKind::Spot, record.asset_market_index),
+            ],
+            Self::PerpBankruptcy(record) => vec![(MarketKind::Perp, record.market_index)],
+            Self::SpotBankruptcy(record) => vec![(MarketKind::Spot, record.market_index)],
+        }
+    }
+}
+
+/// What is known about the liquidated `User` sub account.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LiquidatedUser {
+    pub user: Pubkey,
+    pub authority: Option<Pubkey>,
+    pub sub_account_id: Option<u16>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LiquidationReport {
+    pub slot: u64,
+    pub ts: i64,
+    pub liquidation_id: u64,
+    pub user: LiquidatedUser,
+    pub liquidator: Pubkey,
+    pub liquidation: Liquidation,
+    /// Metadata of `Liquidation::markets`, `None` for markets that were never registered
+    pub markets: Vec<Option<MarketMetadata>>,
+    pub bankrupt: bool,
+    /// precision: QUOTE_PRECISION
+    pub margin_requirement: u128,
+    /// precision: QUOTE_PRECISION
+    pub total_collateral: i128,
+    /// precision: QUOTE_PRECISION
+    pub margin_freed: u64,
+    pub canceled_order_ids: Vec<u32>,
+    /// Value the liquidator took over minus the value it gave up
+    /// precision: QUOTE_PRECISION
+    pub liquidator_profit: i128,
+    /// Fee paid to the insurance fund
+    /// precision: QUOTE_PRECISION
+    pub insurance_fund_fee: u128,
+}
+
+/// Turns `LiquidationRecord`s into `LiquidationReport`s and broadcasts them to every subscriber.
+pub struct LiquidationMonitor {
+    sender: broadcast::Sender<LiquidationReport>,
+    users: HashMap<Pubkey, LiquidatedUser>,
+    spot_decimals: HashMap<u16, u32>,
+    markets: HashMap<(MarketKind, u16), MarketMetadata>,
+}
+
+impl Default for LiquidationMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LiquidationMonitor {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+
+        Self {
+            sender,
+            users: HashMap::new(),
+            spot_decimals: HashMap::new(),
+            markets: HashMap::new(),
+        }
+    }
+
+    /// Stream of every report produced after this call.
+    pub fn subscribe(&self) -> broadcast::Receiver<LiquidationReport> {
+        self.sender.subscribe()
+    }
+
+    /// Registers the token decimals of a spot market, used to value spot transfers.
+    pub fn set_spot_decimals(&mut self, market_index: u16, decimals: u32) {
+        self.spot_decimals.insert(market_index, decimals);
+    }
+
+    /// Registers the symbol and decimals reports show for a market.
+    pub fn set_market_metadata(
+        &mut self,
+        market_kind: MarketKind,
+        market_index: u16,
+        metadata: MarketMetadata,
+    ) {
+        self.markets.insert((market_kind, market_index), metadata);
+    }
+
+    /// Links a user address to its decoded account so reports carry its authority and sub
+    /// account id.
+    pub fn link_user(&mut self, user: Pubkey, account: &User) {
+        self.users.insert(
+            user,
+            LiquidatedUser {
+                user,
+                authority: Some(account.authority),
+                sub_account_id: Some(account.sub_account_id),
+            },
+        );
+    }
+
+    /// Learns user authorities and sub account ids from events and reports liquidations.
+    pub fn apply(&mut self, slot: u64, event: &DriftV2Event) -> Option<LiquidationReport> {
+        match event {
+            DriftV2Event::NewUserRecord(record) => {
+                self.users.insert(
+                    record.user,
+                    LiquidatedUser {
+                        user: record.user,
+                        authority: Some(record.user_authority),
+                        sub_account_id: Some(record.sub_account_id),
+                    },
+                );
+                None
+            }
+            DriftV2Event::DepositRecord(record) => {
+                self.users
+                    .entry(record.user)
+                    .or_insert_with(|| LiquidatedUser {
+                        user: record.user,
+                        ..Default::default()
+                    })
+                    .authority = Some(record.user_authority);
+                None
+            }
+            DriftV2Event::LiquidationRecord(record) => self.report(slot, record),
+            _ => None,
+        }
+    }
+
+    /// Builds the report of a liquidation and sends it to the subscribers. Records whose amounts
+    /// overflow when valued are skipped.
+    pub fn report(&mut self, slot: u64, record: &LiquidationRecord) -> Option<LiquidationReport> {
+        let liquidation = Liquidation::from(record);
+        let (liquidator_profit, insurance_fund_fee) = self.profit_and_fee(&liquidation)?;
+        let user = self
+            .users
+            .get(&record.user)
+            .cloned()
+            .unwrap_or_else(|| LiquidatedUser {
+                user: record.user,
+                ..Default::default()
+            });
+
+        let report = LiquidationReport {
+            slot,
+            ts: record.ts,
+            liquidation_id: record.liquidation_id,
+            user,
+            liquidator: record.liquidator,
+            markets: liquidation
+                .markets()
+                .iter()
+                .map(|market| self.markets.get(market).cloned())
+                .collect(),
+            liquidation,
+            bankrupt: record.bankrupt,
+            margin_requirement: record.margin_requirement,
+            total_collateral: record.total_collateral,
+            margin_freed: record.margin_freed,
+            canceled_order_ids: record.canceled_order_ids.clone(),
+            liquidator_profit,
+            insurance_fund_fee,
+        };
+        // Sending only fails when nobody is subscribed, which is fine.
+        let _ = self.sender.send(report.clone());
+
+        Some(report)
+    }
+
+    fn profit_and_fee(&self, liquidation: &Liquidation) -> Option<(i128, u128)> {
+        Some(match liquidation {
+            Liquidation::Perp(record) => (record.liquidator_fee as i128, record.if_fee as u128),
+            Liquidation::Spot(record) => {
+                let asset = self.quote_value(
+                    record.asset_market_index,
+                    record.asset_transfer,
+                    record.asset_price,
+                )?;
+                let liability = self.quote_value(
+                    record.liability_market_index,
+                    record.liability_transfer,
+                    record.liability_price,
+                )?;
+                let if_fee = self.quote_value(
+                    record.liability_market_index,
+                    record.if_fee as u128,
+                    record.liability_price,
+                )?;
+                (asset.checked_sub(liability)?, if_fee.max(0) as u128)
+            }
+            Liquidation::BorrowForPerpPnl(record) => {
+                let liability = self.quote_value(
+                    record.liability_market_index,
+                    record.liability_transfer,
+                    record.liability_price,
+                )?;
+                ((record.pnl_transfer as i128).checked_sub(liability)?, 0)
+            }
+            Liquidation::PerpPnlForDeposit(record) => {
+                let asset = self.quote_value(
+                    record.asset_market_index,
+                    record.asset_transfer,
+                    record.asset_price,
+                )?;
+                (asset.checked_sub(record.pnl_transfer as i128)?, 0)
+            }
+            Liquidation::PerpBankruptcy(_) | Liquidation::SpotBankruptcy(_) => (0, 0),
+        })
+    }
+
+    /// Value in QUOTE_PRECISION of a token amount at a PRICE_PRECISION price, `None` when it
+    /// doesn't fit an `i128`.
+    fn quote_value(&self, market_index: u16, amount: u128, price: i64) -> Option<i128> {
+        let decimals = self
+            .spot_decimals
+            .get(&market_index)
+            .copied()
+            .unwrap_or(DEFAULT_SPOT_DECIMALS);
+
+        i128::try_from(amount)
+            .ok()?
+            .checked_mul(price as i128)?
+            .checked_div(10i128.checked_pow(decimals)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decoders::drift_v2::NewUserRecord;
+
+    use super::*;
+    use crate::zeroed;
+
+    fn spot_liquidation(asset_transfer: u128, liability_transfer: u128) -> LiquidationRecord {
+        let mut record = LiquidationRecord {
+            liquidation_type: LiquidationType::LiquidateSpot,
+            user: Pubkey::new_unique(),
+            liquidator: Pubkey::new_unique(),
+            liquidation_id: 3,
+            ..zeroed()
+        };
+        // 1 SOL at $100 against 98 USDC.
+        record.liquidate_spot = LiquidateSpotRecord {
+            asset_market_index: 1,
+            asset_price: 100_000_000,
+            asset_transfer,
+            liability_market_index: 0,
+            liability_price: 1_000_000,
+            liability_transfer,
+            if_fee: 500_000,
+        };
+        record
+    }
+
+    fn monitor() -> LiquidationMonitor {
+        let mut monitor = LiquidationMonitor::new();
+        monitor.set_spot_decimals(0, 6);
+        monitor.set_spot_decimals(1, 9);
+        monitor.set_market_metadata(
+            MarketKind::Spot,
+            1,
+            MarketMetadata {
+                symbol: "SOL".to_string(),
+                decimals: 9,
+            },
+        );
+        monitor
+    }
+
+    #[test]
+    fn values_spot_liquidations_in_quote() {
+        let mut monitor = monitor();
+        let mut reports = monitor.subscribe();
+
+        let report = monitor
+            .report(7, &spot_liquidation(1_000_000_000, 98_000_000))
+            .unwrap();
+        assert_eq!(report.liquidator_profit, 2_000_000);
+        assert_eq!(report.insurance_fund_fee, 500_000);
+        assert_eq!(report.slot, 7);
+        assert_eq!(
+            report.markets,
+            [
+                Some(MarketMetadata {
+                    symbol: "SOL".to_string(),
+                    decimals: 9,
+                }),
+                None,
+            ]
+        );
+        assert_eq!(reports.try_recv().unwrap(), report);
+    }
+
+    #[test]
+    fn perp_liquidations_report_their_fees() {
+        let mut record = LiquidationRecord {
+            user: Pubkey::new_unique(),
+            ..zeroed()
+        };
+        record.liquidate_perp.liquidator_fee = 4;
+        record.liquidate_perp.if_fee = 2;
+
+        let report = monitor().report(1, &record).unwrap();
+        assert_eq!(report.liquidation, Liquidation::Perp(record.liquidate_perp));
+        assert_eq!(
+            (report.liquidator_profit, report.insurance_fund_fee),
+            (4, 2)
+        );
+    }
+
+    #[test]
+    fn skips_records_that_overflow() {
+        let mut monitor = monitor();
+        let mut reports = monitor.subscribe();
+
+        assert_eq!(monitor.report(1, &spot_liquidation(u128::MAX, 0)), None);
+        assert_eq!(
+            monitor.report(1, &spot_liquidation(i128::MAX as u128, 0)),
+            None
+        );
+        assert!(reports.try_recv().is_err());
+    }
+
+    #[test]
+    fn reports_carry_what_events_said_about_the_user() {
+        let mut monitor = monitor();
+        let record = spot_liquidation(1_000_000_000, 98_000_000);
+        let authority = Pubkey::new_unique();
+        monitor.apply(
+            0,
+            &DriftV2Event::NewUserRecord(NewUserRecord {
+                user: record.user,
+                user_authority: authority,
+                sub_account_id: 2,
+                ..zeroed()
+            }),
+        );
+
+        let report = monitor
+            .apply(1, &DriftV2Event::LiquidationRecord(record.clone()))
+            .unwrap();
+        assert_eq!(
+            report.user,
+            LiquidatedUser {
+                user: record.user,
+                authority: Some(authority),
+                sub_account_id: Some(2),
+            }
+        );
+    }
+}
@@ -0,0 +1,512 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use anyhow::{bail, Result};
+use decoders::drift_v2::{
+    unpack_account, DriftV2Event, DriftV2Instruction, OracleSource, OrderParams, PerpMarket,
+    SpotMarket,
+};
+use models::{MarketKind, MarketMetadata, SinkRecord};
+use serde::Deserialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::{liquidations::Liquidation, market_kind};
+
+/// Drift mainnet markets shipped with the crate.
+const BUNDLED_MARKETS: &str = include_str!("../../../config/markets.toml");
+/// Perp base amounts always use BASE_PRECISION.
+const PERP_DECIMALS: u32 = 9;
+
+/// Static description of a Drift market.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarketInfo {
+    pub market_kind: MarketKind,
+    pub market_index: u16,
+    /// e.g. `SOL-PERP` or `USDC`
+    pub symbol: String,
+    /// 9 for perps, the token mint decimals for spot
+    pub decimals: u32,
+    /// Token mint, spot markets only
+    pub mint: Option<Pubkey>,
+    pub oracle: Option<Pubkey>,
+    pub oracle_source: Option<OracleSource>,
+}
+
+impl MarketInfo {
+    pub fn metadata(&self) -> MarketMetadata {
+        MarketMetadata {
+            symbol: self.symbol.clone(),
+            decimals: self.decimals,
+        }
+    }
+}
+
+impl From<&PerpMarket> for MarketInfo {
+    fn from(market: &PerpMarket) -> Self {
+        Self {
+            market_kind: MarketKind::Perp,
+            market_index: market.market_index,
+            symbol: decode_name(&market.name),
+            decimals: PERP_DECIMALS,
+            mint: None,
+            oracle: Some(market.amm.oracle),
+            oracle_source: Some(market.amm.oracle_source.clone()),
+        }
+    }
+}
+
+impl From<&SpotMarket> for MarketInfo {
+    fn from(market: &SpotMarket) -> Self {
+        Self {
+            market_kind: MarketKind::Spot,
+            market_index: market.market_index,
+            symbol: decode_name(&market.name),
+            decimals: market.decimals,
+            mint: Some(market.mint),
+            oracle: Some(market.oracle),
+            oracle_source: Some(market.oracle_source.clone()),
+        }
+    }
+}
+
+/// Market names are stored as utf8 padded with spaces.
+fn decode_name(name: &[u8; 32]) -> String {
+    String::from_utf8_lossy(name)
+        .trim_end_matches([' ', '\0'])
+        .to_string()
+}
+
+/// Markets a Drift event refers to.
+pub fn event_markets(event: &DriftV2Event) -> Vec<(MarketKind, u16)> {
+    match event {
+        DriftV2Event::NewUserRecord(_) => vec![],
+        DriftV2Event::DepositRecord(record) => vec![(MarketKind::Spot, record.market_index)],
+        DriftV2Event::SpotInterestRecord(record) => vec![(MarketKind::Spot, record.market_index)],
+        DriftV2Event::FundingPaymentRecord(record) => {
+            vec![(MarketKind::Perp, record.market_index)]
+        }
+        DriftV2Event::FundingRateRecord(record) => vec![(MarketKind::Perp, record.market_index)],
+        DriftV2Event::CurveRecord(record) => vec![(MarketKind::Perp, record.market_index)],
+        DriftV2Event::OrderRecord(record) => vec![(
+            market_kind(&record.order.market_type),
+            record.order.market_index,
+        )],
+        DriftV2Event::OrderActionRecord(record) => {
+            vec![(market_kind(&record.market_type), record.market_index)]
+        }
+        DriftV2Event::LPRecord(record) => vec![(MarketKind::Perp, record.market_index)],
+        DriftV2Event::LiquidationRecord(record) => Liquidation::from(record).markets(),
+        DriftV2Event::SettlePnlRecord(record) => vec![(MarketKind::Perp, record.market_index)],
+        DriftV2Event::InsuranceFundRecord(record) => vec![
+            (MarketKind::Perp, record.perp_market_index),
+            (MarketKind::Spot, record.spot_market_index),
+        ],
+        DriftV2Event::InsuranceFundStakeRecord(record) => {
+            vec![(MarketKind::Spot, record.market_index)]
+        }
+        DriftV2Event::SwapRecord(record) => vec![
+            (MarketKind::Spot, record.in_market_index),
+            (MarketKind::Spot, record.out_market_index),
+        ],
+    }
+}
+
+/// Markets a Drift instruction trades, moves funds in or settles. Admin instructions and
+/// instructions that only name orders return no markets.
+pub fn instruction_markets(instruction: &DriftV2Instruction) -> Vec<(MarketKind, u16)> {
+    match instruction {
+        DriftV2Instruction::Deposit(args) => vec![(MarketKind::Spot, args.market_index)],
+        DriftV2Instruction::Withdraw(args) => vec![(MarketKind::Spot, args.market_index)],
+        DriftV2Instruction::TransferDeposit(args) => vec![(MarketKind::Spot, args.market_index)],
+        DriftV2Instruction::PlacePerpOrder(args) => order_markets([&args.params]),
+        DriftV2Instruction::PlaceAndTakePerpOrder(args) => order_markets([&args.params]),
+        DriftV2Instruction::PlaceAndMakePerpOrder(args) => order_markets([&args.params]),
+        DriftV2Instruction::PlaceSpotOrder(args) => order_markets([&args.params]),
+        DriftV2Instruction::PlaceAndTakeSpotOrder(args) => order_markets([&args.params]),
+        DriftV2Instruction::PlaceAndMakeSpotOrder(args) => order_markets([&args.params]),
+        DriftV2Instruction::PlaceOrders(args) => order_markets(&args.params),
+        DriftV2Instruction::CancelOrders(args) => match (&args.market_type, args.market_index) {
+            (Some(market_type), Some(market_index)) => {
+                vec![(market_kind(market_type), market_index)]
+            }
+            _ => vec![],
+        },
+        DriftV2Instruction::BeginSwap(args) => vec![
+            (MarketKind::Spot, args.in_market_index),
+            (MarketKind::Spot, args.out_market_index),
+        ],
+        DriftV2Instruction::EndSwap(args) => vec![
+            (MarketKind::Spot, args.in_market_index),
+            (MarketKind::Spot, args.out_market_index),
+        ],
+        DriftV2Instruction::AddPerpLpShares(args) => vec![(MarketKind::Perp, args.market_index)],
+        DriftV2Instruction::RemovePerpLpShares(args) => {
+            vec![(MarketKind::Perp, args.market_index)]
+        }
+        DriftV2Instruction::SettlePnl(args) => vec![(MarketKind::Perp, args.market_index)],
+        DriftV2Instruction::SettleMultiplePnls(args) => args
+            .market_indexes
+            .iter()
+            .map(|market_index| (MarketKind::Perp, *market_index))
+            .collect(),
+        DriftV2Instruction::SettleLp(args) => vec![(MarketKind::Perp, args.market_index)],
+        DriftV2Instruction::LiquidatePerp(args) => vec![(MarketKind::Perp, args.market_index)],
+        DriftV2Instruction::LiquidateSpot(args) => vec![
+            (MarketKind::Spot, args.asset_market_index),
+            (MarketKind::Spot, args.liability_market_index),
+        ],
+        DriftV2Instruction::LiquidateBorrowForPerpPnl(args) => vec![
+            (MarketKind::Perp, args.perp_market_index),
+            (MarketKind::Spot, args.spot_market_index),
+        ],
+        DriftV2Instruction::LiquidatePerpPnlForDeposit(args) => vec![
+            (MarketKind::Perp, args.perp_market_index),
+            (MarketKind::Spot, args.spot_market_index),
+        ],
+        DriftV2Instruction::UpdateFundingRate(args) => vec![(MarketKind::Perp, args.market_index)],
+        DriftV2Instruction::AddInsuranceFundStake(args) => {
+            vec![(MarketKind::Spot, args.market_index)]
+        }
+        DriftV2Instruction::RemoveInsuranceFundStake(args) => {
+            vec![(MarketKind::Spot, args.market_index)]
+        }
+        _ => vec![],
+    }
+}
+
+fn order_markets<'a>(params: impl IntoIterator<Item = &'a OrderParams>) -> Vec<(MarketKind, u16)> {
+    params
+        .into_iter()
+        .map(|params| (market_kind(&params.market_type), params.market_index))
+        .collect()
+}
+
+#[derive(Debug, Deserialize)]
+struct MarketsFile {
+    #[serde(default)]
+    perp: Vec<MarketEntry>,
+    #[serde(default)]
+    spot: Vec<MarketEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MarketEntry {
+    market_index: u16,
+    symbol: String,
+    decimals: Option<u32>,
+    mint: Option<String>,
+    oracle: Option<String>,
+    oracle_source: Option<String>,
+}
+
+impl MarketEntry {
+    fn into_info(self, market_kind: MarketKind) -> Result<MarketInfo> {
+        let decimals = match (market_kind, self.decimals) {
+            (_, Some(decimals)) => decimals,
+            (MarketKind::Perp, None) => PERP_DECIMALS,
+            (MarketKind::Spot, None) => bail!("Spot market {} has no decimals", self.symbol),
+        };
+        let oracle_source = match self.oracle_source.as_deref() {
+            Some(oracle_source) => Some(parse_oracle_source(oracle_source)?),
+            None => None,
+        };
+
+        Ok(MarketInfo {
+            market_kind,
+            market_index: self.market_index,
+            symbol: self.symbol,
+            decimals,
+            mint: self.mint.map(|mint| mint.parse()).transpose()?,
+            oracle: self.oracle.map(|oracle| oracle.parse()).transpose()?,
+            oracle_source,
+        })
+    }
+}
+
+fn parse_oracle_source(oracle_source: &str) -> Result<OracleSource> {
+    Ok(match oracle_source {
+        "Pyth" => OracleSource::Pyth,
+        "Switchboard" => OracleSource::Switchboard,
+        "QuoteAsset" => OracleSource::QuoteAsset,
+        "Pyth1K" => OracleSource::Pyth1K,
+        "Pyth1M" => OracleSource::Pyth1M,
+        "PythStableCoin" => OracleSource::PythStableCoin,
+        "Prelaunch" => OracleSource::Prelaunch,
+        _ => bail!("Unknown oracle source: {}", oracle_source),
+    })
+}
+
+/// Resolves market indexes to symbols, decimals, mints and oracles.
+///
+/// Markets decoded from `PerpMarket`/`SpotMarket` accounts always replace what is known, markets
+/// read from a TOML file only fill the gaps.
+#[derive(Default)]
+pub struct MarketRegistry {
+    markets: HashMap<(MarketKind, u16), MarketInfo>,
+}
+
+impl MarketRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry seeded with the Drift mainnet markets of `config/markets.toml`.
+    pub fn bundled() -> Result<Self> {
+        let mut registry = Self::new();
+        registry.load_toml(BUNDLED_MARKETS)?;
+        Ok(registry)
+    }
+
+    pub fn load_toml_file(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        self.load_toml(&fs::read_to_string(path)?)
+    }
+
+    pub fn load_toml(&mut self, content: &str) -> Result<()> {
+        let file = toml::from_str::<MarketsFile>(content)?;
+        let entries = file
+            .perp
+            .into_iter()
+            .map(|entry| entry.into_info(MarketKind::Perp))
+            .chain(
+                file.spot
+                    .into_iter()
+                    .map(|entry| entry.into_info(MarketKind::Spot)),
+            );
+
+        for info in entries {
+            let info = info?;
+            self.markets
+                .entry((info.market_kind, info.market_index))
+                .or_insert(info);
+        }
+
+        Ok(())
+    }
+
+    pub fn insert_perp_market(&mut self, market: &PerpMarket) {
+        self.insert(MarketInfo::from(market));
+    }
+
+    pub fn insert_spot_market(&mut self, market: &SpotMarket) {
+        self.insert(MarketInfo::from(market));
+    }
+
+    /// Decodes a raw `PerpMarket` or `SpotMarket` account and registers it.
+    pub fn insert_account(&mut self, data: &[u8]) -> Result<&MarketInfo> {
+        let info = match unpack_account::<PerpMarket>(data) {
+            Ok(market) => MarketInfo::from(&market),
+            Err(_) => match unpack_account::<SpotMarket>(data) {
+                Ok(market) => MarketInfo::from(&market),
+                Err(_) => bail!("Account is neither a PerpMarket nor a SpotMarket"),
+            },
+        };
+
+        let key = (info.market_kind, info.market_index);
+        self.insert(info);
+        Ok(&self.markets[&key])
+    }
+
+    pub fn insert(&mut self, info: MarketInfo) {
+        self.markets
+            .insert((info.market_kind, info.market_index), info);
+    }
+
+    pub fn get(&self, market_kind: MarketKind, market_index: u16) -> Option<&MarketInfo> {
+        self.markets.get(&(market_kind, market_index))
+    }
+
+    pub fn symbol(&self, market_kind: MarketKind, market_index: u16) -> Option<&str> {
+        self.get(market_kind, market_index)
+            .map(|info| info.symbol.as_str())
+    }
+
+    pub fn markets(&self) -> impl Iterator<Item = &MarketInfo> {
+        self.markets.values()
+    }
+
+    pub fn spot_markets(&self) -> impl Iterator<Item = &MarketInfo> {
+        self.markets
+            .values()
+            .filter(|info| info.market_kind == MarketKind::Spot)
+    }
+
+    /// Metadata of each of `markets`, `None` for markets that were never registered. Used to
+    /// annotate decoded events and instructions, see `event_markets` and `instruction_markets`.
+    pub fn describe(&self, markets: &[(MarketKind, u16)]) -> Vec<Option<MarketMetadata>> {
+        markets
+            .iter()
+            .map(|(market_kind, market_index)| {
+                self.get(*market_kind, *market_index)
+                    .map(MarketInfo::metadata)
+            })
+            .collect()
+    }
+
+    /// Fills the market metadata of a record from its market index.
+    pub fn enrich(&self, record: &mut SinkRecord) {
+        let (market_kind, market_index, market) = match record {
+            SinkRecord::Candle(candle) => {
+                (candle.market_kind, candle.market_index, &mut candle.market)
+            }
+            SinkRecord::FundingRate(rate) => {
+                (MarketKind::Perp, rate.market_index, &mut rate.market)
+            }
+            SinkRecord::FundingPayments(payments) => (
+                MarketKind::Perp,
+                payments.market_index,
+                &mut payments.market,
+            ),
+            SinkRecord::ClosedOrder(order) => {
+                (order.market_kind, order.market_index, &mut order.market)
+            }
+        };
+
+        *market = self
+            .get(market_kind, market_index)
+            .map(MarketInfo::metadata);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decoders::{
+        drift_v2::{MarketType, OrderActionRecord, SwapRecord},
+        Discriminator,
+    };
+
+    use super::*;
+    use crate::zeroed;
+
+    fn name(name: &str) -> [u8; 32] {
+        let mut padded = [b' '; 32];
+        padded[..name.len()].copy_from_slice(name.as_bytes());
+        padded
+    }
+
+    fn account<T: Discriminator + borsh::BorshSerialize>(account: &T) -> Vec<u8> {
+        let mut data = T::DISCRIMINATOR.to_vec();
+        data.extend(borsh::to_vec(account).unwrap());
+        data
+    }
+
+    #[test]
+    fn decodes_padded_names() {
+        assert_eq!(decode_name(&name("SOL-PERP")), "SOL-PERP");
+        assert_eq!(decode_name(&[0; 32]), "");
+
+        let mut nul_padded = [0; 32];
+        nul_padded[..4].copy_from_slice(b"USDC");
+        assert_eq!(decode_name(&nul_padded), "USDC");
+    }
+
+    #[test]
+    fn loads_bundled_markets() {
+        let registry = MarketRegistry::bundled().unwrap();
+
+        assert_eq!(registry.symbol(MarketKind::Perp, 0), Some("SOL-PERP"));
+        let usdc = registry.get(MarketKind::Spot, 0).unwrap();
+        assert_eq!(usdc.symbol, "USDC");
+        assert_eq!(usdc.decimals, 6);
+        assert_eq!(
+            usdc.mint,
+            Some(
+                "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+                    .parse()
+                    .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn toml_defaults_perp_decimals_and_fills_gaps_only() {
+        let mut registry = MarketRegistry::new();
+        registry
+            .load_toml(
+                r#"
+                [[perp]]
+                market_index = 0
+                symbol = "SOL-PERP"
+                oracle_source = "Pyth"
+                "#,
+            )
+            .unwrap();
+        registry
+            .load_toml(
+                r#"
+                [[perp]]
+                market_index = 0
+                symbol = "OTHER-PERP"
+                "#,
+            )
+            .unwrap();
+
+        let info = registry.get(MarketKind::Perp, 0).unwrap();
+        assert_eq!(info.symbol, "SOL-PERP");
+        assert_eq!(info.decimals, PERP_DECIMALS);
+        assert_eq!(info.oracle_source, Some(OracleSource::Pyth));
+    }
+
+    #[test]
+    fn toml_rejects_incomplete_entries() {
+        let mut registry = MarketRegistry::new();
+
+        assert!(registry
+            .load_toml("[[spot]]\nmarket_index = 9\nsymbol = \"X\"")
+            .is_err());
+        assert!(registry
+            .load_toml("[[perp]]\nmarket_index = 9\nsymbol = \"X\"\noracle_source = \"Chainlink\"")
+            .is_err());
+        assert!(registry.get(MarketKind::Spot, 9).is_none());
+    }
+
+    #[test]
+    fn accounts_replace_toml_markets() {
+        let mut registry = MarketRegistry::bundled().unwrap();
+        let spot_market = SpotMarket {
+            market_index: 1,
+            name: name("jitoSOL"),
+            decimals: 9,
+            mint: Pubkey::new_unique(),
+            ..zeroed()
+        };
+
+        let info = registry.insert_account(&account(&spot_market)).unwrap();
+        assert_eq!(info.market_kind, MarketKind::Spot);
+        assert_eq!(info.symbol, "jitoSOL");
+        assert_eq!(info.mint, Some(spot_market.mint));
+
+        let perp_market = PerpMarket {
+            market_index: 70,
+            name: name("NEW-PERP"),
+            ..zeroed()
+        };
+        registry.insert_account(&account(&perp_market)).unwrap();
+        assert_eq!(registry.symbol(MarketKind::Perp, 70), Some("NEW-PERP"));
+        assert_eq!(registry.symbol(MarketKind::Spot, 1), Some("jitoSOL"));
+
+        assert!(registry.insert_account(&[0; 16]).is_err());
+    }
+
+    #[test]
+    fn describes_event_markets() {
+        let registry = MarketRegistry::bundled().unwrap();
+        let swap = DriftV2Event::SwapRecord(SwapRecord {
+            in_market_index: 1,
+            out_market_index: 0,
+            ..zeroed()
+        });
+        let fill = DriftV2Event::OrderActionRecord(OrderActionRecord {
+            market_index: 900,
+            market_type: MarketType::Perp,
+            ..zeroed()
+        });
+
+        let symbols = registry
+            .describe(&event_markets(&swap))
+            .into_iter()
+            .map(|metadata| metadata.unwrap().symbol)
+            .collect::<Vec<_>>();
+        assert_eq!(symbols, ["SOL", "USDC"]);
+        assert_eq!(event_markets(&fill), [(MarketKind::Perp, 900)]);
+        assert_eq!(registry.describe(&event_markets(&fill)), [None]);
+    }
+}
@@ -0,0 +1,529 @@
+use std::collections::{HashMap, HashSet};
+
+use decoders::drift_v2::{
+    DriftV2Event, DriftV2Instruction, MarketType, Order, OrderAction, OrderActionExplanation,
+    OrderActionRecord, OrderRecord, OrderType, PositionDirection,
+};
+use models::{ClosedOrder, SinkRecord};
+use solana_program::pubkey::Pubkey;
+
+use crate::market_kind;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum OrderLifecycleStatus {
+    Open,
+    Filled,
+    Canceled,
+    Expired,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AuctionOutcome {
+    /// The order was placed without an auction
+    NoAuction,
+    /// The first fill landed before the auction ended
+    FilledDuringAuction,
+    /// The first fill landed after the auction ended
+    FilledAfterAuction,
+    /// The order never got a fill
+    Unfilled,
+}
+
+/// Everything known about a single order, from placement to its final action.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderLifecycle {
+    pub user: Pubkey,
+    pub order_id: u32,
+    pub user_order_id: u8,
+    pub market_type: MarketType,
+    pub market_index: u16,
+    pub order_type: OrderType,
+    pub direction: PositionDirection,
+    /// precision for perps: BASE_PRECISION
+    /// precision for spot: token mint precision
+    pub base_asset_amount: u64,
+    pub price: u64,
+    pub auction_duration: u8,
+    pub placed_slot: u64,
+    pub placed_ts: i64,
+    pub triggered_ts: Option<i64>,
+    pub first_fill_slot: Option<u64>,
+    pub first_fill_ts: Option<i64>,
+    pub base_asset_amount_filled: u64,
+    pub quote_asset_amount_filled: u64,
+    /// Number of distinct fill records of this order
+    pub fill_count: u32,
+    fill_record_ids: HashSet<u64>,
+    /// Number of `ModifyOrder` and `ModifyOrderByUserId` instructions targeting this order
+    pub modify_count: u32,
+    /// Set when a cancel instruction targeted this order
+    pub cancel_requested: bool,
+    pub status: OrderLifecycleStatus,
+    /// Explanation of the cancel or expire action that closed the order
+    pub cancel_reason: Option<OrderActionExplanation>,
+    pub closed_ts: Option<i64>,
+}
+
+impl OrderLifecycle {
+    fn placed(user: Pubkey, ts: i64, order: &Order) -> Self {
+        Self {
+            user,
+            order_id: order.order_id,
+            user_order_id: order.user_order_id,
+            market_type: order.market_type.clone(),
+            market_index: order.market_index,
+            order_type: order.order_type.clone(),
+            direction: order.direction.clone(),
+            base_asset_amount: order.base_asset_amount,
+            price: order.price,
+            auction_duration: order.auction_duration,
+            placed_slot: order.slot,
+            placed_ts: ts,
+            triggered_ts: None,
+            first_fill_slot: None,
+            first_fill_ts: None,
+            base_asset_amount_filled: order.base_asset_amount_filled,
+            quote_asset_amount_filled: order.quote_asset_amount_filled,
+            fill_count: 0,
+            fill_record_ids: HashSet::new(),
+            modify_count: 0,
+            cancel_requested: false,
+            status: OrderLifecycleStatus::Open,
+            cancel_reason: None,
+            closed_ts: None,
+        }
+    }
+
+    /// Seconds between placement and the first fill.
+    pub fn time_to_first_fill(&self) -> Option<i64> {
+        self.first_fill_ts.map(|ts| ts - self.placed_ts)
+    }
+
+    /// Filled fraction of the order size, between 0 and 1.
+    pub fn fill_ratio(&self) -> f64 {
+        if self.base_asset_amount == 0 {
+            return 0.0;
+        }
+
+        self.base_asset_amount_filled as f64 / self.base_asset_amount as f64
+    }
+
+    pub fn auction_outcome(&self) -> AuctionOutcome {
+        match self.first_fill_slot {
+            None => AuctionOutcome::Unfilled,
+            Some(_) if self.auction_duration == 0 => AuctionOutcome::NoAuction,
+            Some(slot) if slot <= self.placed_slot + self.auction_duration as u64 => {
+                AuctionOutcome::FilledDuringAuction
+            }
+            Some(_) => AuctionOutcome::FilledAfterAuction,
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.status != OrderLifecycleStatus::Open
+    }
+
+    fn to_record(&self) -> ClosedOrder {
+        ClosedOrder {
+            user: self.user.to_string(),
+            order_id: self.order_id,
+            user_order_id: self.user_order_id,
+            market_kind: market_kind(&self.market_type),
+            market_index: self.market_index,
+            market: None,
+            order_type: format!("{:?}", self.order_type),
+            direction: format!("{:?}", self.direction),
+            base_asset_amount: self.base_asset_amount,
+            price: self.price,
+            placed_slot: self.placed_slot,
+            placed_ts: self.placed_ts,
+            triggered_ts: self.triggered_ts,
+            first_fill_ts: self.first_fill_ts,
+            base_asset_amount_filled: self.base_asset_amount_filled,
+            quote_asset_amount_filled: self.quote_asset_amount_filled,
+            fill_count: self.fill_count,
+            modify_count: self.modify_count,
+            cancel_requested: self.cancel_requested,
+            status: format!("{:?}", self.status),
+            cancel_reason: self
+                .cancel_reason
+                .as_ref()
+                .map(|reason| format!("{:?}", reason)),
+            auction_outcome: format!("{:?}", self.auction_outcome()),
+            closed_ts: self.closed_ts,
+        }
+    }
+}
+
+/// Order book of record linking `OrderRecord`s, `OrderActionRecord`s and the cancel/modify
+/// instructions into one lifecycle per (user, order id).
+#[derive(Default)]
+pub struct OrderTracker {
+    orders: HashMap<(Pubkey, u32), OrderLifecycle>,
+    last_order_ids: HashMap<Pubkey, u32>,
+}
+
+impl OrderTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn order(&self, user: &Pubkey, order_id: u32) -> Option<&OrderLifecycle> {
+        self.orders.get(&(*user, order_id))
+    }
+
+    pub fn orders(&self) -> impl Iterator<Item = &OrderLifecycle> {
+        self.orders.values()
+    }
+
+    /// Removes and returns every order that was filled, canceled or expired.
+    pub fn drain_closed(&mut self) -> Vec<OrderLifecycle> {
+        let closed = self
+            .orders
+            .iter()
+            .filter(|(_, order)| order.is_closed())
+            .map(|(key, _)| *key)
+            .collect::<Vec<_>>();
+
+        closed
+            .iter()
+            .filter_map(|key| self.orders.remove(key))
+            .collect()
+    }
+
+    /// Sink records of the orders closed since the last call, removed from the tracker.
+    pub fn drain_records(&mut self) -> Vec<SinkRecord> {
+        self.drain_closed()
+            .iter()
+            .map(|order| SinkRecord::ClosedOrder(order.to_record()))
+            .collect()
+    }
+
+    pub fn apply_event(&mut self, slot: u64, event: &DriftV2Event) {
+        match event {
+            DriftV2Event::OrderRecord(record) => self.apply_order_record(record),
+            DriftV2Event::OrderActionRecord(record) => self.apply_order_action(slot, record),
+            _ => {}
+        }
+    }
+
+    pub fn apply_order_record(&mut self, record: &OrderRecord) {
+        self.last_order_ids
+            .insert(record.user, record.order.order_id);
+        self.orders
+            .entry((record.user, record.order.order_id))
+            .or_insert_with(|| OrderLifecycle::placed(record.user, record.ts, &record.order));
+    }
+
+    pub fn apply_order_action(&mut self, slot: u64, record: &OrderActionRecord) {
+        let sides = [
+            (
+                record.taker,
+                record.taker_order_id,
+                record.taker_order_cumulative_base_asset_amount_filled,
+                record.taker_order_cumulative_quote_asset_amount_filled,
+            ),
+            (
+                record.maker,
+                record.maker_order_id,
+                record.maker_order_cumulative_base_asset_amount_filled,
+                record.maker_order_cumulative_quote_asset_amount_filled,
+            ),
+        ];
+
+        for (user, order_id, cumulative_base, cumulative_quote) in sides {
+            let order = match (user, order_id) {
+                (Some(user), Some(order_id)) => match self.orders.get_mut(&(user, order_id)) {
+                    Some(order) => order,
+                    None => continue,
+                },
+                _ => continue,
+            };
+
+            match record.action {
+                OrderAction::Place => {}
+                OrderAction::Trigger => order.triggered_ts = Some(record.ts),
+                OrderAction::Fill => {
+                    // A transaction seen twice, e.g. after a reconnect, repeats its fill records.
+                    if let Some(fill_record_id) = record.fill_record_id {
+                        if !order.fill_record_ids.insert(fill_record_id) {
+                            continue;
+                        }
+                    }
+                    order.fill_count += 1;
+                    if order.first_fill_ts.is_none() {
+                        order.first_fill_ts = Some(record.ts);
+                        order.first_fill_slot = Some(slot);
+                    }
+                    if let Some(cumulative_base) = cumulative_base {
+                        order.base_asset_amount_filled =
+                            order.base_asset_amount_filled.max(cumulative_base);
+                    }
+                    if let Some(cumulative_quote) = cumulative_quote {
+                        order.quote_asset_amount_filled =
+                            order.quote_asset_amount_filled.max(cumulative_quote);
+                    }
+                    if order.base_asset_amount_filled >= order.base_asset_amount {
+                        order.status = OrderLifecycleStatus::Filled;
+                        order.closed_ts = Some(record.ts);
+                    }
+                }
+                OrderAction::Cancel => {
+                    order.status = OrderLifecycleStatus::Canceled;
+                    order.cancel_reason = Some(record.action_explanation.clone());
+                    order.closed_ts = Some(record.ts);
+                }
+                OrderAction::Expire => {
+                    order.status = OrderLifecycleStatus::Expired;
+                    order.cancel_reason = Some(record.action_explanation.clone());
+                    order.closed_ts = Some(record.ts);
+                }
+            }
+        }
+    }
+
+    /// Links cancel and modify instructions signed for `user` to the orders they target.
+    pub fn apply_instruction(&mut self, user: Pubkey, instruction: &DriftV2Instruction) {
+        match instruction {
+            DriftV2Instruction::CancelOrder(cancel) => {
+                let order_id = cancel
+                    .order_id
+                    .or_else(|| self.last_order_ids.get(&user).copied());
+                if let Some(order) = order_id.and_then(|id| self.orders.get_mut(&(user, id))) {
+                    order.cancel_requested = true;
+                }
+            }
+            DriftV2Instruction::CancelOrderByUserId(cancel) => {
+                if let Some(order) = self.open_order_by_user_id(user, cancel.user_order_id) {
+                    order.cancel_requested = true;
+                }
+            }
+            DriftV2Instruction::CancelOrdersByIds(cancel) => {
+                for order_id in &cancel.order_ids {
+                    if let Some(order) = self.orders.get_mut(&(user, *order_id)) {
+                        order.cancel_requested = true;
+                    }
+                }
+            }
+            DriftV2Instruction::ModifyOrder(modify) => {
+                let order_id = modify
+                    .order_id
+                    .or_else(|| self.last_order_ids.get(&user).copied());
+                if let Some(order) = order_id.and_then(|id| self.orders.get_mut(&(user, id))) {
+                    order.modify_count += 1;
+                }
+            }
+            DriftV2Instruction::ModifyOrderByUserId(modify) => {
+                if let Some(order) = self.open_order_by_user_id(user, modify.user_order_id) {
+                    order.modify_count += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// User order ids are picked by the client and only unique among the open orders of a user.
+    fn open_order_by_user_id(
+        &mut self,
+        user: Pubkey,
+        user_order_id: u8,
+    ) -> Option<&mut OrderLifecycle> {
+        self.orders.values_mut().find(|order| {
+            order.user == user && order.user_order_id == user_order_id && !order.is_closed()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decoders::drift_v2::{
+        CancelOrder, CancelOrderByUserId, CancelOrdersByIds, ModifyOrder, ModifyOrderByUserId,
+    };
+
+    use super::*;
+    use crate::zeroed;
+
+    fn place(tracker: &mut OrderTracker, user: Pubkey, order_id: u32, user_order_id: u8) {
+        tracker.apply_order_record(&OrderRecord {
+            ts: 100,
+            user,
+            order: Order {
+                slot: 10,
+                order_id,
+                user_order_id,
+                base_asset_amount: 10,
+                auction_duration: 5,
+                ..zeroed()
+            },
+        });
+    }
+
+    fn action(user: Pubkey, order_id: u32, action: OrderAction, ts: i64) -> OrderActionRecord {
+        OrderActionRecord {
+            ts,
+            action,
+            taker: Some(user),
+            taker_order_id: Some(order_id),
+            ..zeroed()
+        }
+    }
+
+    fn fill(user: Pubkey, order_id: u32, ts: i64, cumulative_base: u64) -> OrderActionRecord {
+        OrderActionRecord {
+            taker_order_cumulative_base_asset_amount_filled: Some(cumulative_base),
+            taker_order_cumulative_quote_asset_amount_filled: Some(cumulative_base * 100),
+            ..action(user, order_id, OrderAction::Fill, ts)
+        }
+    }
+
+    #[test]
+    fn fills_close_the_order_once_complete() {
+        let user = Pubkey::new_unique();
+        let mut tracker = OrderTracker::new();
+        place(&mut tracker, user, 1, 0);
+
+        tracker.apply_order_action(12, &fill(user, 1, 103, 4));
+        let order = tracker.order(&user, 1).unwrap();
+        assert_eq!(order.status, OrderLifecycleStatus::Open);
+        assert_eq!(order.time_to_first_fill(), Some(3));
+        assert_eq!(order.fill_ratio(), 0.4);
+        assert_eq!(order.auction_outcome(), AuctionOutcome::FilledDuringAuction);
+
+        tracker.apply_order_action(20, &fill(user, 1, 110, 10));
+        let order = tracker.order(&user, 1).unwrap();
+        assert_eq!(order.status, OrderLifecycleStatus::Filled);
+        assert_eq!(order.fill_count, 2);
+        assert_eq!(order.quote_asset_amount_filled, 1_000);
+        assert_eq!(order.first_fill_slot, Some(12));
+        assert_eq!(order.closed_ts, Some(110));
+
+        assert_eq!(tracker.drain_closed().len(), 1);
+        assert_eq!(tracker.orders().count(), 0);
+    }
+
+    #[test]
+    fn fills_seen_twice_count_once() {
+        let user = Pubkey::new_unique();
+        let mut tracker = OrderTracker::new();
+        place(&mut tracker, user, 1, 0);
+
+        let record = OrderActionRecord {
+            fill_record_id: Some(5),
+            ..fill(user, 1, 103, 4)
+        };
+        tracker.apply_order_action(12, &record);
+        tracker.apply_order_action(12, &record);
+        tracker.apply_order_action(
+            13,
+            &OrderActionRecord {
+                fill_record_id: Some(6),
+                ..fill(user, 1, 104, 10)
+            },
+        );
+
+        let order = tracker.order(&user, 1).unwrap();
+        assert_eq!(order.fill_count, 2);
+        assert_eq!(order.base_asset_amount_filled, 10);
+
+        let records = tracker.drain_records();
+        assert_eq!(records.len(), 1);
+        let SinkRecord::ClosedOrder(closed) = &records[0] else {
+            panic!("expected a closed order: {:?}", records[0]);
+        };
+        assert_eq!(closed.user, user.to_string());
+        assert_eq!((closed.status.as_str(), closed.fill_count), ("Filled", 2));
+        assert_eq!(closed.auction_outcome, "FilledDuringAuction");
+    }
+
+    #[test]
+    fn cancel_and_expire_actions_record_the_reason() {
+        let user = Pubkey::new_unique();
+        let mut tracker = OrderTracker::new();
+        place(&mut tracker, user, 1, 0);
+        place(&mut tracker, user, 2, 0);
+
+        tracker.apply_order_action(
+            11,
+            &OrderActionRecord {
+                action_explanation: OrderActionExplanation::InsufficientFreeCollateral,
+                ..action(user, 1, OrderAction::Cancel, 101)
+            },
+        );
+        tracker.apply_order_action(11, &action(user, 2, OrderAction::Expire, 102));
+
+        let canceled = tracker.order(&user, 1).unwrap();
+        assert_eq!(canceled.status, OrderLifecycleStatus::Canceled);
+        assert_eq!(
+            canceled.cancel_reason,
+            Some(OrderActionExplanation::InsufficientFreeCollateral)
+        );
+        assert_eq!(canceled.auction_outcome(), AuctionOutcome::Unfilled);
+        assert_eq!(
+            tracker.order(&user, 2).unwrap().status,
+            OrderLifecycleStatus::Expired
+        );
+    }
+
+    #[test]
+    fn cancel_instructions_target_orders_by_id() {
+        let user = Pubkey::new_unique();
+        let mut tracker = OrderTracker::new();
+        place(&mut tracker, user, 1, 0);
+        place(&mut tracker, user, 2, 0);
+        place(&mut tracker, user, 3, 0);
+
+        // Without an order id the program cancels the last placed order.
+        tracker.apply_instruction(
+            user,
+            &DriftV2Instruction::CancelOrder(CancelOrder { order_id: None }),
+        );
+        tracker.apply_instruction(
+            user,
+            &DriftV2Instruction::CancelOrdersByIds(CancelOrdersByIds { order_ids: vec![1] }),
+        );
+
+        let requested = |order_id| tracker.order(&user, order_id).unwrap().cancel_requested;
+        assert_eq!(
+            [requested(1), requested(2), requested(3)],
+            [true, false, true]
+        );
+    }
+
+    #[test]
+    fn instructions_target_open_orders_by_user_order_id() {
+        let user = Pubkey::new_unique();
+        let mut tracker = OrderTracker::new();
+        place(&mut tracker, user, 1, 7);
+        tracker.apply_order_action(11, &action(user, 1, OrderAction::Cancel, 101));
+        // The user order id is reused once the first order is closed.
+        place(&mut tracker, user, 2, 7);
+        place(&mut tracker, Pubkey::new_unique(), 3, 7);
+
+        tracker.apply_instruction(
+            user,
+            &DriftV2Instruction::ModifyOrderByUserId(ModifyOrderByUserId {
+                user_order_id: 7,
+                modify_order_params: zeroed(),
+            }),
+        );
+        tracker.apply_instruction(
+            user,
+            &DriftV2Instruction::ModifyOrder(ModifyOrder {
+                order_id: Some(2),
+                modify_order_params: zeroed(),
+            }),
+        );
+        tracker.apply_instruction(
+            user,
+            &DriftV2Instruction::CancelOrderByUserId(CancelOrderByUserId { user_order_id: 7 }),
+        );
+
+        let closed = tracker.order(&user, 1).unwrap();
+        assert_eq!((closed.modify_count, closed.cancel_requested), (0, false));
+        let open = tracker.order(&user, 2).unwrap();
+        assert_eq!((open.modify_count, open.cancel_requested), (2, true));
+        assert!(tracker
+            .orders()
+            .all(|order| order.user == user || !order.cancel_requested));
+    }
+}
@@ -0,0 +1,164 @@
+use decoders::{
+    drift_v2::{pda, OrderAction, OrderActionExplanation, OrderActionRecord},
+    phoenix_v1::{FillEvent, FillSummaryEvent, PhoenixLog},
+};
+use solana_program::pubkey::Pubkey;
+
+/// A Drift spot fill routed to Phoenix with the Phoenix fills behind it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhoenixFill {
+    pub record: OrderActionRecord,
+    pub phoenix_market: Pubkey,
+    /// Makers hit on the Phoenix book, in lots of the Phoenix market.
+    pub fills: Vec<FillEvent>,
+    pub summary: FillSummaryEvent,
+}
+
+/// Pairs the fills of one transaction Drift made against Phoenix with the Phoenix logs of that
+/// transaction, in execution order. Drift swaps on Phoenix as its signer PDA and only records a
+/// fill when Phoenix filled something, which identifies the logs to pair.
+pub fn match_phoenix_fills(records: &[OrderActionRecord], logs: &[PhoenixLog]) -> Vec<PhoenixFill> {
+    let drift_signer = pda::drift_signer();
+    let records = records.iter().filter(|record| {
+        record.action == OrderAction::Fill
+            && record.action_explanation == OrderActionExplanation::OrderFillWithPhoenix
+    });
+    let logs = merge_batches(logs);
+    let logs = logs.iter().filter_map(|log| {
+        if log.header.signer != drift_signer {
+            return None;
+        }
+        log.fill_summary()
+            .filter(|summary| summary.total_base_lots_filled > 0)
+            .map(|summary| (log, summary))
+    });
+
+    records
+        .zip(logs)
+        .map(|(record, (log, summary))| PhoenixFill {
+            record: record.clone(),
+            phoenix_market: log.header.market,
+            fills: log.fills().cloned().collect(),
+            summary: summary.clone(),
+        })
+        .collect()
+}
+
+/// Phoenix splits the events of one instruction across several `Log` calls when they don't fit
+/// in one, each starting with the same header. Merges consecutive batches back into one log.
+fn merge_batches(logs: &[PhoenixLog]) -> Vec<PhoenixLog> {
+    let mut merged: Vec<PhoenixLog> = Vec::new();
+    for log in logs {
+        match merged.last_mut() {
+            Some(last)
+                if last.header.market == log.header.market
+                    && last.header.sequence_number == log.header.sequence_number =>
+            {
+                last.events.extend(log.events.iter().cloned());
+            }
+            _ => merged.push(log.clone()),
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use decoders::phoenix_v1::{AuditLogHeader, PhoenixMarketEvent};
+
+    use super::*;
+    use crate::zeroed;
+
+    fn record() -> OrderActionRecord {
+        OrderActionRecord {
+            action: OrderAction::Fill,
+            action_explanation: OrderActionExplanation::OrderFillWithPhoenix,
+            ..zeroed()
+        }
+    }
+
+    fn log(market: Pubkey, sequence_number: u64, events: Vec<PhoenixMarketEvent>) -> PhoenixLog {
+        PhoenixLog {
+            header: AuditLogHeader {
+                instruction: 0,
+                sequence_number,
+                timestamp: 0,
+                slot: 0,
+                market,
+                signer: pda::drift_signer(),
+                total_events: 0,
+            },
+            events,
+        }
+    }
+
+    fn fill(index: u16, base_lots_filled: u64) -> PhoenixMarketEvent {
+        PhoenixMarketEvent::Fill(FillEvent {
+            index,
+            maker_id: Pubkey::new_unique(),
+            order_sequence_number: index.into(),
+            price_in_ticks: 100,
+            base_lots_filled,
+            base_lots_remaining: 0,
+        })
+    }
+
+    fn summary(index: u16, total_base_lots_filled: u64) -> PhoenixMarketEvent {
+        PhoenixMarketEvent::FillSummary(FillSummaryEvent {
+            index,
+            client_order_id: 0,
+            total_base_lots_filled,
+            total_quote_lots_filled: 0,
+            total_fee_in_quote_lots: 0,
+        })
+    }
+
+    #[test]
+    fn pairs_fills_in_order() {
+        let market = Pubkey::new_unique();
+        let logs = [
+            log(market, 1, vec![fill(0, 5), summary(1, 5)]),
+            log(market, 2, vec![fill(0, 3), fill(1, 4), summary(2, 7)]),
+        ];
+
+        let fills = match_phoenix_fills(&[record(), record()], &logs);
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[0].summary.total_base_lots_filled, 5);
+        assert_eq!(fills[1].fills.len(), 2);
+        assert_eq!(fills[1].phoenix_market, market);
+    }
+
+    #[test]
+    fn skips_logs_without_fills_and_foreign_signers() {
+        let market = Pubkey::new_unique();
+        let mut foreign = log(market, 1, vec![fill(0, 5), summary(1, 5)]);
+        foreign.header.signer = Pubkey::new_unique();
+        let logs = [
+            foreign,
+            log(market, 2, vec![summary(0, 0)]),
+            log(market, 3, vec![fill(0, 2), summary(1, 2)]),
+        ];
+
+        let fills = match_phoenix_fills(&[record()], &logs);
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].summary.total_base_lots_filled, 2);
+    }
+
+    #[test]
+    fn merges_batches_of_one_instruction() {
+        let market = Pubkey::new_unique();
+        // Fills of the first swap overflow into a second `Log`, which holds the summary.
+        let logs = [
+            log(market, 1, vec![fill(0, 1), fill(1, 2)]),
+            log(market, 1, vec![fill(2, 3), summary(3, 6)]),
+            log(market, 2, vec![fill(0, 4), summary(1, 4)]),
+        ];
+
+        let fills = match_phoenix_fills(&[record(), record()], &logs);
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[0].fills.len(), 3);
+        assert_eq!(fills[0].summary.total_base_lots_filled, 6);
+        assert_eq!(fills[1].summary.total_base_lots_filled, 4);
+    }
+}
@@ -0,0 +1,810 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use decoders::drift_v2::{
+    self, DepositDirection, DepositRecord, DriftV2Event, FundingPaymentRecord, LPAction, LPRecord,
+    LiquidationRecord, LiquidationType, MarketType, OrderAction, OrderActionRecord,
+    PositionDirection, SettlePnlRecord, SpotBalanceType, SpotMarket, User,
+};
+use models::MarketKind;
+use solana_program::pubkey::Pubkey;
+
+/// Spot market every perp market settles its pnl in (USDC).
+pub const QUOTE_SPOT_MARKET_INDEX: u16 = 0;
+
+/// precision for cumulative deposit/borrow interest: 1e10
+const SPOT_CUMULATIVE_INTEREST_PRECISION_EXP: u32 = 10;
+/// precision for scaled spot balances: 1e9
+const SPOT_BALANCE_PRECISION_EXP: u32 = 9;
+
+/// Perp position of a user sub account as rebuilt from events.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PerpPosition {
+    pub market_index: u16,
+    /// precision: BASE_PRECISION
+    pub base_asset_amount: i64,
+    /// Includes fees, funding and pnl settled into the quote spot market
+    /// precision: QUOTE_PRECISION
+    pub quote_asset_amount: i64,
+    /// Excludes fees and funding
+    /// precision: QUOTE_PRECISION
+    pub quote_entry_amount: i64,
+    /// precision: QUOTE_PRECISION
+    pub quote_break_even_amount: i64,
+    /// precision: QUOTE_PRECISION
+    pub settled_pnl: i64,
+    /// precision: AMM_RESERVE_PRECISION
+    pub lp_shares: u64,
+    /// precision: FUNDING_RATE_PRECISION
+    pub last_cumulative_funding_rate: i64,
+}
+
+impl From<&drift_v2::PerpPosition> for PerpPosition {
+    fn from(position: &drift_v2::PerpPosition) -> Self {
+        Self {
+            market_index: position.market_index,
+            base_asset_amount: position.base_asset_amount,
+            quote_asset_amount: position.quote_asset_amount,
+            quote_entry_amount: position.quote_entry_amount,
+            quote_break_even_amount: position.quote_break_even_amount,
+            settled_pnl: position.settled_pnl,
+            lp_shares: position.lp_shares,
+            last_cumulative_funding_rate: position.last_cumulative_funding_rate,
+        }
+    }
+}
+
+/// Spot balance of a user sub account as rebuilt from events.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SpotPosition {
+    pub market_index: u16,
+    /// Signed token amount, negative for borrows
+    /// precision: token mint precision
+    pub token_amount: i128,
+    /// precision: token mint precision
+    pub cumulative_deposits: i64,
+}
+
+impl SpotPosition {
+    /// Converts the scaled balance of a decoded position into a token amount using the market's
+    /// cumulative interest. Returns `None` when the position belongs to another market.
+    pub fn from_scaled(position: &drift_v2::SpotPosition, market: &SpotMarket) -> Option<Self> {
+        if position.market_index != market.market_index {
+            return None;
+        }
+
+        let (cumulative_interest, sign) = match position.balance_type {
+            SpotBalanceType::Deposit => (market.cumulative_deposit_interest, 1),
+            SpotBalanceType::Borrow => (market.cumulative_borrow_interest, -1),
+        };
+        let precision_decrease = 10u128.pow(
+            (SPOT_CUMULATIVE_INTEREST_PRECISION_EXP + SPOT_BALANCE_PRECISION_EXP)
+                .saturating_sub(market.decimals),
+        );
+        let token_amount =
+            position.scaled_balance as u128 * cumulative_interest / precision_decrease;
+
+        Some(Self {
+            market_index: position.market_index,
+            token_amount: sign * token_amount as i128,
+            cumulative_deposits: position.cumulative_deposits,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UserPositions {
+    pub authority: Option<Pubkey>,
+    pub perp_positions: BTreeMap<u16, PerpPosition>,
+    pub spot_positions: BTreeMap<u16, SpotPosition>,
+    /// Timestamp of the last event applied
+    pub last_ts: i64,
+}
+
+impl UserPositions {
+    fn perp(&mut self, market_index: u16) -> &mut PerpPosition {
+        self.perp_positions
+            .entry(market_index)
+            .or_insert_with(|| PerpPosition {
+                market_index,
+                ..Default::default()
+            })
+    }
+
+    fn spot(&mut self, market_index: u16) -> &mut SpotPosition {
+        self.spot_positions
+            .entry(market_index)
+            .or_insert_with(|| SpotPosition {
+                market_index,
+                ..Default::default()
+            })
+    }
+}
+
+/// Difference between a tracked position field and the same field on the `User` account.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositionDrift {
+    pub market_kind: MarketKind,
+    pub market_index: u16,
+    pub field: &'static str,
+    pub tracked: i128,
+    pub actual: i128,
+}
+
+/// Keeps the perp and spot positions of every Drift `User` sub account up to date from decoded
+/// events, without polling accounts.
+#[derive(Default)]
+pub struct PositionTracker {
+    users: HashMap<Pubkey, UserPositions>,
+}
+
+impl PositionTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn user(&self, user: &Pubkey) -> Option<&UserPositions> {
+        self.users.get(user)
+    }
+
+    pub fn users(&self) -> impl Iterator<Item = (&Pubkey, &UserPositions)> {
+        self.users.iter()
+    }
+
+    /// Replaces the tracked positions of `user` with the ones on its decoded account. Spot
+    /// positions are only seeded for markets found in `spot_markets`.
+    pub fn seed(&mut self, user: Pubkey, account: &User, spot_markets: &[SpotMarket]) {
+        self.users.insert(user, positions_of(account, spot_markets));
+    }
+
+    /// Compares the tracked positions of `user` with its decoded account, reseeds them and
+    /// returns every field that had drifted.
+    pub fn reconcile(
+        &mut self,
+        user: Pubkey,
+        account: &User,
+        spot_markets: &[SpotMarket],
+    ) -> Vec<PositionDrift> {
+        let actual = positions_of(account, spot_markets);
+        let tracked = self.users.remove(&user).unwrap_or_default();
+        let mut drifts = Vec::new();
+
+        let perp_markets = tracked
+            .perp_positions
+            .keys()
+            .chain(actual.perp_positions.keys())
+            .copied()
+            .collect::<BTreeSet<_>>();
+        for market_index in perp_markets {
+            let empty = PerpPosition::default();
+            let tracked = tracked.perp_positions.get(&market_index).unwrap_or(&empty);
+            let actual = actual.perp_positions.get(&market_index).unwrap_or(&empty);
+            let fields = [
+                (
+                    "base_asset_amount",
+                    tracked.base_asset_amount as i128,
+                    actual.base_asset_amount as i128,
+                ),
+                (
+                    "quote_asset_amount",
+                    tracked.quote_asset_amount as i128,
+                    actual.quote_asset_amount as i128,
+                ),
+                (
+                    "quote_entry_amount",
+                    tracked.quote_entry_amount as i128,
+                    actual.quote_entry_amount as i128,
+                ),
+                (
+                    "lp_shares",
+                    tracked.lp_shares as i128,
+                    actual.lp_shares as i128,
+                ),
+            ];
+            for (field, tracked, actual) in fields {
+                if tracked != actual {
+                    drifts.push(PositionDrift {
+                        market_kind: MarketKind::Perp,
+                        market_index,
+                        field,
+                        tracked,
+                        actual,
+                    });
+                }
+            }
+        }
+
+        let spot_markets = tracked
+            .spot_positions
+            .keys()
+            .chain(actual.spot_positions.keys())
+            .copied()
+            .collect::<BTreeSet<_>>();
+        for market_index in spot_markets {
+            let token_amount = |positions: &UserPositions| {
+                positions
+                    .spot_positions
+                    .get(&market_index)
+                    .map(|position| position.token_amount)
+                    .unwrap_or_default()
+            };
+            let (tracked, actual) = (token_amount(&tracked), token_amount(&actual));
+            if tracked != actual {
+                drifts.push(PositionDrift {
+                    market_kind: MarketKind::Spot,
+                    market_index,
+                    field: "token_amount",
+                    tracked,
+                    actual,
+                });
+            }
+        }
+
+        self.users.insert(user, actual);
+
+        drifts
+    }
+
+    pub fn apply(&mut self, event: &DriftV2Event) {
+        match event {
+            DriftV2Event::OrderActionRecord(record) => self.apply_fill(record),
+            DriftV2Event::DepositRecord(record) => self.apply_deposit(record),
+            DriftV2Event::SettlePnlRecord(record) => self.apply_settle_pnl(record),
+            DriftV2Event::FundingPaymentRecord(record) => self.apply_funding_payment(record),
+            DriftV2Event::LPRecord(record) => self.apply_lp(record),
+            DriftV2Event::LiquidationRecord(record) => self.apply_liquidation(record),
+            _ => {}
+        }
+    }
+
+    pub fn apply_fill(&mut self, record: &OrderActionRecord) {
+        if record.action != OrderAction::Fill {
+            return;
+        }
+        let (base, quote) = match (
+            record.base_asset_amount_filled,
+            record.quote_asset_amount_filled,
+        ) {
+            (Some(base), Some(quote)) => (base, quote),
+            _ => return,
+        };
+
+        let sides = [
+            (
+                record.taker,
+                record.taker_order_direction.clone(),
+                (record.taker_fee.unwrap_or_default()
+                    + record.spot_fulfillment_method_fee.unwrap_or_default())
+                    as i64,
+            ),
+            (
+                record.maker,
+                record.maker_order_direction.clone(),
+                record.maker_fee.unwrap_or_default(),
+            ),
+        ];
+        for (user, direction, fee) in sides {
+            let (user, direction) = match (user, direction) {
+                (Some(user), Some(direction)) => (user, direction),
+                _ => continue,
+            };
+            let (delta_base, delta_quote) = match direction {
+                PositionDirection::Long => (base as i64, -(quote as i64)),
+                PositionDirection::Short => (-(base as i64), quote as i64),
+            };
+
+            let positions = self.users.entry(user).or_default();
+            positions.last_ts = record.ts;
+            match record.market_type {
+                MarketType::Perp => {
+                    let position = positions.perp(record.market_index);
+                    update_perp_position(position, delta_base, delta_quote);
+                    position.quote_asset_amount -= fee;
+                    position.quote_break_even_amount -= fee;
+                }
+                MarketType::Spot => {
+                    positions.spot(record.market_index).token_amount += delta_base as i128;
+                    positions.spot(QUOTE_SPOT_MARKET_INDEX).token_amount +=
+                        delta_quote as i128 - fee as i128;
+                }
+            }
+        }
+    }
+
+    pub fn apply_deposit(&mut self, record: &DepositRecord) {
+        let positions = self.users.entry(record.user).or_default();
+        positions.authority = Some(record.user_authority);
+        positions.last_ts = record.ts;
+
+        let position = positions.spot(record.market_index);
+        match record.direction {
+            DepositDirection::Deposit => {
+                position.token_amount += record.amount as i128;
+                position.cumulative_deposits += record.amount as i64;
+            }
+            DepositDirection::Withdraw => {
+                position.token_amount -= record.amount as i128;
+                position.cumulative_deposits -= record.amount as i64;
+            }
+        }
+    }
+
+    pub fn apply_settle_pnl(&mut self, record: &SettlePnlRecord) {
+        let positions = self.users.entry(record.user).or_default();
+        positions.last_ts = record.ts;
+
+        let position = positions.perp(record.market_index);
+        position.base_asset_amount = record.base_asset_amount;
+        position.quote_asset_amount = record.quote_asset_amount_after;
+        position.quote_entry_amount = record.quote_entry_amount;
+        position.settled_pnl += record.pnl as i64;
+        positions.spot(QUOTE_SPOT_MARKET_INDEX).token_amount += record.pnl;
+    }
+
+    pub fn apply_funding_payment(&mut self, record: &FundingPaymentRecord) {
+        let positions = self.users.entry(record.user).or_default();
+        positions.authority = Some(record.user_authority);
+        positions.last_ts = record.ts;
+
+        let position = positions.perp(record.market_index);
+        position.quote_asset_amount += record.funding_payment;
+        position.quote_break_even_amount += record.funding_payment;
+        position.last_cumulative_funding_rate = record.user_last_cumulative_funding;
+    }
+
+    pub fn apply_lp(&mut self, record: &LPRecord) {
+        let positions = self.users.entry(record.user).or_default();
+        positions.last_ts = record.ts;
+
+        let position = positions.perp(record.market_index);
+        match record.action {
+            LPAction::AddLiquidity => position.lp_shares += record.n_shares,
+            LPAction::RemoveLiquidity | LPAction::RemoveLiquidityDerisk => {
+                position.lp_shares = position.lp_shares.saturating_sub(record.n_shares)
+            }
+            LPAction::SettleLiquidity => {}
+        }
+        update_perp_position(
+            position,
+            record.delta_base_asset_amount,
+            record.delta_quote_asset_amount,
+        );
+    }
+
+    pub fn apply_liquidation(&mut self, record: &LiquidationRecord) {
+        let mut transfer = |perp: Option<(u16, i64, i64)>, spot: &[(u16, i128)]| {
+            for (user, sign) in [(record.user, 1), (record.liquidator, -1)] {
+                let positions = self.users.entry(user).or_default();
+                positions.last_ts = record.ts;
+                if let Some((market_index, delta_base, delta_quote)) = perp {
+                    update_perp_position(
+                        positions.perp(market_index),
+                        sign * delta_base,
+                        sign * delta_quote,
+                    );
+                }
+                for (market_index, delta) in spot {
+                    positions.spot(*market_index).token_amount += sign as i128 * delta;
+                }
+            }
+        };
+
+        match record.liquidation_type {
+            LiquidationType::LiquidatePerp => {
+                let liquidation = &record.liquidate_perp;
+                let fee = (liquidation.liquidator_fee + liquidation.if_fee) as i64;
+                transfer(
+                    Some((
+                        liquidation.market_index,
+                        liquidation.base_asset_amount,
+                        liquidation.quote_asset_amount,
+                    )),
+                    &[],
+                );
+                let user = self.users.entry(record.user).or_default();
+                user.perp(liquidation.market_index).quote_asset_amount -= fee;
+                let liquidator = self.users.entry(record.liquidator).or_default();
+                liquidator.perp(liquidation.market_index).quote_asset_amount +=
+                    liquidation.liquidator_fee as i64;
+            }
+            LiquidationType::LiquidateSpot => {
+                let liquidation = &record.liquidate_spot;
+                transfer(
+                    None,
+                    &[
+                        (
+                            liquidation.asset_market_index,
+                            -(liquidation.asset_transfer as i128),
+                        ),
+                        (
+                            liquidation.liability_market_index,
+                            liquidation.liability_transfer as i128,
+                        ),
+                    ],
+                );
+            }
+            LiquidationType::LiquidateBorrowForPerpPnl => {
+                let liquidation = &record.liquidate_borrow_for_perp_pnl;
+                transfer(
+                    Some((
+                        liquidation.perp_market_index,
+                        0,
+                        -(liquidation.pnl_transfer as i64),
+                    )),
+                    &[(
+                        liquidation.liability_market_index,
+                        liquidation.liability_transfer as i128,
+                    )],
+                );
+            }
+            LiquidationType::LiquidatePerpPnlForDeposit => {
+                let liquidation = &record.liquidate_perp_pnl_for_deposit;
+                transfer(
+                    Some((
+                        liquidation.perp_market_index,
+                        0,
+                        liquidation.pnl_transfer as i64,
+                    )),
+                    &[(
+                        liquidation.asset_market_index,
+                        -(liquidation.asset_transfer as i128),
+                    )],
+                );
+            }
+            LiquidationType::PerpBankruptcy => {
+                let bankruptcy = &record.perp_bankruptcy;
+                let positions = self.users.entry(record.user).or_default();
+                positions.last_ts = record.ts;
+                positions.perp(bankruptcy.market_index).quote_asset_amount -= bankruptcy.pnl as i64;
+            }
+            LiquidationType::SpotBankruptcy => {
+                let bankruptcy = &record.spot_bankruptcy;
+                let positions = self.users.entry(record.user).or_default();
+                positions.last_ts = record.ts;
+                positions.spot(bankruptcy.market_index).token_amount +=
+                    bankruptcy.borrow_amount as i128;
+            }
+        }
+    }
+}
+
+fn positions_of(account: &User, spot_markets: &[SpotMarket]) -> UserPositions {
+    let perp_positions = account
+        .perp_positions
+        .iter()
+        .filter(|position| {
+            position.base_asset_amount != 0
+                || position.quote_asset_amount != 0
+                || position.lp_shares != 0
+        })
+        .map(|position| (position.market_index, PerpPosition::from(position)))
+        .collect();
+    let spot_positions = account
+        .spot_positions
+        .iter()
+        .filter(|position| position.scaled_balance != 0)
+        .filter_map(|position| {
+            spot_markets
+                .iter()
+                .find_map(|market| SpotPosition::from_scaled(position, market))
+        })
+        .map(|position| (position.market_index, position))
+        .collect();
+
+    UserPositions {
+        authority: Some(account.authority),
+        perp_positions,
+        spot_positions,
+        last_ts: 0,
+    }
+}
+
+/// Applies a base/quote delta the way the program does: entry and break even amounts grow when
+/// the position increases, shrink pro rata when it reduces and restart from the remainder when
+/// it flips.
+fn update_perp_position(position: &mut PerpPosition, delta_base: i64, delta_quote: i64) {
+    if delta_base == 0 {
+        position.quote_asset_amount += delta_quote;
+        position.quote_break_even_amount += delta_quote;
+        return;
+    }
+
+    let base = position.base_asset_amount;
+    if base == 0 || base.signum() == delta_base.signum() {
+        position.quote_entry_amount += delta_quote;
+        position.quote_break_even_amount += delta_quote;
+    } else if delta_base.unsigned_abs() <= base.unsigned_abs() {
+        let reduced = |amount: i64| {
+            (amount as i128 * delta_base.unsigned_abs() as i128 / base.unsigned_abs() as i128)
+                as i64
+        };
+        position.quote_entry_amount -= reduced(position.quote_entry_amount);
+        position.quote_break_even_amount -= reduced(position.quote_break_even_amount);
+    } else {
+        let remainder = (delta_quote as i128
+            * (delta_base.unsigned_abs() - base.unsigned_abs()) as i128
+            / delta_base.unsigned_abs() as i128) as i64;
+        position.quote_entry_amount = remainder;
+        position.quote_break_even_amount = remainder;
+    }
+
+    position.base_asset_amount += delta_base;
+    position.quote_asset_amount += delta_quote;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zeroed;
+
+    fn fill(
+        taker: Pubkey,
+        maker: Pubkey,
+        direction: PositionDirection,
+        base: u64,
+        quote: u64,
+    ) -> OrderActionRecord {
+        let maker_direction = match direction {
+            PositionDirection::Long => PositionDirection::Short,
+            PositionDirection::Short => PositionDirection::Long,
+        };
+
+        OrderActionRecord {
+            action: OrderAction::Fill,
+            market_type: MarketType::Perp,
+            market_index: 1,
+            base_asset_amount_filled: Some(base),
+            quote_asset_amount_filled: Some(quote),
+            taker: Some(taker),
+            taker_order_direction: Some(direction),
+            maker: Some(maker),
+            maker_order_direction: Some(maker_direction),
+            ..zeroed()
+        }
+    }
+
+    fn perp(tracker: &PositionTracker, user: &Pubkey) -> PerpPosition {
+        tracker.user(user).unwrap().perp_positions[&1].clone()
+    }
+
+    fn token_amount(tracker: &PositionTracker, user: &Pubkey, market_index: u16) -> i128 {
+        tracker.user(user).unwrap().spot_positions[&market_index].token_amount
+    }
+
+    #[test]
+    fn fills_increase_reduce_and_flip_perp_positions() {
+        let (taker, maker) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut tracker = PositionTracker::new();
+
+        // Makers are paid a rebate, a negative fee.
+        tracker.apply_fill(&OrderActionRecord {
+            taker_fee: Some(1),
+            maker_fee: Some(-1),
+            ..fill(taker, maker, PositionDirection::Long, 2, 200)
+        });
+        tracker.apply_fill(&fill(taker, maker, PositionDirection::Long, 1, 120));
+        let position = perp(&tracker, &taker);
+        assert_eq!(position.base_asset_amount, 3);
+        assert_eq!(position.quote_entry_amount, -320);
+        assert_eq!(position.quote_asset_amount, -321);
+        assert_eq!(position.quote_break_even_amount, -321);
+        assert_eq!(perp(&tracker, &maker).base_asset_amount, -3);
+        assert_eq!(perp(&tracker, &maker).quote_asset_amount, 321);
+
+        // Reducing by a third keeps two thirds of the entry amount.
+        tracker.apply_fill(&fill(taker, maker, PositionDirection::Short, 1, 150));
+        let position = perp(&tracker, &taker);
+        assert_eq!(position.base_asset_amount, 2);
+        assert_eq!(position.quote_entry_amount, -214);
+        assert_eq!(position.quote_asset_amount, -171);
+
+        // Flipping restarts the entry amount from the part past zero.
+        tracker.apply_fill(&fill(taker, maker, PositionDirection::Short, 4, 400));
+        let position = perp(&tracker, &taker);
+        assert_eq!(position.base_asset_amount, -2);
+        assert_eq!(position.quote_entry_amount, 200);
+        assert_eq!(position.quote_break_even_amount, 200);
+    }
+
+    #[test]
+    fn spot_fills_move_token_balances() {
+        let (taker, maker) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut tracker = PositionTracker::new();
+        tracker.apply_fill(&OrderActionRecord {
+            market_type: MarketType::Spot,
+            taker_fee: Some(1),
+            ..fill(taker, maker, PositionDirection::Long, 5, 10)
+        });
+
+        assert_eq!(token_amount(&tracker, &taker, 1), 5);
+        assert_eq!(token_amount(&tracker, &taker, QUOTE_SPOT_MARKET_INDEX), -11);
+        assert_eq!(token_amount(&tracker, &maker, 1), -5);
+        assert_eq!(token_amount(&tracker, &maker, QUOTE_SPOT_MARKET_INDEX), 10);
+    }
+
+    #[test]
+    fn settle_pnl_and_funding_update_the_quote_side() {
+        let user = Pubkey::new_unique();
+        let mut tracker = PositionTracker::new();
+        tracker.apply(&DriftV2Event::SettlePnlRecord(SettlePnlRecord {
+            user,
+            market_index: 1,
+            pnl: 30,
+            base_asset_amount: 2,
+            quote_asset_amount_after: -170,
+            quote_entry_amount: -200,
+            ..zeroed()
+        }));
+        tracker.apply(&DriftV2Event::FundingPaymentRecord(FundingPaymentRecord {
+            user,
+            market_index: 1,
+            funding_payment: -5,
+            user_last_cumulative_funding: 42,
+            ..zeroed()
+        }));
+
+        let position = perp(&tracker, &user);
+        assert_eq!(position.base_asset_amount, 2);
+        assert_eq!(position.quote_asset_amount, -175);
+        assert_eq!(position.quote_entry_amount, -200);
+        assert_eq!(position.quote_break_even_amount, -5);
+        assert_eq!(position.settled_pnl, 30);
+        assert_eq!(position.last_cumulative_funding_rate, 42);
+        assert_eq!(token_amount(&tracker, &user, QUOTE_SPOT_MARKET_INDEX), 30);
+    }
+
+    #[test]
+    fn lp_records_track_shares_and_deltas() {
+        let user = Pubkey::new_unique();
+        let mut tracker = PositionTracker::new();
+        tracker.apply_lp(&LPRecord {
+            user,
+            action: LPAction::AddLiquidity,
+            n_shares: 100,
+            market_index: 1,
+            ..zeroed()
+        });
+        tracker.apply_lp(&LPRecord {
+            user,
+            action: LPAction::RemoveLiquidity,
+            n_shares: 40,
+            market_index: 1,
+            delta_base_asset_amount: 3,
+            delta_quote_asset_amount: -30,
+            ..zeroed()
+        });
+
+        let position = perp(&tracker, &user);
+        assert_eq!(position.lp_shares, 60);
+        assert_eq!(position.base_asset_amount, 3);
+        assert_eq!(position.quote_entry_amount, -30);
+    }
+
+    #[test]
+    fn liquidations_move_positions_to_the_liquidator() {
+        let (user, liquidator) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut tracker = PositionTracker::new();
+        tracker.apply_fill(&fill(
+            user,
+            Pubkey::new_unique(),
+            PositionDirection::Long,
+            2,
+            200,
+        ));
+
+        let mut record = LiquidationRecord {
+            user,
+            liquidator,
+            ..zeroed()
+        };
+        record.liquidate_perp.market_index = 1;
+        record.liquidate_perp.base_asset_amount = -2;
+        record.liquidate_perp.quote_asset_amount = 190;
+        record.liquidate_perp.liquidator_fee = 2;
+        record.liquidate_perp.if_fee = 1;
+        tracker.apply_liquidation(&record);
+
+        assert_eq!(perp(&tracker, &user).base_asset_amount, 0);
+        assert_eq!(perp(&tracker, &user).quote_asset_amount, -13);
+        assert_eq!(perp(&tracker, &liquidator).base_asset_amount, 2);
+        assert_eq!(perp(&tracker, &liquidator).quote_asset_amount, -188);
+
+        record.liquidation_type = LiquidationType::LiquidateSpot;
+        record.liquidate_spot.asset_market_index = 1;
+        record.liquidate_spot.asset_transfer = 10;
+        record.liquidate_spot.liability_market_index = QUOTE_SPOT_MARKET_INDEX;
+        record.liquidate_spot.liability_transfer = 8;
+        tracker.apply_liquidation(&record);
+
+        assert_eq!(token_amount(&tracker, &user, 1), -10);
+        assert_eq!(token_amount(&tracker, &user, QUOTE_SPOT_MARKET_INDEX), 8);
+        assert_eq!(token_amount(&tracker, &liquidator, 1), 10);
+        assert_eq!(
+            token_amount(&tracker, &liquidator, QUOTE_SPOT_MARKET_INDEX),
+            -8
+        );
+    }
+
+    /// USDC with 6 decimals at an interest index of 1.
+    fn usdc() -> SpotMarket {
+        SpotMarket {
+            market_index: QUOTE_SPOT_MARKET_INDEX,
+            decimals: 6,
+            cumulative_deposit_interest: 10u128.pow(SPOT_CUMULATIVE_INTEREST_PRECISION_EXP),
+            cumulative_borrow_interest: 10u128.pow(SPOT_CUMULATIVE_INTEREST_PRECISION_EXP),
+            ..zeroed()
+        }
+    }
+
+    fn account() -> User {
+        let mut account = User {
+            authority: Pubkey::new_unique(),
+            ..zeroed()
+        };
+        account.perp_positions[0].market_index = 1;
+        account.perp_positions[0].base_asset_amount = 5;
+        account.perp_positions[0].quote_asset_amount = -10;
+        account.spot_positions[0].scaled_balance = 10u64.pow(SPOT_BALANCE_PRECISION_EXP);
+        account
+    }
+
+    #[test]
+    fn seeds_positions_from_the_user_account() {
+        let user = Pubkey::new_unique();
+        let account = account();
+        let mut tracker = PositionTracker::new();
+        tracker.seed(user, &account, &[usdc()]);
+
+        let positions = tracker.user(&user).unwrap();
+        assert_eq!(positions.authority, Some(account.authority));
+        assert_eq!(positions.perp_positions.len(), 1);
+        assert_eq!(positions.perp_positions[&1].base_asset_amount, 5);
+        assert_eq!(positions.spot_positions.len(), 1);
+        assert_eq!(
+            token_amount(&tracker, &user, QUOTE_SPOT_MARKET_INDEX),
+            1_000_000
+        );
+    }
+
+    #[test]
+    fn reconcile_reports_drift_on_both_sides() {
+        let user = Pubkey::new_unique();
+        let mut tracker = PositionTracker::new();
+        tracker.apply_fill(&fill(
+            user,
+            Pubkey::new_unique(),
+            PositionDirection::Long,
+            4,
+            8,
+        ));
+        // A balance the account no longer has.
+        tracker.apply_deposit(&DepositRecord {
+            user,
+            market_index: 1,
+            amount: 50,
+            ..zeroed()
+        });
+
+        let drifts = tracker.reconcile(user, &account(), &[usdc()]);
+
+        let drift = |market_kind, market_index, field| {
+            drifts
+                .iter()
+                .find(|drift| {
+                    drift.market_kind == market_kind
+                        && drift.market_index == market_index
+                        && drift.field == field
+                })
+                .map(|drift| (drift.tracked, drift.actual))
+        };
+        assert_eq!(
+            drift(MarketKind::Perp, 1, "base_asset_amount"),
+            Some((4, 5))
+        );
+        assert_eq!(drift(MarketKind::Spot, 1, "token_amount"), Some((50, 0)));
+        assert_eq!(
+            drift(MarketKind::Spot, QUOTE_SPOT_MARKET_INDEX, "token_amount"),
+            Some((0, 1_000_000))
+        );
+        assert!(tracker.reconcile(user, &account(), &[usdc()]).is_empty());
+    }
+}
@@ -0,0 +1,23 @@
+//! `Arbitrary` for the foreign types decoded structs hold, used through
+//! `#[arbitrary(with = ...)]` since `Pubkey` doesn't implement it.
+
+use arbitrary::{Result, Unstructured};
+use solana_program::pubkey::Pubkey;
+
+pub fn pubkey(u: &mut Unstructured) -> Result<Pubkey> {
+    Ok(Pubkey::new_from_array(u.arbitrary()?))
+}
+
+pub fn pubkeys<const N: usize>(u: &mut Unstructured) -> Result<[Pubkey; N]> {
+    Ok(u.arbitrary::<[[u8; 32]; N]>()?.map(Pubkey::new_from_array))
+}
+
+pub fn option_pubkey(u: &mut Unstructured) -> Result<Option<Pubkey>> {
+    Ok(u.arbitrary::<Option<[u8; 32]>>()?
+        .map(Pubkey::new_from_array))
+}
+
+pub fn option_pubkeys<const N: usize>(u: &mut Unstructured) -> Result<Option<[Pubkey; N]>> {
+    Ok(u.arbitrary::<Option<[[u8; 32]; N]>>()?
+        .map(|keys| keys.map(Pubkey::new_from_array)))
+}
@@ -0,0 +1,76 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+solana_program::declare_id!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// Instructions
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AssociatedTokenInstruction {
+    Create,
+    /// `Create` that succeeds when the account already exists.
+    CreateIdempotent,
+    RecoverNested,
+}
+
+impl AssociatedTokenInstruction {
+    /// Instructions are a single tag byte, empty data being the original `Create`.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        match input.first() {
+            None | Some(0) => Ok(Self::Create),
+            Some(1) => Ok(Self::CreateIdempotent),
+            Some(2) => Ok(Self::RecoverNested),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+/// Instruction accounts
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateAccounts {
+    /// Pays the rent of the new account.
+    pub funding_account: Pubkey,
+    pub associated_account: Pubkey,
+    pub wallet: Pubkey,
+    pub mint: Pubkey,
+    pub system_program: Pubkey,
+    /// Token or Token-2022, whichever owns the mint.
+    pub token_program: Pubkey,
+}
+
+impl CreateAccounts {
+    /// Reads the accounts of a `Create` or `CreateIdempotent` instruction.
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        match accounts {
+            [funding_account, associated_account, wallet, mint, system_program, token_program, ..] => {
+                Some(Self {
+                    funding_account: *funding_account,
+                    associated_account: *associated_account,
+                    wallet: *wallet,
+                    mint: *mint,
+                    system_program: *system_program,
+                    token_program: *token_program,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unpack() {
+        assert_eq!(
+            AssociatedTokenInstruction::unpack(&[]).unwrap(),
+            AssociatedTokenInstruction::Create
+        );
+        assert_eq!(
+            AssociatedTokenInstruction::unpack(&[1]).unwrap(),
+            AssociatedTokenInstruction::CreateIdempotent
+        );
+        assert!(AssociatedTokenInstruction::unpack(&[3]).is_err());
+    }
+}
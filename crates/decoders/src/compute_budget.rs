@@ -0,0 +1,68 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+
+solana_program::declare_id!("ComputeBudget111111111111111111111111111111");
+
+/// Compute units a transaction gets per instruction when it doesn't set a limit.
+pub const DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT: u32 = 200_000;
+/// Compute units a transaction can request at most.
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Instructions
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum ComputeBudgetInstruction {
+    RequestUnitsDeprecated {
+        units: u32,
+        additional_fee: u32,
+    },
+    /// Bytes of heap requested, a multiple of 1024.
+    RequestHeapFrame(u32),
+    SetComputeUnitLimit(u32),
+    /// In micro-lamports per compute unit.
+    SetComputeUnitPrice(u64),
+    SetLoadedAccountsDataSizeLimit(u32),
+}
+
+impl ComputeBudgetInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        Ok(borsh::from_slice(input)?)
+    }
+}
+
+/// Lamports paid on top of the base fee for a compute unit limit and price, rounded up like the
+/// runtime does.
+pub fn priority_fee(compute_unit_limit: u32, compute_unit_price: u64) -> u64 {
+    let micro_lamports = compute_unit_limit as u128 * compute_unit_price as u128;
+    micro_lamports.div_ceil(1_000_000).min(u64::MAX as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unpack() {
+        let mut data = vec![3];
+        data.extend_from_slice(&50_000u64.to_le_bytes());
+        assert_eq!(
+            ComputeBudgetInstruction::unpack(&data).unwrap(),
+            ComputeBudgetInstruction::SetComputeUnitPrice(50_000)
+        );
+
+        let mut data = vec![2];
+        data.extend_from_slice(&300_000u32.to_le_bytes());
+        assert_eq!(
+            ComputeBudgetInstruction::unpack(&data).unwrap(),
+            ComputeBudgetInstruction::SetComputeUnitLimit(300_000)
+        );
+        assert!(ComputeBudgetInstruction::unpack(&[1, 0]).is_err());
+    }
+
+    #[test]
+    fn priority_fee_rounds_up() {
+        assert_eq!(priority_fee(300_000, 50_000), 15_000);
+        assert_eq!(priority_fee(200_000, 1), 1);
+        assert_eq!(priority_fee(200_000, 0), 0);
+    }
+}
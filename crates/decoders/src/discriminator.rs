@@ -0,0 +1,548 @@
+/// Longest type name the snake case conversion of `instruction_discriminator` supports.
+const MAX_NAME_LEN: usize = 128;
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const H: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// SHA-256 of the concatenation of `parts`, usable in constants.
+pub const fn sha256(parts: &[&[u8]]) -> [u8; 32] {
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        len += parts[i].len();
+        i += 1;
+    }
+
+    // Message, the 0x80 terminator and the 64 bit length, rounded up to whole blocks.
+    let blocks = (len + 9).div_ceil(64);
+    let mut state = H;
+    let mut block = 0;
+    while block < blocks {
+        let mut w = [0u32; 64];
+        let mut t = 0;
+        while t < 16 {
+            let mut word = 0u32;
+            let mut b = 0;
+            while b < 4 {
+                word = (word << 8) | padded_byte(parts, len, block * 64 + t * 4 + b) as u32;
+                b += 1;
+            }
+            w[t] = word;
+            t += 1;
+        }
+        while t < 64 {
+            let s0 = w[t - 15].rotate_right(7) ^ w[t - 15].rotate_right(18) ^ (w[t - 15] >> 3);
+            let s1 = w[t - 2].rotate_right(17) ^ w[t - 2].rotate_right(19) ^ (w[t - 2] >> 10);
+            w[t] = w[t - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[t - 7])
+                .wrapping_add(s1);
+            t += 1;
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+        t = 0;
+        while t < 64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let temp1 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(K[t])
+                .wrapping_add(w[t]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = s0.wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+            t += 1;
+        }
+
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+        state[4] = state[4].wrapping_add(e);
+        state[5] = state[5].wrapping_add(f);
+        state[6] = state[6].wrapping_add(g);
+        state[7] = state[7].wrapping_add(h);
+        block += 1;
+    }
+
+    let mut hash = [0u8; 32];
+    let mut i = 0;
+    while i < 8 {
+        let bytes = state[i].to_be_bytes();
+        hash[i * 4] = bytes[0];
+        hash[i * 4 + 1] = bytes[1];
+        hash[i * 4 + 2] = bytes[2];
+        hash[i * 4 + 3] = bytes[3];
+        i += 1;
+    }
+    hash
+}
+
+/// Byte `index` of the padded message: the parts, 0x80, zeros, then the bit length.
+const fn padded_byte(parts: &[&[u8]], len: usize, index: usize) -> u8 {
+    if index < len {
+        let mut offset = index;
+        let mut i = 0;
+        while offset >= parts[i].len() {
+            offset -= parts[i].len();
+            i += 1;
+        }
+        return parts[i][offset];
+    }
+    if index == len {
+        return 0x80;
+    }
+
+    let total = (len + 9).div_ceil(64) * 64;
+    if index >= total - 8 {
+        let bit_len = (len as u64) * 8;
+        return bit_len.to_be_bytes()[index - (total - 8)];
+    }
+    0
+}
+
+/// First 8 bytes of `sha256("<namespace>:<name>")`.
+pub const fn discriminator(namespace: &str, name: &[u8]) -> [u8; 8] {
+    let hash = sha256(&[namespace.as_bytes(), b":", name]);
+    [
+        hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7],
+    ]
+}
+
+/// Discriminator of the instruction whose arguments are the `PascalCase` type `name`, derived
+/// from the `snake_case` handler name like anchor does.
+pub const fn instruction_discriminator(name: &str) -> [u8; 8] {
+    let (snake, len) = snake_case_bytes(name.as_bytes());
+    discriminator("global", snake.split_at(len).0)
+}
+
+pub const fn account_discriminator(name: &str) -> [u8; 8] {
+    discriminator("account", name.as_bytes())
+}
+
+pub const fn event_discriminator(name: &str) -> [u8; 8] {
+    discriminator("event", name.as_bytes())
+}
+
+/// Prefix of the instruction data of events emitted with `emit_cpi!`, a self-CPI whose data is
+/// this tag followed by the event discriminator and fields. Anchor stores the tag as a `u64` read
+/// big endian from the hash and writes it little endian, so its bytes are reversed.
+pub const EVENT_IX_TAG: [u8; 8] =
+    u64::from_be_bytes(discriminator("anchor", b"event")).to_le_bytes();
+
+/// `PlacePerpOrder` -> `place_perp_order`, `placePerpOrder` -> `place_perp_order`. Same rule as
+/// the discriminators, without a length limit, for IDL names read at runtime.
+pub fn to_snake_case(name: &str) -> String {
+    let name = name.as_bytes();
+    let mut snake = String::with_capacity(name.len() * 2);
+    for (i, c) in name.iter().enumerate() {
+        if starts_word(name, i) {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase() as char);
+    }
+    snake
+}
+
+/// Snake case of `name` in a fixed buffer, usable in constants.
+const fn snake_case_bytes(name: &[u8]) -> ([u8; MAX_NAME_LEN], usize) {
+    let mut snake = [0u8; MAX_NAME_LEN];
+    let mut len = 0;
+    let mut i = 0;
+    while i < name.len() {
+        if starts_word(name, i) {
+            snake[len] = b'_';
+            len += 1;
+        }
+        snake[len] = name[i].to_ascii_lowercase();
+        len += 1;
+        i += 1;
+    }
+    (snake, len)
+}
+
+/// An uppercase letter starts a new word after a lowercase letter or a digit, or when it ends an
+/// acronym: `UpdateAMMOracle` -> `update_amm_oracle`.
+const fn starts_word(name: &[u8], i: usize) -> bool {
+    if i == 0 || !name[i].is_ascii_uppercase() {
+        return false;
+    }
+    let previous_lower = name[i - 1].is_ascii_lowercase() || name[i - 1].is_ascii_digit();
+    let previous_upper = name[i - 1].is_ascii_uppercase();
+    let next_lower = i + 1 < name.len() && name[i + 1].is_ascii_lowercase();
+    previous_lower || (previous_upper && next_lower)
+}
+
+/// Implements `Discriminator` with the value anchor derives for an instruction, account or event.
+///
+/// ```ignore
+/// impl_discriminator!(instruction PlacePerpOrder);
+/// impl_discriminator!(account PerpMarket);
+/// impl_discriminator!(event OrderActionRecord);
+/// ```
+#[macro_export]
+macro_rules! impl_discriminator {
+    (instruction $ty:ident) => {
+        impl $crate::Discriminator for $ty {
+            const DISCRIMINATOR: [u8; 8] =
+                $crate::discriminator::instruction_discriminator(stringify!($ty));
+        }
+    };
+    (account $ty:ident) => {
+        impl $crate::Discriminator for $ty {
+            const DISCRIMINATOR: [u8; 8] =
+                $crate::discriminator::account_discriminator(stringify!($ty));
+        }
+    };
+    (event $ty:ident) => {
+        impl $crate::Discriminator for $ty {
+            const DISCRIMINATOR: [u8; 8] =
+                $crate::discriminator::event_discriminator(stringify!($ty));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{drift_v2::*, Discriminator};
+
+    #[test]
+    fn sha256_matches_known_digests() {
+        assert_eq!(sha256(&[]), solana_program::hash::hashv(&[]).to_bytes());
+        assert_eq!(
+            sha256(&[b"ab", b"c"]),
+            solana_program::hash::hashv(&[b"abc"]).to_bytes()
+        );
+        // 56 bytes, the length field no longer fits in the first block
+        let long = [b'a'; 56];
+        assert_eq!(
+            sha256(&[&long[..20], &long[20..]]),
+            solana_program::hash::hashv(&[&long]).to_bytes()
+        );
+    }
+
+    #[test]
+    fn snake_case_keeps_acronyms_together() {
+        let (snake, len) = snake_case_bytes(b"UpdateAMMOracle");
+        assert_eq!(&snake[..len], b"update_amm_oracle");
+        let (snake, len) = snake_case_bytes(b"SettleLp");
+        assert_eq!(&snake[..len], b"settle_lp");
+        assert_eq!(to_snake_case("UpdateAMMOracle"), "update_amm_oracle");
+        // IDL names are camelCase.
+        assert_eq!(to_snake_case("initializeUser2"), "initialize_user2");
+        assert_eq!(to_snake_case(&"a".repeat(200)).len(), 200);
+    }
+
+    #[test]
+    fn instruction_discriminators_match_anchor() {
+        // Hard-coded in the Drift decoder before the macro derived them.
+        let pinned = [
+            (
+                InitializeUser::DISCRIMINATOR,
+                [111, 17, 185, 250, 60, 122, 38, 254],
+            ),
+            (
+                InitializeUserStats::DISCRIMINATOR,
+                [254, 243, 72, 98, 251, 130, 168, 213],
+            ),
+            (
+                InitializeReferrerName::DISCRIMINATOR,
+                [235, 126, 231, 10, 42, 164, 26, 61],
+            ),
+            (
+                Deposit::DISCRIMINATOR,
+                [242, 35, 198, 137, 82, 225, 242, 182],
+            ),
+            (
+                Withdraw::DISCRIMINATOR,
+                [183, 18, 70, 156, 148, 109, 161, 34],
+            ),
+            (
+                TransferDeposit::DISCRIMINATOR,
+                [20, 20, 147, 223, 41, 63, 204, 111],
+            ),
+            (
+                PlacePerpOrder::DISCRIMINATOR,
+                [69, 161, 93, 202, 120, 126, 76, 185],
+            ),
+            (
+                CancelOrder::DISCRIMINATOR,
+                [95, 129, 237, 240, 8, 49, 223, 132],
+            ),
+            (
+                CancelOrderByUserId::DISCRIMINATOR,
+                [107, 211, 250, 133, 18, 37, 57, 100],
+            ),
+            (
+                CancelOrders::DISCRIMINATOR,
+                [238, 225, 95, 158, 227, 103, 8, 194],
+            ),
+            (
+                CancelOrdersByIds::DISCRIMINATOR,
+                [134, 19, 144, 165, 94, 240, 210, 94],
+            ),
+            (
+                ModifyOrder::DISCRIMINATOR,
+                [47, 124, 117, 255, 201, 197, 130, 94],
+            ),
+            (
+                PlaceAndTakePerpOrder::DISCRIMINATOR,
+                [213, 51, 1, 187, 108, 220, 230, 224],
+            ),
+            (
+                PlaceAndMakePerpOrder::DISCRIMINATOR,
+                [149, 117, 11, 237, 47, 95, 89, 237],
+            ),
+            (
+                PlaceSpotOrder::DISCRIMINATOR,
+                [45, 79, 81, 160, 248, 90, 91, 220],
+            ),
+            (
+                PlaceOrders::DISCRIMINATOR,
+                [60, 63, 50, 123, 12, 197, 60, 190],
+            ),
+            (
+                BeginSwap::DISCRIMINATOR,
+                [174, 109, 228, 1, 242, 105, 232, 105],
+            ),
+            (
+                EndSwap::DISCRIMINATOR,
+                [177, 184, 27, 193, 34, 13, 210, 145],
+            ),
+            (
+                AddPerpLpShares::DISCRIMINATOR,
+                [56, 209, 56, 197, 119, 254, 188, 117],
+            ),
+            (
+                RemovePerpLpShares::DISCRIMINATOR,
+                [213, 89, 217, 18, 160, 55, 53, 141],
+            ),
+            (
+                UpdateUserCustomMarginRatio::DISCRIMINATOR,
+                [21, 221, 140, 187, 32, 129, 11, 123],
+            ),
+            (
+                UpdateUserMarginTradingEnabled::DISCRIMINATOR,
+                [194, 92, 204, 223, 246, 188, 31, 203],
+            ),
+            (
+                DeleteUser::DISCRIMINATOR,
+                [186, 85, 17, 249, 219, 231, 98, 251],
+            ),
+            (
+                ReclaimRent::DISCRIMINATOR,
+                [218, 200, 19, 197, 227, 89, 192, 22],
+            ),
+            (
+                FillPerpOrder::DISCRIMINATOR,
+                [13, 188, 248, 103, 134, 217, 106, 240],
+            ),
+            (
+                RevertFill::DISCRIMINATOR,
+                [236, 238, 176, 69, 239, 10, 181, 193],
+            ),
+            (
+                FillSpotOrder::DISCRIMINATOR,
+                [212, 206, 130, 173, 21, 34, 199, 40],
+            ),
+            (
+                TriggerOrder::DISCRIMINATOR,
+                [63, 112, 51, 233, 232, 47, 240, 199],
+            ),
+            (
+                UpdateUserIdle::DISCRIMINATOR,
+                [253, 133, 67, 22, 103, 161, 20, 100],
+            ),
+            (
+                SettlePnl::DISCRIMINATOR,
+                [43, 61, 234, 45, 15, 95, 152, 153],
+            ),
+            (
+                SettleMultiplePnls::DISCRIMINATOR,
+                [127, 66, 117, 57, 40, 50, 152, 127],
+            ),
+            (
+                SettleFundingPayment::DISCRIMINATOR,
+                [222, 90, 202, 94, 28, 45, 115, 183],
+            ),
+            (
+                SettleLp::DISCRIMINATOR,
+                [155, 231, 116, 113, 97, 229, 139, 141],
+            ),
+            (
+                LiquidatePerp::DISCRIMINATOR,
+                [75, 35, 119, 247, 191, 18, 139, 2],
+            ),
+            (
+                SettleRevenueToInsuranceFund::DISCRIMINATOR,
+                [200, 120, 93, 136, 69, 38, 199, 159],
+            ),
+            (
+                UpdateFundingRate::DISCRIMINATOR,
+                [201, 178, 116, 212, 166, 144, 72, 238],
+            ),
+            (
+                UpdatePrelaunchOracle::DISCRIMINATOR,
+                [220, 132, 27, 27, 233, 220, 61, 219],
+            ),
+            (
+                UpdatePerpBidAskTwap::DISCRIMINATOR,
+                [247, 23, 255, 65, 212, 90, 221, 194],
+            ),
+            (
+                UpdateAmms::DISCRIMINATOR,
+                [201, 106, 217, 253, 4, 175, 228, 97],
+            ),
+            (
+                InitializeInsuranceFundStake::DISCRIMINATOR,
+                [187, 179, 243, 70, 248, 90, 92, 147],
+            ),
+            (
+                AddInsuranceFundStake::DISCRIMINATOR,
+                [251, 144, 115, 11, 222, 47, 62, 236],
+            ),
+            (
+                RequestRemoveInsuranceFundStake::DISCRIMINATOR,
+                [142, 70, 204, 92, 73, 106, 180, 52],
+            ),
+            (
+                CancelRequestRemoveInsuranceFundStake::DISCRIMINATOR,
+                [97, 235, 78, 62, 212, 42, 241, 127],
+            ),
+            (
+                RemoveInsuranceFundStake::DISCRIMINATOR,
+                [128, 166, 142, 9, 254, 187, 143, 174],
+            ),
+            (
+                DepositIntoSpotMarketRevenuePool::DISCRIMINATOR,
+                [92, 40, 151, 42, 122, 254, 139, 246],
+            ),
+        ];
+        for (derived, expected) in pinned {
+            assert_eq!(derived, expected);
+        }
+    }
+
+    #[test]
+    fn account_discriminators_match_anchor() {
+        // Hard-coded in the Drift decoder before the macro derived them.
+        let pinned = [
+            (
+                PerpMarket::DISCRIMINATOR,
+                [10, 223, 12, 44, 107, 245, 55, 247],
+            ),
+            (
+                SpotMarket::DISCRIMINATOR,
+                [100, 177, 8, 107, 168, 65, 65, 39],
+            ),
+        ];
+        for (derived, expected) in pinned {
+            assert_eq!(derived, expected);
+        }
+    }
+
+    #[test]
+    fn event_discriminators_match_anchor() {
+        // Hard-coded in the Drift decoder before the macro derived them.
+        let pinned = [
+            (
+                NewUserRecord::DISCRIMINATOR,
+                [236, 186, 113, 219, 42, 51, 149, 249],
+            ),
+            (
+                DepositRecord::DISCRIMINATOR,
+                [180, 241, 218, 207, 102, 135, 44, 134],
+            ),
+            (
+                SpotInterestRecord::DISCRIMINATOR,
+                [183, 186, 203, 186, 225, 187, 95, 130],
+            ),
+            (
+                FundingPaymentRecord::DISCRIMINATOR,
+                [8, 59, 96, 20, 137, 201, 56, 95],
+            ),
+            (
+                FundingRateRecord::DISCRIMINATOR,
+                [68, 3, 255, 26, 133, 91, 147, 254],
+            ),
+            (
+                CurveRecord::DISCRIMINATOR,
+                [101, 238, 40, 228, 70, 46, 61, 117],
+            ),
+            (OrderRecord::DISCRIMINATOR, [104, 19, 64, 56, 89, 21, 2, 90]),
+            (
+                OrderActionRecord::DISCRIMINATOR,
+                [224, 52, 67, 71, 194, 237, 109, 1],
+            ),
+            (
+                LPRecord::DISCRIMINATOR,
+                [101, 22, 54, 38, 178, 13, 142, 111],
+            ),
+            (
+                LiquidationRecord::DISCRIMINATOR,
+                [127, 17, 0, 108, 182, 13, 231, 53],
+            ),
+            (
+                SettlePnlRecord::DISCRIMINATOR,
+                [57, 68, 105, 26, 119, 198, 213, 89],
+            ),
+            (
+                InsuranceFundRecord::DISCRIMINATOR,
+                [56, 222, 215, 235, 78, 197, 99, 146],
+            ),
+            (
+                InsuranceFundStakeRecord::DISCRIMINATOR,
+                [68, 66, 156, 7, 216, 148, 250, 114],
+            ),
+            (
+                SwapRecord::DISCRIMINATOR,
+                [162, 187, 123, 194, 138, 56, 250, 241],
+            ),
+        ];
+        for (derived, expected) in pinned {
+            assert_eq!(derived, expected);
+        }
+    }
+
+    #[test]
+    fn macro_derives_the_same_value() {
+        struct PlacePerpOrder;
+        crate::impl_discriminator!(instruction PlacePerpOrder);
+
+        assert_eq!(
+            <PlacePerpOrder as Discriminator>::DISCRIMINATOR,
+            crate::drift_v2::PlacePerpOrder::DISCRIMINATOR
+        );
+    }
+
+    #[test]
+    fn event_ix_tag_matches_anchor() {
+        assert_eq!(
+            EVENT_IX_TAG,
+            [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d]
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{InstructionArgs, ToAccountMetas};
+
+/// Accounts passed after the fixed ones, in the order the program loads them: oracles first,
+/// then spot markets, then perp markets.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RemainingAccounts {
+    oracles: Vec<Pubkey>,
+    spot_markets: Vec<(Pubkey, bool)>,
+    perp_markets: Vec<(Pubkey, bool)>,
+}
+
+impl RemainingAccounts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn oracle(mut self, oracle: Pubkey) -> Self {
+        if !self.oracles.contains(&oracle) {
+            self.oracles.push(oracle);
+        }
+        self
+    }
+
+    pub fn spot_market(mut self, spot_market: Pubkey, writable: bool) -> Self {
+        upsert(&mut self.spot_markets, spot_market, writable);
+        self
+    }
+
+    pub fn perp_market(mut self, perp_market: Pubkey, writable: bool) -> Self {
+        upsert(&mut self.perp_markets, perp_market, writable);
+        self
+    }
+}
+
+/// Keeps markets unique, a market asked for as writable stays writable.
+fn upsert(markets: &mut Vec<(Pubkey, bool)>, market: Pubkey, writable: bool) {
+    match markets.iter_mut().find(|(key, _)| *key == market) {
+        Some(entry) => entry.1 |= writable,
+        None => markets.push((market, writable)),
+    }
+}
+
+impl ToAccountMetas for RemainingAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        let oracles = self
+            .oracles
+            .iter()
+            .map(|oracle| AccountMeta::new_readonly(*oracle, false));
+        let markets = self
+            .spot_markets
+            .iter()
+            .chain(self.perp_markets.iter())
+            .map(|(market, writable)| match writable {
+                true => AccountMeta::new(*market, false),
+                false => AccountMeta::new_readonly(*market, false),
+            });
+
+        oracles.chain(markets).collect()
+    }
+}
+
+/// Builds a Drift V2 instruction from its arguments, fixed accounts and remaining accounts.
+pub fn instruction<T: InstructionArgs>(
+    args: &T,
+    accounts: &T::Accounts,
+    remaining_accounts: &RemainingAccounts,
+) -> Result<Instruction, ProgramError> {
+    let mut data = T::discriminator().to_vec();
+    args.serialize(&mut data)?;
+
+    let mut metas = accounts.to_account_metas();
+    metas.extend(remaining_accounts.to_account_metas());
+
+    Ok(Instruction {
+        program_id: super::ID,
+        accounts: metas,
+        data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drift_v2::{
+        CancelOrder, CancelOrdersByIds, DeletePrelaunchOracle, Deposit, DepositAccounts,
+        DriftV2Instruction, MarketType, OrderParams, OrderTriggerCondition, OrderType, PlaceOrders,
+        PlacePerpOrder, PositionDirection, PostOnlyParam, SettleMultiplePnls, SettlePnlMode,
+    };
+
+    fn order_params() -> OrderParams {
+        OrderParams {
+            order_type: OrderType::Limit,
+            market_type: MarketType::Perp,
+            direction: PositionDirection::Long,
+            user_order_id: 7,
+            base_asset_amount: 1_000_000_000,
+            price: 150_000_000,
+            market_index: 0,
+            reduce_only: false,
+            post_only: PostOnlyParam::MustPostOnly,
+            immediate_or_cancel: false,
+            max_ts: Some(1_700_000_000),
+            trigger_price: None,
+            trigger_condition: OrderTriggerCondition::Above,
+            oracle_price_offset: Some(-500),
+            auction_duration: None,
+            auction_start_price: None,
+            auction_end_price: None,
+        }
+    }
+
+    #[test]
+    fn pack_unpack_round_trip() {
+        let instructions = [
+            DriftV2Instruction::InitializeUserStats,
+            DriftV2Instruction::DeleteUser,
+            DriftV2Instruction::ReclaimRent,
+            DriftV2Instruction::RevertFill,
+            DriftV2Instruction::UpdateUserIdle,
+            DriftV2Instruction::SettleFundingPayment,
+            DriftV2Instruction::UpdatePrelaunchOracle,
+            DriftV2Instruction::UpdatePerpBidAskTwap,
+            DriftV2Instruction::Deposit(Deposit {
+                market_index: 1,
+                amount: 42,
+                reduce_only: true,
+            }),
+            DriftV2Instruction::CancelOrder(CancelOrder { order_id: None }),
+            DriftV2Instruction::CancelOrdersByIds(CancelOrdersByIds {
+                order_ids: vec![1, 2, 3],
+            }),
+            DriftV2Instruction::PlacePerpOrder(PlacePerpOrder {
+                params: order_params(),
+            }),
+            DriftV2Instruction::PlaceOrders(PlaceOrders {
+                params: vec![order_params(), order_params()],
+            }),
+            DriftV2Instruction::SettleMultiplePnls(SettleMultiplePnls {
+                market_indexes: vec![0, 1, 2],
+                mode: SettlePnlMode::TrySettle,
+            }),
+        ];
+
+        for instruction in instructions {
+            let data = instruction.pack().unwrap();
+            assert_eq!(DriftV2Instruction::unpack(&data).unwrap(), instruction);
+        }
+    }
+
+    #[test]
+    fn pack_uses_derived_discriminator() {
+        let instruction = DriftV2Instruction::DeletePrelaunchOracle(DeletePrelaunchOracle {
+            perp_market_index: 3,
+        });
+        let data = instruction.pack().unwrap();
+
+        assert_eq!(
+            data[..8],
+            crate::discriminator::instruction_discriminator("DeletePrelaunchOracle")
+        );
+        assert_eq!(data[8..], [3, 0]);
+        assert_eq!(DriftV2Instruction::unpack(&data), Ok(instruction));
+    }
+
+    #[test]
+    fn instruction_has_idl_flags_and_remaining_accounts() {
+        let accounts = DepositAccounts {
+            state: Pubkey::new_unique(),
+            user: Pubkey::new_unique(),
+            user_stats: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+            spot_market_vault: Pubkey::new_unique(),
+            user_token_account: Pubkey::new_unique(),
+            token_program: Pubkey::new_unique(),
+        };
+        let oracle = Pubkey::new_unique();
+        let spot_market = Pubkey::new_unique();
+        let perp_market = Pubkey::new_unique();
+        let remaining_accounts = RemainingAccounts::new()
+            .perp_market(perp_market, false)
+            .spot_market(spot_market, false)
+            .oracle(oracle)
+            .spot_market(spot_market, true)
+            .oracle(oracle);
+        let args = Deposit {
+            market_index: 1,
+            amount: 42,
+            reduce_only: false,
+        };
+
+        let instruction = instruction(&args, &accounts, &remaining_accounts).unwrap();
+
+        assert_eq!(instruction.program_id, crate::drift_v2::ID);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new_readonly(accounts.state, false),
+                AccountMeta::new(accounts.user, false),
+                AccountMeta::new(accounts.user_stats, false),
+                AccountMeta::new_readonly(accounts.authority, true),
+                AccountMeta::new(accounts.spot_market_vault, false),
+                AccountMeta::new(accounts.user_token_account, false),
+                AccountMeta::new_readonly(accounts.token_program, false),
+                AccountMeta::new_readonly(oracle, false),
+                AccountMeta::new(spot_market, false),
+                AccountMeta::new_readonly(perp_market, false),
+            ]
+        );
+        assert_eq!(
+            DriftV2Instruction::unpack(&instruction.data).unwrap(),
+            DriftV2Instruction::Deposit(args)
+        );
+    }
+}
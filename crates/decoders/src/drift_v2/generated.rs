@@ -0,0 +1,7839 @@
+// Generated from the drift v2.0.0 IDL by `cargo run -p codegen -- idl/drift.json DriftV2`, do not edit.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{instruction::AccountMeta, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{impl_discriminator, Discriminator, InstructionArgs, ToAccountMetas};
+
+solana_program::declare_id!("dRiftyHA39MWEi3m9aunc5MzRF1JYuBsbn6VPcn33UH");
+
+// Instructions
+
+#[allow(clippy::large_enum_variant)]
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum DriftV2Instruction {
+    InitializeUser(InitializeUser),
+    InitializeUserStats,
+    InitializeReferrerName(InitializeReferrerName),
+    Deposit(Deposit),
+    Withdraw(Withdraw),
+    TransferDeposit(TransferDeposit),
+    PlacePerpOrder(PlacePerpOrder),
+    CancelOrder(CancelOrder),
+    CancelOrderByUserId(CancelOrderByUserId),
+    CancelOrders(CancelOrders),
+    CancelOrdersByIds(CancelOrdersByIds),
+    ModifyOrder(ModifyOrder),
+    ModifyOrderByUserId(ModifyOrderByUserId),
+    PlaceAndTakePerpOrder(PlaceAndTakePerpOrder),
+    PlaceAndMakePerpOrder(PlaceAndMakePerpOrder),
+    PlaceSpotOrder(PlaceSpotOrder),
+    PlaceAndTakeSpotOrder(PlaceAndTakeSpotOrder),
+    PlaceAndMakeSpotOrder(PlaceAndMakeSpotOrder),
+    PlaceOrders(PlaceOrders),
+    BeginSwap(BeginSwap),
+    EndSwap(EndSwap),
+    AddPerpLpShares(AddPerpLpShares),
+    RemovePerpLpShares(RemovePerpLpShares),
+    RemovePerpLpSharesInExpiringMarket(RemovePerpLpSharesInExpiringMarket),
+    UpdateUserName(UpdateUserName),
+    UpdateUserCustomMarginRatio(UpdateUserCustomMarginRatio),
+    UpdateUserMarginTradingEnabled(UpdateUserMarginTradingEnabled),
+    UpdateUserDelegate(UpdateUserDelegate),
+    UpdateUserReduceOnly(UpdateUserReduceOnly),
+    UpdateUserAdvancedLp(UpdateUserAdvancedLp),
+    DeleteUser,
+    ReclaimRent,
+    FillPerpOrder(FillPerpOrder),
+    RevertFill,
+    FillSpotOrder(FillSpotOrder),
+    TriggerOrder(TriggerOrder),
+    ForceCancelOrders,
+    UpdateUserIdle,
+    UpdateUserOpenOrdersCount,
+    AdminDisableUpdatePerpBidAskTwap(AdminDisableUpdatePerpBidAskTwap),
+    SettlePnl(SettlePnl),
+    SettleMultiplePnls(SettleMultiplePnls),
+    SettleFundingPayment,
+    SettleLp(SettleLp),
+    SettleExpiredMarket(SettleExpiredMarket),
+    LiquidatePerp(LiquidatePerp),
+    LiquidateSpot(LiquidateSpot),
+    LiquidateBorrowForPerpPnl(LiquidateBorrowForPerpPnl),
+    LiquidatePerpPnlForDeposit(LiquidatePerpPnlForDeposit),
+    ResolvePerpPnlDeficit(ResolvePerpPnlDeficit),
+    ResolvePerpBankruptcy(ResolvePerpBankruptcy),
+    ResolveSpotBankruptcy(ResolveSpotBankruptcy),
+    SettleRevenueToInsuranceFund(SettleRevenueToInsuranceFund),
+    UpdateFundingRate(UpdateFundingRate),
+    UpdatePrelaunchOracle,
+    UpdatePerpBidAskTwap,
+    UpdateSpotMarketCumulativeInterest,
+    UpdateAmms(UpdateAmms),
+    UpdateSpotMarketExpiry(UpdateSpotMarketExpiry),
+    UpdateUserQuoteAssetInsuranceStake,
+    InitializeInsuranceFundStake(InitializeInsuranceFundStake),
+    AddInsuranceFundStake(AddInsuranceFundStake),
+    RequestRemoveInsuranceFundStake(RequestRemoveInsuranceFundStake),
+    CancelRequestRemoveInsuranceFundStake(CancelRequestRemoveInsuranceFundStake),
+    RemoveInsuranceFundStake(RemoveInsuranceFundStake),
+    TransferProtocolIfShares(TransferProtocolIfShares),
+    Initialize,
+    InitializeSpotMarket(InitializeSpotMarket),
+    DeleteInitializedSpotMarket(DeleteInitializedSpotMarket),
+    InitializeSerumFulfillmentConfig(InitializeSerumFulfillmentConfig),
+    UpdateSerumFulfillmentConfigStatus(UpdateSerumFulfillmentConfigStatus),
+    InitializePhoenixFulfillmentConfig(InitializePhoenixFulfillmentConfig),
+    PhoenixFulfillmentConfigStatus(PhoenixFulfillmentConfigStatus),
+    UpdateSerumVault,
+    InitializePerpMarket(InitializePerpMarket),
+    DeleteInitializedPerpMarket(DeleteInitializedPerpMarket),
+    MoveAmmPrice(MoveAmmPrice),
+    RecenterPerpMarketAmm(RecenterPerpMarketAmm),
+    UpdatePerpMarketAmmSummaryStats(UpdatePerpMarketAmmSummaryStats),
+    UpdatePerpMarketExpiry(UpdatePerpMarketExpiry),
+    SettleExpiredMarketPoolsToRevenuePool,
+    DepositIntoPerpMarketFeePool(DepositIntoPerpMarketFeePool),
+    DepositIntoSpotMarketRevenuePool(DepositIntoSpotMarketRevenuePool),
+    RepegAmmCurve(RepegAmmCurve),
+    UpdatePerpMarketAmmOracleTwap,
+    ResetPerpMarketAmmOracleTwap,
+    UpdateK(UpdateK),
+    UpdatePerpMarketMarginRatio(UpdatePerpMarketMarginRatio),
+    UpdatePerpMarketFundingPeriod(UpdatePerpMarketFundingPeriod),
+    UpdatePerpMarketMaxImbalances(UpdatePerpMarketMaxImbalances),
+    UpdatePerpMarketLiquidationFee(UpdatePerpMarketLiquidationFee),
+    UpdateInsuranceFundUnstakingPeriod(UpdateInsuranceFundUnstakingPeriod),
+    UpdateSpotMarketLiquidationFee(UpdateSpotMarketLiquidationFee),
+    UpdateWithdrawGuardThreshold(UpdateWithdrawGuardThreshold),
+    UpdateSpotMarketIfFactor(UpdateSpotMarketIfFactor),
+    UpdateSpotMarketRevenueSettlePeriod(UpdateSpotMarketRevenueSettlePeriod),
+    UpdateSpotMarketStatus(UpdateSpotMarketStatus),
+    UpdateSpotMarketPausedOperations(UpdateSpotMarketPausedOperations),
+    UpdateSpotMarketAssetTier(UpdateSpotMarketAssetTier),
+    UpdateSpotMarketMarginWeights(UpdateSpotMarketMarginWeights),
+    UpdateSpotMarketBorrowRate(UpdateSpotMarketBorrowRate),
+    UpdateSpotMarketMaxTokenDeposits(UpdateSpotMarketMaxTokenDeposits),
+    UpdateSpotMarketScaleInitialAssetWeightStart(UpdateSpotMarketScaleInitialAssetWeightStart),
+    UpdateSpotMarketOracle(UpdateSpotMarketOracle),
+    UpdateSpotMarketStepSizeAndTickSize(UpdateSpotMarketStepSizeAndTickSize),
+    UpdateSpotMarketMinOrderSize(UpdateSpotMarketMinOrderSize),
+    UpdateSpotMarketOrdersEnabled(UpdateSpotMarketOrdersEnabled),
+    UpdateSpotMarketIfPausedOperations(UpdateSpotMarketIfPausedOperations),
+    UpdateSpotMarketName(UpdateSpotMarketName),
+    UpdatePerpMarketStatus(UpdatePerpMarketStatus),
+    UpdatePerpMarketPausedOperations(UpdatePerpMarketPausedOperations),
+    UpdatePerpMarketContractTier(UpdatePerpMarketContractTier),
+    UpdatePerpMarketImfFactor(UpdatePerpMarketImfFactor),
+    UpdatePerpMarketUnrealizedAssetWeight(UpdatePerpMarketUnrealizedAssetWeight),
+    UpdatePerpMarketConcentrationCoef(UpdatePerpMarketConcentrationCoef),
+    UpdatePerpMarketCurveUpdateIntensity(UpdatePerpMarketCurveUpdateIntensity),
+    UpdatePerpMarketTargetBaseAssetAmountPerLp(UpdatePerpMarketTargetBaseAssetAmountPerLp),
+    UpdatePerpMarketPerLpBase(UpdatePerpMarketPerLpBase),
+    UpdateLpCooldownTime(UpdateLpCooldownTime),
+    UpdatePerpFeeStructure(UpdatePerpFeeStructure),
+    UpdateSpotFeeStructure(UpdateSpotFeeStructure),
+    UpdateInitialPctToLiquidate(UpdateInitialPctToLiquidate),
+    UpdateLiquidationDuration(UpdateLiquidationDuration),
+    UpdateLiquidationMarginBufferRatio(UpdateLiquidationMarginBufferRatio),
+    UpdateOracleGuardRails(UpdateOracleGuardRails),
+    UpdateStateSettlementDuration(UpdateStateSettlementDuration),
+    UpdateStateMaxNumberOfSubAccounts(UpdateStateMaxNumberOfSubAccounts),
+    UpdateStateMaxInitializeUserFee(UpdateStateMaxInitializeUserFee),
+    UpdatePerpMarketOracle(UpdatePerpMarketOracle),
+    UpdatePerpMarketBaseSpread(UpdatePerpMarketBaseSpread),
+    UpdateAmmJitIntensity(UpdateAmmJitIntensity),
+    UpdatePerpMarketMaxSpread(UpdatePerpMarketMaxSpread),
+    UpdatePerpMarketStepSizeAndTickSize(UpdatePerpMarketStepSizeAndTickSize),
+    UpdatePerpMarketName(UpdatePerpMarketName),
+    UpdatePerpMarketMinOrderSize(UpdatePerpMarketMinOrderSize),
+    UpdatePerpMarketMaxSlippageRatio(UpdatePerpMarketMaxSlippageRatio),
+    UpdatePerpMarketMaxFillReserveFraction(UpdatePerpMarketMaxFillReserveFraction),
+    UpdatePerpMarketMaxOpenInterest(UpdatePerpMarketMaxOpenInterest),
+    UpdatePerpMarketNumberOfUsers(UpdatePerpMarketNumberOfUsers),
+    UpdatePerpMarketFeeAdjustment(UpdatePerpMarketFeeAdjustment),
+    UpdateSpotMarketFeeAdjustment(UpdateSpotMarketFeeAdjustment),
+    UpdateAdmin(UpdateAdmin),
+    UpdateWhitelistMint(UpdateWhitelistMint),
+    UpdateDiscountMint(UpdateDiscountMint),
+    UpdateExchangeStatus(UpdateExchangeStatus),
+    UpdatePerpAuctionDuration(UpdatePerpAuctionDuration),
+    UpdateSpotAuctionDuration(UpdateSpotAuctionDuration),
+    InitializeProtocolIfSharesTransferConfig,
+    UpdateProtocolIfSharesTransferConfig(UpdateProtocolIfSharesTransferConfig),
+    InitializePrelaunchOracle(InitializePrelaunchOracle),
+    UpdatePrelaunchOracleParams(UpdatePrelaunchOracleParams),
+    DeletePrelaunchOracle(DeletePrelaunchOracle),
+}
+
+/// Names of the `DriftV2Instruction` variants in declaration order, the index of a name is the
+/// borsh tag of its variant.
+pub const INSTRUCTION_NAMES: [&str; 152] = [
+    "InitializeUser",
+    "InitializeUserStats",
+    "InitializeReferrerName",
+    "Deposit",
+    "Withdraw",
+    "TransferDeposit",
+    "PlacePerpOrder",
+    "CancelOrder",
+    "CancelOrderByUserId",
+    "CancelOrders",
+    "CancelOrdersByIds",
+    "ModifyOrder",
+    "ModifyOrderByUserId",
+    "PlaceAndTakePerpOrder",
+    "PlaceAndMakePerpOrder",
+    "PlaceSpotOrder",
+    "PlaceAndTakeSpotOrder",
+    "PlaceAndMakeSpotOrder",
+    "PlaceOrders",
+    "BeginSwap",
+    "EndSwap",
+    "AddPerpLpShares",
+    "RemovePerpLpShares",
+    "RemovePerpLpSharesInExpiringMarket",
+    "UpdateUserName",
+    "UpdateUserCustomMarginRatio",
+    "UpdateUserMarginTradingEnabled",
+    "UpdateUserDelegate",
+    "UpdateUserReduceOnly",
+    "UpdateUserAdvancedLp",
+    "DeleteUser",
+    "ReclaimRent",
+    "FillPerpOrder",
+    "RevertFill",
+    "FillSpotOrder",
+    "TriggerOrder",
+    "ForceCancelOrders",
+    "UpdateUserIdle",
+    "UpdateUserOpenOrdersCount",
+    "AdminDisableUpdatePerpBidAskTwap",
+    "SettlePnl",
+    "SettleMultiplePnls",
+    "SettleFundingPayment",
+    "SettleLp",
+    "SettleExpiredMarket",
+    "LiquidatePerp",
+    "LiquidateSpot",
+    "LiquidateBorrowForPerpPnl",
+    "LiquidatePerpPnlForDeposit",
+    "ResolvePerpPnlDeficit",
+    "ResolvePerpBankruptcy",
+    "ResolveSpotBankruptcy",
+    "SettleRevenueToInsuranceFund",
+    "UpdateFundingRate",
+    "UpdatePrelaunchOracle",
+    "UpdatePerpBidAskTwap",
+    "UpdateSpotMarketCumulativeInterest",
+    "UpdateAmms",
+    "UpdateSpotMarketExpiry",
+    "UpdateUserQuoteAssetInsuranceStake",
+    "InitializeInsuranceFundStake",
+    "AddInsuranceFundStake",
+    "RequestRemoveInsuranceFundStake",
+    "CancelRequestRemoveInsuranceFundStake",
+    "RemoveInsuranceFundStake",
+    "TransferProtocolIfShares",
+    "Initialize",
+    "InitializeSpotMarket",
+    "DeleteInitializedSpotMarket",
+    "InitializeSerumFulfillmentConfig",
+    "UpdateSerumFulfillmentConfigStatus",
+    "InitializePhoenixFulfillmentConfig",
+    "PhoenixFulfillmentConfigStatus",
+    "UpdateSerumVault",
+    "InitializePerpMarket",
+    "DeleteInitializedPerpMarket",
+    "MoveAmmPrice",
+    "RecenterPerpMarketAmm",
+    "UpdatePerpMarketAmmSummaryStats",
+    "UpdatePerpMarketExpiry",
+    "SettleExpiredMarketPoolsToRevenuePool",
+    "DepositIntoPerpMarketFeePool",
+    "DepositIntoSpotMarketRevenuePool",
+    "RepegAmmCurve",
+    "UpdatePerpMarketAmmOracleTwap",
+    "ResetPerpMarketAmmOracleTwap",
+    "UpdateK",
+    "UpdatePerpMarketMarginRatio",
+    "UpdatePerpMarketFundingPeriod",
+    "UpdatePerpMarketMaxImbalances",
+    "UpdatePerpMarketLiquidationFee",
+    "UpdateInsuranceFundUnstakingPeriod",
+    "UpdateSpotMarketLiquidationFee",
+    "UpdateWithdrawGuardThreshold",
+    "UpdateSpotMarketIfFactor",
+    "UpdateSpotMarketRevenueSettlePeriod",
+    "UpdateSpotMarketStatus",
+    "UpdateSpotMarketPausedOperations",
+    "UpdateSpotMarketAssetTier",
+    "UpdateSpotMarketMarginWeights",
+    "UpdateSpotMarketBorrowRate",
+    "UpdateSpotMarketMaxTokenDeposits",
+    "UpdateSpotMarketScaleInitialAssetWeightStart",
+    "UpdateSpotMarketOracle",
+    "UpdateSpotMarketStepSizeAndTickSize",
+    "UpdateSpotMarketMinOrderSize",
+    "UpdateSpotMarketOrdersEnabled",
+    "UpdateSpotMarketIfPausedOperations",
+    "UpdateSpotMarketName",
+    "UpdatePerpMarketStatus",
+    "UpdatePerpMarketPausedOperations",
+    "UpdatePerpMarketContractTier",
+    "UpdatePerpMarketImfFactor",
+    "UpdatePerpMarketUnrealizedAssetWeight",
+    "UpdatePerpMarketConcentrationCoef",
+    "UpdatePerpMarketCurveUpdateIntensity",
+    "UpdatePerpMarketTargetBaseAssetAmountPerLp",
+    "UpdatePerpMarketPerLpBase",
+    "UpdateLpCooldownTime",
+    "UpdatePerpFeeStructure",
+    "UpdateSpotFeeStructure",
+    "UpdateInitialPctToLiquidate",
+    "UpdateLiquidationDuration",
+    "UpdateLiquidationMarginBufferRatio",
+    "UpdateOracleGuardRails",
+    "UpdateStateSettlementDuration",
+    "UpdateStateMaxNumberOfSubAccounts",
+    "UpdateStateMaxInitializeUserFee",
+    "UpdatePerpMarketOracle",
+    "UpdatePerpMarketBaseSpread",
+    "UpdateAmmJitIntensity",
+    "UpdatePerpMarketMaxSpread",
+    "UpdatePerpMarketStepSizeAndTickSize",
+    "UpdatePerpMarketName",
+    "UpdatePerpMarketMinOrderSize",
+    "UpdatePerpMarketMaxSlippageRatio",
+    "UpdatePerpMarketMaxFillReserveFraction",
+    "UpdatePerpMarketMaxOpenInterest",
+    "UpdatePerpMarketNumberOfUsers",
+    "UpdatePerpMarketFeeAdjustment",
+    "UpdateSpotMarketFeeAdjustment",
+    "UpdateAdmin",
+    "UpdateWhitelistMint",
+    "UpdateDiscountMint",
+    "UpdateExchangeStatus",
+    "UpdatePerpAuctionDuration",
+    "UpdateSpotAuctionDuration",
+    "InitializeProtocolIfSharesTransferConfig",
+    "UpdateProtocolIfSharesTransferConfig",
+    "InitializePrelaunchOracle",
+    "UpdatePrelaunchOracleParams",
+    "DeletePrelaunchOracle",
+];
+
+impl DriftV2Instruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (discriminator, rest) = input.split_at(8);
+        if let Ok(discriminator) = discriminator.try_into() {
+            return Ok(match discriminator {
+                InitializeUser::DISCRIMINATOR => Self::InitializeUser(borsh::from_slice(rest)?),
+                InitializeUserStats::DISCRIMINATOR => Self::InitializeUserStats,
+                InitializeReferrerName::DISCRIMINATOR => {
+                    Self::InitializeReferrerName(borsh::from_slice(rest)?)
+                }
+                Deposit::DISCRIMINATOR => Self::Deposit(borsh::from_slice(rest)?),
+                Withdraw::DISCRIMINATOR => Self::Withdraw(borsh::from_slice(rest)?),
+                TransferDeposit::DISCRIMINATOR => Self::TransferDeposit(borsh::from_slice(rest)?),
+                PlacePerpOrder::DISCRIMINATOR => Self::PlacePerpOrder(borsh::from_slice(rest)?),
+                CancelOrder::DISCRIMINATOR => Self::CancelOrder(borsh::from_slice(rest)?),
+                CancelOrderByUserId::DISCRIMINATOR => {
+                    Self::CancelOrderByUserId(borsh::from_slice(rest)?)
+                }
+                CancelOrders::DISCRIMINATOR => Self::CancelOrders(borsh::from_slice(rest)?),
+                CancelOrdersByIds::DISCRIMINATOR => {
+                    Self::CancelOrdersByIds(borsh::from_slice(rest)?)
+                }
+                ModifyOrder::DISCRIMINATOR => Self::ModifyOrder(borsh::from_slice(rest)?),
+                ModifyOrderByUserId::DISCRIMINATOR => {
+                    Self::ModifyOrderByUserId(borsh::from_slice(rest)?)
+                }
+                PlaceAndTakePerpOrder::DISCRIMINATOR => {
+                    Self::PlaceAndTakePerpOrder(borsh::from_slice(rest)?)
+                }
+                PlaceAndMakePerpOrder::DISCRIMINATOR => {
+                    Self::PlaceAndMakePerpOrder(borsh::from_slice(rest)?)
+                }
+                PlaceSpotOrder::DISCRIMINATOR => Self::PlaceSpotOrder(borsh::from_slice(rest)?),
+                PlaceAndTakeSpotOrder::DISCRIMINATOR => {
+                    Self::PlaceAndTakeSpotOrder(borsh::from_slice(rest)?)
+                }
+                PlaceAndMakeSpotOrder::DISCRIMINATOR => {
+                    Self::PlaceAndMakeSpotOrder(borsh::from_slice(rest)?)
+                }
+                PlaceOrders::DISCRIMINATOR => Self::PlaceOrders(borsh::from_slice(rest)?),
+                BeginSwap::DISCRIMINATOR => Self::BeginSwap(borsh::from_slice(rest)?),
+                EndSwap::DISCRIMINATOR => Self::EndSwap(borsh::from_slice(rest)?),
+                AddPerpLpShares::DISCRIMINATOR => Self::AddPerpLpShares(borsh::from_slice(rest)?),
+                RemovePerpLpShares::DISCRIMINATOR => {
+                    Self::RemovePerpLpShares(borsh::from_slice(rest)?)
+                }
+                RemovePerpLpSharesInExpiringMarket::DISCRIMINATOR => {
+                    Self::RemovePerpLpSharesInExpiringMarket(borsh::from_slice(rest)?)
+                }
+                UpdateUserName::DISCRIMINATOR => Self::UpdateUserName(borsh::from_slice(rest)?),
+                UpdateUserCustomMarginRatio::DISCRIMINATOR => {
+                    Self::UpdateUserCustomMarginRatio(borsh::from_slice(rest)?)
+                }
+                UpdateUserMarginTradingEnabled::DISCRIMINATOR => {
+                    Self::UpdateUserMarginTradingEnabled(borsh::from_slice(rest)?)
+                }
+                UpdateUserDelegate::DISCRIMINATOR => {
+                    Self::UpdateUserDelegate(borsh::from_slice(rest)?)
+                }
+                UpdateUserReduceOnly::DISCRIMINATOR => {
+                    Self::UpdateUserReduceOnly(borsh::from_slice(rest)?)
+                }
+                UpdateUserAdvancedLp::DISCRIMINATOR => {
+                    Self::UpdateUserAdvancedLp(borsh::from_slice(rest)?)
+                }
+                DeleteUser::DISCRIMINATOR => Self::DeleteUser,
+                ReclaimRent::DISCRIMINATOR => Self::ReclaimRent,
+                FillPerpOrder::DISCRIMINATOR => Self::FillPerpOrder(borsh::from_slice(rest)?),
+                RevertFill::DISCRIMINATOR => Self::RevertFill,
+                FillSpotOrder::DISCRIMINATOR => Self::FillSpotOrder(borsh::from_slice(rest)?),
+                TriggerOrder::DISCRIMINATOR => Self::TriggerOrder(borsh::from_slice(rest)?),
+                ForceCancelOrders::DISCRIMINATOR => Self::ForceCancelOrders,
+                UpdateUserIdle::DISCRIMINATOR => Self::UpdateUserIdle,
+                UpdateUserOpenOrdersCount::DISCRIMINATOR => Self::UpdateUserOpenOrdersCount,
+                AdminDisableUpdatePerpBidAskTwap::DISCRIMINATOR => {
+                    Self::AdminDisableUpdatePerpBidAskTwap(borsh::from_slice(rest)?)
+                }
+                SettlePnl::DISCRIMINATOR => Self::SettlePnl(borsh::from_slice(rest)?),
+                SettleMultiplePnls::DISCRIMINATOR => {
+                    Self::SettleMultiplePnls(borsh::from_slice(rest)?)
+                }
+                SettleFundingPayment::DISCRIMINATOR => Self::SettleFundingPayment,
+                SettleLp::DISCRIMINATOR => Self::SettleLp(borsh::from_slice(rest)?),
+                SettleExpiredMarket::DISCRIMINATOR => {
+                    Self::SettleExpiredMarket(borsh::from_slice(rest)?)
+                }
+                LiquidatePerp::DISCRIMINATOR => Self::LiquidatePerp(borsh::from_slice(rest)?),
+                LiquidateSpot::DISCRIMINATOR => Self::LiquidateSpot(borsh::from_slice(rest)?),
+                LiquidateBorrowForPerpPnl::DISCRIMINATOR => {
+                    Self::LiquidateBorrowForPerpPnl(borsh::from_slice(rest)?)
+                }
+                LiquidatePerpPnlForDeposit::DISCRIMINATOR => {
+                    Self::LiquidatePerpPnlForDeposit(borsh::from_slice(rest)?)
+                }
+                ResolvePerpPnlDeficit::DISCRIMINATOR => {
+                    Self::ResolvePerpPnlDeficit(borsh::from_slice(rest)?)
+                }
+                ResolvePerpBankruptcy::DISCRIMINATOR => {
+                    Self::ResolvePerpBankruptcy(borsh::from_slice(rest)?)
+                }
+                ResolveSpotBankruptcy::DISCRIMINATOR => {
+                    Self::ResolveSpotBankruptcy(borsh::from_slice(rest)?)
+                }
+                SettleRevenueToInsuranceFund::DISCRIMINATOR => {
+                    Self::SettleRevenueToInsuranceFund(borsh::from_slice(rest)?)
+                }
+                UpdateFundingRate::DISCRIMINATOR => {
+                    Self::UpdateFundingRate(borsh::from_slice(rest)?)
+                }
+                UpdatePrelaunchOracle::DISCRIMINATOR => Self::UpdatePrelaunchOracle,
+                UpdatePerpBidAskTwap::DISCRIMINATOR => Self::UpdatePerpBidAskTwap,
+                UpdateSpotMarketCumulativeInterest::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketCumulativeInterest
+                }
+                UpdateAmms::DISCRIMINATOR => Self::UpdateAmms(borsh::from_slice(rest)?),
+                UpdateSpotMarketExpiry::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketExpiry(borsh::from_slice(rest)?)
+                }
+                UpdateUserQuoteAssetInsuranceStake::DISCRIMINATOR => {
+                    Self::UpdateUserQuoteAssetInsuranceStake
+                }
+                InitializeInsuranceFundStake::DISCRIMINATOR => {
+                    Self::InitializeInsuranceFundStake(borsh::from_slice(rest)?)
+                }
+                AddInsuranceFundStake::DISCRIMINATOR => {
+                    Self::AddInsuranceFundStake(borsh::from_slice(rest)?)
+                }
+                RequestRemoveInsuranceFundStake::DISCRIMINATOR => {
+                    Self::RequestRemoveInsuranceFundStake(borsh::from_slice(rest)?)
+                }
+                CancelRequestRemoveInsuranceFundStake::DISCRIMINATOR => {
+                    Self::CancelRequestRemoveInsuranceFundStake(borsh::from_slice(rest)?)
+                }
+                RemoveInsuranceFundStake::DISCRIMINATOR => {
+                    Self::RemoveInsuranceFundStake(borsh::from_slice(rest)?)
+                }
+                TransferProtocolIfShares::DISCRIMINATOR => {
+                    Self::TransferProtocolIfShares(borsh::from_slice(rest)?)
+                }
+                Initialize::DISCRIMINATOR => Self::Initialize,
+                InitializeSpotMarket::DISCRIMINATOR => {
+                    Self::InitializeSpotMarket(borsh::from_slice(rest)?)
+                }
+                DeleteInitializedSpotMarket::DISCRIMINATOR => {
+                    Self::DeleteInitializedSpotMarket(borsh::from_slice(rest)?)
+                }
+                InitializeSerumFulfillmentConfig::DISCRIMINATOR => {
+                    Self::InitializeSerumFulfillmentConfig(borsh::from_slice(rest)?)
+                }
+                UpdateSerumFulfillmentConfigStatus::DISCRIMINATOR => {
+                    Self::UpdateSerumFulfillmentConfigStatus(borsh::from_slice(rest)?)
+                }
+                InitializePhoenixFulfillmentConfig::DISCRIMINATOR => {
+                    Self::InitializePhoenixFulfillmentConfig(borsh::from_slice(rest)?)
+                }
+                PhoenixFulfillmentConfigStatus::DISCRIMINATOR => {
+                    Self::PhoenixFulfillmentConfigStatus(borsh::from_slice(rest)?)
+                }
+                UpdateSerumVault::DISCRIMINATOR => Self::UpdateSerumVault,
+                InitializePerpMarket::DISCRIMINATOR => {
+                    Self::InitializePerpMarket(borsh::from_slice(rest)?)
+                }
+                DeleteInitializedPerpMarket::DISCRIMINATOR => {
+                    Self::DeleteInitializedPerpMarket(borsh::from_slice(rest)?)
+                }
+                MoveAmmPrice::DISCRIMINATOR => Self::MoveAmmPrice(borsh::from_slice(rest)?),
+                RecenterPerpMarketAmm::DISCRIMINATOR => {
+                    Self::RecenterPerpMarketAmm(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketAmmSummaryStats::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketAmmSummaryStats(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketExpiry::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketExpiry(borsh::from_slice(rest)?)
+                }
+                SettleExpiredMarketPoolsToRevenuePool::DISCRIMINATOR => {
+                    Self::SettleExpiredMarketPoolsToRevenuePool
+                }
+                DepositIntoPerpMarketFeePool::DISCRIMINATOR => {
+                    Self::DepositIntoPerpMarketFeePool(borsh::from_slice(rest)?)
+                }
+                DepositIntoSpotMarketRevenuePool::DISCRIMINATOR => {
+                    Self::DepositIntoSpotMarketRevenuePool(borsh::from_slice(rest)?)
+                }
+                RepegAmmCurve::DISCRIMINATOR => Self::RepegAmmCurve(borsh::from_slice(rest)?),
+                UpdatePerpMarketAmmOracleTwap::DISCRIMINATOR => Self::UpdatePerpMarketAmmOracleTwap,
+                ResetPerpMarketAmmOracleTwap::DISCRIMINATOR => Self::ResetPerpMarketAmmOracleTwap,
+                UpdateK::DISCRIMINATOR => Self::UpdateK(borsh::from_slice(rest)?),
+                UpdatePerpMarketMarginRatio::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketMarginRatio(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketFundingPeriod::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketFundingPeriod(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketMaxImbalances::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketMaxImbalances(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketLiquidationFee::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketLiquidationFee(borsh::from_slice(rest)?)
+                }
+                UpdateInsuranceFundUnstakingPeriod::DISCRIMINATOR => {
+                    Self::UpdateInsuranceFundUnstakingPeriod(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketLiquidationFee::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketLiquidationFee(borsh::from_slice(rest)?)
+                }
+                UpdateWithdrawGuardThreshold::DISCRIMINATOR => {
+                    Self::UpdateWithdrawGuardThreshold(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketIfFactor::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketIfFactor(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketRevenueSettlePeriod::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketRevenueSettlePeriod(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketStatus::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketStatus(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketPausedOperations::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketPausedOperations(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketAssetTier::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketAssetTier(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketMarginWeights::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketMarginWeights(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketBorrowRate::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketBorrowRate(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketMaxTokenDeposits::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketMaxTokenDeposits(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketScaleInitialAssetWeightStart::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketScaleInitialAssetWeightStart(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketOracle::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketOracle(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketStepSizeAndTickSize::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketStepSizeAndTickSize(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketMinOrderSize::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketMinOrderSize(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketOrdersEnabled::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketOrdersEnabled(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketIfPausedOperations::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketIfPausedOperations(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketName::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketName(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketStatus::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketStatus(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketPausedOperations::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketPausedOperations(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketContractTier::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketContractTier(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketImfFactor::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketImfFactor(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketUnrealizedAssetWeight::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketUnrealizedAssetWeight(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketConcentrationCoef::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketConcentrationCoef(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketCurveUpdateIntensity::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketCurveUpdateIntensity(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketTargetBaseAssetAmountPerLp::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketTargetBaseAssetAmountPerLp(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketPerLpBase::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketPerLpBase(borsh::from_slice(rest)?)
+                }
+                UpdateLpCooldownTime::DISCRIMINATOR => {
+                    Self::UpdateLpCooldownTime(borsh::from_slice(rest)?)
+                }
+                UpdatePerpFeeStructure::DISCRIMINATOR => {
+                    Self::UpdatePerpFeeStructure(borsh::from_slice(rest)?)
+                }
+                UpdateSpotFeeStructure::DISCRIMINATOR => {
+                    Self::UpdateSpotFeeStructure(borsh::from_slice(rest)?)
+                }
+                UpdateInitialPctToLiquidate::DISCRIMINATOR => {
+                    Self::UpdateInitialPctToLiquidate(borsh::from_slice(rest)?)
+                }
+                UpdateLiquidationDuration::DISCRIMINATOR => {
+                    Self::UpdateLiquidationDuration(borsh::from_slice(rest)?)
+                }
+                UpdateLiquidationMarginBufferRatio::DISCRIMINATOR => {
+                    Self::UpdateLiquidationMarginBufferRatio(borsh::from_slice(rest)?)
+                }
+                UpdateOracleGuardRails::DISCRIMINATOR => {
+                    Self::UpdateOracleGuardRails(borsh::from_slice(rest)?)
+                }
+                UpdateStateSettlementDuration::DISCRIMINATOR => {
+                    Self::UpdateStateSettlementDuration(borsh::from_slice(rest)?)
+                }
+                UpdateStateMaxNumberOfSubAccounts::DISCRIMINATOR => {
+                    Self::UpdateStateMaxNumberOfSubAccounts(borsh::from_slice(rest)?)
+                }
+                UpdateStateMaxInitializeUserFee::DISCRIMINATOR => {
+                    Self::UpdateStateMaxInitializeUserFee(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketOracle::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketOracle(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketBaseSpread::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketBaseSpread(borsh::from_slice(rest)?)
+                }
+                UpdateAmmJitIntensity::DISCRIMINATOR => {
+                    Self::UpdateAmmJitIntensity(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketMaxSpread::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketMaxSpread(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketStepSizeAndTickSize::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketStepSizeAndTickSize(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketName::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketName(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketMinOrderSize::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketMinOrderSize(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketMaxSlippageRatio::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketMaxSlippageRatio(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketMaxFillReserveFraction::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketMaxFillReserveFraction(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketMaxOpenInterest::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketMaxOpenInterest(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketNumberOfUsers::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketNumberOfUsers(borsh::from_slice(rest)?)
+                }
+                UpdatePerpMarketFeeAdjustment::DISCRIMINATOR => {
+                    Self::UpdatePerpMarketFeeAdjustment(borsh::from_slice(rest)?)
+                }
+                UpdateSpotMarketFeeAdjustment::DISCRIMINATOR => {
+                    Self::UpdateSpotMarketFeeAdjustment(borsh::from_slice(rest)?)
+                }
+                UpdateAdmin::DISCRIMINATOR => Self::UpdateAdmin(borsh::from_slice(rest)?),
+                UpdateWhitelistMint::DISCRIMINATOR => {
+                    Self::UpdateWhitelistMint(borsh::from_slice(rest)?)
+                }
+                UpdateDiscountMint::DISCRIMINATOR => {
+                    Self::UpdateDiscountMint(borsh::from_slice(rest)?)
+                }
+                UpdateExchangeStatus::DISCRIMINATOR => {
+                    Self::UpdateExchangeStatus(borsh::from_slice(rest)?)
+                }
+                UpdatePerpAuctionDuration::DISCRIMINATOR => {
+                    Self::UpdatePerpAuctionDuration(borsh::from_slice(rest)?)
+                }
+                UpdateSpotAuctionDuration::DISCRIMINATOR => {
+                    Self::UpdateSpotAuctionDuration(borsh::from_slice(rest)?)
+                }
+                InitializeProtocolIfSharesTransferConfig::DISCRIMINATOR => {
+                    Self::InitializeProtocolIfSharesTransferConfig
+                }
+                UpdateProtocolIfSharesTransferConfig::DISCRIMINATOR => {
+                    Self::UpdateProtocolIfSharesTransferConfig(borsh::from_slice(rest)?)
+                }
+                InitializePrelaunchOracle::DISCRIMINATOR => {
+                    Self::InitializePrelaunchOracle(borsh::from_slice(rest)?)
+                }
+                UpdatePrelaunchOracleParams::DISCRIMINATOR => {
+                    Self::UpdatePrelaunchOracleParams(borsh::from_slice(rest)?)
+                }
+                DeletePrelaunchOracle::DISCRIMINATOR => {
+                    Self::DeletePrelaunchOracle(borsh::from_slice(rest)?)
+                }
+                _ => return Err(ProgramError::InvalidInstructionData),
+            });
+        }
+
+        Err(ProgramError::InvalidInstructionData)
+    }
+
+    /// Serializes the instruction back into its discriminator followed by the borsh encoded
+    /// arguments.
+    pub fn pack(&self) -> Result<Vec<u8>, ProgramError> {
+        match self {
+            Self::InitializeUser(args) => pack_instruction(args),
+            Self::InitializeUserStats => Ok(InitializeUserStats::DISCRIMINATOR.to_vec()),
+            Self::InitializeReferrerName(args) => pack_instruction(args),
+            Self::Deposit(args) => pack_instruction(args),
+            Self::Withdraw(args) => pack_instruction(args),
+            Self::TransferDeposit(args) => pack_instruction(args),
+            Self::PlacePerpOrder(args) => pack_instruction(args),
+            Self::CancelOrder(args) => pack_instruction(args),
+            Self::CancelOrderByUserId(args) => pack_instruction(args),
+            Self::CancelOrders(args) => pack_instruction(args),
+            Self::CancelOrdersByIds(args) => pack_instruction(args),
+            Self::ModifyOrder(args) => pack_instruction(args),
+            Self::ModifyOrderByUserId(args) => pack_instruction(args),
+            Self::PlaceAndTakePerpOrder(args) => pack_instruction(args),
+            Self::PlaceAndMakePerpOrder(args) => pack_instruction(args),
+            Self::PlaceSpotOrder(args) => pack_instruction(args),
+            Self::PlaceAndTakeSpotOrder(args) => pack_instruction(args),
+            Self::PlaceAndMakeSpotOrder(args) => pack_instruction(args),
+            Self::PlaceOrders(args) => pack_instruction(args),
+            Self::BeginSwap(args) => pack_instruction(args),
+            Self::EndSwap(args) => pack_instruction(args),
+            Self::AddPerpLpShares(args) => pack_instruction(args),
+            Self::RemovePerpLpShares(args) => pack_instruction(args),
+            Self::RemovePerpLpSharesInExpiringMarket(args) => pack_instruction(args),
+            Self::UpdateUserName(args) => pack_instruction(args),
+            Self::UpdateUserCustomMarginRatio(args) => pack_instruction(args),
+            Self::UpdateUserMarginTradingEnabled(args) => pack_instruction(args),
+            Self::UpdateUserDelegate(args) => pack_instruction(args),
+            Self::UpdateUserReduceOnly(args) => pack_instruction(args),
+            Self::UpdateUserAdvancedLp(args) => pack_instruction(args),
+            Self::DeleteUser => Ok(DeleteUser::DISCRIMINATOR.to_vec()),
+            Self::ReclaimRent => Ok(ReclaimRent::DISCRIMINATOR.to_vec()),
+            Self::FillPerpOrder(args) => pack_instruction(args),
+            Self::RevertFill => Ok(RevertFill::DISCRIMINATOR.to_vec()),
+            Self::FillSpotOrder(args) => pack_instruction(args),
+            Self::TriggerOrder(args) => pack_instruction(args),
+            Self::ForceCancelOrders => Ok(ForceCancelOrders::DISCRIMINATOR.to_vec()),
+            Self::UpdateUserIdle => Ok(UpdateUserIdle::DISCRIMINATOR.to_vec()),
+            Self::UpdateUserOpenOrdersCount => {
+                Ok(UpdateUserOpenOrdersCount::DISCRIMINATOR.to_vec())
+            }
+            Self::AdminDisableUpdatePerpBidAskTwap(args) => pack_instruction(args),
+            Self::SettlePnl(args) => pack_instruction(args),
+            Self::SettleMultiplePnls(args) => pack_instruction(args),
+            Self::SettleFundingPayment => Ok(SettleFundingPayment::DISCRIMINATOR.to_vec()),
+            Self::SettleLp(args) => pack_instruction(args),
+            Self::SettleExpiredMarket(args) => pack_instruction(args),
+            Self::LiquidatePerp(args) => pack_instruction(args),
+            Self::LiquidateSpot(args) => pack_instruction(args),
+            Self::LiquidateBorrowForPerpPnl(args) => pack_instruction(args),
+            Self::LiquidatePerpPnlForDeposit(args) => pack_instruction(args),
+            Self::ResolvePerpPnlDeficit(args) => pack_instruction(args),
+            Self::ResolvePerpBankruptcy(args) => pack_instruction(args),
+            Self::ResolveSpotBankruptcy(args) => pack_instruction(args),
+            Self::SettleRevenueToInsuranceFund(args) => pack_instruction(args),
+            Self::UpdateFundingRate(args) => pack_instruction(args),
+            Self::UpdatePrelaunchOracle => Ok(UpdatePrelaunchOracle::DISCRIMINATOR.to_vec()),
+            Self::UpdatePerpBidAskTwap => Ok(UpdatePerpBidAskTwap::DISCRIMINATOR.to_vec()),
+            Self::UpdateSpotMarketCumulativeInterest => {
+                Ok(UpdateSpotMarketCumulativeInterest::DISCRIMINATOR.to_vec())
+            }
+            Self::UpdateAmms(args) => pack_instruction(args),
+            Self::UpdateSpotMarketExpiry(args) => pack_instruction(args),
+            Self::UpdateUserQuoteAssetInsuranceStake => {
+                Ok(UpdateUserQuoteAssetInsuranceStake::DISCRIMINATOR.to_vec())
+            }
+            Self::InitializeInsuranceFundStake(args) => pack_instruction(args),
+            Self::AddInsuranceFundStake(args) => pack_instruction(args),
+            Self::RequestRemoveInsuranceFundStake(args) => pack_instruction(args),
+            Self::CancelRequestRemoveInsuranceFundStake(args) => pack_instruction(args),
+            Self::RemoveInsuranceFundStake(args) => pack_instruction(args),
+            Self::TransferProtocolIfShares(args) => pack_instruction(args),
+            Self::Initialize => Ok(Initialize::DISCRIMINATOR.to_vec()),
+            Self::InitializeSpotMarket(args) => pack_instruction(args),
+            Self::DeleteInitializedSpotMarket(args) => pack_instruction(args),
+            Self::InitializeSerumFulfillmentConfig(args) => pack_instruction(args),
+            Self::UpdateSerumFulfillmentConfigStatus(args) => pack_instruction(args),
+            Self::InitializePhoenixFulfillmentConfig(args) => pack_instruction(args),
+            Self::PhoenixFulfillmentConfigStatus(args) => pack_instruction(args),
+            Self::UpdateSerumVault => Ok(UpdateSerumVault::DISCRIMINATOR.to_vec()),
+            Self::InitializePerpMarket(args) => pack_instruction(args),
+            Self::DeleteInitializedPerpMarket(args) => pack_instruction(args),
+            Self::MoveAmmPrice(args) => pack_instruction(args),
+            Self::RecenterPerpMarketAmm(args) => pack_instruction(args),
+            Self::UpdatePerpMarketAmmSummaryStats(args) => pack_instruction(args),
+            Self::UpdatePerpMarketExpiry(args) => pack_instruction(args),
+            Self::SettleExpiredMarketPoolsToRevenuePool => {
+                Ok(SettleExpiredMarketPoolsToRevenuePool::DISCRIMINATOR.to_vec())
+            }
+            Self::DepositIntoPerpMarketFeePool(args) => pack_instruction(args),
+            Self::DepositIntoSpotMarketRevenuePool(args) => pack_instruction(args),
+            Self::RepegAmmCurve(args) => pack_instruction(args),
+            Self::UpdatePerpMarketAmmOracleTwap => {
+                Ok(UpdatePerpMarketAmmOracleTwap::DISCRIMINATOR.to_vec())
+            }
+            Self::ResetPerpMarketAmmOracleTwap => {
+                Ok(ResetPerpMarketAmmOracleTwap::DISCRIMINATOR.to_vec())
+            }
+            Self::UpdateK(args) => pack_instruction(args),
+            Self::UpdatePerpMarketMarginRatio(args) => pack_instruction(args),
+            Self::UpdatePerpMarketFundingPeriod(args) => pack_instruction(args),
+            Self::UpdatePerpMarketMaxImbalances(args) => pack_instruction(args),
+            Self::UpdatePerpMarketLiquidationFee(args) => pack_instruction(args),
+            Self::UpdateInsuranceFundUnstakingPeriod(args) => pack_instruction(args),
+            Self::UpdateSpotMarketLiquidationFee(args) => pack_instruction(args),
+            Self::UpdateWithdrawGuardThreshold(args) => pack_instruction(args),
+            Self::UpdateSpotMarketIfFactor(args) => pack_instruction(args),
+            Self::UpdateSpotMarketRevenueSettlePeriod(args) => pack_instruction(args),
+            Self::UpdateSpotMarketStatus(args) => pack_instruction(args),
+            Self::UpdateSpotMarketPausedOperations(args) => pack_instruction(args),
+            Self::UpdateSpotMarketAssetTier(args) => pack_instruction(args),
+            Self::UpdateSpotMarketMarginWeights(args) => pack_instruction(args),
+            Self::UpdateSpotMarketBorrowRate(args) => pack_instruction(args),
+            Self::UpdateSpotMarketMaxTokenDeposits(args) => pack_instruction(args),
+            Self::UpdateSpotMarketScaleInitialAssetWeightStart(args) => pack_instruction(args),
+            Self::UpdateSpotMarketOracle(args) => pack_instruction(args),
+            Self::UpdateSpotMarketStepSizeAndTickSize(args) => pack_instruction(args),
+            Self::UpdateSpotMarketMinOrderSize(args) => pack_instruction(args),
+            Self::UpdateSpotMarketOrdersEnabled(args) => pack_instruction(args),
+            Self::UpdateSpotMarketIfPausedOperations(args) => pack_instruction(args),
+            Self::UpdateSpotMarketName(args) => pack_instruction(args),
+            Self::UpdatePerpMarketStatus(args) => pack_instruction(args),
+            Self::UpdatePerpMarketPausedOperations(args) => pack_instruction(args),
+            Self::UpdatePerpMarketContractTier(args) => pack_instruction(args),
+            Self::UpdatePerpMarketImfFactor(args) => pack_instruction(args),
+            Self::UpdatePerpMarketUnrealizedAssetWeight(args) => pack_instruction(args),
+            Self::UpdatePerpMarketConcentrationCoef(args) => pack_instruction(args),
+            Self::UpdatePerpMarketCurveUpdateIntensity(args) => pack_instruction(args),
+            Self::UpdatePerpMarketTargetBaseAssetAmountPerLp(args) => pack_instruction(args),
+            Self::UpdatePerpMarketPerLpBase(args) => pack_instruction(args),
+            Self::UpdateLpCooldownTime(args) => pack_instruction(args),
+            Self::UpdatePerpFeeStructure(args) => pack_instruction(args),
+            Self::UpdateSpotFeeStructure(args) => pack_instruction(args),
+            Self::UpdateInitialPctToLiquidate(args) => pack_instruction(args),
+            Self::UpdateLiquidationDuration(args) => pack_instruction(args),
+            Self::UpdateLiquidationMarginBufferRatio(args) => pack_instruction(args),
+            Self::UpdateOracleGuardRails(args) => pack_instruction(args),
+            Self::UpdateStateSettlementDuration(args) => pack_instruction(args),
+            Self::UpdateStateMaxNumberOfSubAccounts(args) => pack_instruction(args),
+            Self::UpdateStateMaxInitializeUserFee(args) => pack_instruction(args),
+            Self::UpdatePerpMarketOracle(args) => pack_instruction(args),
+            Self::UpdatePerpMarketBaseSpread(args) => pack_instruction(args),
+            Self::UpdateAmmJitIntensity(args) => pack_instruction(args),
+            Self::UpdatePerpMarketMaxSpread(args) => pack_instruction(args),
+            Self::UpdatePerpMarketStepSizeAndTickSize(args) => pack_instruction(args),
+            Self::UpdatePerpMarketName(args) => pack_instruction(args),
+            Self::UpdatePerpMarketMinOrderSize(args) => pack_instruction(args),
+            Self::UpdatePerpMarketMaxSlippageRatio(args) => pack_instruction(args),
+            Self::UpdatePerpMarketMaxFillReserveFraction(args) => pack_instruction(args),
+            Self::UpdatePerpMarketMaxOpenInterest(args) => pack_instruction(args),
+            Self::UpdatePerpMarketNumberOfUsers(args) => pack_instruction(args),
+            Self::UpdatePerpMarketFeeAdjustment(args) => pack_instruction(args),
+            Self::UpdateSpotMarketFeeAdjustment(args) => pack_instruction(args),
+            Self::UpdateAdmin(args) => pack_instruction(args),
+            Self::UpdateWhitelistMint(args) => pack_instruction(args),
+            Self::UpdateDiscountMint(args) => pack_instruction(args),
+            Self::UpdateExchangeStatus(args) => pack_instruction(args),
+            Self::UpdatePerpAuctionDuration(args) => pack_instruction(args),
+            Self::UpdateSpotAuctionDuration(args) => pack_instruction(args),
+            Self::InitializeProtocolIfSharesTransferConfig => {
+                Ok(InitializeProtocolIfSharesTransferConfig::DISCRIMINATOR.to_vec())
+            }
+            Self::UpdateProtocolIfSharesTransferConfig(args) => pack_instruction(args),
+            Self::InitializePrelaunchOracle(args) => pack_instruction(args),
+            Self::UpdatePrelaunchOracleParams(args) => pack_instruction(args),
+            Self::DeletePrelaunchOracle(args) => pack_instruction(args),
+        }
+    }
+}
+
+fn pack_instruction<T: BorshSerialize + Discriminator>(args: &T) -> Result<Vec<u8>, ProgramError> {
+    let mut data = T::DISCRIMINATOR.to_vec();
+    args.serialize(&mut data)?;
+    Ok(data)
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct InitializeUser {
+    pub sub_account_id: u16,
+    pub name: [u8; 32],
+}
+
+impl_discriminator!(instruction InitializeUser);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct InitializeUserStats {}
+
+impl_discriminator!(instruction InitializeUserStats);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct InitializeReferrerName {
+    pub name: [u8; 32],
+}
+
+impl_discriminator!(instruction InitializeReferrerName);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct Deposit {
+    pub market_index: u16,
+    pub amount: u64,
+    pub reduce_only: bool,
+}
+
+impl_discriminator!(instruction Deposit);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct Withdraw {
+    pub market_index: u16,
+    pub amount: u64,
+    pub reduce_only: bool,
+}
+
+impl_discriminator!(instruction Withdraw);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct TransferDeposit {
+    pub market_index: u16,
+    pub amount: u64,
+}
+
+impl_discriminator!(instruction TransferDeposit);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PlacePerpOrder {
+    pub params: OrderParams,
+}
+
+impl_discriminator!(instruction PlacePerpOrder);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct CancelOrder {
+    pub order_id: Option<u32>,
+}
+
+impl_discriminator!(instruction CancelOrder);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct CancelOrderByUserId {
+    pub user_order_id: u8,
+}
+
+impl_discriminator!(instruction CancelOrderByUserId);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct CancelOrders {
+    pub market_type: Option<MarketType>,
+    pub market_index: Option<u16>,
+    pub direction: Option<PositionDirection>,
+}
+
+impl_discriminator!(instruction CancelOrders);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct CancelOrdersByIds {
+    pub order_ids: Vec<u32>,
+}
+
+impl_discriminator!(instruction CancelOrdersByIds);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct ModifyOrder {
+    pub order_id: Option<u32>,
+    pub modify_order_params: ModifyOrderParams,
+}
+
+impl_discriminator!(instruction ModifyOrder);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct ModifyOrderByUserId {
+    pub user_order_id: u8,
+    pub modify_order_params: ModifyOrderParams,
+}
+
+impl_discriminator!(instruction ModifyOrderByUserId);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PlaceAndTakePerpOrder {
+    pub params: OrderParams,
+    pub maker_order_id: Option<u32>,
+}
+
+impl_discriminator!(instruction PlaceAndTakePerpOrder);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PlaceAndMakePerpOrder {
+    pub params: OrderParams,
+    pub taker_order_id: u32,
+}
+
+impl_discriminator!(instruction PlaceAndMakePerpOrder);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PlaceSpotOrder {
+    pub params: OrderParams,
+}
+
+impl_discriminator!(instruction PlaceSpotOrder);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PlaceAndTakeSpotOrder {
+    pub params: OrderParams,
+    pub fulfillment_type: Option<SpotFulfillmentType>,
+    pub maker_order_id: Option<u32>,
+}
+
+impl_discriminator!(instruction PlaceAndTakeSpotOrder);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PlaceAndMakeSpotOrder {
+    pub params: OrderParams,
+    pub taker_order_id: u32,
+    pub fulfillment_type: Option<SpotFulfillmentType>,
+}
+
+impl_discriminator!(instruction PlaceAndMakeSpotOrder);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PlaceOrders {
+    pub params: Vec<OrderParams>,
+}
+
+impl_discriminator!(instruction PlaceOrders);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct BeginSwap {
+    pub in_market_index: u16,
+    pub out_market_index: u16,
+    pub amount_in: u64,
+}
+
+impl_discriminator!(instruction BeginSwap);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct EndSwap {
+    pub in_market_index: u16,
+    pub out_market_index: u16,
+    pub limit_price: Option<u64>,
+    pub reduce_only: Option<SwapReduceOnly>,
+}
+
+impl_discriminator!(instruction EndSwap);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct AddPerpLpShares {
+    pub n_shares: u64,
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction AddPerpLpShares);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct RemovePerpLpShares {
+    pub shares_to_burn: u64,
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction RemovePerpLpShares);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct RemovePerpLpSharesInExpiringMarket {
+    pub shares_to_burn: u64,
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction RemovePerpLpSharesInExpiringMarket);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateUserName {
+    pub sub_account_id: u16,
+    pub name: [u8; 32],
+}
+
+impl_discriminator!(instruction UpdateUserName);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateUserCustomMarginRatio {
+    pub sub_account_id: u16,
+    pub margin_ratio: u32,
+}
+
+impl_discriminator!(instruction UpdateUserCustomMarginRatio);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateUserMarginTradingEnabled {
+    pub sub_account_id: u16,
+    pub margin_trading_enabled: bool,
+}
+
+impl_discriminator!(instruction UpdateUserMarginTradingEnabled);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateUserDelegate {
+    pub sub_account_id: u16,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub delegate: Pubkey,
+}
+
+impl_discriminator!(instruction UpdateUserDelegate);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateUserReduceOnly {
+    pub sub_account_id: u16,
+    pub reduce_only: bool,
+}
+
+impl_discriminator!(instruction UpdateUserReduceOnly);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateUserAdvancedLp {
+    pub sub_account_id: u16,
+    pub advanced_lp: bool,
+}
+
+impl_discriminator!(instruction UpdateUserAdvancedLp);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct DeleteUser {}
+
+impl_discriminator!(instruction DeleteUser);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct ReclaimRent {}
+
+impl_discriminator!(instruction ReclaimRent);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct FillPerpOrder {
+    pub order_id: Option<u32>,
+    pub maker_order_id: Option<u32>,
+}
+
+impl_discriminator!(instruction FillPerpOrder);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct RevertFill {}
+
+impl_discriminator!(instruction RevertFill);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct FillSpotOrder {
+    pub order_id: Option<u32>,
+    pub fulfillment_type: Option<SpotFulfillmentType>,
+    pub maker_order_id: Option<u32>,
+}
+
+impl_discriminator!(instruction FillSpotOrder);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct TriggerOrder {
+    pub order_id: u32,
+}
+
+impl_discriminator!(instruction TriggerOrder);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct ForceCancelOrders {}
+
+impl_discriminator!(instruction ForceCancelOrders);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateUserIdle {}
+
+impl_discriminator!(instruction UpdateUserIdle);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateUserOpenOrdersCount {}
+
+impl_discriminator!(instruction UpdateUserOpenOrdersCount);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct AdminDisableUpdatePerpBidAskTwap {
+    pub disable: bool,
+}
+
+impl_discriminator!(instruction AdminDisableUpdatePerpBidAskTwap);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct SettlePnl {
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction SettlePnl);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct SettleMultiplePnls {
+    pub market_indexes: Vec<u16>,
+    pub mode: SettlePnlMode,
+}
+
+impl_discriminator!(instruction SettleMultiplePnls);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct SettleFundingPayment {}
+
+impl_discriminator!(instruction SettleFundingPayment);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct SettleLp {
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction SettleLp);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct SettleExpiredMarket {
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction SettleExpiredMarket);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct LiquidatePerp {
+    pub market_index: u16,
+    pub liquidator_max_base_asset_amount: u64,
+    pub limit_price: Option<u64>,
+}
+
+impl_discriminator!(instruction LiquidatePerp);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct LiquidateSpot {
+    pub asset_market_index: u16,
+    pub liability_market_index: u16,
+    pub liquidator_max_liability_transfer: u128,
+    pub limit_price: Option<u64>,
+}
+
+impl_discriminator!(instruction LiquidateSpot);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct LiquidateBorrowForPerpPnl {
+    pub perp_market_index: u16,
+    pub spot_market_index: u16,
+    pub liquidator_max_liability_transfer: u128,
+    pub limit_price: Option<u64>,
+}
+
+impl_discriminator!(instruction LiquidateBorrowForPerpPnl);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct LiquidatePerpPnlForDeposit {
+    pub perp_market_index: u16,
+    pub spot_market_index: u16,
+    pub liquidator_max_pnl_transfer: u128,
+    pub limit_price: Option<u64>,
+}
+
+impl_discriminator!(instruction LiquidatePerpPnlForDeposit);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct ResolvePerpPnlDeficit {
+    pub spot_market_index: u16,
+    pub perp_market_index: u16,
+}
+
+impl_discriminator!(instruction ResolvePerpPnlDeficit);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct ResolvePerpBankruptcy {
+    pub quote_spot_market_index: u16,
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction ResolvePerpBankruptcy);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct ResolveSpotBankruptcy {
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction ResolveSpotBankruptcy);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct SettleRevenueToInsuranceFund {
+    pub spot_market_index: u16,
+}
+
+impl_discriminator!(instruction SettleRevenueToInsuranceFund);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateFundingRate {
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction UpdateFundingRate);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePrelaunchOracle {}
+
+impl_discriminator!(instruction UpdatePrelaunchOracle);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpBidAskTwap {}
+
+impl_discriminator!(instruction UpdatePerpBidAskTwap);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketCumulativeInterest {}
+
+impl_discriminator!(instruction UpdateSpotMarketCumulativeInterest);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateAmms {
+    pub market_indexes: [u16; 5],
+}
+
+impl_discriminator!(instruction UpdateAmms);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketExpiry {
+    pub expiry_ts: i64,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketExpiry);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateUserQuoteAssetInsuranceStake {}
+
+impl_discriminator!(instruction UpdateUserQuoteAssetInsuranceStake);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct InitializeInsuranceFundStake {
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction InitializeInsuranceFundStake);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct AddInsuranceFundStake {
+    pub market_index: u16,
+    pub amount: u64,
+}
+
+impl_discriminator!(instruction AddInsuranceFundStake);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct RequestRemoveInsuranceFundStake {
+    pub market_index: u16,
+    pub amount: u64,
+}
+
+impl_discriminator!(instruction RequestRemoveInsuranceFundStake);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct CancelRequestRemoveInsuranceFundStake {
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction CancelRequestRemoveInsuranceFundStake);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct RemoveInsuranceFundStake {
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction RemoveInsuranceFundStake);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct TransferProtocolIfShares {
+    pub market_index: u16,
+    pub shares: u128,
+}
+
+impl_discriminator!(instruction TransferProtocolIfShares);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct Initialize {}
+
+impl_discriminator!(instruction Initialize);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct InitializeSpotMarket {
+    pub optimal_utilization: u32,
+    pub optimal_borrow_rate: u32,
+    pub max_borrow_rate: u32,
+    pub oracle_source: OracleSource,
+    pub initial_asset_weight: u32,
+    pub maintenance_asset_weight: u32,
+    pub initial_liability_weight: u32,
+    pub maintenance_liability_weight: u32,
+    pub imf_factor: u32,
+    pub liquidator_fee: u32,
+    pub if_liquidation_fee: u32,
+    pub active_status: bool,
+    pub asset_tier: AssetTier,
+    pub scale_initial_asset_weight_start: u64,
+    pub withdraw_guard_threshold: u64,
+    pub order_tick_size: u64,
+    pub order_step_size: u64,
+    pub if_total_factor: u32,
+    pub name: [u8; 32],
+}
+
+impl_discriminator!(instruction InitializeSpotMarket);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct DeleteInitializedSpotMarket {
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction DeleteInitializedSpotMarket);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct InitializeSerumFulfillmentConfig {
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction InitializeSerumFulfillmentConfig);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSerumFulfillmentConfigStatus {
+    pub status: SpotFulfillmentConfigStatus,
+}
+
+impl_discriminator!(instruction UpdateSerumFulfillmentConfigStatus);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct InitializePhoenixFulfillmentConfig {
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction InitializePhoenixFulfillmentConfig);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PhoenixFulfillmentConfigStatus {
+    pub status: SpotFulfillmentConfigStatus,
+}
+
+impl_discriminator!(instruction PhoenixFulfillmentConfigStatus);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSerumVault {}
+
+impl_discriminator!(instruction UpdateSerumVault);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct InitializePerpMarket {
+    pub market_index: u16,
+    pub amm_base_asset_reserve: u128,
+    pub amm_quote_asset_reserve: u128,
+    pub amm_periodicity: i64,
+    pub amm_peg_multiplier: u128,
+    pub oracle_source: OracleSource,
+    pub contract_tier: ContractTier,
+    pub margin_ratio_initial: u32,
+    pub margin_ratio_maintenance: u32,
+    pub liquidator_fee: u32,
+    pub if_liquidation_fee: u32,
+    pub imf_factor: u32,
+    pub active_status: bool,
+    pub base_spread: u32,
+    pub max_spread: u32,
+    pub max_open_interest: u128,
+    pub max_revenue_withdraw_per_period: u64,
+    pub quote_max_insurance: u64,
+    pub order_step_size: u64,
+    pub order_tick_size: u64,
+    pub min_order_size: u64,
+    pub concentration_coef_scale: u128,
+    pub curve_update_intensity: u8,
+    pub amm_jit_intensity: u8,
+    pub name: [u8; 32],
+}
+
+impl_discriminator!(instruction InitializePerpMarket);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct DeleteInitializedPerpMarket {
+    pub market_index: u16,
+}
+
+impl_discriminator!(instruction DeleteInitializedPerpMarket);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct MoveAmmPrice {
+    pub base_asset_reserve: u128,
+    pub quote_asset_reserve: u128,
+    pub sqrt_k: u128,
+}
+
+impl_discriminator!(instruction MoveAmmPrice);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct RecenterPerpMarketAmm {
+    pub peg_multiplier: u128,
+    pub sqrt_k: u128,
+}
+
+impl_discriminator!(instruction RecenterPerpMarketAmm);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketAmmSummaryStats {
+    pub params: UpdatePerpMarketSummaryStatsParams,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketAmmSummaryStats);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketExpiry {
+    pub expiry_ts: i64,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketExpiry);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct SettleExpiredMarketPoolsToRevenuePool {}
+
+impl_discriminator!(instruction SettleExpiredMarketPoolsToRevenuePool);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct DepositIntoPerpMarketFeePool {
+    pub amount: u64,
+}
+
+impl_discriminator!(instruction DepositIntoPerpMarketFeePool);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct DepositIntoSpotMarketRevenuePool {
+    pub amount: u64,
+}
+
+impl_discriminator!(instruction DepositIntoSpotMarketRevenuePool);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct RepegAmmCurve {
+    pub new_peg_candidate: u128,
+}
+
+impl_discriminator!(instruction RepegAmmCurve);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketAmmOracleTwap {}
+
+impl_discriminator!(instruction UpdatePerpMarketAmmOracleTwap);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct ResetPerpMarketAmmOracleTwap {}
+
+impl_discriminator!(instruction ResetPerpMarketAmmOracleTwap);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateK {
+    pub sqrt_k: u128,
+}
+
+impl_discriminator!(instruction UpdateK);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketMarginRatio {
+    pub margin_ratio_initial: u32,
+    pub margin_ratio_maintenance: u32,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketMarginRatio);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketFundingPeriod {
+    pub funding_period: i64,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketFundingPeriod);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketMaxImbalances {
+    pub unrealized_max_imbalance: u64,
+    pub max_revenue_withdraw_per_period: u64,
+    pub quote_max_insurance: u64,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketMaxImbalances);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketLiquidationFee {
+    pub liquidator_fee: u32,
+    pub if_liquidation_fee: u32,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketLiquidationFee);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateInsuranceFundUnstakingPeriod {
+    pub insurance_fund_unstaking_period: i64,
+}
+
+impl_discriminator!(instruction UpdateInsuranceFundUnstakingPeriod);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketLiquidationFee {
+    pub liquidator_fee: u32,
+    pub if_liquidation_fee: u32,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketLiquidationFee);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateWithdrawGuardThreshold {
+    pub withdraw_guard_threshold: u64,
+}
+
+impl_discriminator!(instruction UpdateWithdrawGuardThreshold);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketIfFactor {
+    pub spot_market_index: u16,
+    pub user_if_factor: u32,
+    pub total_if_factor: u32,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketIfFactor);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketRevenueSettlePeriod {
+    pub revenue_settle_period: i64,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketRevenueSettlePeriod);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketStatus {
+    pub status: MarketStatus,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketStatus);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketPausedOperations {
+    pub paused_operations: u8,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketPausedOperations);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketAssetTier {
+    pub asset_tier: AssetTier,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketAssetTier);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketMarginWeights {
+    pub initial_asset_weight: u32,
+    pub maintenance_asset_weight: u32,
+    pub initial_liability_weight: u32,
+    pub maintenance_liability_weight: u32,
+    pub imf_factor: u32,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketMarginWeights);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketBorrowRate {
+    pub optimal_utilization: u32,
+    pub optimal_borrow_rate: u32,
+    pub max_borrow_rate: u32,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketBorrowRate);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketMaxTokenDeposits {
+    pub max_token_deposits: u64,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketMaxTokenDeposits);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketScaleInitialAssetWeightStart {
+    pub scale_initial_asset_weight_start: u64,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketScaleInitialAssetWeightStart);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketOracle {
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub oracle: Pubkey,
+    pub oracle_source: OracleSource,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketOracle);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketStepSizeAndTickSize {
+    pub step_size: u64,
+    pub tick_size: u64,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketStepSizeAndTickSize);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketMinOrderSize {
+    pub order_size: u64,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketMinOrderSize);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketOrdersEnabled {
+    pub orders_enabled: bool,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketOrdersEnabled);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketIfPausedOperations {
+    pub paused_operations: u8,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketIfPausedOperations);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketName {
+    pub name: [u8; 32],
+}
+
+impl_discriminator!(instruction UpdateSpotMarketName);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketStatus {
+    pub status: MarketStatus,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketStatus);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketPausedOperations {
+    pub paused_operations: u8,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketPausedOperations);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketContractTier {
+    pub contract_tier: ContractTier,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketContractTier);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketImfFactor {
+    pub imf_factor: u32,
+    pub unrealized_pnl_imf_factor: u32,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketImfFactor);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketUnrealizedAssetWeight {
+    pub unrealized_initial_asset_weight: u32,
+    pub unrealized_maintenance_asset_weight: u32,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketUnrealizedAssetWeight);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketConcentrationCoef {
+    pub concentration_scale: u128,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketConcentrationCoef);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketCurveUpdateIntensity {
+    pub curve_update_intensity: u8,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketCurveUpdateIntensity);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketTargetBaseAssetAmountPerLp {
+    pub target_base_asset_amount_per_lp: i32,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketTargetBaseAssetAmountPerLp);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketPerLpBase {
+    pub per_lp_base: i8,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketPerLpBase);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateLpCooldownTime {
+    pub lp_cooldown_time: u64,
+}
+
+impl_discriminator!(instruction UpdateLpCooldownTime);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpFeeStructure {
+    pub fee_structure: FeeStructure,
+}
+
+impl_discriminator!(instruction UpdatePerpFeeStructure);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotFeeStructure {
+    pub fee_structure: FeeStructure,
+}
+
+impl_discriminator!(instruction UpdateSpotFeeStructure);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateInitialPctToLiquidate {
+    pub initial_pct_to_liquidate: u16,
+}
+
+impl_discriminator!(instruction UpdateInitialPctToLiquidate);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateLiquidationDuration {
+    pub liquidation_duration: u8,
+}
+
+impl_discriminator!(instruction UpdateLiquidationDuration);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateLiquidationMarginBufferRatio {
+    pub liquidation_margin_buffer_ratio: u32,
+}
+
+impl_discriminator!(instruction UpdateLiquidationMarginBufferRatio);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateOracleGuardRails {
+    pub oracle_guard_rails: OracleGuardRails,
+}
+
+impl_discriminator!(instruction UpdateOracleGuardRails);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateStateSettlementDuration {
+    pub settlement_duration: u16,
+}
+
+impl_discriminator!(instruction UpdateStateSettlementDuration);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateStateMaxNumberOfSubAccounts {
+    pub max_number_of_sub_accounts: u16,
+}
+
+impl_discriminator!(instruction UpdateStateMaxNumberOfSubAccounts);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateStateMaxInitializeUserFee {
+    pub max_initialize_user_fee: u16,
+}
+
+impl_discriminator!(instruction UpdateStateMaxInitializeUserFee);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketOracle {
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub oracle: Pubkey,
+    pub oracle_source: OracleSource,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketOracle);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketBaseSpread {
+    pub base_spread: u32,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketBaseSpread);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateAmmJitIntensity {
+    pub amm_jit_intensity: u8,
+}
+
+impl_discriminator!(instruction UpdateAmmJitIntensity);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketMaxSpread {
+    pub max_spread: u32,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketMaxSpread);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketStepSizeAndTickSize {
+    pub step_size: u64,
+    pub tick_size: u64,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketStepSizeAndTickSize);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketName {
+    pub name: [u8; 32],
+}
+
+impl_discriminator!(instruction UpdatePerpMarketName);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketMinOrderSize {
+    pub order_size: u64,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketMinOrderSize);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketMaxSlippageRatio {
+    pub max_slippage_ratio: u16,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketMaxSlippageRatio);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketMaxFillReserveFraction {
+    pub max_fill_reserve_fraction: u16,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketMaxFillReserveFraction);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketMaxOpenInterest {
+    pub max_open_interest: u128,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketMaxOpenInterest);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketNumberOfUsers {
+    pub number_of_users: Option<u32>,
+    pub number_of_users_with_base: Option<u32>,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketNumberOfUsers);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketFeeAdjustment {
+    pub fee_adjustment: i16,
+}
+
+impl_discriminator!(instruction UpdatePerpMarketFeeAdjustment);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotMarketFeeAdjustment {
+    pub fee_adjustment: i16,
+}
+
+impl_discriminator!(instruction UpdateSpotMarketFeeAdjustment);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateAdmin {
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub admin: Pubkey,
+}
+
+impl_discriminator!(instruction UpdateAdmin);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateWhitelistMint {
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub whitelist_mint: Pubkey,
+}
+
+impl_discriminator!(instruction UpdateWhitelistMint);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateDiscountMint {
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub discount_mint: Pubkey,
+}
+
+impl_discriminator!(instruction UpdateDiscountMint);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateExchangeStatus {
+    pub exchange_status: u8,
+}
+
+impl_discriminator!(instruction UpdateExchangeStatus);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpAuctionDuration {
+    pub min_perp_auction_duration: u8,
+}
+
+impl_discriminator!(instruction UpdatePerpAuctionDuration);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateSpotAuctionDuration {
+    pub default_spot_auction_duration: u8,
+}
+
+impl_discriminator!(instruction UpdateSpotAuctionDuration);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct InitializeProtocolIfSharesTransferConfig {}
+
+impl_discriminator!(instruction InitializeProtocolIfSharesTransferConfig);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdateProtocolIfSharesTransferConfig {
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::option_pubkeys)
+    )]
+    pub whitelisted_signers: Option<[Pubkey; 4]>,
+    pub max_transfer_per_epoch: Option<u128>,
+}
+
+impl_discriminator!(instruction UpdateProtocolIfSharesTransferConfig);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct InitializePrelaunchOracle {
+    pub params: PrelaunchOracleParams,
+}
+
+impl_discriminator!(instruction InitializePrelaunchOracle);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePrelaunchOracleParams {
+    pub params: PrelaunchOracleParams,
+}
+
+impl_discriminator!(instruction UpdatePrelaunchOracleParams);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct DeletePrelaunchOracle {
+    pub perp_market_index: u16,
+}
+
+impl_discriminator!(instruction DeletePrelaunchOracle);
+
+// Instruction accounts
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializeUserAccounts {
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub payer: Pubkey,
+    pub rent: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl ToAccountMetas for InitializeUserAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.user, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new(self.state, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new_readonly(self.rent, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for InitializeUser {
+    type Accounts = InitializeUserAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializeUserStatsAccounts {
+    pub user_stats: Pubkey,
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub payer: Pubkey,
+    pub rent: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl ToAccountMetas for InitializeUserStatsAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new(self.state, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new_readonly(self.rent, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for InitializeUserStats {
+    type Accounts = InitializeUserStatsAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializeReferrerNameAccounts {
+    pub referrer_name: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub authority: Pubkey,
+    pub payer: Pubkey,
+    pub rent: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl ToAccountMetas for InitializeReferrerNameAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.referrer_name, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new_readonly(self.rent, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for InitializeReferrerName {
+    type Accounts = InitializeReferrerNameAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DepositAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub authority: Pubkey,
+    pub spot_market_vault: Pubkey,
+    pub user_token_account: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl ToAccountMetas for DepositAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.spot_market_vault, false),
+            AccountMeta::new(self.user_token_account, false),
+            AccountMeta::new_readonly(self.token_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for Deposit {
+    type Accounts = DepositAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct WithdrawAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub authority: Pubkey,
+    pub spot_market_vault: Pubkey,
+    pub drift_signer: Pubkey,
+    pub user_token_account: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl ToAccountMetas for WithdrawAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.spot_market_vault, false),
+            AccountMeta::new_readonly(self.drift_signer, false),
+            AccountMeta::new(self.user_token_account, false),
+            AccountMeta::new_readonly(self.token_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for Withdraw {
+    type Accounts = WithdrawAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct TransferDepositAccounts {
+    pub from_user: Pubkey,
+    pub to_user: Pubkey,
+    pub user_stats: Pubkey,
+    pub authority: Pubkey,
+    pub state: Pubkey,
+    pub spot_market_vault: Pubkey,
+}
+
+impl ToAccountMetas for TransferDepositAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.from_user, false),
+            AccountMeta::new(self.to_user, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market_vault, false),
+        ]
+    }
+}
+
+impl InstructionArgs for TransferDeposit {
+    type Accounts = TransferDepositAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlacePerpOrderAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for PlacePerpOrderAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for PlacePerpOrder {
+    type Accounts = PlacePerpOrderAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CancelOrderAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for CancelOrderAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for CancelOrder {
+    type Accounts = CancelOrderAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CancelOrderByUserIdAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for CancelOrderByUserIdAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for CancelOrderByUserId {
+    type Accounts = CancelOrderByUserIdAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CancelOrdersAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for CancelOrdersAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for CancelOrders {
+    type Accounts = CancelOrdersAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CancelOrdersByIdsAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for CancelOrdersByIdsAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for CancelOrdersByIds {
+    type Accounts = CancelOrdersByIdsAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ModifyOrderAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for ModifyOrderAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for ModifyOrder {
+    type Accounts = ModifyOrderAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ModifyOrderByUserIdAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for ModifyOrderByUserIdAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.user, false),
+            AccountMeta::new_readonly(self.authority, false),
+        ]
+    }
+}
+
+impl InstructionArgs for ModifyOrderByUserId {
+    type Accounts = ModifyOrderByUserIdAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceAndTakePerpOrderAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for PlaceAndTakePerpOrderAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for PlaceAndTakePerpOrder {
+    type Accounts = PlaceAndTakePerpOrderAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceAndMakePerpOrderAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub taker: Pubkey,
+    pub taker_stats: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for PlaceAndMakePerpOrderAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new(self.taker, false),
+            AccountMeta::new(self.taker_stats, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for PlaceAndMakePerpOrder {
+    type Accounts = PlaceAndMakePerpOrderAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceSpotOrderAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for PlaceSpotOrderAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for PlaceSpotOrder {
+    type Accounts = PlaceSpotOrderAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceAndTakeSpotOrderAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for PlaceAndTakeSpotOrderAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.user, false),
+            AccountMeta::new_readonly(self.user_stats, false),
+            AccountMeta::new_readonly(self.authority, false),
+        ]
+    }
+}
+
+impl InstructionArgs for PlaceAndTakeSpotOrder {
+    type Accounts = PlaceAndTakeSpotOrderAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceAndMakeSpotOrderAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub taker: Pubkey,
+    pub taker_stats: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for PlaceAndMakeSpotOrderAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.user, false),
+            AccountMeta::new_readonly(self.user_stats, false),
+            AccountMeta::new_readonly(self.taker, false),
+            AccountMeta::new_readonly(self.taker_stats, false),
+            AccountMeta::new_readonly(self.authority, false),
+        ]
+    }
+}
+
+impl InstructionArgs for PlaceAndMakeSpotOrder {
+    type Accounts = PlaceAndMakeSpotOrderAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceOrdersAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for PlaceOrdersAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for PlaceOrders {
+    type Accounts = PlaceOrdersAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct BeginSwapAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub authority: Pubkey,
+    pub out_spot_market_vault: Pubkey,
+    pub in_spot_market_vault: Pubkey,
+    pub out_token_account: Pubkey,
+    pub in_token_account: Pubkey,
+    pub token_program: Pubkey,
+    pub drift_signer: Pubkey,
+    /// Instructions Sysvar for instruction introspection
+    pub instructions: Pubkey,
+}
+
+impl ToAccountMetas for BeginSwapAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.out_spot_market_vault, false),
+            AccountMeta::new(self.in_spot_market_vault, false),
+            AccountMeta::new(self.out_token_account, false),
+            AccountMeta::new(self.in_token_account, false),
+            AccountMeta::new_readonly(self.token_program, false),
+            AccountMeta::new_readonly(self.drift_signer, false),
+            AccountMeta::new_readonly(self.instructions, false),
+        ]
+    }
+}
+
+impl InstructionArgs for BeginSwap {
+    type Accounts = BeginSwapAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct EndSwapAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub authority: Pubkey,
+    pub out_spot_market_vault: Pubkey,
+    pub in_spot_market_vault: Pubkey,
+    pub out_token_account: Pubkey,
+    pub in_token_account: Pubkey,
+    pub token_program: Pubkey,
+    pub drift_signer: Pubkey,
+    /// Instructions Sysvar for instruction introspection
+    pub instructions: Pubkey,
+}
+
+impl ToAccountMetas for EndSwapAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.out_spot_market_vault, false),
+            AccountMeta::new(self.in_spot_market_vault, false),
+            AccountMeta::new(self.out_token_account, false),
+            AccountMeta::new(self.in_token_account, false),
+            AccountMeta::new_readonly(self.token_program, false),
+            AccountMeta::new_readonly(self.drift_signer, false),
+            AccountMeta::new_readonly(self.instructions, false),
+        ]
+    }
+}
+
+impl InstructionArgs for EndSwap {
+    type Accounts = EndSwapAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct AddPerpLpSharesAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for AddPerpLpSharesAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for AddPerpLpShares {
+    type Accounts = AddPerpLpSharesAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RemovePerpLpSharesAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for RemovePerpLpSharesAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for RemovePerpLpShares {
+    type Accounts = RemovePerpLpSharesAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RemovePerpLpSharesInExpiringMarketAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+}
+
+impl ToAccountMetas for RemovePerpLpSharesInExpiringMarketAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.user, false),
+        ]
+    }
+}
+
+impl InstructionArgs for RemovePerpLpSharesInExpiringMarket {
+    type Accounts = RemovePerpLpSharesInExpiringMarketAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateUserNameAccounts {
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for UpdateUserNameAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.user, false),
+            AccountMeta::new_readonly(self.authority, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateUserName {
+    type Accounts = UpdateUserNameAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateUserCustomMarginRatioAccounts {
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for UpdateUserCustomMarginRatioAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.user, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateUserCustomMarginRatio {
+    type Accounts = UpdateUserCustomMarginRatioAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateUserMarginTradingEnabledAccounts {
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for UpdateUserMarginTradingEnabledAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.user, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateUserMarginTradingEnabled {
+    type Accounts = UpdateUserMarginTradingEnabledAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateUserDelegateAccounts {
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for UpdateUserDelegateAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.user, false),
+            AccountMeta::new_readonly(self.authority, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateUserDelegate {
+    type Accounts = UpdateUserDelegateAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateUserReduceOnlyAccounts {
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for UpdateUserReduceOnlyAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.user, false),
+            AccountMeta::new_readonly(self.authority, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateUserReduceOnly {
+    type Accounts = UpdateUserReduceOnlyAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateUserAdvancedLpAccounts {
+    pub user: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for UpdateUserAdvancedLpAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.user, false),
+            AccountMeta::new_readonly(self.authority, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateUserAdvancedLp {
+    type Accounts = UpdateUserAdvancedLpAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DeleteUserAccounts {
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub state: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for DeleteUserAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.user, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new(self.state, false),
+            AccountMeta::new(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for DeleteUser {
+    type Accounts = DeleteUserAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ReclaimRentAccounts {
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub rent: Pubkey,
+}
+
+impl ToAccountMetas for ReclaimRentAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.user, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new_readonly(self.rent, false),
+        ]
+    }
+}
+
+impl InstructionArgs for ReclaimRent {
+    type Accounts = ReclaimRentAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct FillPerpOrderAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub filler: Pubkey,
+    pub filler_stats: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+}
+
+impl ToAccountMetas for FillPerpOrderAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.filler, false),
+            AccountMeta::new(self.filler_stats, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new(self.user_stats, false),
+        ]
+    }
+}
+
+impl InstructionArgs for FillPerpOrder {
+    type Accounts = FillPerpOrderAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RevertFillAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub filler: Pubkey,
+    pub filler_stats: Pubkey,
+}
+
+impl ToAccountMetas for RevertFillAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.filler, false),
+            AccountMeta::new(self.filler_stats, false),
+        ]
+    }
+}
+
+impl InstructionArgs for RevertFill {
+    type Accounts = RevertFillAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct FillSpotOrderAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub filler: Pubkey,
+    pub filler_stats: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+}
+
+impl ToAccountMetas for FillSpotOrderAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.filler, false),
+            AccountMeta::new(self.filler_stats, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new(self.user_stats, false),
+        ]
+    }
+}
+
+impl InstructionArgs for FillSpotOrder {
+    type Accounts = FillSpotOrderAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct TriggerOrderAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub filler: Pubkey,
+    pub user: Pubkey,
+}
+
+impl ToAccountMetas for TriggerOrderAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.filler, false),
+            AccountMeta::new(self.user, false),
+        ]
+    }
+}
+
+impl InstructionArgs for TriggerOrder {
+    type Accounts = TriggerOrderAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ForceCancelOrdersAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub filler: Pubkey,
+    pub user: Pubkey,
+}
+
+impl ToAccountMetas for ForceCancelOrdersAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, false),
+            AccountMeta::new_readonly(self.filler, false),
+            AccountMeta::new_readonly(self.user, false),
+        ]
+    }
+}
+
+impl InstructionArgs for ForceCancelOrders {
+    type Accounts = ForceCancelOrdersAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateUserIdleAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub filler: Pubkey,
+    pub user: Pubkey,
+}
+
+impl ToAccountMetas for UpdateUserIdleAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.filler, false),
+            AccountMeta::new(self.user, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateUserIdle {
+    type Accounts = UpdateUserIdleAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateUserOpenOrdersCountAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub filler: Pubkey,
+    pub user: Pubkey,
+}
+
+impl ToAccountMetas for UpdateUserOpenOrdersCountAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, false),
+            AccountMeta::new_readonly(self.filler, false),
+            AccountMeta::new_readonly(self.user, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateUserOpenOrdersCount {
+    type Accounts = UpdateUserOpenOrdersCountAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct AdminDisableUpdatePerpBidAskTwapAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub user_stats: Pubkey,
+}
+
+impl ToAccountMetas for AdminDisableUpdatePerpBidAskTwapAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.user_stats, false),
+        ]
+    }
+}
+
+impl InstructionArgs for AdminDisableUpdatePerpBidAskTwap {
+    type Accounts = AdminDisableUpdatePerpBidAskTwapAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SettlePnlAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub authority: Pubkey,
+    pub spot_market_vault: Pubkey,
+}
+
+impl ToAccountMetas for SettlePnlAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new_readonly(self.spot_market_vault, false),
+        ]
+    }
+}
+
+impl InstructionArgs for SettlePnl {
+    type Accounts = SettlePnlAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SettleMultiplePnlsAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+    pub authority: Pubkey,
+    pub spot_market_vault: Pubkey,
+}
+
+impl ToAccountMetas for SettleMultiplePnlsAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new_readonly(self.spot_market_vault, false),
+        ]
+    }
+}
+
+impl InstructionArgs for SettleMultiplePnls {
+    type Accounts = SettleMultiplePnlsAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SettleFundingPaymentAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+}
+
+impl ToAccountMetas for SettleFundingPaymentAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+        ]
+    }
+}
+
+impl InstructionArgs for SettleFundingPayment {
+    type Accounts = SettleFundingPaymentAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SettleLpAccounts {
+    pub state: Pubkey,
+    pub user: Pubkey,
+}
+
+impl ToAccountMetas for SettleLpAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.user, false),
+        ]
+    }
+}
+
+impl InstructionArgs for SettleLp {
+    type Accounts = SettleLpAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SettleExpiredMarketAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for SettleExpiredMarketAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, false),
+        ]
+    }
+}
+
+impl InstructionArgs for SettleExpiredMarket {
+    type Accounts = SettleExpiredMarketAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LiquidatePerpAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub liquidator: Pubkey,
+    pub liquidator_stats: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+}
+
+impl ToAccountMetas for LiquidatePerpAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.liquidator, false),
+            AccountMeta::new(self.liquidator_stats, false),
+            AccountMeta::new(self.user, false),
+            AccountMeta::new(self.user_stats, false),
+        ]
+    }
+}
+
+impl InstructionArgs for LiquidatePerp {
+    type Accounts = LiquidatePerpAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LiquidateSpotAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub liquidator: Pubkey,
+    pub liquidator_stats: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+}
+
+impl ToAccountMetas for LiquidateSpotAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, false),
+            AccountMeta::new_readonly(self.liquidator, false),
+            AccountMeta::new_readonly(self.liquidator_stats, false),
+            AccountMeta::new_readonly(self.user, false),
+            AccountMeta::new_readonly(self.user_stats, false),
+        ]
+    }
+}
+
+impl InstructionArgs for LiquidateSpot {
+    type Accounts = LiquidateSpotAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LiquidateBorrowForPerpPnlAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub liquidator: Pubkey,
+    pub liquidator_stats: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+}
+
+impl ToAccountMetas for LiquidateBorrowForPerpPnlAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, false),
+            AccountMeta::new_readonly(self.liquidator, false),
+            AccountMeta::new_readonly(self.liquidator_stats, false),
+            AccountMeta::new_readonly(self.user, false),
+            AccountMeta::new_readonly(self.user_stats, false),
+        ]
+    }
+}
+
+impl InstructionArgs for LiquidateBorrowForPerpPnl {
+    type Accounts = LiquidateBorrowForPerpPnlAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LiquidatePerpPnlForDepositAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub liquidator: Pubkey,
+    pub liquidator_stats: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+}
+
+impl ToAccountMetas for LiquidatePerpPnlForDepositAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, false),
+            AccountMeta::new_readonly(self.liquidator, false),
+            AccountMeta::new_readonly(self.liquidator_stats, false),
+            AccountMeta::new_readonly(self.user, false),
+            AccountMeta::new_readonly(self.user_stats, false),
+        ]
+    }
+}
+
+impl InstructionArgs for LiquidatePerpPnlForDeposit {
+    type Accounts = LiquidatePerpPnlForDepositAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ResolvePerpPnlDeficitAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub spot_market_vault: Pubkey,
+    pub insurance_fund_vault: Pubkey,
+    pub drift_signer: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl ToAccountMetas for ResolvePerpPnlDeficitAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, false),
+            AccountMeta::new_readonly(self.spot_market_vault, false),
+            AccountMeta::new_readonly(self.insurance_fund_vault, false),
+            AccountMeta::new_readonly(self.drift_signer, false),
+            AccountMeta::new_readonly(self.token_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for ResolvePerpPnlDeficit {
+    type Accounts = ResolvePerpPnlDeficitAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ResolvePerpBankruptcyAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub liquidator: Pubkey,
+    pub liquidator_stats: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub spot_market_vault: Pubkey,
+    pub insurance_fund_vault: Pubkey,
+    pub drift_signer: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl ToAccountMetas for ResolvePerpBankruptcyAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, false),
+            AccountMeta::new_readonly(self.liquidator, false),
+            AccountMeta::new_readonly(self.liquidator_stats, false),
+            AccountMeta::new_readonly(self.user, false),
+            AccountMeta::new_readonly(self.user_stats, false),
+            AccountMeta::new_readonly(self.spot_market_vault, false),
+            AccountMeta::new_readonly(self.insurance_fund_vault, false),
+            AccountMeta::new_readonly(self.drift_signer, false),
+            AccountMeta::new_readonly(self.token_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for ResolvePerpBankruptcy {
+    type Accounts = ResolvePerpBankruptcyAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ResolveSpotBankruptcyAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub liquidator: Pubkey,
+    pub liquidator_stats: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub spot_market_vault: Pubkey,
+    pub insurance_fund_vault: Pubkey,
+    pub drift_signer: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl ToAccountMetas for ResolveSpotBankruptcyAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, false),
+            AccountMeta::new_readonly(self.liquidator, false),
+            AccountMeta::new_readonly(self.liquidator_stats, false),
+            AccountMeta::new_readonly(self.user, false),
+            AccountMeta::new_readonly(self.user_stats, false),
+            AccountMeta::new_readonly(self.spot_market_vault, false),
+            AccountMeta::new_readonly(self.insurance_fund_vault, false),
+            AccountMeta::new_readonly(self.drift_signer, false),
+            AccountMeta::new_readonly(self.token_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for ResolveSpotBankruptcy {
+    type Accounts = ResolveSpotBankruptcyAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SettleRevenueToInsuranceFundAccounts {
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+    pub spot_market_vault: Pubkey,
+    pub drift_signer: Pubkey,
+    pub insurance_fund_vault: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl ToAccountMetas for SettleRevenueToInsuranceFundAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.spot_market, false),
+            AccountMeta::new(self.spot_market_vault, false),
+            AccountMeta::new_readonly(self.drift_signer, false),
+            AccountMeta::new(self.insurance_fund_vault, false),
+            AccountMeta::new_readonly(self.token_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for SettleRevenueToInsuranceFund {
+    type Accounts = SettleRevenueToInsuranceFundAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateFundingRateAccounts {
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+    pub oracle: Pubkey,
+}
+
+impl ToAccountMetas for UpdateFundingRateAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.perp_market, false),
+            AccountMeta::new_readonly(self.oracle, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateFundingRate {
+    type Accounts = UpdateFundingRateAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePrelaunchOracleAccounts {
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+    pub oracle: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePrelaunchOracleAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+            AccountMeta::new(self.oracle, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePrelaunchOracle {
+    type Accounts = UpdatePrelaunchOracleAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpBidAskTwapAccounts {
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+    pub oracle: Pubkey,
+    pub keeper_stats: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpBidAskTwapAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.perp_market, false),
+            AccountMeta::new_readonly(self.oracle, false),
+            AccountMeta::new_readonly(self.keeper_stats, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpBidAskTwap {
+    type Accounts = UpdatePerpBidAskTwapAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketCumulativeInterestAccounts {
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+    pub oracle: Pubkey,
+    pub spot_market_vault: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketCumulativeInterestAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+            AccountMeta::new_readonly(self.oracle, false),
+            AccountMeta::new_readonly(self.spot_market_vault, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketCumulativeInterest {
+    type Accounts = UpdateSpotMarketCumulativeInterestAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateAmmsAccounts {
+    pub state: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl ToAccountMetas for UpdateAmmsAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, true),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateAmms {
+    type Accounts = UpdateAmmsAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketExpiryAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketExpiryAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketExpiry {
+    type Accounts = UpdateSpotMarketExpiryAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateUserQuoteAssetInsuranceStakeAccounts {
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+    pub insurance_fund_stake: Pubkey,
+    pub user_stats: Pubkey,
+    pub authority: Pubkey,
+    pub insurance_fund_vault: Pubkey,
+}
+
+impl ToAccountMetas for UpdateUserQuoteAssetInsuranceStakeAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+            AccountMeta::new_readonly(self.insurance_fund_stake, false),
+            AccountMeta::new_readonly(self.user_stats, false),
+            AccountMeta::new_readonly(self.authority, false),
+            AccountMeta::new_readonly(self.insurance_fund_vault, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateUserQuoteAssetInsuranceStake {
+    type Accounts = UpdateUserQuoteAssetInsuranceStakeAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializeInsuranceFundStakeAccounts {
+    pub spot_market: Pubkey,
+    pub insurance_fund_stake: Pubkey,
+    pub user_stats: Pubkey,
+    pub state: Pubkey,
+    pub authority: Pubkey,
+    pub payer: Pubkey,
+    pub rent: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl ToAccountMetas for InitializeInsuranceFundStakeAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.spot_market, false),
+            AccountMeta::new(self.insurance_fund_stake, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new_readonly(self.rent, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for InitializeInsuranceFundStake {
+    type Accounts = InitializeInsuranceFundStakeAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct AddInsuranceFundStakeAccounts {
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+    pub insurance_fund_stake: Pubkey,
+    pub user_stats: Pubkey,
+    pub authority: Pubkey,
+    pub spot_market_vault: Pubkey,
+    pub insurance_fund_vault: Pubkey,
+    pub drift_signer: Pubkey,
+    pub user_token_account: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl ToAccountMetas for AddInsuranceFundStakeAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.spot_market, false),
+            AccountMeta::new(self.insurance_fund_stake, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.spot_market_vault, false),
+            AccountMeta::new(self.insurance_fund_vault, false),
+            AccountMeta::new_readonly(self.drift_signer, false),
+            AccountMeta::new(self.user_token_account, false),
+            AccountMeta::new_readonly(self.token_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for AddInsuranceFundStake {
+    type Accounts = AddInsuranceFundStakeAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RequestRemoveInsuranceFundStakeAccounts {
+    pub spot_market: Pubkey,
+    pub insurance_fund_stake: Pubkey,
+    pub user_stats: Pubkey,
+    pub authority: Pubkey,
+    pub insurance_fund_vault: Pubkey,
+}
+
+impl ToAccountMetas for RequestRemoveInsuranceFundStakeAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.spot_market, false),
+            AccountMeta::new(self.insurance_fund_stake, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.insurance_fund_vault, false),
+        ]
+    }
+}
+
+impl InstructionArgs for RequestRemoveInsuranceFundStake {
+    type Accounts = RequestRemoveInsuranceFundStakeAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CancelRequestRemoveInsuranceFundStakeAccounts {
+    pub spot_market: Pubkey,
+    pub insurance_fund_stake: Pubkey,
+    pub user_stats: Pubkey,
+    pub authority: Pubkey,
+    pub insurance_fund_vault: Pubkey,
+}
+
+impl ToAccountMetas for CancelRequestRemoveInsuranceFundStakeAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.spot_market, false),
+            AccountMeta::new(self.insurance_fund_stake, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.insurance_fund_vault, false),
+        ]
+    }
+}
+
+impl InstructionArgs for CancelRequestRemoveInsuranceFundStake {
+    type Accounts = CancelRequestRemoveInsuranceFundStakeAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RemoveInsuranceFundStakeAccounts {
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+    pub insurance_fund_stake: Pubkey,
+    pub user_stats: Pubkey,
+    pub authority: Pubkey,
+    pub insurance_fund_vault: Pubkey,
+    pub drift_signer: Pubkey,
+    pub user_token_account: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl ToAccountMetas for RemoveInsuranceFundStakeAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.spot_market, false),
+            AccountMeta::new(self.insurance_fund_stake, false),
+            AccountMeta::new(self.user_stats, false),
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new(self.insurance_fund_vault, false),
+            AccountMeta::new_readonly(self.drift_signer, false),
+            AccountMeta::new(self.user_token_account, false),
+            AccountMeta::new_readonly(self.token_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for RemoveInsuranceFundStake {
+    type Accounts = RemoveInsuranceFundStakeAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct TransferProtocolIfSharesAccounts {
+    pub signer: Pubkey,
+    pub transfer_config: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+    pub insurance_fund_stake: Pubkey,
+    pub user_stats: Pubkey,
+    pub authority: Pubkey,
+    pub insurance_fund_vault: Pubkey,
+}
+
+impl ToAccountMetas for TransferProtocolIfSharesAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.signer, false),
+            AccountMeta::new_readonly(self.transfer_config, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+            AccountMeta::new_readonly(self.insurance_fund_stake, false),
+            AccountMeta::new_readonly(self.user_stats, false),
+            AccountMeta::new_readonly(self.authority, false),
+            AccountMeta::new_readonly(self.insurance_fund_vault, false),
+        ]
+    }
+}
+
+impl InstructionArgs for TransferProtocolIfShares {
+    type Accounts = TransferProtocolIfSharesAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializeAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub quote_asset_mint: Pubkey,
+    pub drift_signer: Pubkey,
+    pub rent: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl ToAccountMetas for InitializeAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.quote_asset_mint, false),
+            AccountMeta::new_readonly(self.drift_signer, false),
+            AccountMeta::new_readonly(self.rent, false),
+            AccountMeta::new_readonly(self.system_program, false),
+            AccountMeta::new_readonly(self.token_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for Initialize {
+    type Accounts = InitializeAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializeSpotMarketAccounts {
+    pub spot_market: Pubkey,
+    pub spot_market_mint: Pubkey,
+    pub spot_market_vault: Pubkey,
+    pub insurance_fund_vault: Pubkey,
+    pub drift_signer: Pubkey,
+    pub state: Pubkey,
+    pub oracle: Pubkey,
+    pub admin: Pubkey,
+    pub rent: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl ToAccountMetas for InitializeSpotMarketAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.spot_market, false),
+            AccountMeta::new_readonly(self.spot_market_mint, false),
+            AccountMeta::new_readonly(self.spot_market_vault, false),
+            AccountMeta::new_readonly(self.insurance_fund_vault, false),
+            AccountMeta::new_readonly(self.drift_signer, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.oracle, false),
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.rent, false),
+            AccountMeta::new_readonly(self.system_program, false),
+            AccountMeta::new_readonly(self.token_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for InitializeSpotMarket {
+    type Accounts = InitializeSpotMarketAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DeleteInitializedSpotMarketAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+    pub spot_market_vault: Pubkey,
+    pub insurance_fund_vault: Pubkey,
+    pub drift_signer: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl ToAccountMetas for DeleteInitializedSpotMarketAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+            AccountMeta::new_readonly(self.spot_market_vault, false),
+            AccountMeta::new_readonly(self.insurance_fund_vault, false),
+            AccountMeta::new_readonly(self.drift_signer, false),
+            AccountMeta::new_readonly(self.token_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for DeleteInitializedSpotMarket {
+    type Accounts = DeleteInitializedSpotMarketAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializeSerumFulfillmentConfigAccounts {
+    pub base_spot_market: Pubkey,
+    pub quote_spot_market: Pubkey,
+    pub state: Pubkey,
+    pub serum_program: Pubkey,
+    pub serum_market: Pubkey,
+    pub serum_open_orders: Pubkey,
+    pub drift_signer: Pubkey,
+    pub serum_fulfillment_config: Pubkey,
+    pub admin: Pubkey,
+    pub rent: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl ToAccountMetas for InitializeSerumFulfillmentConfigAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.base_spot_market, false),
+            AccountMeta::new_readonly(self.quote_spot_market, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.serum_program, false),
+            AccountMeta::new_readonly(self.serum_market, false),
+            AccountMeta::new_readonly(self.serum_open_orders, false),
+            AccountMeta::new_readonly(self.drift_signer, false),
+            AccountMeta::new_readonly(self.serum_fulfillment_config, false),
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.rent, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for InitializeSerumFulfillmentConfig {
+    type Accounts = InitializeSerumFulfillmentConfigAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSerumFulfillmentConfigStatusAccounts {
+    pub state: Pubkey,
+    pub serum_fulfillment_config: Pubkey,
+    pub admin: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSerumFulfillmentConfigStatusAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.serum_fulfillment_config, false),
+            AccountMeta::new_readonly(self.admin, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSerumFulfillmentConfigStatus {
+    type Accounts = UpdateSerumFulfillmentConfigStatusAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializePhoenixFulfillmentConfigAccounts {
+    pub base_spot_market: Pubkey,
+    pub quote_spot_market: Pubkey,
+    pub state: Pubkey,
+    pub phoenix_program: Pubkey,
+    pub phoenix_market: Pubkey,
+    pub drift_signer: Pubkey,
+    pub phoenix_fulfillment_config: Pubkey,
+    pub admin: Pubkey,
+    pub rent: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl ToAccountMetas for InitializePhoenixFulfillmentConfigAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.base_spot_market, false),
+            AccountMeta::new_readonly(self.quote_spot_market, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.phoenix_program, false),
+            AccountMeta::new_readonly(self.phoenix_market, false),
+            AccountMeta::new_readonly(self.drift_signer, false),
+            AccountMeta::new_readonly(self.phoenix_fulfillment_config, false),
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.rent, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for InitializePhoenixFulfillmentConfig {
+    type Accounts = InitializePhoenixFulfillmentConfigAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PhoenixFulfillmentConfigStatusAccounts {
+    pub state: Pubkey,
+    pub phoenix_fulfillment_config: Pubkey,
+    pub admin: Pubkey,
+}
+
+impl ToAccountMetas for PhoenixFulfillmentConfigStatusAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.phoenix_fulfillment_config, false),
+            AccountMeta::new_readonly(self.admin, false),
+        ]
+    }
+}
+
+impl InstructionArgs for PhoenixFulfillmentConfigStatus {
+    type Accounts = PhoenixFulfillmentConfigStatusAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSerumVaultAccounts {
+    pub state: Pubkey,
+    pub admin: Pubkey,
+    pub srm_vault: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSerumVaultAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.srm_vault, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSerumVault {
+    type Accounts = UpdateSerumVaultAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializePerpMarketAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+    pub oracle: Pubkey,
+    pub rent: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl ToAccountMetas for InitializePerpMarketAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+            AccountMeta::new_readonly(self.oracle, false),
+            AccountMeta::new_readonly(self.rent, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for InitializePerpMarket {
+    type Accounts = InitializePerpMarketAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DeleteInitializedPerpMarketAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for DeleteInitializedPerpMarketAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for DeleteInitializedPerpMarket {
+    type Accounts = DeleteInitializedPerpMarketAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct MoveAmmPriceAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for MoveAmmPriceAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for MoveAmmPrice {
+    type Accounts = MoveAmmPriceAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RecenterPerpMarketAmmAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for RecenterPerpMarketAmmAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for RecenterPerpMarketAmm {
+    type Accounts = RecenterPerpMarketAmmAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketAmmSummaryStatsAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+    pub spot_market: Pubkey,
+    pub oracle: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketAmmSummaryStatsAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+            AccountMeta::new_readonly(self.oracle, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketAmmSummaryStats {
+    type Accounts = UpdatePerpMarketAmmSummaryStatsAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketExpiryAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketExpiryAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketExpiry {
+    type Accounts = UpdatePerpMarketExpiryAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SettleExpiredMarketPoolsToRevenuePoolAccounts {
+    pub state: Pubkey,
+    pub admin: Pubkey,
+    pub spot_market: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for SettleExpiredMarketPoolsToRevenuePoolAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for SettleExpiredMarketPoolsToRevenuePool {
+    type Accounts = SettleExpiredMarketPoolsToRevenuePoolAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DepositIntoPerpMarketFeePoolAccounts {
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+    pub admin: Pubkey,
+    pub source_vault: Pubkey,
+    pub drift_signer: Pubkey,
+    pub quote_spot_market: Pubkey,
+    pub spot_market_vault: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl ToAccountMetas for DepositIntoPerpMarketFeePoolAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.source_vault, false),
+            AccountMeta::new_readonly(self.drift_signer, false),
+            AccountMeta::new_readonly(self.quote_spot_market, false),
+            AccountMeta::new_readonly(self.spot_market_vault, false),
+            AccountMeta::new_readonly(self.token_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for DepositIntoPerpMarketFeePool {
+    type Accounts = DepositIntoPerpMarketFeePoolAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DepositIntoSpotMarketRevenuePoolAccounts {
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+    pub authority: Pubkey,
+    pub spot_market_vault: Pubkey,
+    pub user_token_account: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl ToAccountMetas for DepositIntoSpotMarketRevenuePoolAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new(self.spot_market, false),
+            AccountMeta::new(self.authority, true),
+            AccountMeta::new(self.spot_market_vault, false),
+            AccountMeta::new(self.user_token_account, false),
+            AccountMeta::new_readonly(self.token_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for DepositIntoSpotMarketRevenuePool {
+    type Accounts = DepositIntoSpotMarketRevenuePoolAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RepegAmmCurveAccounts {
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+    pub oracle: Pubkey,
+    pub admin: Pubkey,
+}
+
+impl ToAccountMetas for RepegAmmCurveAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+            AccountMeta::new_readonly(self.oracle, false),
+            AccountMeta::new_readonly(self.admin, false),
+        ]
+    }
+}
+
+impl InstructionArgs for RepegAmmCurve {
+    type Accounts = RepegAmmCurveAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketAmmOracleTwapAccounts {
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+    pub oracle: Pubkey,
+    pub admin: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketAmmOracleTwapAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+            AccountMeta::new_readonly(self.oracle, false),
+            AccountMeta::new_readonly(self.admin, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketAmmOracleTwap {
+    type Accounts = UpdatePerpMarketAmmOracleTwapAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ResetPerpMarketAmmOracleTwapAccounts {
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+    pub oracle: Pubkey,
+    pub admin: Pubkey,
+}
+
+impl ToAccountMetas for ResetPerpMarketAmmOracleTwapAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+            AccountMeta::new_readonly(self.oracle, false),
+            AccountMeta::new_readonly(self.admin, false),
+        ]
+    }
+}
+
+impl InstructionArgs for ResetPerpMarketAmmOracleTwap {
+    type Accounts = ResetPerpMarketAmmOracleTwapAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateKAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+    pub oracle: Pubkey,
+}
+
+impl ToAccountMetas for UpdateKAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+            AccountMeta::new_readonly(self.oracle, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateK {
+    type Accounts = UpdateKAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketMarginRatioAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketMarginRatioAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketMarginRatio {
+    type Accounts = UpdatePerpMarketMarginRatioAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketFundingPeriodAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketFundingPeriodAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketFundingPeriod {
+    type Accounts = UpdatePerpMarketFundingPeriodAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketMaxImbalancesAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketMaxImbalancesAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketMaxImbalances {
+    type Accounts = UpdatePerpMarketMaxImbalancesAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketLiquidationFeeAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketLiquidationFeeAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketLiquidationFee {
+    type Accounts = UpdatePerpMarketLiquidationFeeAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateInsuranceFundUnstakingPeriodAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateInsuranceFundUnstakingPeriodAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateInsuranceFundUnstakingPeriod {
+    type Accounts = UpdateInsuranceFundUnstakingPeriodAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketLiquidationFeeAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketLiquidationFeeAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketLiquidationFee {
+    type Accounts = UpdateSpotMarketLiquidationFeeAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateWithdrawGuardThresholdAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateWithdrawGuardThresholdAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateWithdrawGuardThreshold {
+    type Accounts = UpdateWithdrawGuardThresholdAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketIfFactorAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketIfFactorAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketIfFactor {
+    type Accounts = UpdateSpotMarketIfFactorAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketRevenueSettlePeriodAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketRevenueSettlePeriodAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketRevenueSettlePeriod {
+    type Accounts = UpdateSpotMarketRevenueSettlePeriodAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketStatusAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketStatusAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketStatus {
+    type Accounts = UpdateSpotMarketStatusAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketPausedOperationsAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketPausedOperationsAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketPausedOperations {
+    type Accounts = UpdateSpotMarketPausedOperationsAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketAssetTierAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketAssetTierAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketAssetTier {
+    type Accounts = UpdateSpotMarketAssetTierAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketMarginWeightsAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketMarginWeightsAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketMarginWeights {
+    type Accounts = UpdateSpotMarketMarginWeightsAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketBorrowRateAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketBorrowRateAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketBorrowRate {
+    type Accounts = UpdateSpotMarketBorrowRateAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketMaxTokenDepositsAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketMaxTokenDepositsAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketMaxTokenDeposits {
+    type Accounts = UpdateSpotMarketMaxTokenDepositsAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketScaleInitialAssetWeightStartAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketScaleInitialAssetWeightStartAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketScaleInitialAssetWeightStart {
+    type Accounts = UpdateSpotMarketScaleInitialAssetWeightStartAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketOracleAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+    pub oracle: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketOracleAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+            AccountMeta::new_readonly(self.oracle, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketOracle {
+    type Accounts = UpdateSpotMarketOracleAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketStepSizeAndTickSizeAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketStepSizeAndTickSizeAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketStepSizeAndTickSize {
+    type Accounts = UpdateSpotMarketStepSizeAndTickSizeAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketMinOrderSizeAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketMinOrderSizeAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketMinOrderSize {
+    type Accounts = UpdateSpotMarketMinOrderSizeAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketOrdersEnabledAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketOrdersEnabledAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketOrdersEnabled {
+    type Accounts = UpdateSpotMarketOrdersEnabledAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketIfPausedOperationsAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketIfPausedOperationsAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketIfPausedOperations {
+    type Accounts = UpdateSpotMarketIfPausedOperationsAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketNameAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketNameAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketName {
+    type Accounts = UpdateSpotMarketNameAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketStatusAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketStatusAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketStatus {
+    type Accounts = UpdatePerpMarketStatusAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketPausedOperationsAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketPausedOperationsAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketPausedOperations {
+    type Accounts = UpdatePerpMarketPausedOperationsAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketContractTierAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketContractTierAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketContractTier {
+    type Accounts = UpdatePerpMarketContractTierAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketImfFactorAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketImfFactorAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketImfFactor {
+    type Accounts = UpdatePerpMarketImfFactorAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketUnrealizedAssetWeightAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketUnrealizedAssetWeightAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketUnrealizedAssetWeight {
+    type Accounts = UpdatePerpMarketUnrealizedAssetWeightAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketConcentrationCoefAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketConcentrationCoefAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketConcentrationCoef {
+    type Accounts = UpdatePerpMarketConcentrationCoefAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketCurveUpdateIntensityAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketCurveUpdateIntensityAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketCurveUpdateIntensity {
+    type Accounts = UpdatePerpMarketCurveUpdateIntensityAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketTargetBaseAssetAmountPerLpAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketTargetBaseAssetAmountPerLpAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketTargetBaseAssetAmountPerLp {
+    type Accounts = UpdatePerpMarketTargetBaseAssetAmountPerLpAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketPerLpBaseAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketPerLpBaseAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketPerLpBase {
+    type Accounts = UpdatePerpMarketPerLpBaseAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateLpCooldownTimeAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdateLpCooldownTimeAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateLpCooldownTime {
+    type Accounts = UpdateLpCooldownTimeAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpFeeStructureAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpFeeStructureAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpFeeStructure {
+    type Accounts = UpdatePerpFeeStructureAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotFeeStructureAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotFeeStructureAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotFeeStructure {
+    type Accounts = UpdateSpotFeeStructureAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateInitialPctToLiquidateAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdateInitialPctToLiquidateAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateInitialPctToLiquidate {
+    type Accounts = UpdateInitialPctToLiquidateAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateLiquidationDurationAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdateLiquidationDurationAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateLiquidationDuration {
+    type Accounts = UpdateLiquidationDurationAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateLiquidationMarginBufferRatioAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdateLiquidationMarginBufferRatioAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateLiquidationMarginBufferRatio {
+    type Accounts = UpdateLiquidationMarginBufferRatioAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateOracleGuardRailsAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdateOracleGuardRailsAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateOracleGuardRails {
+    type Accounts = UpdateOracleGuardRailsAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateStateSettlementDurationAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdateStateSettlementDurationAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateStateSettlementDuration {
+    type Accounts = UpdateStateSettlementDurationAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateStateMaxNumberOfSubAccountsAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdateStateMaxNumberOfSubAccountsAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateStateMaxNumberOfSubAccounts {
+    type Accounts = UpdateStateMaxNumberOfSubAccountsAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateStateMaxInitializeUserFeeAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdateStateMaxInitializeUserFeeAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateStateMaxInitializeUserFee {
+    type Accounts = UpdateStateMaxInitializeUserFeeAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketOracleAccounts {
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+    pub oracle: Pubkey,
+    pub admin: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketOracleAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+            AccountMeta::new_readonly(self.oracle, false),
+            AccountMeta::new_readonly(self.admin, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketOracle {
+    type Accounts = UpdatePerpMarketOracleAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketBaseSpreadAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketBaseSpreadAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketBaseSpread {
+    type Accounts = UpdatePerpMarketBaseSpreadAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateAmmJitIntensityAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateAmmJitIntensityAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateAmmJitIntensity {
+    type Accounts = UpdateAmmJitIntensityAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketMaxSpreadAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketMaxSpreadAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketMaxSpread {
+    type Accounts = UpdatePerpMarketMaxSpreadAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketStepSizeAndTickSizeAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketStepSizeAndTickSizeAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketStepSizeAndTickSize {
+    type Accounts = UpdatePerpMarketStepSizeAndTickSizeAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketNameAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketNameAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketName {
+    type Accounts = UpdatePerpMarketNameAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketMinOrderSizeAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketMinOrderSizeAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketMinOrderSize {
+    type Accounts = UpdatePerpMarketMinOrderSizeAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketMaxSlippageRatioAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketMaxSlippageRatioAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketMaxSlippageRatio {
+    type Accounts = UpdatePerpMarketMaxSlippageRatioAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketMaxFillReserveFractionAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketMaxFillReserveFractionAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketMaxFillReserveFraction {
+    type Accounts = UpdatePerpMarketMaxFillReserveFractionAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketMaxOpenInterestAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketMaxOpenInterestAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketMaxOpenInterest {
+    type Accounts = UpdatePerpMarketMaxOpenInterestAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketNumberOfUsersAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketNumberOfUsersAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketNumberOfUsers {
+    type Accounts = UpdatePerpMarketNumberOfUsersAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketFeeAdjustmentAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub perp_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpMarketFeeAdjustmentAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpMarketFeeAdjustment {
+    type Accounts = UpdatePerpMarketFeeAdjustmentAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotMarketFeeAdjustmentAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+    pub spot_market: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotMarketFeeAdjustmentAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.spot_market, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotMarketFeeAdjustment {
+    type Accounts = UpdateSpotMarketFeeAdjustmentAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateAdminAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdateAdminAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateAdmin {
+    type Accounts = UpdateAdminAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateWhitelistMintAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdateWhitelistMintAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateWhitelistMint {
+    type Accounts = UpdateWhitelistMintAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateDiscountMintAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdateDiscountMintAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateDiscountMint {
+    type Accounts = UpdateDiscountMintAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateExchangeStatusAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdateExchangeStatusAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateExchangeStatus {
+    type Accounts = UpdateExchangeStatusAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePerpAuctionDurationAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePerpAuctionDurationAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePerpAuctionDuration {
+    type Accounts = UpdatePerpAuctionDurationAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateSpotAuctionDurationAccounts {
+    pub admin: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdateSpotAuctionDurationAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateSpotAuctionDuration {
+    type Accounts = UpdateSpotAuctionDurationAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializeProtocolIfSharesTransferConfigAccounts {
+    pub admin: Pubkey,
+    pub protocol_if_shares_transfer_config: Pubkey,
+    pub state: Pubkey,
+    pub rent: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl ToAccountMetas for InitializeProtocolIfSharesTransferConfigAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.protocol_if_shares_transfer_config, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.rent, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for InitializeProtocolIfSharesTransferConfig {
+    type Accounts = InitializeProtocolIfSharesTransferConfigAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateProtocolIfSharesTransferConfigAccounts {
+    pub admin: Pubkey,
+    pub protocol_if_shares_transfer_config: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdateProtocolIfSharesTransferConfigAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.protocol_if_shares_transfer_config, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdateProtocolIfSharesTransferConfig {
+    type Accounts = UpdateProtocolIfSharesTransferConfigAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializePrelaunchOracleAccounts {
+    pub admin: Pubkey,
+    pub prelaunch_oracle: Pubkey,
+    pub state: Pubkey,
+    pub rent: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl ToAccountMetas for InitializePrelaunchOracleAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.prelaunch_oracle, false),
+            AccountMeta::new_readonly(self.state, false),
+            AccountMeta::new_readonly(self.rent, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+impl InstructionArgs for InitializePrelaunchOracle {
+    type Accounts = InitializePrelaunchOracleAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdatePrelaunchOracleParamsAccounts {
+    pub admin: Pubkey,
+    pub prelaunch_oracle: Pubkey,
+    pub perp_market: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for UpdatePrelaunchOracleParamsAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.prelaunch_oracle, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for UpdatePrelaunchOracleParams {
+    type Accounts = UpdatePrelaunchOracleParamsAccounts;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DeletePrelaunchOracleAccounts {
+    pub admin: Pubkey,
+    pub prelaunch_oracle: Pubkey,
+    pub perp_market: Pubkey,
+    pub state: Pubkey,
+}
+
+impl ToAccountMetas for DeletePrelaunchOracleAccounts {
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.admin, false),
+            AccountMeta::new_readonly(self.prelaunch_oracle, false),
+            AccountMeta::new_readonly(self.perp_market, false),
+            AccountMeta::new_readonly(self.state, false),
+        ]
+    }
+}
+
+impl InstructionArgs for DeletePrelaunchOracle {
+    type Accounts = DeletePrelaunchOracleAccounts;
+}
+
+// Accounts
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PhoenixV1FulfillmentConfig {
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub pubkey: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub phoenix_program_id: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub phoenix_log_authority: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub phoenix_market: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub phoenix_base_vault: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub phoenix_quote_vault: Pubkey,
+    pub market_index: u16,
+    pub fulfillment_type: SpotFulfillmentType,
+    pub status: SpotFulfillmentConfigStatus,
+    pub padding: [u8; 4],
+}
+
+impl_discriminator!(account PhoenixV1FulfillmentConfig);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct SerumV3FulfillmentConfig {
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub pubkey: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub serum_program_id: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub serum_market: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub serum_request_queue: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub serum_event_queue: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub serum_bids: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub serum_asks: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub serum_base_vault: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub serum_quote_vault: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub serum_open_orders: Pubkey,
+    pub serum_signer_nonce: u64,
+    pub market_index: u16,
+    pub fulfillment_type: SpotFulfillmentType,
+    pub status: SpotFulfillmentConfigStatus,
+    pub padding: [u8; 4],
+}
+
+impl_discriminator!(account SerumV3FulfillmentConfig);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct InsuranceFundStake {
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub authority: Pubkey,
+    pub if_shares: u128,
+    pub last_withdraw_request_shares: u128,
+    pub if_base: u128,
+    pub last_valid_ts: i64,
+    pub last_withdraw_request_value: u64,
+    pub last_withdraw_request_ts: i64,
+    pub cost_basis: i64,
+    pub market_index: u16,
+    pub padding: [u8; 14],
+}
+
+impl_discriminator!(account InsuranceFundStake);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct ProtocolIfSharesTransferConfig {
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkeys)
+    )]
+    pub whitelisted_signers: [Pubkey; 4],
+    pub max_transfer_per_epoch: u128,
+    pub current_epoch_transfer: u128,
+    pub next_epoch_ts: i64,
+    pub padding: [u128; 8],
+}
+
+impl_discriminator!(account ProtocolIfSharesTransferConfig);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PrelaunchOracle {
+    pub price: i64,
+    pub max_price: i64,
+    pub confidence: u64,
+    pub last_update_slot: u64,
+    pub amm_last_update_slot: u64,
+    pub perp_market_index: u16,
+    pub padding: [u8; 70],
+}
+
+impl_discriminator!(account PrelaunchOracle);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PerpMarket {
+    /// The perp market's address. It is a pda of the market index
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub pubkey: Pubkey,
+    /// The automated market maker
+    pub amm: AMM,
+    /// The market's pnl pool. When users settle negative pnl, the balance increases.
+    /// When users settle positive pnl, the balance decreases. Can not go negative.
+    pub pnl_pool: PoolBalance,
+    /// Encoded display name for the perp market e.g. SOL-PERP
+    pub name: [u8; 32],
+    /// The perp market's claim on the insurance fund
+    pub insurance_claim: InsuranceClaim,
+    /// The max pnl imbalance before positive pnl asset weight is discounted
+    /// pnl imbalance is the difference between long and short pnl. When it's greater than 0,
+    /// the amm has negative pnl and the initial asset weight for positive pnl is discounted
+    /// precision = QUOTE_PRECISION
+    pub unrealized_pnl_max_imbalance: u64,
+    /// The ts when the market will be expired. Only set if market is in reduce only mode
+    pub expiry_ts: i64,
+    /// The price at which positions will be settled. Only set if market is expired
+    /// precision = PRICE_PRECISION
+    pub expiry_price: i64,
+    /// Every trade has a fill record id. This is the next id to be used
+    pub next_fill_record_id: u64,
+    /// Every funding rate update has a record id. This is the next id to be used
+    pub next_funding_rate_record_id: u64,
+    /// Every amm k updated has a record id. This is the next id to be used
+    pub next_curve_record_id: u64,
+    /// The initial margin fraction factor. Used to increase margin ratio for large positions
+    /// precision: MARGIN_PRECISION
+    pub imf_factor: u32,
+    /// The imf factor for unrealized pnl. Used to discount asset weight for large positive pnl
+    /// precision: MARGIN_PRECISION
+    pub unrealized_pnl_imf_factor: u32,
+    /// The fee the liquidator is paid for taking over perp position
+    /// precision: LIQUIDATOR_FEE_PRECISION
+    pub liquidator_fee: u32,
+    /// The fee the insurance fund receives from liquidation
+    /// precision: LIQUIDATOR_FEE_PRECISION
+    pub if_liquidation_fee: u32,
+    /// The margin ratio which determines how much collateral is required to open a position
+    /// e.g. margin ratio of .1 means a user must have $100 of total collateral to open a $1000 position
+    /// precision: MARGIN_PRECISION
+    pub margin_ratio_initial: u32,
+    /// The margin ratio which determines when a user will be liquidated
+    /// e.g. margin ratio of .05 means a user must have $50 of total collateral to maintain a $1000 position
+    /// else they will be liquidated
+    /// precision: MARGIN_PRECISION
+    pub margin_ratio_maintenance: u32,
+    /// The initial asset weight for positive pnl. Negative pnl always has an asset weight of 1
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub unrealized_pnl_initial_asset_weight: u32,
+    /// The maintenance asset weight for positive pnl. Negative pnl always has an asset weight of 1
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub unrealized_pnl_maintenance_asset_weight: u32,
+    /// number of users in a position (base)
+    pub number_of_users_with_base: u32,
+    /// number of users in a position (pnl) or pnl (quote)
+    pub number_of_users: u32,
+    pub market_index: u16,
+    /// Whether a market is active, reduce only, expired, etc
+    /// Affects whether users can open/close positions
+    pub status: MarketStatus,
+    /// Currently only Perpetual markets are supported
+    pub contract_type: ContractType,
+    /// The contract tier determines how much insurance a market can receive, with more speculative markets receiving less insurance
+    /// It also influences the order perp markets can be liquidated, with less speculative markets being liquidated first
+    pub contract_tier: ContractTier,
+    pub paused_operations: u8,
+    /// The spot market that pnl is settled in
+    pub quote_spot_market_index: u16,
+    /// Between -100 and 100, represents what % to increase/decrease the fee by
+    /// E.g. if this is -50 and the fee is 5bps, the new fee will be 2.5bps
+    /// if this is 50 and the fee is 5bps, the new fee will be 7.5bps
+    pub fee_adjustment: i16,
+    pub padding: [u8; 46],
+}
+
+impl_discriminator!(account PerpMarket);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct SpotMarket {
+    /// The address of the spot market. It is a pda of the market index
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub pubkey: Pubkey,
+    /// The oracle used to price the markets deposits/borrows
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub oracle: Pubkey,
+    /// The token mint of the market
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub mint: Pubkey,
+    /// The vault used to store the market's deposits
+    /// The amount in the vault should be equal to or greater than deposits - borrows
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub vault: Pubkey,
+    /// The encoded display name for the market e.g. SOL
+    pub name: [u8; 32],
+    pub historical_oracle_data: HistoricalOracleData,
+    pub historical_index_data: HistoricalIndexData,
+    /// Revenue the protocol has collected in this markets token
+    /// e.g. for SOL-PERP, funds can be settled in usdc and will flow into the USDC revenue pool
+    pub revenue_pool: PoolBalance,
+    /// The fees collected from swaps between this market and the quote market
+    /// Is settled to the quote markets revenue pool
+    pub spot_fee_pool: PoolBalance,
+    /// Details on the insurance fund covering bankruptcies in this markets token
+    /// Covers bankruptcies for borrows with this markets token and perps settling in this markets token
+    pub insurance_fund: InsuranceFund,
+    /// The total spot fees collected for this market
+    /// precision: QUOTE_PRECISION
+    pub total_spot_fee: u128,
+    /// The sum of the scaled balances for deposits across users and pool balances
+    /// To convert to the deposit token amount, multiply by the cumulative deposit interest
+    /// precision: SPOT_BALANCE_PRECISION
+    pub deposit_balance: u128,
+    /// The sum of the scaled balances for borrows across users and pool balances
+    /// To convert to the borrow token amount, multiply by the cumulative borrow interest
+    /// precision: SPOT_BALANCE_PRECISION
+    pub borrow_balance: u128,
+    /// The cumulative interest earned by depositors
+    /// Used to calculate the deposit token amount from the deposit balance
+    /// precision: SPOT_CUMULATIVE_INTEREST_PRECISION
+    pub cumulative_deposit_interest: u128,
+    /// The cumulative interest earned by borrowers
+    /// Used to calculate the borrow token amount from the borrow balance
+    /// precision: SPOT_CUMULATIVE_INTEREST_PRECISION
+    pub cumulative_borrow_interest: u128,
+    /// The total socialized loss from borrows, in the mint's token
+    /// precision: token mint precision
+    pub total_social_loss: u128,
+    /// The total socialized loss from borrows, in the quote market's token
+    /// preicision: QUOTE_PRECISION
+    pub total_quote_social_loss: u128,
+    /// no withdraw limits/guards when deposits below this threshold
+    /// precision: token mint precision
+    pub withdraw_guard_threshold: u64,
+    /// The max amount of token deposits in this market
+    /// 0 if there is no limit
+    /// precision: token mint precision
+    pub max_token_deposits: u64,
+    /// 24hr average of deposit token amount
+    /// precision: token mint precision
+    pub deposit_token_twap: u64,
+    /// 24hr average of borrow token amount
+    /// precision: token mint precision
+    pub borrow_token_twap: u64,
+    /// 24hr average of utilization
+    /// which is borrow amount over token amount
+    /// precision: SPOT_UTILIZATION_PRECISION
+    pub utilization_twap: u64,
+    /// Last time the cumulative deposit and borrow interest was updated
+    pub last_interest_ts: u64,
+    /// Last time the deposit/borrow/utilization averages were updated
+    pub last_twap_ts: u64,
+    /// The time the market is set to expire. Only set if market is in reduce only mode
+    pub expiry_ts: i64,
+    /// Spot orders must be a multiple of the step size
+    /// precision: token mint precision
+    pub order_step_size: u64,
+    /// Spot orders must be a multiple of the tick size
+    /// precision: PRICE_PRECISION
+    pub order_tick_size: u64,
+    /// The minimum order size
+    /// precision: token mint precision
+    pub min_order_size: u64,
+    /// The maximum spot position size
+    /// if the limit is 0, there is no limit
+    /// precision: token mint precision
+    pub max_position_size: u64,
+    /// Every spot trade has a fill record id. This is the next id to use
+    pub next_fill_record_id: u64,
+    /// Every deposit has a deposit record id. This is the next id to use
+    pub next_deposit_record_id: u64,
+    /// The initial asset weight used to calculate a deposits contribution to a users initial total collateral
+    /// e.g. if the asset weight is .8, $100 of deposits contributes $80 to the users initial total collateral
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub initial_asset_weight: u32,
+    /// The maintenance asset weight used to calculate a deposits contribution to a users maintenance total collateral
+    /// e.g. if the asset weight is .9, $100 of deposits contributes $90 to the users maintenance total collateral
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub maintenance_asset_weight: u32,
+    /// The initial liability weight used to calculate a borrows contribution to a users initial margin requirement
+    /// e.g. if the liability weight is .9, $100 of borrows contributes $90 to the users initial margin requirement
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub initial_liability_weight: u32,
+    /// The maintenance liability weight used to calculate a borrows contribution to a users maintenance margin requirement
+    /// e.g. if the liability weight is .8, $100 of borrows contributes $80 to the users maintenance margin requirement
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub maintenance_liability_weight: u32,
+    /// The initial margin fraction factor. Used to increase liability weight/decrease asset weight for large positions
+    /// precision: MARGIN_PRECISION
+    pub imf_factor: u32,
+    /// The fee the liquidator is paid for taking over borrow/deposit
+    /// precision: LIQUIDATOR_FEE_PRECISION
+    pub liquidator_fee: u32,
+    /// The fee the insurance fund receives from liquidation
+    /// precision: LIQUIDATOR_FEE_PRECISION
+    pub if_liquidation_fee: u32,
+    /// The optimal utilization rate for this market.
+    /// Used to determine the markets borrow rate
+    /// precision: SPOT_UTILIZATION_PRECISION
+    pub optimal_utilization: u32,
+    /// The borrow rate for this market when the market has optimal utilization
+    /// precision: SPOT_RATE_PRECISION
+    pub optimal_borrow_rate: u32,
+    /// The borrow rate for this market when the market has 1000 utilization
+    /// precision: SPOT_RATE_PRECISION
+    pub max_borrow_rate: u32,
+    /// The market's token mint's decimals. To from decimals to a precision, 10^decimals
+    pub decimals: u32,
+    pub market_index: u16,
+    /// Whether or not spot trading is enabled
+    pub orders_enabled: bool,
+    pub oracle_source: OracleSource,
+    pub status: MarketStatus,
+    /// The asset tier affects how a deposit can be used as collateral and the priority for a borrow being liquidated
+    pub asset_tier: AssetTier,
+    pub paused_operations: u8,
+    pub if_paused_operations: u8,
+    pub fee_adjustment: i16,
+    pub padding1: [u8; 2],
+    /// For swaps, the amount of token loaned out in the begin_swap ix
+    /// precision: token mint precision
+    pub flash_loan_amount: u64,
+    /// For swaps, the amount in the users token account in the begin_swap ix
+    /// Used to calculate how much of the token left the system in end_swap ix
+    /// precision: token mint precision
+    pub flash_loan_initial_token_amount: u64,
+    /// The total fees received from swaps
+    /// precision: token mint precision
+    pub total_swap_fee: u64,
+    /// When to begin scaling down the initial asset weight
+    /// disabled when 0
+    /// precision: QUOTE_PRECISION
+    pub scale_initial_asset_weight_start: u64,
+    pub padding: [u8; 48],
+}
+
+impl_discriminator!(account SpotMarket);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct State {
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub admin: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub whitelist_mint: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub discount_mint: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub signer: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub srm_vault: Pubkey,
+    pub perp_fee_structure: FeeStructure,
+    pub spot_fee_structure: FeeStructure,
+    pub oracle_guard_rails: OracleGuardRails,
+    pub number_of_authorities: u64,
+    pub number_of_sub_accounts: u64,
+    pub lp_cooldown_time: u64,
+    pub liquidation_margin_buffer_ratio: u32,
+    pub settlement_duration: u16,
+    pub number_of_markets: u16,
+    pub number_of_spot_markets: u16,
+    pub signer_nonce: u8,
+    pub min_perp_auction_duration: u8,
+    pub default_market_order_time_in_force: u8,
+    pub default_spot_auction_duration: u8,
+    pub exchange_status: u8,
+    pub liquidation_duration: u8,
+    pub initial_pct_to_liquidate: u16,
+    pub max_number_of_sub_accounts: u16,
+    pub max_initialize_user_fee: u16,
+    pub padding: [u8; 10],
+}
+
+impl_discriminator!(account State);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct User {
+    /// The owner/authority of the account
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub authority: Pubkey,
+    /// An addresses that can control the account on the authority's behalf. Has limited power, cant withdraw
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub delegate: Pubkey,
+    /// Encoded display name e.g. "toly"
+    pub name: [u8; 32],
+    /// The user's spot positions
+    pub spot_positions: [SpotPosition; 8],
+    /// The user's perp positions
+    pub perp_positions: [PerpPosition; 8],
+    /// The user's orders
+    pub orders: [Order; 32],
+    /// The last time the user added perp lp positions
+    pub last_add_perp_lp_shares_ts: i64,
+    /// The total values of deposits the user has made
+    /// precision: QUOTE_PRECISION
+    pub total_deposits: u64,
+    /// The total values of withdrawals the user has made
+    /// precision: QUOTE_PRECISION
+    pub total_withdraws: u64,
+    /// The total socialized loss the users has incurred upon the protocol
+    /// precision: QUOTE_PRECISION
+    pub total_social_loss: u64,
+    /// Fees (taker fees, maker rebate, referrer reward, filler reward) and pnl for perps
+    /// precision: QUOTE_PRECISION
+    pub settled_perp_pnl: i64,
+    /// Fees (taker fees, maker rebate, filler reward) for spot
+    /// precision: QUOTE_PRECISION
+    pub cumulative_spot_fees: i64,
+    /// Cumulative funding paid/received for perps
+    /// precision: QUOTE_PRECISION
+    pub cumulative_perp_funding: i64,
+    /// The amount of margin freed during liquidation. Used to force the liquidation to occur over a period of time
+    /// Defaults to zero when not being liquidated
+    /// precision: QUOTE_PRECISION
+    pub liquidation_margin_freed: u64,
+    /// The last slot a user was active. Used to determine if a user is idle
+    pub last_active_slot: u64,
+    /// Every user order has an order id. This is the next order id to be used
+    pub next_order_id: u32,
+    /// Custom max initial margin ratio for the user
+    pub max_margin_ratio: u32,
+    /// The next liquidation id to be used for user
+    pub next_liquidation_id: u16,
+    /// The sub account id for this user
+    pub sub_account_id: u16,
+    /// Whether the user is active, being liquidated or bankrupt
+    pub status: u8,
+    /// Whether the user has enabled margin trading
+    pub is_margin_trading_enabled: bool,
+    /// User is idle if they haven't interacted with the protocol in 1 week and they have no orders, perp positions or borrows
+    /// Off-chain keeper bots can ignore users that are idle
+    pub idle: bool,
+    /// number of open orders
+    pub open_orders: u8,
+    /// Whether or not user has open order
+    pub has_open_order: bool,
+    /// number of open orders with auction
+    pub open_auctions: u8,
+    /// Whether or not user has open order with auction
+    pub has_open_auction: bool,
+    pub padding: [u8; 21],
+}
+
+impl_discriminator!(account User);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UserStats {
+    /// The authority for all of a users sub accounts
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub authority: Pubkey,
+    /// The address that referred this user
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub referrer: Pubkey,
+    /// Stats on the fees paid by the user
+    pub fees: UserFees,
+    /// The timestamp of the next epoch
+    /// Epoch is used to limit referrer rewards earned in single epoch
+    pub next_epoch_ts: i64,
+    /// Rolling 30day maker volume for user
+    /// precision: QUOTE_PRECISION
+    pub maker_volume_30d: u64,
+    /// Rolling 30day taker volume for user
+    /// precision: QUOTE_PRECISION
+    pub taker_volume_30d: u64,
+    /// Rolling 30day filler volume for user
+    /// precision: QUOTE_PRECISION
+    pub filler_volume_30d: u64,
+    /// last time the maker volume was updated
+    pub last_maker_volume_30d_ts: i64,
+    /// last time the taker volume was updated
+    pub last_taker_volume_30d_ts: i64,
+    /// last time the filler volume was updated
+    pub last_filler_volume_30d_ts: i64,
+    /// The amount of tokens staked in the quote spot markets if
+    pub if_staked_quote_asset_amount: u64,
+    /// The current number of sub accounts
+    pub number_of_sub_accounts: u16,
+    /// The number of sub accounts created. Can be greater than the number of sub accounts if user
+    /// has deleted sub accounts
+    pub number_of_sub_accounts_created: u16,
+    /// Whether the user is a referrer. Sub account 0 can not be deleted if user is a referrer
+    pub is_referrer: bool,
+    pub disable_update_perp_bid_ask_twap: bool,
+    pub padding: [u8; 50],
+}
+
+impl_discriminator!(account UserStats);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct ReferrerName {
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub authority: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub user: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub user_stats: Pubkey,
+    pub name: [u8; 32],
+}
+
+impl_discriminator!(account ReferrerName);
+
+// Types definition
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UpdatePerpMarketSummaryStatsParams {
+    pub quote_asset_amount_with_unsettled_lp: Option<i64>,
+    pub net_unsettled_funding_pnl: Option<i64>,
+    pub update_amm_summary_stats: Option<bool>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct LiquidatePerpRecord {
+    pub market_index: u16,
+    pub oracle_price: i64,
+    pub base_asset_amount: i64,
+    pub quote_asset_amount: i64,
+    /// precision: AMM_RESERVE_PRECISION
+    pub lp_shares: u64,
+    pub fill_record_id: u64,
+    pub user_order_id: u32,
+    pub liquidator_order_id: u32,
+    /// precision: QUOTE_PRECISION
+    pub liquidator_fee: u64,
+    /// precision: QUOTE_PRECISION
+    pub if_fee: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct LiquidateSpotRecord {
+    pub asset_market_index: u16,
+    pub asset_price: i64,
+    pub asset_transfer: u128,
+    pub liability_market_index: u16,
+    pub liability_price: i64,
+    /// precision: token mint precision
+    pub liability_transfer: u128,
+    /// precision: token mint precision
+    pub if_fee: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct LiquidateBorrowForPerpPnlRecord {
+    pub perp_market_index: u16,
+    pub market_oracle_price: i64,
+    pub pnl_transfer: u128,
+    pub liability_market_index: u16,
+    pub liability_price: i64,
+    pub liability_transfer: u128,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct LiquidatePerpPnlForDepositRecord {
+    pub perp_market_index: u16,
+    pub market_oracle_price: i64,
+    pub pnl_transfer: u128,
+    pub asset_market_index: u16,
+    pub asset_price: i64,
+    pub asset_transfer: u128,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PerpBankruptcyRecord {
+    pub market_index: u16,
+    pub pnl: i128,
+    pub if_payment: u128,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::option_pubkey)
+    )]
+    pub clawback_user: Option<Pubkey>,
+    pub clawback_user_payment: Option<i128>,
+    pub cumulative_funding_rate_delta: i128,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct SpotBankruptcyRecord {
+    pub market_index: u16,
+    pub borrow_amount: u128,
+    pub if_payment: u128,
+    pub cumulative_deposit_interest_delta: u128,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct MarketIdentifier {
+    pub market_type: MarketType,
+    pub market_index: u16,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct HistoricalOracleData {
+    /// precision: PRICE_PRECISION
+    pub last_oracle_price: i64,
+    /// precision: PRICE_PRECISION
+    pub last_oracle_conf: u64,
+    /// number of slots since last update
+    pub last_oracle_delay: i64,
+    /// precision: PRICE_PRECISION
+    pub last_oracle_price_twap: i64,
+    /// precision: PRICE_PRECISION
+    pub last_oracle_price_twap_5min: i64,
+    /// unix_timestamp of last snapshot
+    pub last_oracle_price_twap_ts: i64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct HistoricalIndexData {
+    /// precision: PRICE_PRECISION
+    pub last_index_bid_price: u64,
+    /// precision: PRICE_PRECISION
+    pub last_index_ask_price: u64,
+    /// precision: PRICE_PRECISION
+    pub last_index_price_twap: u64,
+    /// precision: PRICE_PRECISION
+    pub last_index_price_twap_5min: u64,
+    /// unix_timestamp of last snapshot
+    pub last_index_price_twap_ts: i64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PrelaunchOracleParams {
+    pub perp_market_index: u16,
+    pub price: Option<i64>,
+    pub max_price: Option<i64>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct OrderParams {
+    pub order_type: OrderType,
+    pub market_type: MarketType,
+    pub direction: PositionDirection,
+    pub user_order_id: u8,
+    pub base_asset_amount: u64,
+    pub price: u64,
+    pub market_index: u16,
+    pub reduce_only: bool,
+    pub post_only: PostOnlyParam,
+    pub immediate_or_cancel: bool,
+    pub max_ts: Option<i64>,
+    pub trigger_price: Option<u64>,
+    pub trigger_condition: OrderTriggerCondition,
+    pub oracle_price_offset: Option<i32>,
+    pub auction_duration: Option<u8>,
+    pub auction_start_price: Option<i64>,
+    pub auction_end_price: Option<i64>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct ModifyOrderParams {
+    pub direction: Option<PositionDirection>,
+    pub base_asset_amount: Option<u64>,
+    pub price: Option<u64>,
+    pub reduce_only: Option<bool>,
+    pub post_only: Option<PostOnlyParam>,
+    pub immediate_or_cancel: Option<bool>,
+    pub max_ts: Option<i64>,
+    pub trigger_price: Option<u64>,
+    pub trigger_condition: Option<OrderTriggerCondition>,
+    pub oracle_price_offset: Option<i32>,
+    pub auction_duration: Option<u8>,
+    pub auction_start_price: Option<i64>,
+    pub auction_end_price: Option<i64>,
+    pub policy: Option<ModifyOrderPolicy>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct InsuranceClaim {
+    /// The amount of revenue last settled
+    /// Positive if funds left the perp market,
+    /// negative if funds were pulled into the perp market
+    /// precision: QUOTE_PRECISION
+    pub revenue_withdraw_since_last_settle: i64,
+    /// The max amount of revenue that can be withdrawn per period
+    /// precision: QUOTE_PRECISION
+    pub max_revenue_withdraw_per_period: u64,
+    /// The max amount of insurance that perp market can use to resolve bankruptcy and pnl deficits
+    /// precision: QUOTE_PRECISION
+    pub quote_max_insurance: u64,
+    /// The amount of insurance that has been used to resolve bankruptcy and pnl deficits
+    /// precision: QUOTE_PRECISION
+    pub quote_settled_insurance: u64,
+    /// The last time revenue was settled in/out of market
+    pub last_revenue_withdraw_ts: i64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PoolBalance {
+    /// To get the pool's token amount, you must multiply the scaled balance by the market's cumulative
+    /// deposit interest
+    /// precision: SPOT_BALANCE_PRECISION
+    pub scaled_balance: i64,
+    /// The spot market the pool is for
+    pub market_index: u16,
+    pub padding: [u8; 6],
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct AMM {
+    /// oracle price data public key
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub oracle: Pubkey,
+    /// stores historically witnessed oracle data
+    pub historical_oracle_data: HistoricalOracleData,
+    /// accumulated base asset amount since inception per lp share
+    /// precision: QUOTE_PRECISION
+    pub base_asset_amount_per_lp: i128,
+    /// accumulated quote asset amount since inception per lp share
+    /// precision: QUOTE_PRECISION
+    pub quote_asset_amount_per_lp: i128,
+    /// partition of fees from perp market trading moved from pnl settlements
+    pub fee_pool: PoolBalance,
+    /// `x` reserves for constant product mm formula (x * y = k)
+    /// precision: AMM_RESERVE_PRECISION
+    pub base_asset_reserve: u128,
+    /// `y` reserves for constant product mm formula (x * y = k)
+    /// precision: AMM_RESERVE_PRECISION
+    pub quote_asset_reserve: u128,
+    /// determines how close the min/max base asset reserve sit vs base reserves
+    /// allow for decreasing slippage without increasing liquidity and v.v.
+    /// precision: PERCENTAGE_PRECISION
+    pub concentration_coef: u128,
+    /// minimum base_asset_reserve allowed before AMM is unavailable
+    /// precision: AMM_RESERVE_PRECISION
+    pub min_base_asset_reserve: u128,
+    /// maximum base_asset_reserve allowed before AMM is unavailable
+    /// precision: AMM_RESERVE_PRECISION
+    pub max_base_asset_reserve: u128,
+    /// `sqrt(k)` in constant product mm formula (x * y = k). stored to avoid drift caused by integer math issues
+    /// precision: AMM_RESERVE_PRECISION
+    pub sqrt_k: u128,
+    /// normalizing numerical factor for y, its use offers lowest slippage in cp-curve when market is balanced
+    /// precision: PEG_PRECISION
+    pub peg_multiplier: u128,
+    /// y when market is balanced. stored to save computation
+    /// precision: AMM_RESERVE_PRECISION
+    pub terminal_quote_asset_reserve: u128,
+    /// always non-negative. tracks number of total longs in market (regardless of counterparty)
+    /// precision: BASE_PRECISION
+    pub base_asset_amount_long: i128,
+    /// always non-positive. tracks number of total shorts in market (regardless of counterparty)
+    /// precision: BASE_PRECISION
+    pub base_asset_amount_short: i128,
+    /// tracks net position (longs-shorts) in market with AMM as counterparty
+    /// precision: BASE_PRECISION
+    pub base_asset_amount_with_amm: i128,
+    /// tracks net position (longs-shorts) in market with LPs as counterparty
+    /// precision: BASE_PRECISION
+    pub base_asset_amount_with_unsettled_lp: i128,
+    /// max allowed open interest, blocks trades that breach this value
+    /// precision: BASE_PRECISION
+    pub max_open_interest: u128,
+    /// sum of all user's perp quote_asset_amount in market
+    /// precision: QUOTE_PRECISION
+    pub quote_asset_amount: i128,
+    /// sum of all long user's quote_entry_amount in market
+    /// precision: QUOTE_PRECISION
+    pub quote_entry_amount_long: i128,
+    /// sum of all short user's quote_entry_amount in market
+    /// precision: QUOTE_PRECISION
+    pub quote_entry_amount_short: i128,
+    /// sum of all long user's quote_break_even_amount in market
+    /// precision: QUOTE_PRECISION
+    pub quote_break_even_amount_long: i128,
+    /// sum of all short user's quote_break_even_amount in market
+    /// precision: QUOTE_PRECISION
+    pub quote_break_even_amount_short: i128,
+    /// total user lp shares of sqrt_k (protocol owned liquidity = sqrt_k - last_funding_rate)
+    /// precision: AMM_RESERVE_PRECISION
+    pub user_lp_shares: u128,
+    /// last funding rate in this perp market (unit is quote per base)
+    /// precision: QUOTE_PRECISION
+    pub last_funding_rate: i64,
+    /// last funding rate for longs in this perp market (unit is quote per base)
+    /// precision: QUOTE_PRECISION
+    pub last_funding_rate_long: i64,
+    /// last funding rate for shorts in this perp market (unit is quote per base)
+    /// precision: QUOTE_PRECISION
+    pub last_funding_rate_short: i64,
+    /// estimate of last 24h of funding rate perp market (unit is quote per base)
+    /// precision: QUOTE_PRECISION
+    pub last_24h_avg_funding_rate: i64,
+    /// total fees collected by this perp market
+    /// precision: QUOTE_PRECISION
+    pub total_fee: i128,
+    /// total fees collected by the vAMM's bid/ask spread
+    /// precision: QUOTE_PRECISION
+    pub total_mm_fee: i128,
+    /// total fees collected by exchange fee schedule
+    /// precision: QUOTE_PRECISION
+    pub total_exchange_fee: u128,
+    /// total fees minus any recognized upnl and pool withdraws
+    /// precision: QUOTE_PRECISION
+    pub total_fee_minus_distributions: i128,
+    /// sum of all fees from fee pool withdrawn to revenue pool
+    /// precision: QUOTE_PRECISION
+    pub total_fee_withdrawn: u128,
+    /// all fees collected by market for liquidations
+    /// precision: QUOTE_PRECISION
+    pub total_liquidation_fee: u128,
+    /// accumulated funding rate for longs since inception in market
+    pub cumulative_funding_rate_long: i128,
+    /// accumulated funding rate for shorts since inception in market
+    pub cumulative_funding_rate_short: i128,
+    /// accumulated social loss paid by users since inception in market
+    pub total_social_loss: u128,
+    /// transformed base_asset_reserve for users going long
+    /// precision: AMM_RESERVE_PRECISION
+    pub ask_base_asset_reserve: u128,
+    /// transformed quote_asset_reserve for users going long
+    /// precision: AMM_RESERVE_PRECISION
+    pub ask_quote_asset_reserve: u128,
+    /// transformed quote_asset_reserve for users going short
+    /// precision: AMM_RESERVE_PRECISION
+    pub bid_quote_asset_reserve: u128,
+    /// the last seen oracle price partially shrunk toward the amm reserve price
+    /// precision: PRICE_PRECISION
+    pub last_oracle_normalised_price: i64,
+    /// the gap between the oracle price and the reserve price = y * peg_multiplier / x
+    pub last_oracle_reserve_price_spread_pct: i64,
+    /// average estimate of bid price over funding_period
+    /// precision: PRICE_PRECISION
+    pub last_bid_price_twap: u64,
+    /// average estimate of ask price over funding_period
+    /// precision: PRICE_PRECISION
+    pub last_ask_price_twap: u64,
+    /// average estimate of (bid+ask)/2 price over funding_period
+    /// precision: PRICE_PRECISION
+    pub last_mark_price_twap: u64,
+    /// average estimate of (bid+ask)/2 price over FIVE_MINUTES
+    /// precision: PRICE_PRECISION
+    pub last_mark_price_twap_5min: u64,
+    /// the last blockchain slot the amm was updated
+    pub last_update_slot: u64,
+    /// the pct size of the oracle confidence interval
+    /// precision: PERCENTAGE_PRECISION
+    pub last_oracle_conf_pct: u64,
+    /// the total_fee_minus_distribution change since the last funding update
+    /// precision: QUOTE_PRECISION
+    pub net_revenue_since_last_funding: i64,
+    /// the last funding rate update unix_timestamp
+    pub last_funding_rate_ts: i64,
+    /// the peridocity of the funding rate updates
+    pub funding_period: i64,
+    /// the base step size (increment) of orders
+    /// precision: BASE_PRECISION
+    pub order_step_size: u64,
+    /// the price tick size of orders
+    /// precision: PRICE_PRECISION
+    pub order_tick_size: u64,
+    /// the minimum base size of an order
+    /// precision: BASE_PRECISION
+    pub min_order_size: u64,
+    /// the max base size a single user can have
+    /// precision: BASE_PRECISION
+    pub max_position_size: u64,
+    /// estimated total of volume in market
+    /// QUOTE_PRECISION
+    pub volume_24h: u64,
+    /// the volume intensity of long fills against AMM
+    pub long_intensity_volume: u64,
+    /// the volume intensity of short fills against AMM
+    pub short_intensity_volume: u64,
+    /// the blockchain unix timestamp at the time of the last trade
+    pub last_trade_ts: i64,
+    /// estimate of standard deviation of the fill (mark) prices
+    /// precision: PRICE_PRECISION
+    pub mark_std: u64,
+    /// estimate of standard deviation of the oracle price at each update
+    /// precision: PRICE_PRECISION
+    pub oracle_std: u64,
+    /// the last unix_timestamp the mark twap was updated
+    pub last_mark_price_twap_ts: i64,
+    /// the minimum spread the AMM can quote. also used as step size for some spread logic increases.
+    pub base_spread: u32,
+    /// the maximum spread the AMM can quote
+    pub max_spread: u32,
+    /// the spread for asks vs the reserve price
+    pub long_spread: u32,
+    /// the spread for bids vs the reserve price
+    pub short_spread: u32,
+    /// the count intensity of long fills against AMM
+    pub long_intensity_count: u32,
+    /// the count intensity of short fills against AMM
+    pub short_intensity_count: u32,
+    /// the fraction of total available liquidity a single fill on the AMM can consume
+    pub max_fill_reserve_fraction: u16,
+    /// the maximum slippage a single fill on the AMM can push
+    pub max_slippage_ratio: u16,
+    /// the update intensity of AMM formulaic updates (adjusting k). 0-100
+    pub curve_update_intensity: u8,
+    /// the jit intensity of AMM. larger intensity means larger participation in jit. 0 means no jit participation.
+    /// (0, 100] is intensity for protocol-owned AMM. (100, 200] is intensity for user LP-owned AMM.
+    pub amm_jit_intensity: u8,
+    /// the oracle provider information. used to decode/scale the oracle public key
+    pub oracle_source: OracleSource,
+    /// tracks whether the oracle was considered valid at the last AMM update
+    pub last_oracle_valid: bool,
+    /// the target value for `base_asset_amount_per_lp`, used during AMM JIT with LP split
+    /// precision: BASE_PRECISION
+    pub target_base_asset_amount_per_lp: i32,
+    /// expo for unit of per_lp, base 10 (if per_lp_base=X, then per_lp unit is 10^X)
+    pub per_lp_base: i8,
+    pub padding1: u8,
+    pub padding2: u16,
+    pub total_fee_earned_per_lp: u64,
+    pub net_unsettled_funding_pnl: i64,
+    pub quote_asset_amount_with_unsettled_lp: i64,
+    pub reference_price_offset: i32,
+    pub padding: [u8; 12],
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct InsuranceFund {
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub vault: Pubkey,
+    pub total_shares: u128,
+    pub user_shares: u128,
+    pub shares_base: u128,
+    pub unstaking_period: i64,
+    pub last_revenue_settle_ts: i64,
+    pub revenue_settle_period: i64,
+    pub total_factor: u32,
+    pub user_factor: u32,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct OracleGuardRails {
+    pub price_divergence: PriceDivergenceGuardRails,
+    pub validity: ValidityGuardRails,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PriceDivergenceGuardRails {
+    pub mark_oracle_percent_divergence: u64,
+    pub oracle_twap_5min_percent_divergence: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct ValidityGuardRails {
+    pub slots_before_stale_for_amm: i64,
+    pub slots_before_stale_for_margin: i64,
+    pub confidence_interval_max_size: u64,
+    pub too_volatile_ratio: i64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct FeeStructure {
+    pub fee_tiers: [FeeTier; 10],
+    pub filler_reward_structure: OrderFillerRewardStructure,
+    pub referrer_reward_epoch_upper_bound: u64,
+    pub flat_filler_fee: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct FeeTier {
+    pub fee_numerator: u32,
+    pub fee_denominator: u32,
+    pub maker_rebate_numerator: u32,
+    pub maker_rebate_denominator: u32,
+    pub referrer_reward_numerator: u32,
+    pub referrer_reward_denominator: u32,
+    pub referee_fee_numerator: u32,
+    pub referee_fee_denominator: u32,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct OrderFillerRewardStructure {
+    pub reward_numerator: u32,
+    pub reward_denominator: u32,
+    pub time_based_reward_lower_bound: u128,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct UserFees {
+    /// Total taker fee paid
+    /// precision: QUOTE_PRECISION
+    pub total_fee_paid: u64,
+    /// Total maker fee rebate
+    /// precision: QUOTE_PRECISION
+    pub total_fee_rebate: u64,
+    /// Total discount from holding token
+    /// precision: QUOTE_PRECISION
+    pub total_token_discount: u64,
+    /// Total discount from being referred
+    /// precision: QUOTE_PRECISION
+    pub total_referee_discount: u64,
+    /// Total reward to referrer
+    /// precision: QUOTE_PRECISION
+    pub total_referrer_reward: u64,
+    /// Total reward to referrer this epoch
+    /// precision: QUOTE_PRECISION
+    pub current_epoch_referrer_reward: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct SpotPosition {
+    /// The scaled balance of the position. To get the token amount, multiply by the cumulative deposit/borrow
+    /// interest of corresponding market.
+    /// precision: SPOT_BALANCE_PRECISION
+    pub scaled_balance: u64,
+    /// How many spot bids the user has open
+    /// precision: token mint precision
+    pub open_bids: i64,
+    /// How many spot asks the user has open
+    /// precision: token mint precision
+    pub open_asks: i64,
+    /// The cumulative deposits/borrows a user has made into a market
+    /// precision: token mint precision
+    pub cumulative_deposits: i64,
+    /// The market index of the corresponding spot market
+    pub market_index: u16,
+    /// Whether the position is deposit or borrow
+    pub balance_type: SpotBalanceType,
+    /// Number of open orders
+    pub open_orders: u8,
+    pub padding: [u8; 4],
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct PerpPosition {
+    /// The perp market's last cumulative funding rate. Used to calculate the funding payment owed to user
+    /// precision: FUNDING_RATE_PRECISION
+    pub last_cumulative_funding_rate: i64,
+    /// the size of the users perp position
+    /// precision: BASE_PRECISION
+    pub base_asset_amount: i64,
+    /// Used to calculate the users pnl. Upon entry, is equal to base_asset_amount * avg entry price - fees
+    /// Updated when the user open/closes position or settles pnl. Includes fees/funding
+    /// precision: QUOTE_PRECISION
+    pub quote_asset_amount: i64,
+    /// The amount of quote the user would need to exit their position at to break even
+    /// Updated when the user open/closes position or settles pnl. Includes fees/funding
+    /// precision: QUOTE_PRECISION
+    pub quote_break_even_amount: i64,
+    /// The amount quote the user entered the position with. Equal to base asset amount * avg entry price
+    /// Updated when the user open/closes position. Excludes fees/funding
+    /// precision: QUOTE_PRECISION
+    pub quote_entry_amount: i64,
+    /// The amount of open bids the user has in this perp market
+    /// precision: BASE_PRECISION
+    pub open_bids: i64,
+    /// The amount of open asks the user has in this perp market
+    /// precision: BASE_PRECISION
+    pub open_asks: i64,
+    /// The amount of pnl settled in this market since opening the position
+    /// precision: QUOTE_PRECISION
+    pub settled_pnl: i64,
+    /// The number of lp (liquidity provider) shares the user has in this perp market
+    /// LP shares allow users to provide liquidity via the AMM
+    /// precision: BASE_PRECISION
+    pub lp_shares: u64,
+    /// The last base asset amount per lp the amm had
+    /// Used to settle the users lp position
+    /// precision: BASE_PRECISION
+    pub last_base_asset_amount_per_lp: i64,
+    /// The last quote asset amount per lp the amm had
+    /// Used to settle the users lp position
+    /// precision: QUOTE_PRECISION
+    pub last_quote_asset_amount_per_lp: i64,
+    /// Settling LP position can lead to a small amount of base asset being left over smaller than step size
+    /// This records that remainder so it can be settled later on
+    /// precision: BASE_PRECISION
+    pub remainder_base_asset_amount: i32,
+    /// The market index for the perp market
+    pub market_index: u16,
+    /// The number of open orders
+    pub open_orders: u8,
+    pub per_lp_base: i8,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct Order {
+    /// The slot the order was placed
+    pub slot: u64,
+    /// The limit price for the order (can be 0 for market orders)
+    /// For orders with an auction, this price isn't used until the auction is complete
+    /// precision: PRICE_PRECISION
+    pub price: u64,
+    /// The size of the order
+    /// precision for perps: BASE_PRECISION
+    /// precision for spot: token mint precision
+    pub base_asset_amount: u64,
+    /// The amount of the order filled
+    /// precision for perps: BASE_PRECISION
+    /// precision for spot: token mint precision
+    pub base_asset_amount_filled: u64,
+    /// The amount of quote filled for the order
+    /// precision: QUOTE_PRECISION
+    pub quote_asset_amount_filled: u64,
+    /// At what price the order will be triggered. Only relevant for trigger orders
+    /// precision: PRICE_PRECISION
+    pub trigger_price: u64,
+    /// The start price for the auction. Only relevant for market/oracle orders
+    /// precision: PRICE_PRECISION
+    pub auction_start_price: i64,
+    /// The end price for the auction. Only relevant for market/oracle orders
+    /// precision: PRICE_PRECISION
+    pub auction_end_price: i64,
+    /// The time when the order will expire
+    pub max_ts: i64,
+    /// If set, the order limit price is the oracle price + this offset
+    /// precision: PRICE_PRECISION
+    pub oracle_price_offset: i32,
+    /// The id for the order. Each users has their own order id space
+    pub order_id: u32,
+    /// The perp/spot market index
+    pub market_index: u16,
+    /// Whether the order is open or unused
+    pub status: OrderStatus,
+    /// The type of order
+    pub order_type: OrderType,
+    /// Whether market is spot or perp
+    pub market_type: MarketType,
+    /// User generated order id. Can make it easier to place/cancel orders
+    pub user_order_id: u8,
+    /// What the users position was when the order was placed
+    pub existing_position_direction: PositionDirection,
+    /// Whether the user is going long or short. LONG = bid, SHORT = ask
+    pub direction: PositionDirection,
+    /// Whether the order is allowed to only reduce position size
+    pub reduce_only: bool,
+    /// Whether the order must be a maker
+    pub post_only: bool,
+    /// Whether the order must be canceled the same slot it is placed
+    pub immediate_or_cancel: bool,
+    /// Whether the order is triggered above or below the trigger price. Only relevant for trigger orders
+    pub trigger_condition: OrderTriggerCondition,
+    /// How many slots the auction lasts
+    pub auction_duration: u8,
+    pub padding: [u8; 3],
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum SwapDirection {
+    Add,
+    Remove,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum ModifyOrderId {
+    UserOrderId(u8),
+    OrderId(u32),
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum PositionDirection {
+    Long,
+    Short,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum SpotFulfillmentType {
+    SerumV3,
+    Match,
+    PhoenixV1,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum SwapReduceOnly {
+    In,
+    Out,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum TwapPeriod {
+    FundingPeriod,
+    FiveMin,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum LiquidationMultiplierType {
+    Discount,
+    Premium,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum MarginRequirementType {
+    Initial,
+    Fill,
+    Maintenance,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum OracleValidity {
+    NonPositive,
+    TooVolatile,
+    TooUncertain,
+    StaleForMargin,
+    InsufficientDataPoints,
+    StaleForAMM,
+    Valid,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum DriftAction {
+    UpdateFunding,
+    SettlePnl,
+    TriggerOrder,
+    FillOrderMatch,
+    FillOrderAmm,
+    Liquidate,
+    MarginCalc,
+    UpdateTwap,
+    UpdateAMMCurve,
+    OracleOrderPrice,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum PositionUpdateType {
+    Open,
+    Increase,
+    Reduce,
+    Close,
+    Flip,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum DepositExplanation {
+    None,
+    Transfer,
+    Borrow,
+    RepayBorrow,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum DepositDirection {
+    Deposit,
+    Withdraw,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum OrderAction {
+    Place,
+    Cancel,
+    Fill,
+    Trigger,
+    Expire,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum OrderActionExplanation {
+    None,
+    InsufficientFreeCollateral,
+    OraclePriceBreachedLimitPrice,
+    MarketOrderFilledToLimitPrice,
+    OrderExpired,
+    Liquidation,
+    OrderFilledWithAMM,
+    OrderFilledWithAMMJit,
+    OrderFilledWithMatch,
+    OrderFilledWithMatchJit,
+    MarketExpired,
+    RiskingIncreasingOrder,
+    ReduceOnlyOrderIncreasedPosition,
+    OrderFillWithSerum,
+    NoBorrowLiquidity,
+    OrderFillWithPhoenix,
+    OrderFilledWithAMMJitLPSplit,
+    OrderFilledWithLPJit,
+    DeriskLp,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum LPAction {
+    AddLiquidity,
+    RemoveLiquidity,
+    SettleLiquidity,
+    RemoveLiquidityDerisk,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum LiquidationType {
+    LiquidatePerp,
+    LiquidateSpot,
+    LiquidateBorrowForPerpPnl,
+    LiquidatePerpPnlForDeposit,
+    PerpBankruptcy,
+    SpotBankruptcy,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum SettlePnlExplanation {
+    None,
+    ExpiredPosition,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum StakeAction {
+    Stake,
+    UnstakeRequest,
+    UnstakeCancelRequest,
+    Unstake,
+    UnstakeTransfer,
+    StakeTransfer,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum FillMode {
+    Fill,
+    PlaceAndMake,
+    PlaceAndTake,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum PerpFulfillmentMethod {
+    AMM(Option<u64>),
+    Match(
+        #[cfg_attr(
+            any(test, feature = "arbitrary"),
+            arbitrary(with = crate::arbitrary_support::pubkey)
+        )]
+        Pubkey,
+        u16,
+    ),
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum SpotFulfillmentMethod {
+    ExternalMarket,
+    Match(
+        #[cfg_attr(
+            any(test, feature = "arbitrary"),
+            arbitrary(with = crate::arbitrary_support::pubkey)
+        )]
+        Pubkey,
+        u16,
+    ),
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum MarginCalculationMode {
+    Standard {
+        track_open_orders_fraction: bool,
+    },
+    Liquidation {
+        market_to_track_margin_requirement: Option<MarketIdentifier>,
+    },
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum OracleSource {
+    Pyth,
+    Switchboard,
+    QuoteAsset,
+    Pyth1K,
+    Pyth1M,
+    PythStableCoin,
+    Prelaunch,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum PostOnlyParam {
+    None,
+    MustPostOnly,
+    TryPostOnly,
+    Slide,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum ModifyOrderPolicy {
+    TryModify,
+    MustModify,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum PerpOperation {
+    UpdateFunding,
+    AmmFill,
+    Fill,
+    SettlePnl,
+    SettlePnlWithPosition,
+    Liquidation,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum SpotOperation {
+    UpdateCumulativeInterest,
+    Fill,
+    Withdraw,
+    Liquidation,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum InsuranceFundOperation {
+    Init,
+    Add,
+    RequestRemove,
+    Remove,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum MarketStatus {
+    Initialized,
+    Active,
+    FundingPaused,
+    AmmPaused,
+    FillPaused,
+    WithdrawPaused,
+    ReduceOnly,
+    Settlement,
+    Delisted,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum ContractType {
+    Perpetual,
+    Future,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum ContractTier {
+    A,
+    B,
+    C,
+    Speculative,
+    HighlySpeculative,
+    Isolated,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum AMMLiquiditySplit {
+    ProtocolOwned,
+    LPOwned,
+    Shared,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum SettlePnlMode {
+    MustSettle,
+    TrySettle,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum SpotBalanceType {
+    Deposit,
+    Borrow,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum SpotFulfillmentConfigStatus {
+    Enabled,
+    Disabled,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum AssetTier {
+    Collateral,
+    Protected,
+    Cross,
+    Isolated,
+    Unlisted,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum ExchangeStatus {
+    DepositPaused,
+    WithdrawPaused,
+    AmmPaused,
+    FillPaused,
+    LiqPaused,
+    FundingPaused,
+    SettlePnlPaused,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum UserStatus {
+    BeingLiquidated,
+    Bankrupt,
+    ReduceOnly,
+    AdvancedLp,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum AssetType {
+    Base,
+    Quote,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum OrderStatus {
+    Init,
+    Open,
+    Filled,
+    Canceled,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum OrderType {
+    Market,
+    Limit,
+    TriggerMarket,
+    TriggerLimit,
+    Oracle,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum OrderTriggerCondition {
+    Above,
+    Below,
+    TriggeredAbove,
+    TriggeredBelow,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum MarketType {
+    Spot,
+    Perp,
+}
+
+// Events
+
+#[allow(clippy::large_enum_variant)]
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub enum DriftV2Event {
+    NewUserRecord(NewUserRecord),
+    DepositRecord(DepositRecord),
+    SpotInterestRecord(SpotInterestRecord),
+    FundingPaymentRecord(FundingPaymentRecord),
+    FundingRateRecord(FundingRateRecord),
+    CurveRecord(CurveRecord),
+    OrderRecord(OrderRecord),
+    OrderActionRecord(OrderActionRecord),
+    LPRecord(LPRecord),
+    LiquidationRecord(LiquidationRecord),
+    SettlePnlRecord(SettlePnlRecord),
+    InsuranceFundRecord(InsuranceFundRecord),
+    InsuranceFundStakeRecord(InsuranceFundStakeRecord),
+    SwapRecord(SwapRecord),
+}
+
+impl DriftV2Event {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (discriminator, rest) = input.split_at(8);
+        if let Ok(discriminator) = discriminator.try_into() {
+            return Ok(match discriminator {
+                NewUserRecord::DISCRIMINATOR => Self::NewUserRecord(borsh::from_slice(rest)?),
+                DepositRecord::DISCRIMINATOR => Self::DepositRecord(borsh::from_slice(rest)?),
+                SpotInterestRecord::DISCRIMINATOR => {
+                    Self::SpotInterestRecord(borsh::from_slice(rest)?)
+                }
+                FundingPaymentRecord::DISCRIMINATOR => {
+                    Self::FundingPaymentRecord(borsh::from_slice(rest)?)
+                }
+                FundingRateRecord::DISCRIMINATOR => {
+                    Self::FundingRateRecord(borsh::from_slice(rest)?)
+                }
+                CurveRecord::DISCRIMINATOR => Self::CurveRecord(borsh::from_slice(rest)?),
+                OrderRecord::DISCRIMINATOR => Self::OrderRecord(borsh::from_slice(rest)?),
+                OrderActionRecord::DISCRIMINATOR => {
+                    Self::OrderActionRecord(borsh::from_slice(rest)?)
+                }
+                LPRecord::DISCRIMINATOR => Self::LPRecord(borsh::from_slice(rest)?),
+                LiquidationRecord::DISCRIMINATOR => {
+                    Self::LiquidationRecord(borsh::from_slice(rest)?)
+                }
+                SettlePnlRecord::DISCRIMINATOR => Self::SettlePnlRecord(borsh::from_slice(rest)?),
+                InsuranceFundRecord::DISCRIMINATOR => {
+                    Self::InsuranceFundRecord(borsh::from_slice(rest)?)
+                }
+                InsuranceFundStakeRecord::DISCRIMINATOR => {
+                    Self::InsuranceFundStakeRecord(borsh::from_slice(rest)?)
+                }
+                SwapRecord::DISCRIMINATOR => Self::SwapRecord(borsh::from_slice(rest)?),
+                _ => return Err(ProgramError::InvalidInstructionData),
+            });
+        }
+
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct NewUserRecord {
+    pub ts: i64,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub user_authority: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub user: Pubkey,
+    pub sub_account_id: u16,
+    pub name: [u8; 32],
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub referrer: Pubkey,
+}
+
+impl_discriminator!(event NewUserRecord);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct DepositRecord {
+    pub ts: i64,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub user_authority: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub user: Pubkey,
+    pub direction: DepositDirection,
+    pub deposit_record_id: u64,
+    pub amount: u64,
+    pub market_index: u16,
+    pub oracle_price: i64,
+    pub market_deposit_balance: u128,
+    pub market_withdraw_balance: u128,
+    pub market_cumulative_deposit_interest: u128,
+    pub market_cumulative_borrow_interest: u128,
+    pub total_deposits_after: u64,
+    pub total_withdraws_after: u64,
+    pub explanation: DepositExplanation,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::option_pubkey)
+    )]
+    pub transfer_user: Option<Pubkey>,
+}
+
+impl_discriminator!(event DepositRecord);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct SpotInterestRecord {
+    pub ts: i64,
+    pub market_index: u16,
+    pub deposit_balance: u128,
+    pub cumulative_deposit_interest: u128,
+    pub borrow_balance: u128,
+    pub cumulative_borrow_interest: u128,
+    pub optimal_utilization: u32,
+    pub optimal_borrow_rate: u32,
+    pub max_borrow_rate: u32,
+}
+
+impl_discriminator!(event SpotInterestRecord);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct FundingPaymentRecord {
+    pub ts: i64,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub user_authority: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub user: Pubkey,
+    pub market_index: u16,
+    pub funding_payment: i64,
+    pub base_asset_amount: i64,
+    pub user_last_cumulative_funding: i64,
+    pub amm_cumulative_funding_long: i128,
+    pub amm_cumulative_funding_short: i128,
+}
+
+impl_discriminator!(event FundingPaymentRecord);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct FundingRateRecord {
+    pub ts: i64,
+    pub record_id: u64,
+    pub market_index: u16,
+    pub funding_rate: i64,
+    pub funding_rate_long: i128,
+    pub funding_rate_short: i128,
+    pub cumulative_funding_rate_long: i128,
+    pub cumulative_funding_rate_short: i128,
+    pub oracle_price_twap: i64,
+    pub mark_price_twap: u64,
+    pub period_revenue: i64,
+    pub base_asset_amount_with_amm: i128,
+    pub base_asset_amount_with_unsettled_lp: i128,
+}
+
+impl_discriminator!(event FundingRateRecord);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct CurveRecord {
+    pub ts: i64,
+    pub record_id: u64,
+    pub peg_multiplier_before: u128,
+    pub base_asset_reserve_before: u128,
+    pub quote_asset_reserve_before: u128,
+    pub sqrt_k_before: u128,
+    pub peg_multiplier_after: u128,
+    pub base_asset_reserve_after: u128,
+    pub quote_asset_reserve_after: u128,
+    pub sqrt_k_after: u128,
+    pub base_asset_amount_long: u128,
+    pub base_asset_amount_short: u128,
+    pub base_asset_amount_with_amm: i128,
+    pub total_fee: i128,
+    pub total_fee_minus_distributions: i128,
+    pub adjustment_cost: i128,
+    pub oracle_price: i64,
+    pub fill_record: u128,
+    pub number_of_users: u32,
+    pub market_index: u16,
+}
+
+impl_discriminator!(event CurveRecord);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct OrderRecord {
+    pub ts: i64,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub user: Pubkey,
+    pub order: Order,
+}
+
+impl_discriminator!(event OrderRecord);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct OrderActionRecord {
+    pub ts: i64,
+    pub action: OrderAction,
+    pub action_explanation: OrderActionExplanation,
+    pub market_index: u16,
+    pub market_type: MarketType,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::option_pubkey)
+    )]
+    pub filler: Option<Pubkey>,
+    pub filler_reward: Option<u64>,
+    pub fill_record_id: Option<u64>,
+    pub base_asset_amount_filled: Option<u64>,
+    pub quote_asset_amount_filled: Option<u64>,
+    pub taker_fee: Option<u64>,
+    pub maker_fee: Option<i64>,
+    pub referrer_reward: Option<u32>,
+    pub quote_asset_amount_surplus: Option<i64>,
+    pub spot_fulfillment_method_fee: Option<u64>,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::option_pubkey)
+    )]
+    pub taker: Option<Pubkey>,
+    pub taker_order_id: Option<u32>,
+    pub taker_order_direction: Option<PositionDirection>,
+    pub taker_order_base_asset_amount: Option<u64>,
+    pub taker_order_cumulative_base_asset_amount_filled: Option<u64>,
+    pub taker_order_cumulative_quote_asset_amount_filled: Option<u64>,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::option_pubkey)
+    )]
+    pub maker: Option<Pubkey>,
+    pub maker_order_id: Option<u32>,
+    pub maker_order_direction: Option<PositionDirection>,
+    pub maker_order_base_asset_amount: Option<u64>,
+    pub maker_order_cumulative_base_asset_amount_filled: Option<u64>,
+    pub maker_order_cumulative_quote_asset_amount_filled: Option<u64>,
+    pub oracle_price: i64,
+}
+
+impl_discriminator!(event OrderActionRecord);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct LPRecord {
+    pub ts: i64,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub user: Pubkey,
+    pub action: LPAction,
+    pub n_shares: u64,
+    pub market_index: u16,
+    pub delta_base_asset_amount: i64,
+    pub delta_quote_asset_amount: i64,
+    pub pnl: i64,
+}
+
+impl_discriminator!(event LPRecord);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct LiquidationRecord {
+    pub ts: i64,
+    pub liquidation_type: LiquidationType,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub user: Pubkey,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub liquidator: Pubkey,
+    pub margin_requirement: u128,
+    pub total_collateral: i128,
+    pub margin_freed: u64,
+    pub liquidation_id: u64,
+    pub bankrupt: bool,
+    pub canceled_order_ids: Vec<u32>,
+    pub liquidate_perp: LiquidatePerpRecord,
+    pub liquidate_spot: LiquidateSpotRecord,
+    pub liquidate_borrow_for_perp_pnl: LiquidateBorrowForPerpPnlRecord,
+    pub liquidate_perp_pnl_for_deposit: LiquidatePerpPnlForDepositRecord,
+    pub perp_bankruptcy: PerpBankruptcyRecord,
+    pub spot_bankruptcy: SpotBankruptcyRecord,
+}
+
+impl_discriminator!(event LiquidationRecord);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct SettlePnlRecord {
+    pub ts: i64,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub user: Pubkey,
+    pub market_index: u16,
+    pub pnl: i128,
+    pub base_asset_amount: i64,
+    pub quote_asset_amount_after: i64,
+    pub quote_entry_amount: i64,
+    pub settle_price: i64,
+    pub explanation: SettlePnlExplanation,
+}
+
+impl_discriminator!(event SettlePnlRecord);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct InsuranceFundRecord {
+    pub ts: i64,
+    pub spot_market_index: u16,
+    pub perp_market_index: u16,
+    pub user_if_factor: u32,
+    pub total_if_factor: u32,
+    pub vault_amount_before: u64,
+    pub insurance_vault_amount_before: u64,
+    pub total_if_shares_before: u128,
+    pub total_if_shares_after: u128,
+    pub amount: i64,
+}
+
+impl_discriminator!(event InsuranceFundRecord);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct InsuranceFundStakeRecord {
+    pub ts: i64,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub user_authority: Pubkey,
+    pub action: StakeAction,
+    pub amount: u64,
+    pub market_index: u16,
+    pub insurance_vault_amount_before: u64,
+    pub if_shares_before: u128,
+    pub user_if_shares_before: u128,
+    pub total_if_shares_before: u128,
+    pub if_shares_after: u128,
+    pub user_if_shares_after: u128,
+    pub total_if_shares_after: u128,
+}
+
+impl_discriminator!(event InsuranceFundStakeRecord);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct SwapRecord {
+    pub ts: i64,
+    #[cfg_attr(
+        any(test, feature = "arbitrary"),
+        arbitrary(with = crate::arbitrary_support::pubkey)
+    )]
+    pub user: Pubkey,
+    pub amount_out: u64,
+    pub amount_in: u64,
+    pub out_market_index: u16,
+    pub in_market_index: u16,
+    pub out_oracle_price: i64,
+    pub in_oracle_price: i64,
+    pub fee: u64,
+}
+
+impl_discriminator!(event SwapRecord);
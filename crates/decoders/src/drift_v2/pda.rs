@@ -0,0 +1,603 @@
+use std::collections::HashMap;
+
+use solana_program::pubkey::Pubkey;
+
+use super::{DriftV2Event, DriftV2Instruction, ID};
+
+/// Sub account ids tried when a `User` address is seen without its sub account id.
+pub const MAX_SUB_ACCOUNT_SEARCH: u16 = 8;
+/// Settle pnl always moves quote through the vault of spot market 0.
+const QUOTE_SPOT_MARKET_INDEX: u16 = 0;
+
+pub fn state() -> Pubkey {
+    Pubkey::find_program_address(&[b"drift_state"], &ID).0
+}
+
+pub fn drift_signer() -> Pubkey {
+    Pubkey::find_program_address(&[b"drift_signer"], &ID).0
+}
+
+pub fn user(authority: &Pubkey, sub_account_id: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"user", authority.as_ref(), &sub_account_id.to_le_bytes()],
+        &ID,
+    )
+    .0
+}
+
+pub fn user_stats(authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"user_stats", authority.as_ref()], &ID).0
+}
+
+pub fn perp_market(market_index: u16) -> Pubkey {
+    Pubkey::find_program_address(&[b"perp_market", &market_index.to_le_bytes()], &ID).0
+}
+
+pub fn spot_market(market_index: u16) -> Pubkey {
+    Pubkey::find_program_address(&[b"spot_market", &market_index.to_le_bytes()], &ID).0
+}
+
+pub fn spot_market_vault(market_index: u16) -> Pubkey {
+    Pubkey::find_program_address(&[b"spot_market_vault", &market_index.to_le_bytes()], &ID).0
+}
+
+pub fn insurance_fund_vault(market_index: u16) -> Pubkey {
+    Pubkey::find_program_address(&[b"insurance_fund_vault", &market_index.to_le_bytes()], &ID).0
+}
+
+pub fn insurance_fund_stake(authority: &Pubkey, market_index: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"insurance_fund_stake",
+            authority.as_ref(),
+            &market_index.to_le_bytes(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+/// What a Drift program derived address stands for.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DriftAddress {
+    State,
+    DriftSigner,
+    User {
+        authority: Pubkey,
+        sub_account_id: u16,
+    },
+    UserStats {
+        authority: Pubkey,
+    },
+    PerpMarket {
+        market_index: u16,
+    },
+    SpotMarket {
+        market_index: u16,
+    },
+    SpotMarketVault {
+        market_index: u16,
+    },
+    InsuranceFundVault {
+        market_index: u16,
+    },
+    InsuranceFundStake {
+        authority: Pubkey,
+        market_index: u16,
+    },
+}
+
+/// `User` accounts a Drift event names: the user, and the liquidator, transfer user, filler,
+/// taker or maker where the event has one.
+pub fn event_users(event: &DriftV2Event) -> Vec<Pubkey> {
+    match event {
+        DriftV2Event::NewUserRecord(record) => vec![record.user],
+        DriftV2Event::DepositRecord(record) => {
+            let mut users = vec![record.user];
+            users.extend(record.transfer_user);
+            users
+        }
+        DriftV2Event::FundingPaymentRecord(record) => vec![record.user],
+        DriftV2Event::OrderRecord(record) => vec![record.user],
+        DriftV2Event::OrderActionRecord(record) => [record.filler, record.taker, record.maker]
+            .into_iter()
+            .flatten()
+            .collect(),
+        DriftV2Event::LPRecord(record) => vec![record.user],
+        DriftV2Event::LiquidationRecord(record) => vec![record.user, record.liquidator],
+        DriftV2Event::SettlePnlRecord(record) => vec![record.user],
+        DriftV2Event::SwapRecord(record) => vec![record.user],
+        DriftV2Event::SpotInterestRecord(_)
+        | DriftV2Event::FundingRateRecord(_)
+        | DriftV2Event::CurveRecord(_)
+        | DriftV2Event::InsuranceFundRecord(_)
+        | DriftV2Event::InsuranceFundStakeRecord(_) => vec![],
+    }
+}
+
+/// Reverse lookup from an address to the seeds it was derived from.
+///
+/// Market addresses can be indexed up front with `index_markets`. User, user stats and insurance
+/// fund stake addresses are learned from observed instructions: the derived address is only
+/// recorded when it matches the account actually passed to the instruction.
+pub struct PdaIndex {
+    addresses: HashMap<Pubkey, DriftAddress>,
+}
+
+impl Default for PdaIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PdaIndex {
+    pub fn new() -> Self {
+        let mut addresses = HashMap::new();
+        addresses.insert(state(), DriftAddress::State);
+        addresses.insert(drift_signer(), DriftAddress::DriftSigner);
+
+        Self { addresses }
+    }
+
+    pub fn get(&self, address: &Pubkey) -> Option<&DriftAddress> {
+        self.addresses.get(address)
+    }
+
+    pub fn len(&self) -> usize {
+        self.addresses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty()
+    }
+
+    /// What each of `addresses` the index knows stands for, in order. Used to annotate decoded
+    /// instructions and events, see `event_users`.
+    pub fn describe(&self, addresses: &[Pubkey]) -> Vec<&DriftAddress> {
+        addresses
+            .iter()
+            .filter_map(|address| self.get(address))
+            .collect()
+    }
+
+    /// Indexes the market, vault and insurance fund vault addresses of the first perp and spot
+    /// markets.
+    pub fn index_markets(&mut self, perp_markets: u16, spot_markets: u16) {
+        for market_index in 0..perp_markets {
+            self.addresses.insert(
+                perp_market(market_index),
+                DriftAddress::PerpMarket { market_index },
+            );
+        }
+        for market_index in 0..spot_markets {
+            self.index_spot_market(market_index);
+        }
+    }
+
+    /// Learns the addresses referenced by a decoded instruction and its ordered accounts.
+    pub fn observe(&mut self, instruction: &DriftV2Instruction, accounts: &[Pubkey]) {
+        match instruction {
+            DriftV2Instruction::InitializeUser(args) => {
+                self.observe_user(accounts, 0, 3, Some(args.sub_account_id));
+                self.observe_user_stats(accounts, 1, 3);
+            }
+            DriftV2Instruction::InitializeUserStats => self.observe_user_stats(accounts, 0, 2),
+            DriftV2Instruction::InitializeReferrerName(_) => {
+                self.observe_user(accounts, 1, 3, None);
+                self.observe_user_stats(accounts, 2, 3);
+            }
+            DriftV2Instruction::Deposit(args) => {
+                self.observe_user(accounts, 1, 3, None);
+                self.observe_user_stats(accounts, 2, 3);
+                self.observe_spot_market_vault(accounts, 4, args.market_index);
+            }
+            DriftV2Instruction::Withdraw(args) => {
+                self.observe_user(accounts, 1, 3, None);
+                self.observe_user_stats(accounts, 2, 3);
+                self.observe_spot_market_vault(accounts, 4, args.market_index);
+            }
+            DriftV2Instruction::TransferDeposit(args) => {
+                self.observe_user(accounts, 0, 3, None);
+                self.observe_user(accounts, 1, 3, None);
+                self.observe_user_stats(accounts, 2, 3);
+                self.observe_spot_market_vault(accounts, 5, args.market_index);
+            }
+            DriftV2Instruction::PlacePerpOrder(_)
+            | DriftV2Instruction::CancelOrder(_)
+            | DriftV2Instruction::CancelOrderByUserId(_)
+            | DriftV2Instruction::CancelOrders(_)
+            | DriftV2Instruction::CancelOrdersByIds(_)
+            | DriftV2Instruction::ModifyOrder(_)
+            | DriftV2Instruction::PlaceSpotOrder(_)
+            | DriftV2Instruction::PlaceOrders(_)
+            | DriftV2Instruction::AddPerpLpShares(_)
+            | DriftV2Instruction::RemovePerpLpShares(_) => {
+                self.observe_user(accounts, 1, 2, None);
+            }
+            DriftV2Instruction::PlaceAndTakePerpOrder(_) => {
+                self.observe_user(accounts, 1, 3, None);
+                self.observe_user_stats(accounts, 2, 3);
+            }
+            DriftV2Instruction::PlaceAndMakePerpOrder(_) => {
+                self.observe_user(accounts, 1, 5, None);
+                self.observe_user_stats(accounts, 2, 5);
+            }
+            DriftV2Instruction::BeginSwap(args) => {
+                self.observe_user(accounts, 1, 3, None);
+                self.observe_user_stats(accounts, 2, 3);
+                self.observe_spot_market_vault(accounts, 4, args.out_market_index);
+                self.observe_spot_market_vault(accounts, 5, args.in_market_index);
+            }
+            DriftV2Instruction::EndSwap(args) => {
+                self.observe_user(accounts, 1, 3, None);
+                self.observe_user_stats(accounts, 2, 3);
+                self.observe_spot_market_vault(accounts, 4, args.out_market_index);
+                self.observe_spot_market_vault(accounts, 5, args.in_market_index);
+            }
+            DriftV2Instruction::UpdateUserCustomMarginRatio(args) => {
+                self.observe_user(accounts, 0, 1, Some(args.sub_account_id));
+            }
+            DriftV2Instruction::UpdateUserMarginTradingEnabled(args) => {
+                self.observe_user(accounts, 0, 1, Some(args.sub_account_id));
+            }
+            DriftV2Instruction::DeleteUser | DriftV2Instruction::ReclaimRent => {
+                self.observe_user(accounts, 0, 3, None);
+                self.observe_user_stats(accounts, 1, 3);
+            }
+            DriftV2Instruction::SettlePnl(_) | DriftV2Instruction::SettleMultiplePnls(_) => {
+                self.observe_user(accounts, 1, 2, None);
+                self.observe_spot_market_vault(accounts, 3, QUOTE_SPOT_MARKET_INDEX);
+            }
+            DriftV2Instruction::SettleRevenueToInsuranceFund(args) => {
+                self.observe_spot_market(accounts, 1, args.spot_market_index);
+            }
+            DriftV2Instruction::UpdateFundingRate(args) => {
+                self.observe_perp_market(accounts, 1, args.market_index);
+            }
+            DriftV2Instruction::InitializeInsuranceFundStake(args) => {
+                self.observe_spot_market(accounts, 0, args.market_index);
+                self.observe_insurance_fund_stake(accounts, 1, 4, args.market_index);
+                self.observe_user_stats(accounts, 2, 4);
+            }
+            DriftV2Instruction::AddInsuranceFundStake(args) => {
+                self.observe_spot_market(accounts, 1, args.market_index);
+                self.observe_insurance_fund_stake(accounts, 2, 4, args.market_index);
+                self.observe_user_stats(accounts, 3, 4);
+            }
+            DriftV2Instruction::RequestRemoveInsuranceFundStake(args) => {
+                self.observe_spot_market(accounts, 0, args.market_index);
+                self.observe_insurance_fund_stake(accounts, 1, 3, args.market_index);
+                self.observe_user_stats(accounts, 2, 3);
+            }
+            DriftV2Instruction::CancelRequestRemoveInsuranceFundStake(args) => {
+                self.observe_spot_market(accounts, 0, args.market_index);
+                self.observe_insurance_fund_stake(accounts, 1, 3, args.market_index);
+                self.observe_user_stats(accounts, 2, 3);
+            }
+            DriftV2Instruction::RemoveInsuranceFundStake(args) => {
+                self.observe_spot_market(accounts, 1, args.market_index);
+                self.observe_insurance_fund_stake(accounts, 2, 4, args.market_index);
+                self.observe_user_stats(accounts, 3, 4);
+            }
+            _ => {}
+        }
+    }
+
+    fn index_spot_market(&mut self, market_index: u16) {
+        self.addresses.insert(
+            spot_market(market_index),
+            DriftAddress::SpotMarket { market_index },
+        );
+        self.addresses.insert(
+            spot_market_vault(market_index),
+            DriftAddress::SpotMarketVault { market_index },
+        );
+        self.addresses.insert(
+            insurance_fund_vault(market_index),
+            DriftAddress::InsuranceFundVault { market_index },
+        );
+    }
+
+    /// Records `address` when the account at `position` is the expected derived address.
+    fn record(
+        &mut self,
+        accounts: &[Pubkey],
+        position: usize,
+        address: Pubkey,
+        kind: DriftAddress,
+    ) {
+        if accounts.get(position) == Some(&address) {
+            self.addresses.insert(address, kind);
+        }
+    }
+
+    fn observe_user(
+        &mut self,
+        accounts: &[Pubkey],
+        position: usize,
+        authority_position: usize,
+        sub_account_id: Option<u16>,
+    ) {
+        let (address, authority) = match (accounts.get(position), accounts.get(authority_position))
+        {
+            (Some(address), Some(authority)) => (*address, *authority),
+            _ => return,
+        };
+        if self.addresses.contains_key(&address) {
+            return;
+        }
+
+        let sub_account_ids = match sub_account_id {
+            Some(sub_account_id) => sub_account_id..sub_account_id + 1,
+            None => 0..MAX_SUB_ACCOUNT_SEARCH,
+        };
+        for sub_account_id in sub_account_ids {
+            if user(&authority, sub_account_id) == address {
+                self.addresses.insert(
+                    address,
+                    DriftAddress::User {
+                        authority,
+                        sub_account_id,
+                    },
+                );
+                return;
+            }
+        }
+    }
+
+    fn observe_user_stats(
+        &mut self,
+        accounts: &[Pubkey],
+        position: usize,
+        authority_position: usize,
+    ) {
+        if let Some(authority) = accounts.get(authority_position).copied() {
+            self.record(
+                accounts,
+                position,
+                user_stats(&authority),
+                DriftAddress::UserStats { authority },
+            );
+        }
+    }
+
+    fn observe_insurance_fund_stake(
+        &mut self,
+        accounts: &[Pubkey],
+        position: usize,
+        authority_position: usize,
+        market_index: u16,
+    ) {
+        if let Some(authority) = accounts.get(authority_position).copied() {
+            self.record(
+                accounts,
+                position,
+                insurance_fund_stake(&authority, market_index),
+                DriftAddress::InsuranceFundStake {
+                    authority,
+                    market_index,
+                },
+            );
+        }
+    }
+
+    fn observe_perp_market(&mut self, accounts: &[Pubkey], position: usize, market_index: u16) {
+        self.record(
+            accounts,
+            position,
+            perp_market(market_index),
+            DriftAddress::PerpMarket { market_index },
+        );
+    }
+
+    fn observe_spot_market(&mut self, accounts: &[Pubkey], position: usize, market_index: u16) {
+        if accounts.get(position) == Some(&spot_market(market_index)) {
+            self.index_spot_market(market_index);
+        }
+    }
+
+    fn observe_spot_market_vault(
+        &mut self,
+        accounts: &[Pubkey],
+        position: usize,
+        market_index: u16,
+    ) {
+        if accounts.get(position) == Some(&spot_market_vault(market_index)) {
+            self.index_spot_market(market_index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::drift_v2::{Deposit, FundingPaymentRecord, InitializeUser};
+
+    fn address(address: &str) -> Pubkey {
+        Pubkey::from_str(address).unwrap()
+    }
+
+    #[test]
+    fn derives_mainnet_addresses() {
+        assert_eq!(
+            state(),
+            address("5zpq7DvB6UdFFvpmBPspGPNfUGoBRRCE2HHg5u3gxcsN")
+        );
+        assert_eq!(
+            drift_signer(),
+            address("JCNCMFXo5M5qwUPg2Utu1u6YWp3MbygxqBsBeXXJfrw")
+        );
+        assert_eq!(
+            perp_market(0),
+            address("8UJgxaiQx5nTrdDgph5FiahMmzduuLTLf5WmsPegYA6W")
+        );
+        assert_eq!(
+            spot_market(0),
+            address("6gMq3mRCKf8aP3ttTyYhuijVZ2LGi14oDsBbkgubfLB3")
+        );
+        assert_eq!(
+            spot_market(1),
+            address("3x85u7SWkmmr7YQGYhtjARgxwegTLJgkSLRprfXod6rh")
+        );
+        assert_eq!(
+            spot_market_vault(0),
+            address("GXWqPpjQpdz7KZw9p7f5PX2eGxHAhvpNXiviFkAB8zXg")
+        );
+    }
+
+    #[test]
+    fn new_index_knows_state_and_signer() {
+        let index = PdaIndex::new();
+
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.get(&state()), Some(&DriftAddress::State));
+        assert_eq!(index.get(&drift_signer()), Some(&DriftAddress::DriftSigner));
+    }
+
+    #[test]
+    fn index_markets_records_markets_and_vaults() {
+        let mut index = PdaIndex::new();
+        index.index_markets(2, 1);
+
+        assert_eq!(index.len(), 2 + 2 + 3);
+        assert_eq!(
+            index.get(&perp_market(1)),
+            Some(&DriftAddress::PerpMarket { market_index: 1 })
+        );
+        assert_eq!(
+            index.get(&spot_market_vault(0)),
+            Some(&DriftAddress::SpotMarketVault { market_index: 0 })
+        );
+        assert_eq!(
+            index.get(&insurance_fund_vault(0)),
+            Some(&DriftAddress::InsuranceFundVault { market_index: 0 })
+        );
+        assert_eq!(index.get(&spot_market(1)), None);
+    }
+
+    #[test]
+    fn observe_initialize_user_learns_user_and_stats() {
+        let authority = Pubkey::new_unique();
+        let accounts = [
+            user(&authority, 3),
+            user_stats(&authority),
+            state(),
+            authority,
+        ];
+        let instruction = DriftV2Instruction::InitializeUser(InitializeUser {
+            sub_account_id: 3,
+            name: [0; 32],
+        });
+
+        let mut index = PdaIndex::new();
+        index.observe(&instruction, &accounts);
+
+        assert_eq!(
+            index.get(&accounts[0]),
+            Some(&DriftAddress::User {
+                authority,
+                sub_account_id: 3,
+            })
+        );
+        assert_eq!(
+            index.get(&accounts[1]),
+            Some(&DriftAddress::UserStats { authority })
+        );
+    }
+
+    #[test]
+    fn describes_known_event_users() {
+        let authority = Pubkey::new_unique();
+        let mut index = PdaIndex::new();
+        index.observe(
+            &DriftV2Instruction::InitializeUser(InitializeUser {
+                sub_account_id: 0,
+                name: [0; 32],
+            }),
+            &[
+                user(&authority, 0),
+                user_stats(&authority),
+                state(),
+                authority,
+            ],
+        );
+
+        let event = DriftV2Event::FundingPaymentRecord(FundingPaymentRecord {
+            ts: 0,
+            user_authority: authority,
+            user: user(&authority, 0),
+            market_index: 0,
+            funding_payment: 0,
+            base_asset_amount: 0,
+            user_last_cumulative_funding: 0,
+            amm_cumulative_funding_long: 0,
+            amm_cumulative_funding_short: 0,
+        });
+        let mut users = event_users(&event);
+        users.push(Pubkey::new_unique());
+        assert_eq!(
+            index.describe(&users),
+            [&DriftAddress::User {
+                authority,
+                sub_account_id: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn observe_searches_sub_account_ids() {
+        let authority = Pubkey::new_unique();
+        let accounts = [
+            state(),
+            user(&authority, 5),
+            user_stats(&authority),
+            authority,
+            spot_market_vault(1),
+        ];
+        let instruction = DriftV2Instruction::Deposit(Deposit {
+            market_index: 1,
+            amount: 1,
+            reduce_only: false,
+        });
+
+        let mut index = PdaIndex::new();
+        index.observe(&instruction, &accounts);
+
+        assert_eq!(
+            index.get(&accounts[1]),
+            Some(&DriftAddress::User {
+                authority,
+                sub_account_id: 5,
+            })
+        );
+        assert_eq!(
+            index.get(&accounts[4]),
+            Some(&DriftAddress::SpotMarketVault { market_index: 1 })
+        );
+    }
+
+    #[test]
+    fn observe_ignores_accounts_that_do_not_match() {
+        let authority = Pubkey::new_unique();
+        let accounts = [
+            state(),
+            user(&authority, MAX_SUB_ACCOUNT_SEARCH),
+            Pubkey::new_unique(),
+            authority,
+            spot_market_vault(2),
+        ];
+        let instruction = DriftV2Instruction::Deposit(Deposit {
+            market_index: 1,
+            amount: 1,
+            reduce_only: false,
+        });
+
+        let mut index = PdaIndex::new();
+        index.observe(&instruction, &accounts);
+        index.observe(&instruction, &accounts[..2]);
+
+        assert_eq!(index.len(), 2);
+    }
+}
@@ -0,0 +1,556 @@
+use std::str::FromStr;
+
+use anyhow::bail;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+
+use super::{
+    DriftV2Instruction, MarketType, ModifyOrder, ModifyOrderByUserId, ModifyOrderParams,
+    OrderParams, OrderTriggerCondition, OrderType, PlaceAndMakePerpOrder, PlaceAndMakeSpotOrder,
+    PlaceAndTakePerpOrder, PlaceAndTakeSpotOrder, PlaceOrders, PlacePerpOrder, PlaceSpotOrder,
+    PositionDirection, PostOnlyParam, SpotFulfillmentType,
+};
+use crate::Discriminator;
+
+/// Layout revisions of the Drift V2 instruction arguments.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum IdlRevision {
+    /// `OrderParams` without the trailing auction fields and `ModifyOrderParams` without the
+    /// trailing `policy` field
+    V1,
+    /// Layout of the definitions generated from `idl/drift.json`
+    V2,
+}
+
+impl IdlRevision {
+    pub const OLDEST: IdlRevision = IdlRevision::V1;
+    pub const LATEST: IdlRevision = IdlRevision::V2;
+}
+
+impl FromStr for IdlRevision {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "v1" => Ok(IdlRevision::V1),
+            "v2" => Ok(IdlRevision::V2),
+            _ => bail!("Unknown IDL revision: {}", s),
+        }
+    }
+}
+
+/// Slot from which a revision is live on chain.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Upgrade {
+    pub slot: u64,
+    pub revision: IdlRevision,
+}
+
+/// Decodes instructions with the layout that was live at their slot.
+///
+/// Slots before the first upgrade use `IdlRevision::OLDEST`. Without any upgrade every slot uses
+/// `IdlRevision::LATEST`.
+#[derive(Clone, Debug, Default)]
+pub struct VersionedDecoder {
+    upgrades: Vec<Upgrade>,
+}
+
+impl VersionedDecoder {
+    pub fn new(mut upgrades: Vec<Upgrade>) -> Self {
+        upgrades.sort_by_key(|upgrade| upgrade.slot);
+        Self { upgrades }
+    }
+
+    pub fn revision_at(&self, slot: u64) -> IdlRevision {
+        if self.upgrades.is_empty() {
+            return IdlRevision::LATEST;
+        }
+
+        self.upgrades
+            .iter()
+            .take_while(|upgrade| upgrade.slot <= slot)
+            .last()
+            .map_or(IdlRevision::OLDEST, |upgrade| upgrade.revision)
+    }
+
+    pub fn unpack_instruction(
+        &self,
+        slot: u64,
+        input: &[u8],
+    ) -> Result<DriftV2Instruction, ProgramError> {
+        unpack_instruction(self.revision_at(slot), input)
+    }
+}
+
+/// Decodes an instruction with the layout of `revision`. Older layouts are converted to the
+/// current definitions, with fields they lacked left to `None`.
+pub fn unpack_instruction(
+    revision: IdlRevision,
+    input: &[u8],
+) -> Result<DriftV2Instruction, ProgramError> {
+    if revision == IdlRevision::V1 && input.len() >= 8 {
+        let (discriminator, rest) = input.split_at(8);
+        if let Some(instruction) = unpack_v1(discriminator, rest)? {
+            return Ok(instruction);
+        }
+    }
+
+    DriftV2Instruction::unpack(input)
+}
+
+/// Decodes the instructions whose arguments changed since `IdlRevision::V1`, `None` for the
+/// others.
+fn unpack_v1(
+    discriminator: &[u8],
+    rest: &[u8],
+) -> Result<Option<DriftV2Instruction>, ProgramError> {
+    let Ok(discriminator) = <[u8; 8]>::try_from(discriminator) else {
+        return Ok(None);
+    };
+
+    Ok(Some(match discriminator {
+        PlacePerpOrder::DISCRIMINATOR => {
+            DriftV2Instruction::PlacePerpOrder(from_v1::<PlacePerpOrderV1, _>(rest)?)
+        }
+        PlaceAndTakePerpOrder::DISCRIMINATOR => {
+            DriftV2Instruction::PlaceAndTakePerpOrder(from_v1::<PlaceAndTakePerpOrderV1, _>(rest)?)
+        }
+        PlaceAndMakePerpOrder::DISCRIMINATOR => {
+            DriftV2Instruction::PlaceAndMakePerpOrder(from_v1::<PlaceAndMakePerpOrderV1, _>(rest)?)
+        }
+        PlaceSpotOrder::DISCRIMINATOR => {
+            DriftV2Instruction::PlaceSpotOrder(from_v1::<PlaceSpotOrderV1, _>(rest)?)
+        }
+        PlaceAndTakeSpotOrder::DISCRIMINATOR => {
+            DriftV2Instruction::PlaceAndTakeSpotOrder(from_v1::<PlaceAndTakeSpotOrderV1, _>(rest)?)
+        }
+        PlaceAndMakeSpotOrder::DISCRIMINATOR => {
+            DriftV2Instruction::PlaceAndMakeSpotOrder(from_v1::<PlaceAndMakeSpotOrderV1, _>(rest)?)
+        }
+        PlaceOrders::DISCRIMINATOR => {
+            DriftV2Instruction::PlaceOrders(from_v1::<PlaceOrdersV1, _>(rest)?)
+        }
+        ModifyOrder::DISCRIMINATOR => {
+            DriftV2Instruction::ModifyOrder(from_v1::<ModifyOrderV1, _>(rest)?)
+        }
+        ModifyOrderByUserId::DISCRIMINATOR => {
+            DriftV2Instruction::ModifyOrderByUserId(from_v1::<ModifyOrderByUserIdV1, _>(rest)?)
+        }
+        _ => return Ok(None),
+    }))
+}
+
+fn from_v1<V: BorshDeserialize + Into<T>, T>(rest: &[u8]) -> Result<T, ProgramError> {
+    Ok(borsh::from_slice::<V>(rest)?.into())
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct OrderParamsV1 {
+    pub order_type: OrderType,
+    pub market_type: MarketType,
+    pub direction: PositionDirection,
+    pub user_order_id: u8,
+    pub base_asset_amount: u64,
+    pub price: u64,
+    pub market_index: u16,
+    pub reduce_only: bool,
+    pub post_only: PostOnlyParam,
+    pub immediate_or_cancel: bool,
+    pub max_ts: Option<i64>,
+    pub trigger_price: Option<u64>,
+    pub trigger_condition: OrderTriggerCondition,
+    pub oracle_price_offset: Option<i32>,
+}
+
+impl From<OrderParamsV1> for OrderParams {
+    fn from(params: OrderParamsV1) -> Self {
+        Self {
+            order_type: params.order_type,
+            market_type: params.market_type,
+            direction: params.direction,
+            user_order_id: params.user_order_id,
+            base_asset_amount: params.base_asset_amount,
+            price: params.price,
+            market_index: params.market_index,
+            reduce_only: params.reduce_only,
+            post_only: params.post_only,
+            immediate_or_cancel: params.immediate_or_cancel,
+            max_ts: params.max_ts,
+            trigger_price: params.trigger_price,
+            trigger_condition: params.trigger_condition,
+            oracle_price_offset: params.oracle_price_offset,
+            auction_duration: None,
+            auction_start_price: None,
+            auction_end_price: None,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlacePerpOrderV1 {
+    pub params: OrderParamsV1,
+}
+
+impl From<PlacePerpOrderV1> for PlacePerpOrder {
+    fn from(place: PlacePerpOrderV1) -> Self {
+        Self {
+            params: place.params.into(),
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceAndTakePerpOrderV1 {
+    pub params: OrderParamsV1,
+    pub maker_order_id: Option<u32>,
+}
+
+impl From<PlaceAndTakePerpOrderV1> for PlaceAndTakePerpOrder {
+    fn from(place: PlaceAndTakePerpOrderV1) -> Self {
+        Self {
+            params: place.params.into(),
+            maker_order_id: place.maker_order_id,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceAndMakePerpOrderV1 {
+    pub params: OrderParamsV1,
+    pub taker_order_id: u32,
+}
+
+impl From<PlaceAndMakePerpOrderV1> for PlaceAndMakePerpOrder {
+    fn from(place: PlaceAndMakePerpOrderV1) -> Self {
+        Self {
+            params: place.params.into(),
+            taker_order_id: place.taker_order_id,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceSpotOrderV1 {
+    pub params: OrderParamsV1,
+}
+
+impl From<PlaceSpotOrderV1> for PlaceSpotOrder {
+    fn from(place: PlaceSpotOrderV1) -> Self {
+        Self {
+            params: place.params.into(),
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceAndTakeSpotOrderV1 {
+    pub params: OrderParamsV1,
+    pub fulfillment_type: Option<SpotFulfillmentType>,
+    pub maker_order_id: Option<u32>,
+}
+
+impl From<PlaceAndTakeSpotOrderV1> for PlaceAndTakeSpotOrder {
+    fn from(place: PlaceAndTakeSpotOrderV1) -> Self {
+        Self {
+            params: place.params.into(),
+            fulfillment_type: place.fulfillment_type,
+            maker_order_id: place.maker_order_id,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceAndMakeSpotOrderV1 {
+    pub params: OrderParamsV1,
+    pub taker_order_id: u32,
+    pub fulfillment_type: Option<SpotFulfillmentType>,
+}
+
+impl From<PlaceAndMakeSpotOrderV1> for PlaceAndMakeSpotOrder {
+    fn from(place: PlaceAndMakeSpotOrderV1) -> Self {
+        Self {
+            params: place.params.into(),
+            taker_order_id: place.taker_order_id,
+            fulfillment_type: place.fulfillment_type,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceOrdersV1 {
+    pub params: Vec<OrderParamsV1>,
+}
+
+impl From<PlaceOrdersV1> for PlaceOrders {
+    fn from(place: PlaceOrdersV1) -> Self {
+        Self {
+            params: place.params.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ModifyOrderV1 {
+    pub order_id: Option<u32>,
+    pub modify_order_params: ModifyOrderParamsV1,
+}
+
+impl From<ModifyOrderV1> for ModifyOrder {
+    fn from(modify: ModifyOrderV1) -> Self {
+        Self {
+            order_id: modify.order_id,
+            modify_order_params: modify.modify_order_params.into(),
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ModifyOrderByUserIdV1 {
+    pub user_order_id: u8,
+    pub modify_order_params: ModifyOrderParamsV1,
+}
+
+impl From<ModifyOrderByUserIdV1> for ModifyOrderByUserId {
+    fn from(modify: ModifyOrderByUserIdV1) -> Self {
+        Self {
+            user_order_id: modify.user_order_id,
+            modify_order_params: modify.modify_order_params.into(),
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ModifyOrderParamsV1 {
+    pub direction: Option<PositionDirection>,
+    pub base_asset_amount: Option<u64>,
+    pub price: Option<u64>,
+    pub reduce_only: Option<bool>,
+    pub post_only: Option<PostOnlyParam>,
+    pub immediate_or_cancel: Option<bool>,
+    pub max_ts: Option<i64>,
+    pub trigger_price: Option<u64>,
+    pub trigger_condition: Option<OrderTriggerCondition>,
+    pub oracle_price_offset: Option<i32>,
+    pub auction_duration: Option<u8>,
+    pub auction_start_price: Option<i64>,
+    pub auction_end_price: Option<i64>,
+}
+
+impl From<ModifyOrderParamsV1> for ModifyOrderParams {
+    fn from(params: ModifyOrderParamsV1) -> Self {
+        Self {
+            direction: params.direction,
+            base_asset_amount: params.base_asset_amount,
+            price: params.price,
+            reduce_only: params.reduce_only,
+            post_only: params.post_only,
+            immediate_or_cancel: params.immediate_or_cancel,
+            max_ts: params.max_ts,
+            trigger_price: params.trigger_price,
+            trigger_condition: params.trigger_condition,
+            oracle_price_offset: params.oracle_price_offset,
+            auction_duration: params.auction_duration,
+            auction_start_price: params.auction_start_price,
+            auction_end_price: params.auction_end_price,
+            policy: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drift_v2::Deposit;
+
+    const UPGRADE_SLOT: u64 = 200;
+
+    fn decoder() -> VersionedDecoder {
+        VersionedDecoder::new(vec![Upgrade {
+            slot: UPGRADE_SLOT,
+            revision: IdlRevision::V2,
+        }])
+    }
+
+    fn instruction<D: Discriminator, T: BorshSerialize>(args: &T) -> Vec<u8> {
+        let mut data = D::DISCRIMINATOR.to_vec();
+        data.extend(borsh::to_vec(args).unwrap());
+        data
+    }
+
+    fn order_params_v1() -> OrderParamsV1 {
+        OrderParamsV1 {
+            order_type: OrderType::Limit,
+            market_type: MarketType::Perp,
+            direction: PositionDirection::Short,
+            user_order_id: 4,
+            base_asset_amount: 1_000_000_000,
+            price: 150_000_000,
+            market_index: 0,
+            reduce_only: false,
+            post_only: PostOnlyParam::MustPostOnly,
+            immediate_or_cancel: false,
+            max_ts: None,
+            trigger_price: None,
+            trigger_condition: OrderTriggerCondition::Above,
+            oracle_price_offset: Some(-50),
+        }
+    }
+
+    fn modify_order_params_v1() -> ModifyOrderParamsV1 {
+        ModifyOrderParamsV1 {
+            direction: None,
+            base_asset_amount: Some(2),
+            price: Some(3),
+            reduce_only: None,
+            post_only: None,
+            immediate_or_cancel: None,
+            max_ts: None,
+            trigger_price: None,
+            trigger_condition: None,
+            oracle_price_offset: None,
+            auction_duration: Some(10),
+            auction_start_price: None,
+            auction_end_price: None,
+        }
+    }
+
+    #[test]
+    fn selects_revision_by_slot() {
+        let decoder = decoder();
+
+        assert_eq!(decoder.revision_at(0), IdlRevision::V1);
+        assert_eq!(decoder.revision_at(UPGRADE_SLOT - 1), IdlRevision::V1);
+        assert_eq!(decoder.revision_at(UPGRADE_SLOT), IdlRevision::V2);
+        assert_eq!(decoder.revision_at(u64::MAX), IdlRevision::V2);
+        assert_eq!(
+            VersionedDecoder::default().revision_at(0),
+            IdlRevision::LATEST
+        );
+    }
+
+    #[test]
+    fn unsorted_upgrades_are_ordered_by_slot() {
+        let decoder = VersionedDecoder::new(vec![
+            Upgrade {
+                slot: 300,
+                revision: IdlRevision::V2,
+            },
+            Upgrade {
+                slot: 100,
+                revision: IdlRevision::V1,
+            },
+        ]);
+
+        assert_eq!(decoder.revision_at(150), IdlRevision::V1);
+        assert_eq!(decoder.revision_at(300), IdlRevision::V2);
+    }
+
+    #[test]
+    fn parses_revisions_and_keeps_unknown_input() {
+        assert_eq!("v1".parse::<IdlRevision>().unwrap(), IdlRevision::V1);
+        assert_eq!("v2".parse::<IdlRevision>().unwrap(), IdlRevision::V2);
+
+        let err = "v9".parse::<IdlRevision>().unwrap_err();
+        assert!(err.to_string().contains("v9"));
+    }
+
+    #[test]
+    fn decodes_v1_order_params_before_the_upgrade() {
+        let decoder = decoder();
+        let data = instruction::<PlacePerpOrder, _>(&PlacePerpOrderV1 {
+            params: order_params_v1(),
+        });
+
+        let decoded = decoder.unpack_instruction(UPGRADE_SLOT - 1, &data).unwrap();
+        assert_eq!(
+            decoded,
+            DriftV2Instruction::PlacePerpOrder(PlacePerpOrder {
+                params: order_params_v1().into(),
+            })
+        );
+        assert!(decoder.unpack_instruction(UPGRADE_SLOT, &data).is_err());
+    }
+
+    #[test]
+    fn decodes_v2_order_params_after_the_upgrade() {
+        let decoder = decoder();
+        let place = PlaceAndTakePerpOrder {
+            params: OrderParams {
+                auction_duration: Some(10),
+                auction_start_price: Some(149_000_000),
+                auction_end_price: Some(151_000_000),
+                ..order_params_v1().into()
+            },
+            maker_order_id: Some(7),
+        };
+        let data = instruction::<PlaceAndTakePerpOrder, _>(&place);
+
+        assert_eq!(
+            decoder.unpack_instruction(UPGRADE_SLOT, &data).unwrap(),
+            DriftV2Instruction::PlaceAndTakePerpOrder(place)
+        );
+        assert!(decoder.unpack_instruction(UPGRADE_SLOT - 1, &data).is_err());
+    }
+
+    #[test]
+    fn decodes_v1_order_lists() {
+        let data = instruction::<PlaceOrders, _>(&PlaceOrdersV1 {
+            params: vec![order_params_v1(), order_params_v1()],
+        });
+
+        let decoded = decoder().unpack_instruction(0, &data).unwrap();
+        let DriftV2Instruction::PlaceOrders(place) = decoded else {
+            panic!("Unexpected instruction: {:?}", decoded);
+        };
+        assert_eq!(place.params.len(), 2);
+        assert_eq!(place.params[1].auction_duration, None);
+    }
+
+    #[test]
+    fn decodes_v1_modify_order_params_before_the_upgrade() {
+        let decoder = decoder();
+        let by_id = instruction::<ModifyOrder, _>(&ModifyOrderV1 {
+            order_id: Some(1),
+            modify_order_params: modify_order_params_v1(),
+        });
+        let by_user_id = instruction::<ModifyOrderByUserId, _>(&ModifyOrderByUserIdV1 {
+            user_order_id: 4,
+            modify_order_params: modify_order_params_v1(),
+        });
+
+        assert_eq!(
+            decoder.unpack_instruction(0, &by_id).unwrap(),
+            DriftV2Instruction::ModifyOrder(ModifyOrder {
+                order_id: Some(1),
+                modify_order_params: modify_order_params_v1().into(),
+            })
+        );
+        assert_eq!(
+            decoder.unpack_instruction(0, &by_user_id).unwrap(),
+            DriftV2Instruction::ModifyOrderByUserId(ModifyOrderByUserId {
+                user_order_id: 4,
+                modify_order_params: modify_order_params_v1().into(),
+            })
+        );
+        assert!(decoder
+            .unpack_instruction(UPGRADE_SLOT, &by_user_id)
+            .is_err());
+    }
+
+    #[test]
+    fn unchanged_instructions_decode_under_every_revision() {
+        let deposit = Deposit {
+            market_index: 1,
+            amount: 5,
+            reduce_only: false,
+        };
+        let data = instruction::<Deposit, _>(&deposit);
+
+        for slot in [0, UPGRADE_SLOT] {
+            assert_eq!(
+                decoder().unpack_instruction(slot, &data).unwrap(),
+                DriftV2Instruction::Deposit(deposit.clone())
+            );
+        }
+    }
+}
@@ -0,0 +1,564 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{discriminator::EVENT_IX_TAG, impl_discriminator, Discriminator};
+
+solana_program::declare_id!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+
+/// Instructions
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum JupiterV6Instruction {
+    Route(Route),
+    RouteWithTokenLedger(RouteWithTokenLedger),
+    ExactOutRoute(ExactOutRoute),
+    SharedAccountsRoute(SharedAccountsRoute),
+    SharedAccountsRouteWithTokenLedger(SharedAccountsRouteWithTokenLedger),
+    SharedAccountsExactOutRoute(SharedAccountsExactOutRoute),
+    SetTokenLedger,
+    CreateOpenOrders,
+    CreateTokenAccount(CreateTokenAccount),
+    CreateProgramOpenOrders(CreateProgramOpenOrders),
+    Claim(Claim),
+    ClaimToken(ClaimToken),
+    CreateTokenLedger,
+}
+
+impl JupiterV6Instruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (discriminator, rest) = input.split_at(8);
+        if let Ok(discriminator) = discriminator.try_into() {
+            return Ok(match discriminator {
+                Route::DISCRIMINATOR => Self::Route(borsh::from_slice(rest)?),
+                RouteWithTokenLedger::DISCRIMINATOR => {
+                    Self::RouteWithTokenLedger(borsh::from_slice(rest)?)
+                }
+                ExactOutRoute::DISCRIMINATOR => Self::ExactOutRoute(borsh::from_slice(rest)?),
+                SharedAccountsRoute::DISCRIMINATOR => {
+                    Self::SharedAccountsRoute(borsh::from_slice(rest)?)
+                }
+                SharedAccountsRouteWithTokenLedger::DISCRIMINATOR => {
+                    Self::SharedAccountsRouteWithTokenLedger(borsh::from_slice(rest)?)
+                }
+                SharedAccountsExactOutRoute::DISCRIMINATOR => {
+                    Self::SharedAccountsExactOutRoute(borsh::from_slice(rest)?)
+                }
+                SetTokenLedger::DISCRIMINATOR => Self::SetTokenLedger,
+                CreateOpenOrders::DISCRIMINATOR => Self::CreateOpenOrders,
+                CreateTokenAccount::DISCRIMINATOR => {
+                    Self::CreateTokenAccount(borsh::from_slice(rest)?)
+                }
+                CreateProgramOpenOrders::DISCRIMINATOR => {
+                    Self::CreateProgramOpenOrders(borsh::from_slice(rest)?)
+                }
+                Claim::DISCRIMINATOR => Self::Claim(borsh::from_slice(rest)?),
+                ClaimToken::DISCRIMINATOR => Self::ClaimToken(borsh::from_slice(rest)?),
+                CreateTokenLedger::DISCRIMINATOR => Self::CreateTokenLedger,
+                _ => return Err(ProgramError::InvalidInstructionData),
+            });
+        }
+
+        Err(ProgramError::InvalidInstructionData)
+    }
+
+    /// Hops of the route, `None` for instructions that don't swap.
+    pub fn route_plan(&self) -> Option<&[RoutePlanStep]> {
+        match self {
+            Self::Route(route) => Some(&route.route_plan),
+            Self::RouteWithTokenLedger(route) => Some(&route.route_plan),
+            Self::ExactOutRoute(route) => Some(&route.route_plan),
+            Self::SharedAccountsRoute(route) => Some(&route.route_plan),
+            Self::SharedAccountsRouteWithTokenLedger(route) => Some(&route.route_plan),
+            Self::SharedAccountsExactOutRoute(route) => Some(&route.route_plan),
+            _ => None,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Route {
+    pub route_plan: Vec<RoutePlanStep>,
+    pub in_amount: u64,
+    pub quoted_out_amount: u64,
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
+}
+
+impl_discriminator!(instruction Route);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RouteWithTokenLedger {
+    pub route_plan: Vec<RoutePlanStep>,
+    pub quoted_out_amount: u64,
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
+}
+
+impl_discriminator!(instruction RouteWithTokenLedger);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ExactOutRoute {
+    pub route_plan: Vec<RoutePlanStep>,
+    pub out_amount: u64,
+    pub quoted_in_amount: u64,
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
+}
+
+impl_discriminator!(instruction ExactOutRoute);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SharedAccountsRoute {
+    pub id: u8,
+    pub route_plan: Vec<RoutePlanStep>,
+    pub in_amount: u64,
+    pub quoted_out_amount: u64,
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
+}
+
+impl_discriminator!(instruction SharedAccountsRoute);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SharedAccountsRouteWithTokenLedger {
+    pub id: u8,
+    pub route_plan: Vec<RoutePlanStep>,
+    pub quoted_out_amount: u64,
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
+}
+
+impl_discriminator!(instruction SharedAccountsRouteWithTokenLedger);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SharedAccountsExactOutRoute {
+    pub id: u8,
+    pub route_plan: Vec<RoutePlanStep>,
+    pub out_amount: u64,
+    pub quoted_in_amount: u64,
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
+}
+
+impl_discriminator!(instruction SharedAccountsExactOutRoute);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SetTokenLedger {}
+
+impl_discriminator!(instruction SetTokenLedger);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CreateOpenOrders {}
+
+impl_discriminator!(instruction CreateOpenOrders);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CreateTokenAccount {
+    pub bump: u8,
+}
+
+impl_discriminator!(instruction CreateTokenAccount);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CreateProgramOpenOrders {
+    pub id: u8,
+}
+
+impl_discriminator!(instruction CreateProgramOpenOrders);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Claim {
+    pub id: u8,
+}
+
+impl_discriminator!(instruction Claim);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ClaimToken {
+    pub id: u8,
+}
+
+impl_discriminator!(instruction ClaimToken);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CreateTokenLedger {}
+
+impl_discriminator!(instruction CreateTokenLedger);
+
+/// Instruction accounts
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RouteAccounts {
+    pub token_program: Pubkey,
+    pub user_transfer_authority: Pubkey,
+    pub user_source_token_account: Pubkey,
+    pub user_destination_token_account: Pubkey,
+    /// Optional accounts, here and below, hold the Jupiter program id when omitted.
+    pub destination_token_account: Pubkey,
+    pub destination_mint: Pubkey,
+    pub platform_fee_account: Pubkey,
+    pub event_authority: Pubkey,
+    pub program: Pubkey,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RouteWithTokenLedgerAccounts {
+    pub token_program: Pubkey,
+    pub user_transfer_authority: Pubkey,
+    pub user_source_token_account: Pubkey,
+    pub user_destination_token_account: Pubkey,
+    pub destination_token_account: Pubkey,
+    pub destination_mint: Pubkey,
+    pub platform_fee_account: Pubkey,
+    pub token_ledger: Pubkey,
+    pub event_authority: Pubkey,
+    pub program: Pubkey,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ExactOutRouteAccounts {
+    pub token_program: Pubkey,
+    pub user_transfer_authority: Pubkey,
+    pub user_source_token_account: Pubkey,
+    pub user_destination_token_account: Pubkey,
+    pub destination_token_account: Pubkey,
+    pub source_mint: Pubkey,
+    pub destination_mint: Pubkey,
+    pub platform_fee_account: Pubkey,
+    pub token_2022_program: Pubkey,
+    pub event_authority: Pubkey,
+    pub program: Pubkey,
+}
+
+/// Also the accounts of `SharedAccountsExactOutRoute`.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SharedAccountsRouteAccounts {
+    pub token_program: Pubkey,
+    pub program_authority: Pubkey,
+    pub user_transfer_authority: Pubkey,
+    pub source_token_account: Pubkey,
+    pub program_source_token_account: Pubkey,
+    pub program_destination_token_account: Pubkey,
+    pub destination_token_account: Pubkey,
+    pub source_mint: Pubkey,
+    pub destination_mint: Pubkey,
+    pub platform_fee_account: Pubkey,
+    pub token_2022_program: Pubkey,
+    pub event_authority: Pubkey,
+    pub program: Pubkey,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SharedAccountsRouteWithTokenLedgerAccounts {
+    pub token_program: Pubkey,
+    pub program_authority: Pubkey,
+    pub user_transfer_authority: Pubkey,
+    pub source_token_account: Pubkey,
+    pub program_source_token_account: Pubkey,
+    pub program_destination_token_account: Pubkey,
+    pub destination_token_account: Pubkey,
+    pub source_mint: Pubkey,
+    pub destination_mint: Pubkey,
+    pub platform_fee_account: Pubkey,
+    pub token_2022_program: Pubkey,
+    pub token_ledger: Pubkey,
+    pub event_authority: Pubkey,
+    pub program: Pubkey,
+}
+
+/// Accounts
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct TokenLedger {
+    pub token_account: Pubkey,
+    pub amount: u64,
+}
+
+impl_discriminator!(account TokenLedger);
+
+/// Types
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq)]
+pub enum Side {
+    Bid,
+    Ask,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RemainingAccountsInfo {
+    pub slices: Vec<RemainingAccountsSlice>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RemainingAccountsSlice {
+    pub accounts_type: AccountsType,
+    pub length: u8,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq)]
+pub enum AccountsType {
+    TransferHookA,
+    TransferHookB,
+}
+
+/// One hop of a route: `percent` of the amount held at `input_index` is swapped through `swap`
+/// and lands at `output_index`. Indexes refer to the intermediate amounts of the route, 0 being
+/// the input.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RoutePlanStep {
+    pub swap: Swap,
+    pub percent: u8,
+    pub input_index: u8,
+    pub output_index: u8,
+}
+
+/// The AMM a hop swaps through, the variant order is the program's.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum Swap {
+    Saber,
+    SaberAddDecimalsDeposit,
+    SaberAddDecimalsWithdraw,
+    TokenSwap,
+    Sencha,
+    Step,
+    Cropper,
+    Raydium,
+    Crema {
+        a_to_b: bool,
+    },
+    Lifinity,
+    Mercurial,
+    Cykura,
+    Serum {
+        side: Side,
+    },
+    MarinadeDeposit,
+    MarinadeUnstake,
+    Aldrin {
+        side: Side,
+    },
+    AldrinV2 {
+        side: Side,
+    },
+    Whirlpool {
+        a_to_b: bool,
+    },
+    Invariant {
+        x_to_y: bool,
+    },
+    Meteora,
+    GooseFX,
+    DeltaFi {
+        stable: bool,
+    },
+    Balansol,
+    MarcoPolo {
+        x_to_y: bool,
+    },
+    Dradex {
+        side: Side,
+    },
+    LifinityV2,
+    RaydiumClmm,
+    Openbook {
+        side: Side,
+    },
+    Phoenix {
+        side: Side,
+    },
+    Symmetry {
+        from_token_id: u64,
+        to_token_id: u64,
+    },
+    TokenSwapV2,
+    HeliumTreasuryManagementRedeemV0,
+    StakeDexStakeWrappedSol,
+    StakeDexSwapViaStake {
+        bridge_stake_seed: u32,
+    },
+    GooseFXV2,
+    Perps,
+    PerpsAddLiquidity,
+    PerpsRemoveLiquidity,
+    MeteoraDlmm,
+    OpenBookV2 {
+        side: Side,
+    },
+    RaydiumClmmV2,
+    StakeDexPrefundWithdrawStakeAndDepositStake {
+        bridge_stake_seed: u32,
+    },
+    Clone {
+        pool_index: u8,
+        quantity_is_input: bool,
+        quantity_is_collateral: bool,
+    },
+    SanctumS {
+        src_lst_value_calc_accs: u8,
+        dst_lst_value_calc_accs: u8,
+        src_lst_index: u32,
+        dst_lst_index: u32,
+    },
+    SanctumSAddLiquidity {
+        lst_value_calc_accs: u8,
+        lst_index: u32,
+    },
+    SanctumSRemoveLiquidity {
+        lst_value_calc_accs: u8,
+        lst_index: u32,
+    },
+    RaydiumCP,
+    WhirlpoolSwapV2 {
+        a_to_b: bool,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    },
+    OneIntro,
+    PumpdotfunWrappedBuy,
+    PumpdotfunWrappedSell,
+    PerpsV2,
+    PerpsV2AddLiquidity,
+    PerpsV2RemoveLiquidity,
+    MoonshotWrappedBuy,
+    MoonshotWrappedSell,
+}
+
+/// Events
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum JupiterV6Event {
+    SwapEvent(SwapEvent),
+    FeeEvent(FeeEvent),
+}
+
+impl JupiterV6Event {
+    /// Decodes an event from its discriminator followed by its fields.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (discriminator, rest) = input.split_at(8);
+        if let Ok(discriminator) = discriminator.try_into() {
+            return Ok(match discriminator {
+                SwapEvent::DISCRIMINATOR => Self::SwapEvent(borsh::from_slice(rest)?),
+                FeeEvent::DISCRIMINATOR => Self::FeeEvent(borsh::from_slice(rest)?),
+                _ => return Err(ProgramError::InvalidInstructionData),
+            });
+        }
+
+        Err(ProgramError::InvalidInstructionData)
+    }
+
+    /// Decodes an event from the data of the self-CPI instruction Jupiter emits it with, rather
+    /// than logging it.
+    pub fn unpack_cpi(input: &[u8]) -> Result<Self, ProgramError> {
+        match input.strip_prefix(&EVENT_IX_TAG) {
+            Some(event) => Self::unpack(event),
+            None => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+/// Emitted once per hop of a route with the amounts that actually moved through `amm`, the
+/// program the hop swapped through.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SwapEvent {
+    pub amm: Pubkey,
+    pub input_mint: Pubkey,
+    pub input_amount: u64,
+    pub output_mint: Pubkey,
+    pub output_amount: u64,
+}
+
+impl_discriminator!(event SwapEvent);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct FeeEvent {
+    pub account: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+impl_discriminator!(event FeeEvent);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discriminators_match_program() {
+        assert_eq!(Route::DISCRIMINATOR, [229, 23, 203, 151, 122, 227, 173, 42]);
+        assert_eq!(
+            SharedAccountsRoute::DISCRIMINATOR,
+            [193, 32, 155, 51, 65, 214, 156, 129]
+        );
+    }
+
+    #[test]
+    fn unpack_route() {
+        let route = Route {
+            route_plan: vec![
+                RoutePlanStep {
+                    swap: Swap::WhirlpoolSwapV2 {
+                        a_to_b: true,
+                        remaining_accounts_info: Some(RemainingAccountsInfo {
+                            slices: vec![RemainingAccountsSlice {
+                                accounts_type: AccountsType::TransferHookB,
+                                length: 2,
+                            }],
+                        }),
+                    },
+                    percent: 100,
+                    input_index: 0,
+                    output_index: 1,
+                },
+                RoutePlanStep {
+                    swap: Swap::Phoenix { side: Side::Ask },
+                    percent: 100,
+                    input_index: 1,
+                    output_index: 2,
+                },
+            ],
+            in_amount: 1_000_000,
+            quoted_out_amount: 6_000,
+            slippage_bps: 50,
+            platform_fee_bps: 0,
+        };
+        let mut data = Route::DISCRIMINATOR.to_vec();
+        data.extend(borsh::to_vec(&route).unwrap());
+
+        let instruction = JupiterV6Instruction::unpack(&data).unwrap();
+        assert_eq!(instruction.route_plan(), Some(route.route_plan.as_slice()));
+        assert_eq!(instruction, JupiterV6Instruction::Route(route));
+    }
+
+    #[test]
+    fn unpack_swap_event_cpi() {
+        let event = SwapEvent {
+            amm: Pubkey::new_unique(),
+            input_mint: Pubkey::new_unique(),
+            input_amount: 1_000_000,
+            output_mint: Pubkey::new_unique(),
+            output_amount: 6_000,
+        };
+        let mut data = SwapEvent::DISCRIMINATOR.to_vec();
+        data.extend(borsh::to_vec(&event).unwrap());
+
+        assert_eq!(
+            JupiterV6Event::unpack(&data),
+            Ok(JupiterV6Event::SwapEvent(event.clone()))
+        );
+        // Without the self-CPI tag the data is a logged event, not an instruction.
+        assert!(JupiterV6Event::unpack_cpi(&data).is_err());
+
+        let cpi = [EVENT_IX_TAG.as_slice(), &data].concat();
+        assert_eq!(
+            JupiterV6Event::unpack_cpi(&cpi),
+            Ok(JupiterV6Event::SwapEvent(event))
+        );
+    }
+}
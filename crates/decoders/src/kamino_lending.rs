@@ -0,0 +1,610 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{impl_discriminator, Discriminator};
+
+solana_program::declare_id!("KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD");
+
+/// Fractional bits of the `_sf` (scaled fraction) fields, U68F60 fixed point numbers.
+pub const FRACTION_BITS: u32 = 60;
+/// Collateral deposits an `Obligation` holds.
+pub const OBLIGATION_DEPOSITS: usize = 8;
+/// Liquidity borrows an `Obligation` holds.
+pub const OBLIGATION_BORROWS: usize = 5;
+
+/// Instructions
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum KaminoLendingInstruction {
+    InitObligation(InitObligation),
+    RefreshReserve,
+    RefreshObligation,
+    DepositReserveLiquidity(DepositReserveLiquidity),
+    RedeemReserveCollateral(RedeemReserveCollateral),
+    DepositObligationCollateral(DepositObligationCollateral),
+    WithdrawObligationCollateral(WithdrawObligationCollateral),
+    BorrowObligationLiquidity(BorrowObligationLiquidity),
+    RepayObligationLiquidity(RepayObligationLiquidity),
+    DepositReserveLiquidityAndObligationCollateral(DepositReserveLiquidityAndObligationCollateral),
+    WithdrawObligationCollateralAndRedeemReserveCollateral(
+        WithdrawObligationCollateralAndRedeemReserveCollateral,
+    ),
+    LiquidateObligationAndRedeemReserveCollateral(LiquidateObligationAndRedeemReserveCollateral),
+    FlashBorrowReserveLiquidity(FlashBorrowReserveLiquidity),
+    FlashRepayReserveLiquidity(FlashRepayReserveLiquidity),
+}
+
+impl KaminoLendingInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (discriminator, rest) = input.split_at(8);
+        if let Ok(discriminator) = discriminator.try_into() {
+            return Ok(match discriminator {
+                InitObligation::DISCRIMINATOR => Self::InitObligation(borsh::from_slice(rest)?),
+                RefreshReserve::DISCRIMINATOR => Self::RefreshReserve,
+                RefreshObligation::DISCRIMINATOR => Self::RefreshObligation,
+                DepositReserveLiquidity::DISCRIMINATOR => {
+                    Self::DepositReserveLiquidity(borsh::from_slice(rest)?)
+                }
+                RedeemReserveCollateral::DISCRIMINATOR => {
+                    Self::RedeemReserveCollateral(borsh::from_slice(rest)?)
+                }
+                DepositObligationCollateral::DISCRIMINATOR => {
+                    Self::DepositObligationCollateral(borsh::from_slice(rest)?)
+                }
+                WithdrawObligationCollateral::DISCRIMINATOR => {
+                    Self::WithdrawObligationCollateral(borsh::from_slice(rest)?)
+                }
+                BorrowObligationLiquidity::DISCRIMINATOR => {
+                    Self::BorrowObligationLiquidity(borsh::from_slice(rest)?)
+                }
+                RepayObligationLiquidity::DISCRIMINATOR => {
+                    Self::RepayObligationLiquidity(borsh::from_slice(rest)?)
+                }
+                DepositReserveLiquidityAndObligationCollateral::DISCRIMINATOR => {
+                    Self::DepositReserveLiquidityAndObligationCollateral(borsh::from_slice(rest)?)
+                }
+                WithdrawObligationCollateralAndRedeemReserveCollateral::DISCRIMINATOR => {
+                    Self::WithdrawObligationCollateralAndRedeemReserveCollateral(borsh::from_slice(
+                        rest,
+                    )?)
+                }
+                LiquidateObligationAndRedeemReserveCollateral::DISCRIMINATOR => {
+                    Self::LiquidateObligationAndRedeemReserveCollateral(borsh::from_slice(rest)?)
+                }
+                FlashBorrowReserveLiquidity::DISCRIMINATOR => {
+                    Self::FlashBorrowReserveLiquidity(borsh::from_slice(rest)?)
+                }
+                FlashRepayReserveLiquidity::DISCRIMINATOR => {
+                    Self::FlashRepayReserveLiquidity(borsh::from_slice(rest)?)
+                }
+                _ => return Err(ProgramError::InvalidInstructionData),
+            });
+        }
+
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitObligation {
+    pub args: InitObligationArgs,
+}
+
+impl_discriminator!(instruction InitObligation);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RefreshReserve {}
+
+impl_discriminator!(instruction RefreshReserve);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RefreshObligation {}
+
+impl_discriminator!(instruction RefreshObligation);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DepositReserveLiquidity {
+    pub liquidity_amount: u64,
+}
+
+impl_discriminator!(instruction DepositReserveLiquidity);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RedeemReserveCollateral {
+    pub collateral_amount: u64,
+}
+
+impl_discriminator!(instruction RedeemReserveCollateral);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DepositObligationCollateral {
+    pub collateral_amount: u64,
+}
+
+impl_discriminator!(instruction DepositObligationCollateral);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct WithdrawObligationCollateral {
+    pub collateral_amount: u64,
+}
+
+impl_discriminator!(instruction WithdrawObligationCollateral);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct BorrowObligationLiquidity {
+    pub liquidity_amount: u64,
+}
+
+impl_discriminator!(instruction BorrowObligationLiquidity);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RepayObligationLiquidity {
+    pub liquidity_amount: u64,
+}
+
+impl_discriminator!(instruction RepayObligationLiquidity);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DepositReserveLiquidityAndObligationCollateral {
+    pub liquidity_amount: u64,
+}
+
+impl_discriminator!(instruction DepositReserveLiquidityAndObligationCollateral);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct WithdrawObligationCollateralAndRedeemReserveCollateral {
+    pub collateral_amount: u64,
+}
+
+impl_discriminator!(instruction WithdrawObligationCollateralAndRedeemReserveCollateral);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LiquidateObligationAndRedeemReserveCollateral {
+    pub liquidity_amount: u64,
+    pub min_acceptable_received_liquidity_amount: u64,
+    pub max_allowed_ltv_override_percent: u64,
+}
+
+impl_discriminator!(instruction LiquidateObligationAndRedeemReserveCollateral);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct FlashBorrowReserveLiquidity {
+    pub liquidity_amount: u64,
+}
+
+impl_discriminator!(instruction FlashBorrowReserveLiquidity);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct FlashRepayReserveLiquidity {
+    pub liquidity_amount: u64,
+    /// Index of the `FlashBorrowReserveLiquidity` instruction in the transaction.
+    pub borrow_instruction_index: u8,
+}
+
+impl_discriminator!(instruction FlashRepayReserveLiquidity);
+
+/// Instruction accounts
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RefreshObligationAccounts {
+    pub lending_market: Pubkey,
+    pub obligation: Pubkey,
+    /// Deposit reserves followed by borrow reserves, in the order of the obligation.
+    pub reserves: Vec<Pubkey>,
+}
+
+impl RefreshObligationAccounts {
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        match accounts {
+            [lending_market, obligation, reserves @ ..] => Some(Self {
+                lending_market: *lending_market,
+                obligation: *obligation,
+                reserves: reserves.to_vec(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BorrowObligationLiquidityAccounts {
+    pub owner: Pubkey,
+    pub obligation: Pubkey,
+    pub lending_market: Pubkey,
+    pub lending_market_authority: Pubkey,
+    pub borrow_reserve: Pubkey,
+    pub borrow_reserve_liquidity_mint: Pubkey,
+    pub reserve_source_liquidity: Pubkey,
+    pub borrow_reserve_liquidity_fee_receiver: Pubkey,
+    pub user_destination_liquidity: Pubkey,
+    pub referrer_token_state: Pubkey,
+    pub token_program: Pubkey,
+    pub instruction_sysvar_account: Pubkey,
+}
+
+impl BorrowObligationLiquidityAccounts {
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        match accounts {
+            [owner, obligation, lending_market, lending_market_authority, borrow_reserve, borrow_reserve_liquidity_mint, reserve_source_liquidity, borrow_reserve_liquidity_fee_receiver, user_destination_liquidity, referrer_token_state, token_program, instruction_sysvar_account, ..] => {
+                Some(Self {
+                    owner: *owner,
+                    obligation: *obligation,
+                    lending_market: *lending_market,
+                    lending_market_authority: *lending_market_authority,
+                    borrow_reserve: *borrow_reserve,
+                    borrow_reserve_liquidity_mint: *borrow_reserve_liquidity_mint,
+                    reserve_source_liquidity: *reserve_source_liquidity,
+                    borrow_reserve_liquidity_fee_receiver: *borrow_reserve_liquidity_fee_receiver,
+                    user_destination_liquidity: *user_destination_liquidity,
+                    referrer_token_state: *referrer_token_state,
+                    token_program: *token_program,
+                    instruction_sysvar_account: *instruction_sysvar_account,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RepayObligationLiquidityAccounts {
+    pub owner: Pubkey,
+    pub obligation: Pubkey,
+    pub lending_market: Pubkey,
+    pub repay_reserve: Pubkey,
+    pub reserve_liquidity_mint: Pubkey,
+    pub reserve_destination_liquidity: Pubkey,
+    pub user_source_liquidity: Pubkey,
+    pub token_program: Pubkey,
+    pub instruction_sysvar_account: Pubkey,
+}
+
+impl RepayObligationLiquidityAccounts {
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        match accounts {
+            [owner, obligation, lending_market, repay_reserve, reserve_liquidity_mint, reserve_destination_liquidity, user_source_liquidity, token_program, instruction_sysvar_account, ..] => {
+                Some(Self {
+                    owner: *owner,
+                    obligation: *obligation,
+                    lending_market: *lending_market,
+                    repay_reserve: *repay_reserve,
+                    reserve_liquidity_mint: *reserve_liquidity_mint,
+                    reserve_destination_liquidity: *reserve_destination_liquidity,
+                    user_source_liquidity: *user_source_liquidity,
+                    token_program: *token_program,
+                    instruction_sysvar_account: *instruction_sysvar_account,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Accounts
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Obligation {
+    pub tag: u64,
+    pub last_update: LastUpdate,
+    pub lending_market: Pubkey,
+    pub owner: Pubkey,
+    pub deposits: [ObligationCollateral; OBLIGATION_DEPOSITS],
+    pub lowest_reserve_deposit_liquidation_ltv: u64,
+    /// Market value of the deposits in USD, as of the last refresh.
+    pub deposited_value_sf: u128,
+    pub borrows: [ObligationLiquidity; OBLIGATION_BORROWS],
+    pub borrow_factor_adjusted_debt_value_sf: u128,
+    pub borrowed_assets_market_value_sf: u128,
+    pub allowed_borrow_value_sf: u128,
+    pub unhealthy_borrow_value_sf: u128,
+    pub deposits_asset_tiers: [u8; OBLIGATION_DEPOSITS],
+    pub borrows_asset_tiers: [u8; OBLIGATION_BORROWS],
+    pub elevation_group: u8,
+    pub num_of_obsolete_reserves: u8,
+    pub has_debt: u8,
+    pub referrer: Pubkey,
+    pub borrowing_disabled: u8,
+    pub autodeleverage_target_ltv_pct: u8,
+    pub lowest_reserve_deposit_max_ltv_pct: u8,
+    pub reserved: [u8; 5],
+    pub highest_borrow_factor_pct: u64,
+    pub autodeleverage_margin_call_started_timestamp: u64,
+    pub padding_3: [u64; 125],
+}
+
+impl_discriminator!(account Obligation);
+
+impl Obligation {
+    pub fn active_deposits(&self) -> impl Iterator<Item = &ObligationCollateral> {
+        self.deposits
+            .iter()
+            .filter(|deposit| deposit.deposit_reserve != Pubkey::default())
+    }
+
+    pub fn active_borrows(&self) -> impl Iterator<Item = &ObligationLiquidity> {
+        self.borrows
+            .iter()
+            .filter(|borrow| borrow.borrow_reserve != Pubkey::default())
+    }
+
+    /// Debt adjusted by borrow factors over the deposited value, as of the last refresh. `None`
+    /// without deposits.
+    pub fn loan_to_value(&self) -> Option<f64> {
+        if self.deposited_value_sf == 0 {
+            return None;
+        }
+        Some(
+            sf_to_f64(self.borrow_factor_adjusted_debt_value_sf)
+                / sf_to_f64(self.deposited_value_sf),
+        )
+    }
+}
+
+/// Zero copy in the program, its packed layout is the borsh encoding of the fields.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Reserve {
+    pub version: u64,
+    pub last_update: LastUpdate,
+    pub lending_market: Pubkey,
+    pub farm_collateral: Pubkey,
+    pub farm_debt: Pubkey,
+    pub liquidity: ReserveLiquidity,
+    pub reserve_liquidity_padding: [u64; 150],
+    pub collateral: ReserveCollateral,
+    pub reserve_collateral_padding: [u64; 150],
+    pub config: ReserveConfig,
+    /// The token info, withdrawal caps and elevation group limits ending the config, not decoded.
+    pub config_rest: [u8; 744],
+    pub config_padding: [u64; 116],
+    pub borrowed_amount_outside_elevation_group: u64,
+    pub borrowed_amounts_against_this_reserve_in_elevation_groups: [u64; 32],
+    pub padding: [u64; 207],
+}
+
+impl_discriminator!(account Reserve);
+
+impl Reserve {
+    /// Liquidity supplied to the reserve, lent out or not, net of fees owed to the protocol and
+    /// referrers.
+    pub fn total_supply_sf(&self) -> Option<u128> {
+        let liquidity = &self.liquidity;
+        ((liquidity.available_amount as u128) << FRACTION_BITS)
+            .checked_add(liquidity.borrowed_amount_sf)?
+            .checked_sub(liquidity.accumulated_protocol_fees_sf)?
+            .checked_sub(liquidity.accumulated_referrer_fees_sf)?
+            .checked_sub(liquidity.pending_referrer_fees_sf)
+    }
+
+    /// Liquidity a unit of collateral token is worth, scaled like the `_sf` fields. Starts at one
+    /// and grows as interest accrues.
+    pub fn collateral_exchange_rate_sf(&self) -> Option<u128> {
+        let total_supply_sf = self.total_supply_sf()?;
+        match self.collateral.mint_total_supply {
+            0 => Some(1 << FRACTION_BITS),
+            _ if total_supply_sf == 0 => Some(1 << FRACTION_BITS),
+            supply => Some(total_supply_sf / supply as u128),
+        }
+    }
+
+    /// Liquidity, in native units, that collateral tokens can be redeemed for, rounded down.
+    pub fn collateral_to_liquidity(&self, collateral_amount: u64) -> Option<u64> {
+        let liquidity_sf =
+            (collateral_amount as u128).checked_mul(self.collateral_exchange_rate_sf()?)?;
+        u64::try_from(liquidity_sf >> FRACTION_BITS).ok()
+    }
+
+    /// USD price of one token of the reserve, as of the last refresh.
+    pub fn market_price(&self) -> f64 {
+        sf_to_f64(self.liquidity.market_price_sf)
+    }
+}
+
+/// Types
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitObligationArgs {
+    pub tag: u8,
+    pub id: u8,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LastUpdate {
+    pub slot: u64,
+    pub stale: u8,
+    pub price_status: u8,
+    pub placeholder: [u8; 6],
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ObligationCollateral {
+    pub deposit_reserve: Pubkey,
+    /// In collateral tokens of the reserve.
+    pub deposited_amount: u64,
+    pub market_value_sf: u128,
+    pub borrowed_amount_against_this_collateral_in_elevation_group: u64,
+    pub padding: [u64; 9],
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ObligationLiquidity {
+    pub borrow_reserve: Pubkey,
+    pub cumulative_borrow_rate_bsf: BigFractionBytes,
+    pub padding: u64,
+    /// Borrowed amount plus interest in native units, as of the last refresh.
+    pub borrowed_amount_sf: u128,
+    pub market_value_sf: u128,
+    pub borrow_factor_adjusted_market_value_sf: u128,
+    pub borrowed_amount_outside_elevation_groups: u64,
+    pub padding2: [u64; 7],
+}
+
+impl ObligationLiquidity {
+    pub fn borrowed_amount(&self) -> f64 {
+        sf_to_f64(self.borrowed_amount_sf)
+    }
+}
+
+/// 256 bit unsigned fixed point number with `FRACTION_BITS` fractional bits, little endian limbs.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct BigFractionBytes {
+    pub value: [u64; 4],
+    pub padding: [u64; 2],
+}
+
+impl BigFractionBytes {
+    pub fn to_f64(&self) -> f64 {
+        let value = self
+            .value
+            .iter()
+            .rev()
+            .fold(0f64, |acc, limb| acc * 2f64.powi(64) + *limb as f64);
+        value / 2f64.powi(FRACTION_BITS as i32)
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ReserveLiquidity {
+    pub mint_pubkey: Pubkey,
+    pub supply_vault: Pubkey,
+    pub fee_vault: Pubkey,
+    /// Native amount held by the supply vault.
+    pub available_amount: u64,
+    pub borrowed_amount_sf: u128,
+    pub market_price_sf: u128,
+    pub market_price_last_updated_ts: u64,
+    pub mint_decimals: u64,
+    pub deposit_limit_crossed_timestamp: u64,
+    pub borrow_limit_crossed_timestamp: u64,
+    pub cumulative_borrow_rate_bsf: BigFractionBytes,
+    pub accumulated_protocol_fees_sf: u128,
+    pub accumulated_referrer_fees_sf: u128,
+    pub pending_referrer_fees_sf: u128,
+    pub absolute_referral_rate_sf: u128,
+    pub token_program: Pubkey,
+    pub padding2: [u64; 51],
+    pub padding3: [u128; 32],
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ReserveCollateral {
+    pub mint_pubkey: Pubkey,
+    pub mint_total_supply: u64,
+    pub supply_vault: Pubkey,
+    pub padding1: [u128; 32],
+    pub padding2: [u128; 32],
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ReserveConfig {
+    pub status: u8,
+    pub asset_tier: u8,
+    pub host_fixed_interest_rate_bps: u16,
+    pub reserved_2: [u8; 2],
+    pub reserved_3: [u8; 8],
+    pub protocol_take_rate_pct: u8,
+    pub protocol_liquidation_fee_pct: u8,
+    pub loan_to_value_pct: u8,
+    pub liquidation_threshold_pct: u8,
+    pub min_liquidation_bonus_bps: u16,
+    pub max_liquidation_bonus_bps: u16,
+    pub bad_debt_liquidation_bonus_bps: u16,
+    pub deleveraging_margin_call_period_secs: u64,
+    pub deleveraging_threshold_slots_per_bps: u64,
+    pub fees: ReserveFees,
+    pub borrow_rate_curve: BorrowRateCurve,
+    pub borrow_factor_pct: u64,
+    pub deposit_limit: u64,
+    pub borrow_limit: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ReserveFees {
+    pub borrow_fee_sf: u64,
+    pub flash_loan_fee_sf: u64,
+    pub padding: [u8; 8],
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct BorrowRateCurve {
+    pub points: [CurvePoint; 11],
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CurvePoint {
+    pub utilization_rate_bps: u32,
+    pub borrow_rate_bps: u32,
+}
+
+/// Converts a `_sf` field to a float.
+pub fn sf_to_f64(sf: u128) -> f64 {
+    sf as f64 / (1u64 << FRACTION_BITS) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zeroed_account;
+
+    #[test]
+    fn unpack_liquidate() {
+        let liquidate = LiquidateObligationAndRedeemReserveCollateral {
+            liquidity_amount: 1_000_000,
+            min_acceptable_received_liquidity_amount: 990_000,
+            max_allowed_ltv_override_percent: 0,
+        };
+        let mut data = LiquidateObligationAndRedeemReserveCollateral::DISCRIMINATOR.to_vec();
+        liquidate.serialize(&mut data).unwrap();
+
+        assert_eq!(
+            KaminoLendingInstruction::unpack(&data).unwrap(),
+            KaminoLendingInstruction::LiquidateObligationAndRedeemReserveCollateral(liquidate)
+        );
+        assert_eq!(
+            KaminoLendingInstruction::unpack(&RefreshObligation::DISCRIMINATOR).unwrap(),
+            KaminoLendingInstruction::RefreshObligation
+        );
+    }
+
+    #[test]
+    fn accounts_have_program_sizes() {
+        let obligation = zeroed_account::<Obligation>(3344);
+        assert_eq!(obligation.loan_to_value(), None);
+
+        zeroed_account::<Reserve>(8624);
+    }
+
+    #[test]
+    fn reserve_converts_collateral() {
+        let mut reserve = zeroed_account::<Reserve>(8624);
+        assert_eq!(reserve.collateral_to_liquidity(1_000), Some(1_000));
+
+        // 1_000 supplied with 200 of them lent out and 10 owed in fees, for 800 collateral tokens.
+        reserve.liquidity.available_amount = 800;
+        reserve.liquidity.borrowed_amount_sf = 210 << FRACTION_BITS;
+        reserve.liquidity.accumulated_protocol_fees_sf = 10 << FRACTION_BITS;
+        reserve.collateral.mint_total_supply = 800;
+
+        assert_eq!(reserve.total_supply_sf(), Some(1_000 << FRACTION_BITS));
+        assert_eq!(reserve.collateral_to_liquidity(400), Some(500));
+        assert_eq!(reserve.collateral_to_liquidity(1), Some(1));
+    }
+
+    #[test]
+    fn obligation_loan_to_value() {
+        let mut obligation = zeroed_account::<Obligation>(3344);
+        obligation.deposited_value_sf = 1_000 << FRACTION_BITS;
+        obligation.borrow_factor_adjusted_debt_value_sf = 250 << FRACTION_BITS;
+        obligation.borrows[1].borrow_reserve = Pubkey::new_unique();
+        obligation.borrows[1].borrowed_amount_sf = 3 << (FRACTION_BITS - 1);
+
+        assert_eq!(obligation.loan_to_value(), Some(0.25));
+        assert_eq!(obligation.active_deposits().count(), 0);
+        let borrows = obligation.active_borrows().collect::<Vec<_>>();
+        assert_eq!(borrows.len(), 1);
+        assert_eq!(borrows[0].borrowed_amount(), 1.5);
+    }
+}
@@ -1,9 +1,62 @@
+#[cfg(any(test, feature = "arbitrary"))]
+mod arbitrary_support;
+pub mod associated_token;
+pub mod compute_budget;
+pub mod discriminator;
 pub mod drift_v2;
+pub mod jupiter_v6;
+pub mod kamino_lending;
+pub mod marginfi_v2;
+pub mod math;
+pub mod openbook_v2;
+pub mod phoenix_v1;
+pub mod raydium_amm_v4;
+pub mod raydium_clmm;
+pub mod serum_v3;
+pub mod spl_token;
+pub mod system_program;
+pub mod whirlpool;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{instruction::AccountMeta, program_error::ProgramError};
 
 /// 8 byte unique identifier for a type.
-pub(crate) trait Discriminator {
+pub trait Discriminator {
     const DISCRIMINATOR: [u8; 8];
     fn discriminator() -> [u8; 8] {
         Self::DISCRIMINATOR
     }
 }
+
+/// Ordered account metas of an instruction, with the writable and signer flags of its IDL.
+pub trait ToAccountMetas {
+    fn to_account_metas(&self) -> Vec<AccountMeta>;
+}
+
+/// Links instruction arguments to the accounts the instruction expects.
+pub trait InstructionArgs: Discriminator + BorshSerialize {
+    type Accounts: ToAccountMetas;
+}
+
+/// Decodes an anchor account from its data, checking the 8 byte discriminator first. Trailing
+/// bytes are ignored.
+pub fn unpack_account<T: Discriminator + BorshDeserialize>(
+    input: &[u8],
+) -> Result<T, ProgramError> {
+    if input.len() < 8 || input[..8] != T::DISCRIMINATOR {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(T::deserialize(&mut &input[8..])?)
+}
+
+/// Decodes an account of `len` zero bytes after the discriminator of `T`, asserting that `T`
+/// covers exactly the `len` bytes the program allocates for it.
+#[cfg(test)]
+fn zeroed_account<T: Discriminator + BorshDeserialize + borsh::BorshSerialize>(len: usize) -> T {
+    let mut data = vec![0; len];
+    data[..8].copy_from_slice(&T::DISCRIMINATOR);
+    let account = unpack_account::<T>(&data).unwrap();
+    assert_eq!(borsh::to_vec(&account).unwrap().len(), len - 8);
+    account
+}
@@ -0,0 +1,686 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{impl_discriminator, Discriminator};
+
+solana_program::declare_id!("MFv2hWf31Z9kbCa1snEPYctwafyvdvnV7FDnsLakBNA");
+
+/// Balances a `MarginfiAccount` holds.
+pub const MAX_LENDING_ACCOUNT_BALANCES: usize = 16;
+/// Oracle accounts a `BankConfig` holds.
+pub const MAX_ORACLE_KEYS: usize = 5;
+
+/// Instructions
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MarginfiV2Instruction {
+    MarginfiAccountInitialize,
+    LendingAccountDeposit(LendingAccountDeposit),
+    LendingAccountWithdraw(LendingAccountWithdraw),
+    LendingAccountBorrow(LendingAccountBorrow),
+    LendingAccountRepay(LendingAccountRepay),
+    LendingAccountLiquidate(LendingAccountLiquidate),
+    LendingAccountStartFlashloan(LendingAccountStartFlashloan),
+    LendingAccountEndFlashloan,
+}
+
+impl MarginfiV2Instruction {
+    /// The `Option<bool>` flags of deposits, withdrawals and repayments were appended later,
+    /// older transactions leave them out.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (discriminator, mut rest) = input.split_at(8);
+        if let Ok(discriminator) = discriminator.try_into() {
+            return Ok(match discriminator {
+                MarginfiAccountInitialize::DISCRIMINATOR => Self::MarginfiAccountInitialize,
+                LendingAccountDeposit::DISCRIMINATOR => {
+                    Self::LendingAccountDeposit(LendingAccountDeposit {
+                        amount: u64::deserialize(&mut rest)?,
+                        deposit_up_to_limit: trailing_flag(rest)?,
+                    })
+                }
+                LendingAccountWithdraw::DISCRIMINATOR => {
+                    Self::LendingAccountWithdraw(LendingAccountWithdraw {
+                        amount: u64::deserialize(&mut rest)?,
+                        withdraw_all: trailing_flag(rest)?,
+                    })
+                }
+                LendingAccountBorrow::DISCRIMINATOR => {
+                    Self::LendingAccountBorrow(borsh::from_slice(rest)?)
+                }
+                LendingAccountRepay::DISCRIMINATOR => {
+                    Self::LendingAccountRepay(LendingAccountRepay {
+                        amount: u64::deserialize(&mut rest)?,
+                        repay_all: trailing_flag(rest)?,
+                    })
+                }
+                LendingAccountLiquidate::DISCRIMINATOR => {
+                    Self::LendingAccountLiquidate(borsh::from_slice(rest)?)
+                }
+                LendingAccountStartFlashloan::DISCRIMINATOR => {
+                    Self::LendingAccountStartFlashloan(borsh::from_slice(rest)?)
+                }
+                LendingAccountEndFlashloan::DISCRIMINATOR => Self::LendingAccountEndFlashloan,
+                _ => return Err(ProgramError::InvalidInstructionData),
+            });
+        }
+
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+/// Reads a trailing `Option<bool>`, `None` when left out.
+fn trailing_flag(rest: &[u8]) -> Result<Option<bool>, ProgramError> {
+    match rest.is_empty() {
+        true => Ok(None),
+        false => Ok(borsh::from_slice(rest)?),
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct MarginfiAccountInitialize {}
+
+impl_discriminator!(instruction MarginfiAccountInitialize);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LendingAccountDeposit {
+    pub amount: u64,
+    pub deposit_up_to_limit: Option<bool>,
+}
+
+impl_discriminator!(instruction LendingAccountDeposit);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LendingAccountWithdraw {
+    pub amount: u64,
+    pub withdraw_all: Option<bool>,
+}
+
+impl_discriminator!(instruction LendingAccountWithdraw);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LendingAccountBorrow {
+    pub amount: u64,
+}
+
+impl_discriminator!(instruction LendingAccountBorrow);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LendingAccountRepay {
+    pub amount: u64,
+    pub repay_all: Option<bool>,
+}
+
+impl_discriminator!(instruction LendingAccountRepay);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LendingAccountLiquidate {
+    pub asset_amount: u64,
+}
+
+impl_discriminator!(instruction LendingAccountLiquidate);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LendingAccountStartFlashloan {
+    /// Index of the `LendingAccountEndFlashloan` instruction in the transaction.
+    pub end_index: u64,
+}
+
+impl_discriminator!(instruction LendingAccountStartFlashloan);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LendingAccountEndFlashloan {}
+
+impl_discriminator!(instruction LendingAccountEndFlashloan);
+
+/// Instruction accounts
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LendingAccountDepositAccounts {
+    pub marginfi_group: Pubkey,
+    pub marginfi_account: Pubkey,
+    pub signer: Pubkey,
+    pub bank: Pubkey,
+    pub signer_token_account: Pubkey,
+    pub bank_liquidity_vault: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl LendingAccountDepositAccounts {
+    /// Reads the accounts of a `LendingAccountDeposit` or `LendingAccountRepay` instruction.
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        match accounts {
+            [marginfi_group, marginfi_account, signer, bank, signer_token_account, bank_liquidity_vault, token_program, ..] => {
+                Some(Self {
+                    marginfi_group: *marginfi_group,
+                    marginfi_account: *marginfi_account,
+                    signer: *signer,
+                    bank: *bank,
+                    signer_token_account: *signer_token_account,
+                    bank_liquidity_vault: *bank_liquidity_vault,
+                    token_program: *token_program,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LendingAccountWithdrawAccounts {
+    pub marginfi_group: Pubkey,
+    pub marginfi_account: Pubkey,
+    pub signer: Pubkey,
+    pub bank: Pubkey,
+    pub destination_token_account: Pubkey,
+    pub bank_liquidity_vault_authority: Pubkey,
+    pub bank_liquidity_vault: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl LendingAccountWithdrawAccounts {
+    /// Reads the accounts of a `LendingAccountWithdraw` or `LendingAccountBorrow` instruction,
+    /// the oracles of the account's banks come after them.
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        match accounts {
+            [marginfi_group, marginfi_account, signer, bank, destination_token_account, bank_liquidity_vault_authority, bank_liquidity_vault, token_program, ..] => {
+                Some(Self {
+                    marginfi_group: *marginfi_group,
+                    marginfi_account: *marginfi_account,
+                    signer: *signer,
+                    bank: *bank,
+                    destination_token_account: *destination_token_account,
+                    bank_liquidity_vault_authority: *bank_liquidity_vault_authority,
+                    bank_liquidity_vault: *bank_liquidity_vault,
+                    token_program: *token_program,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LendingAccountLiquidateAccounts {
+    pub marginfi_group: Pubkey,
+    pub asset_bank: Pubkey,
+    pub liab_bank: Pubkey,
+    pub liquidator_marginfi_account: Pubkey,
+    pub signer: Pubkey,
+    pub liquidatee_marginfi_account: Pubkey,
+    pub bank_liquidity_vault_authority: Pubkey,
+    pub bank_liquidity_vault: Pubkey,
+    pub bank_insurance_vault: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl LendingAccountLiquidateAccounts {
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        match accounts {
+            [marginfi_group, asset_bank, liab_bank, liquidator_marginfi_account, signer, liquidatee_marginfi_account, bank_liquidity_vault_authority, bank_liquidity_vault, bank_insurance_vault, token_program, ..] => {
+                Some(Self {
+                    marginfi_group: *marginfi_group,
+                    asset_bank: *asset_bank,
+                    liab_bank: *liab_bank,
+                    liquidator_marginfi_account: *liquidator_marginfi_account,
+                    signer: *signer,
+                    liquidatee_marginfi_account: *liquidatee_marginfi_account,
+                    bank_liquidity_vault_authority: *bank_liquidity_vault_authority,
+                    bank_liquidity_vault: *bank_liquidity_vault,
+                    bank_insurance_vault: *bank_insurance_vault,
+                    token_program: *token_program,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Accounts
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct MarginfiAccount {
+    pub group: Pubkey,
+    pub authority: Pubkey,
+    pub lending_account: LendingAccount,
+    pub account_flags: u64,
+    pub padding: [u64; 63],
+}
+
+impl_discriminator!(account MarginfiAccount);
+
+impl MarginfiAccount {
+    pub fn active_balances(&self) -> impl Iterator<Item = &Balance> {
+        self.lending_account
+            .balances
+            .iter()
+            .filter(|balance| balance.is_active())
+    }
+}
+
+/// Zero copy in the program, its packed layout is the borsh encoding of the fields.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Bank {
+    pub mint: Pubkey,
+    pub mint_decimals: u8,
+    pub group: Pubkey,
+    pub pad0: [u8; 7],
+    /// Native amount of tokens a deposit share is worth.
+    pub asset_share_value: WrappedI80F48,
+    /// Native amount of tokens a borrow share is worth.
+    pub liability_share_value: WrappedI80F48,
+    pub liquidity_vault: Pubkey,
+    pub liquidity_vault_bump: u8,
+    pub liquidity_vault_authority_bump: u8,
+    pub insurance_vault: Pubkey,
+    pub insurance_vault_bump: u8,
+    pub insurance_vault_authority_bump: u8,
+    pub pad1: [u8; 4],
+    pub collected_insurance_fees_outstanding: WrappedI80F48,
+    pub fee_vault: Pubkey,
+    pub fee_vault_bump: u8,
+    pub fee_vault_authority_bump: u8,
+    pub pad2: [u8; 6],
+    pub collected_group_fees_outstanding: WrappedI80F48,
+    pub total_liability_shares: WrappedI80F48,
+    pub total_asset_shares: WrappedI80F48,
+    pub last_update: i64,
+    pub config: BankConfig,
+    pub flags: u64,
+    pub emissions_rate: u64,
+    pub emissions_remaining: WrappedI80F48,
+    pub emissions_mint: Pubkey,
+    pub padding_0: [[u64; 2]; 28],
+    pub padding_1: [[u64; 2]; 32],
+}
+
+impl_discriminator!(account Bank);
+
+impl Bank {
+    /// Native amount of tokens deposit shares are worth.
+    pub fn asset_amount(&self, shares: WrappedI80F48) -> Option<WrappedI80F48> {
+        shares.checked_mul(self.asset_share_value)
+    }
+
+    /// Native amount of tokens borrow shares are worth.
+    pub fn liability_amount(&self, shares: WrappedI80F48) -> Option<WrappedI80F48> {
+        shares.checked_mul(self.liability_share_value)
+    }
+
+    pub fn total_deposits(&self) -> Option<WrappedI80F48> {
+        self.asset_amount(self.total_asset_shares)
+    }
+
+    pub fn total_borrows(&self) -> Option<WrappedI80F48> {
+        self.liability_amount(self.total_liability_shares)
+    }
+}
+
+/// Types
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LendingAccount {
+    pub balances: [Balance; MAX_LENDING_ACCOUNT_BALANCES],
+    pub padding: [u64; 8],
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Balance {
+    pub active: u8,
+    pub bank_pk: Pubkey,
+    pub pad0: [u8; 7],
+    pub asset_shares: WrappedI80F48,
+    pub liability_shares: WrappedI80F48,
+    pub emissions_outstanding: WrappedI80F48,
+    pub last_update: u64,
+    pub padding: [u64; 1],
+}
+
+impl Balance {
+    pub fn is_active(&self) -> bool {
+        self.active != 0
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct BankConfig {
+    pub asset_weight_init: WrappedI80F48,
+    pub asset_weight_maint: WrappedI80F48,
+    pub liability_weight_init: WrappedI80F48,
+    pub liability_weight_maint: WrappedI80F48,
+    pub deposit_limit: u64,
+    pub interest_rate_config: InterestRateConfig,
+    /// 0 paused, 1 operational, 2 reduce only.
+    pub operational_state: u8,
+    pub oracle_setup: u8,
+    pub oracle_keys: [Pubkey; MAX_ORACLE_KEYS],
+    pub pad0: [u8; 6],
+    pub borrow_limit: u64,
+    /// 0 collateral, 1 isolated.
+    pub risk_tier: u8,
+    pub pad1: [u8; 7],
+    pub total_asset_value_init_limit: u64,
+    pub oracle_max_age: u16,
+    pub padding: [u8; 38],
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InterestRateConfig {
+    pub optimal_utilization_rate: WrappedI80F48,
+    pub plateau_interest_rate: WrappedI80F48,
+    pub max_interest_rate: WrappedI80F48,
+    pub insurance_fee_fixed_apr: WrappedI80F48,
+    pub insurance_ir_fee: WrappedI80F48,
+    pub protocol_fixed_fee_apr: WrappedI80F48,
+    pub protocol_ir_fee: WrappedI80F48,
+    pub padding: [[u64; 2]; 8],
+}
+
+/// Signed fixed point number with 80 integer and 48 fractional bits, stored as the little endian
+/// bytes of its `i128` bits.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct WrappedI80F48 {
+    pub value: [u8; 16],
+}
+
+impl WrappedI80F48 {
+    pub const FRAC_BITS: u32 = 48;
+
+    pub fn from_bits(bits: i128) -> Self {
+        Self {
+            value: bits.to_le_bytes(),
+        }
+    }
+
+    pub fn to_bits(self) -> i128 {
+        i128::from_le_bytes(self.value)
+    }
+
+    pub fn from_num(num: i64) -> Self {
+        Self::from_bits((num as i128) << Self::FRAC_BITS)
+    }
+
+    /// Integer part, rounded toward negative infinity like the program does.
+    pub fn floor(self) -> i128 {
+        self.to_bits() >> Self::FRAC_BITS
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.to_bits() as f64 / (1u64 << Self::FRAC_BITS) as f64
+    }
+
+    /// Exact product rounded toward negative infinity, `None` on overflow. The intermediate
+    /// product needs 256 bits, shares and share values overflow an `i128` multiplication.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (a, b) = (self.to_bits(), rhs.to_bits());
+        let negative = (a < 0) != (b < 0);
+        let (hi, lo) = widening_mul(a.unsigned_abs(), b.unsigned_abs());
+        if hi >> Self::FRAC_BITS != 0 {
+            return None;
+        }
+
+        let fraction = lo & ((1u128 << Self::FRAC_BITS) - 1);
+        let magnitude = (lo >> Self::FRAC_BITS) | (hi << (128 - Self::FRAC_BITS));
+        let bits = match negative {
+            true => {
+                let magnitude = magnitude.checked_add((fraction != 0) as u128)?;
+                if magnitude > i128::MIN.unsigned_abs() {
+                    return None;
+                }
+                (magnitude as i128).wrapping_neg()
+            }
+            false => i128::try_from(magnitude).ok()?,
+        };
+        Some(Self::from_bits(bits))
+    }
+}
+
+/// Full 256 bit product of two `u128`, as its high and low halves.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a1, a0) = (a >> 64, a & MASK);
+    let (b1, b0) = (b >> 64, b & MASK);
+
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+
+    let mid = (p00 >> 64) + (p01 & MASK) + (p10 & MASK);
+    let lo = (p00 & MASK) | (mid << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct AccountEventHeader {
+    pub signer: Option<Pubkey>,
+    pub marginfi_account: Pubkey,
+    pub marginfi_account_authority: Pubkey,
+    pub marginfi_group: Pubkey,
+}
+
+/// Health of the liquidatee and balances of both parties, in UI units of the banks' tokens.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LiquidationBalances {
+    pub liquidatee_asset_balance: f64,
+    pub liquidatee_liability_balance: f64,
+    pub liquidator_asset_balance: f64,
+    pub liquidator_liability_balance: f64,
+}
+
+/// Events
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum MarginfiV2Event {
+    MarginfiAccountCreateEvent(MarginfiAccountCreateEvent),
+    LendingAccountDepositEvent(LendingAccountDepositEvent),
+    LendingAccountWithdrawEvent(LendingAccountWithdrawEvent),
+    LendingAccountBorrowEvent(LendingAccountBorrowEvent),
+    LendingAccountRepayEvent(LendingAccountRepayEvent),
+    LendingAccountLiquidateEvent(LendingAccountLiquidateEvent),
+}
+
+impl MarginfiV2Event {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (discriminator, rest) = input.split_at(8);
+        if let Ok(discriminator) = discriminator.try_into() {
+            return Ok(match discriminator {
+                MarginfiAccountCreateEvent::DISCRIMINATOR => {
+                    Self::MarginfiAccountCreateEvent(borsh::from_slice(rest)?)
+                }
+                LendingAccountDepositEvent::DISCRIMINATOR => {
+                    Self::LendingAccountDepositEvent(borsh::from_slice(rest)?)
+                }
+                LendingAccountWithdrawEvent::DISCRIMINATOR => {
+                    Self::LendingAccountWithdrawEvent(borsh::from_slice(rest)?)
+                }
+                LendingAccountBorrowEvent::DISCRIMINATOR => {
+                    Self::LendingAccountBorrowEvent(borsh::from_slice(rest)?)
+                }
+                LendingAccountRepayEvent::DISCRIMINATOR => {
+                    Self::LendingAccountRepayEvent(borsh::from_slice(rest)?)
+                }
+                LendingAccountLiquidateEvent::DISCRIMINATOR => {
+                    Self::LendingAccountLiquidateEvent(borsh::from_slice(rest)?)
+                }
+                _ => return Err(ProgramError::InvalidInstructionData),
+            });
+        }
+
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct MarginfiAccountCreateEvent {
+    pub header: AccountEventHeader,
+}
+
+impl_discriminator!(event MarginfiAccountCreateEvent);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LendingAccountDepositEvent {
+    pub header: AccountEventHeader,
+    pub bank: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+impl_discriminator!(event LendingAccountDepositEvent);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LendingAccountWithdrawEvent {
+    pub header: AccountEventHeader,
+    pub bank: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub close_balance: bool,
+}
+
+impl_discriminator!(event LendingAccountWithdrawEvent);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LendingAccountBorrowEvent {
+    pub header: AccountEventHeader,
+    pub bank: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+impl_discriminator!(event LendingAccountBorrowEvent);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LendingAccountRepayEvent {
+    pub header: AccountEventHeader,
+    pub bank: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub close_balance: bool,
+}
+
+impl_discriminator!(event LendingAccountRepayEvent);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LendingAccountLiquidateEvent {
+    pub header: AccountEventHeader,
+    pub liquidatee_marginfi_account: Pubkey,
+    pub liquidatee_marginfi_account_authority: Pubkey,
+    pub asset_bank: Pubkey,
+    pub asset_mint: Pubkey,
+    pub liability_bank: Pubkey,
+    pub liability_mint: Pubkey,
+    pub liquidatee_pre_health: f64,
+    pub liquidatee_post_health: f64,
+    pub pre_balances: LiquidationBalances,
+    pub post_balances: LiquidationBalances,
+}
+
+impl_discriminator!(event LendingAccountLiquidateEvent);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zeroed_account;
+
+    #[test]
+    fn unpack_with_trailing_flags() {
+        let mut data = LendingAccountDeposit::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        assert_eq!(
+            MarginfiV2Instruction::unpack(&data).unwrap(),
+            MarginfiV2Instruction::LendingAccountDeposit(LendingAccountDeposit {
+                amount: 1_000,
+                deposit_up_to_limit: None,
+            })
+        );
+
+        let mut data = LendingAccountRepay::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.extend_from_slice(&[1, 1]);
+        assert_eq!(
+            MarginfiV2Instruction::unpack(&data).unwrap(),
+            MarginfiV2Instruction::LendingAccountRepay(LendingAccountRepay {
+                amount: 0,
+                repay_all: Some(true),
+            })
+        );
+
+        data.push(0);
+        assert!(MarginfiV2Instruction::unpack(&data).is_err());
+    }
+
+    #[test]
+    fn i80f48_mul_is_exact() {
+        let one_and_half = WrappedI80F48::from_bits(3 << 47);
+        assert_eq!(
+            WrappedI80F48::from_num(10).checked_mul(one_and_half),
+            Some(WrappedI80F48::from_num(15))
+        );
+
+        // 2^70 shares worth 1.5 each, the raw product needs 166 bits.
+        let shares = WrappedI80F48::from_bits(1 << 118);
+        assert_eq!(shares.checked_mul(one_and_half).unwrap().floor(), 3 << 69);
+        assert_eq!(shares.checked_mul(shares), None);
+
+        // The smallest fraction squared rounds down, away from zero for negative products.
+        let epsilon = WrappedI80F48::from_bits(1);
+        assert_eq!(epsilon.checked_mul(epsilon).unwrap().to_bits(), 0);
+        let minus_epsilon = WrappedI80F48::from_bits(-1);
+        assert_eq!(epsilon.checked_mul(minus_epsilon).unwrap().to_bits(), -1);
+        assert_eq!(
+            WrappedI80F48::from_num(-3)
+                .checked_mul(one_and_half)
+                .unwrap()
+                .to_f64(),
+            -4.5
+        );
+        assert_eq!(WrappedI80F48::from_bits(-(3 << 47)).floor(), -2);
+    }
+
+    #[test]
+    fn accounts_have_program_sizes() {
+        let account = zeroed_account::<MarginfiAccount>(2312);
+        assert_eq!(account.active_balances().count(), 0);
+
+        zeroed_account::<Bank>(1864);
+    }
+
+    #[test]
+    fn bank_converts_shares() {
+        let mut bank = zeroed_account::<Bank>(1864);
+        bank.asset_share_value = WrappedI80F48::from_bits(3 << 47);
+        bank.total_asset_shares = WrappedI80F48::from_num(1_000_000);
+
+        assert_eq!(bank.total_deposits().unwrap().floor(), 1_500_000);
+    }
+
+    #[test]
+    fn unpack_deposit_event() {
+        let event = LendingAccountDepositEvent {
+            header: AccountEventHeader {
+                signer: Some(Pubkey::new_unique()),
+                marginfi_account: Pubkey::new_unique(),
+                marginfi_account_authority: Pubkey::new_unique(),
+                marginfi_group: Pubkey::new_unique(),
+            },
+            bank: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            amount: 5_000_000,
+        };
+        let mut data = LendingAccountDepositEvent::DISCRIMINATOR.to_vec();
+        event.serialize(&mut data).unwrap();
+
+        assert_eq!(
+            MarginfiV2Event::unpack(&data).unwrap(),
+            MarginfiV2Event::LendingAccountDepositEvent(event)
+        );
+    }
+}
@@ -0,0 +1,31 @@
+//! Price math shared by the concentrated liquidity pools, which store their price as the Q64.64
+//! square root of the raw price of token A (or 0) in token B (or 1).
+
+/// Price of token A in token B for a Q64.64 square root price, adjusted for the decimals of the
+/// two mints.
+pub fn sqrt_price_x64_to_price(sqrt_price_x64: u128, decimals_a: u8, decimals_b: u8) -> f64 {
+    let sqrt_price = sqrt_price_x64 as f64 / 2f64.powi(64);
+    sqrt_price * sqrt_price * 10f64.powi(decimals_a as i32 - decimals_b as i32)
+}
+
+/// Price of token A in token B at `tick`, each tick being a 0.01% move.
+pub fn tick_to_price(tick: i32, decimals_a: u8, decimals_b: u8) -> f64 {
+    1.0001f64.powi(tick) * 10f64.powi(decimals_a as i32 - decimals_b as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prices_from_sqrt_price_and_ticks() {
+        // SOL (9 decimals) at 150 USDC (6 decimals): 150 * 10^6 / 10^9 in raw units.
+        let sqrt_price_x64 = ((150e-3f64).sqrt() * 2f64.powi(64)) as u128;
+        let price = sqrt_price_x64_to_price(sqrt_price_x64, 9, 6);
+        assert!((price - 150.0).abs() < 1e-9);
+
+        assert_eq!(tick_to_price(0, 6, 6), 1.0);
+        let tick = (150e-3f64).ln() / 1.0001f64.ln();
+        assert!((tick_to_price(tick.round() as i32, 9, 6) - 150.0).abs() < 0.01);
+    }
+}
@@ -0,0 +1,696 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{impl_discriminator, Discriminator};
+
+solana_program::declare_id!("opnb2LAfJYbRMAHHvqjCwQxanZn7ReEHp1k81EohpZb");
+
+/// Nodes of an `EventHeap`, whether used or free.
+pub const MAX_NUM_EVENTS: usize = 600;
+
+/// Instructions
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum OpenbookV2Instruction {
+    CreateMarket(CreateMarket),
+    CloseMarket,
+    CreateOpenOrdersIndexer,
+    CloseOpenOrdersIndexer,
+    CreateOpenOrdersAccount(CreateOpenOrdersAccount),
+    CloseOpenOrdersAccount,
+    PlaceOrder(PlaceOrder),
+    EditOrder(EditOrder),
+    EditOrderPegged(EditOrderPegged),
+    PlaceOrders(PlaceOrders),
+    CancelAllAndPlaceOrders(CancelAllAndPlaceOrders),
+    PlaceOrderPegged(PlaceOrderPegged),
+    PlaceTakeOrder(PlaceTakeOrder),
+    ConsumeEvents(ConsumeEvents),
+    ConsumeGivenEvents(ConsumeGivenEvents),
+    CancelOrder(CancelOrder),
+    CancelOrderByClientOrderId(CancelOrderByClientOrderId),
+    CancelAllOrders(CancelAllOrders),
+    Deposit(Deposit),
+    Refill(Refill),
+    SettleFunds,
+    SettleFundsExpired,
+    SweepFees,
+    SetDelegate,
+    SetMarketExpired,
+    PruneOrders(PruneOrders),
+}
+
+impl OpenbookV2Instruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (discriminator, rest) = input.split_at(8);
+        if let Ok(discriminator) = discriminator.try_into() {
+            return Ok(match discriminator {
+                CreateMarket::DISCRIMINATOR => Self::CreateMarket(borsh::from_slice(rest)?),
+                CloseMarket::DISCRIMINATOR => Self::CloseMarket,
+                CreateOpenOrdersIndexer::DISCRIMINATOR => Self::CreateOpenOrdersIndexer,
+                CloseOpenOrdersIndexer::DISCRIMINATOR => Self::CloseOpenOrdersIndexer,
+                CreateOpenOrdersAccount::DISCRIMINATOR => {
+                    Self::CreateOpenOrdersAccount(borsh::from_slice(rest)?)
+                }
+                CloseOpenOrdersAccount::DISCRIMINATOR => Self::CloseOpenOrdersAccount,
+                PlaceOrder::DISCRIMINATOR => Self::PlaceOrder(borsh::from_slice(rest)?),
+                EditOrder::DISCRIMINATOR => Self::EditOrder(borsh::from_slice(rest)?),
+                EditOrderPegged::DISCRIMINATOR => Self::EditOrderPegged(borsh::from_slice(rest)?),
+                PlaceOrders::DISCRIMINATOR => Self::PlaceOrders(borsh::from_slice(rest)?),
+                CancelAllAndPlaceOrders::DISCRIMINATOR => {
+                    Self::CancelAllAndPlaceOrders(borsh::from_slice(rest)?)
+                }
+                PlaceOrderPegged::DISCRIMINATOR => Self::PlaceOrderPegged(borsh::from_slice(rest)?),
+                PlaceTakeOrder::DISCRIMINATOR => Self::PlaceTakeOrder(borsh::from_slice(rest)?),
+                ConsumeEvents::DISCRIMINATOR => Self::ConsumeEvents(borsh::from_slice(rest)?),
+                ConsumeGivenEvents::DISCRIMINATOR => {
+                    Self::ConsumeGivenEvents(borsh::from_slice(rest)?)
+                }
+                CancelOrder::DISCRIMINATOR => Self::CancelOrder(borsh::from_slice(rest)?),
+                CancelOrderByClientOrderId::DISCRIMINATOR => {
+                    Self::CancelOrderByClientOrderId(borsh::from_slice(rest)?)
+                }
+                CancelAllOrders::DISCRIMINATOR => Self::CancelAllOrders(borsh::from_slice(rest)?),
+                Deposit::DISCRIMINATOR => Self::Deposit(borsh::from_slice(rest)?),
+                Refill::DISCRIMINATOR => Self::Refill(borsh::from_slice(rest)?),
+                SettleFunds::DISCRIMINATOR => Self::SettleFunds,
+                SettleFundsExpired::DISCRIMINATOR => Self::SettleFundsExpired,
+                SweepFees::DISCRIMINATOR => Self::SweepFees,
+                SetDelegate::DISCRIMINATOR => Self::SetDelegate,
+                SetMarketExpired::DISCRIMINATOR => Self::SetMarketExpired,
+                PruneOrders::DISCRIMINATOR => Self::PruneOrders(borsh::from_slice(rest)?),
+                _ => return Err(ProgramError::InvalidInstructionData),
+            });
+        }
+
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CreateMarket {
+    pub name: String,
+    pub oracle_config: OracleConfigParams,
+    pub quote_lot_size: i64,
+    pub base_lot_size: i64,
+    pub maker_fee: i64,
+    pub taker_fee: i64,
+    pub time_expiry: i64,
+}
+
+impl_discriminator!(instruction CreateMarket);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CloseMarket {}
+
+impl_discriminator!(instruction CloseMarket);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CreateOpenOrdersIndexer {}
+
+impl_discriminator!(instruction CreateOpenOrdersIndexer);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CloseOpenOrdersIndexer {}
+
+impl_discriminator!(instruction CloseOpenOrdersIndexer);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CreateOpenOrdersAccount {
+    pub name: String,
+}
+
+impl_discriminator!(instruction CreateOpenOrdersAccount);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CloseOpenOrdersAccount {}
+
+impl_discriminator!(instruction CloseOpenOrdersAccount);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceOrder {
+    pub args: PlaceOrderArgs,
+}
+
+impl_discriminator!(instruction PlaceOrder);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct EditOrder {
+    pub client_order_id: u64,
+    pub expected_cancel_size: i64,
+    pub place_order: PlaceOrderArgs,
+}
+
+impl_discriminator!(instruction EditOrder);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct EditOrderPegged {
+    pub client_order_id: u64,
+    pub expected_cancel_size: i64,
+    pub place_order: PlaceOrderPeggedArgs,
+}
+
+impl_discriminator!(instruction EditOrderPegged);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceOrders {
+    pub orders_type: PlaceOrderType,
+    pub bids: Vec<PlaceMultipleOrdersArgs>,
+    pub asks: Vec<PlaceMultipleOrdersArgs>,
+    pub limit: u8,
+}
+
+impl_discriminator!(instruction PlaceOrders);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CancelAllAndPlaceOrders {
+    pub orders_type: PlaceOrderType,
+    pub bids: Vec<PlaceMultipleOrdersArgs>,
+    pub asks: Vec<PlaceMultipleOrdersArgs>,
+    pub limit: u8,
+}
+
+impl_discriminator!(instruction CancelAllAndPlaceOrders);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceOrderPegged {
+    pub args: PlaceOrderPeggedArgs,
+}
+
+impl_discriminator!(instruction PlaceOrderPegged);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceTakeOrder {
+    pub args: PlaceTakeOrderArgs,
+}
+
+impl_discriminator!(instruction PlaceTakeOrder);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ConsumeEvents {
+    pub limit: u64,
+}
+
+impl_discriminator!(instruction ConsumeEvents);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ConsumeGivenEvents {
+    pub slots: Vec<u64>,
+}
+
+impl_discriminator!(instruction ConsumeGivenEvents);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CancelOrder {
+    pub order_id: u128,
+}
+
+impl_discriminator!(instruction CancelOrder);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CancelOrderByClientOrderId {
+    pub client_order_id: u64,
+}
+
+impl_discriminator!(instruction CancelOrderByClientOrderId);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CancelAllOrders {
+    pub side_option: Option<Side>,
+    pub limit: u8,
+}
+
+impl_discriminator!(instruction CancelAllOrders);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Deposit {
+    pub base_amount: u64,
+    pub quote_amount: u64,
+}
+
+impl_discriminator!(instruction Deposit);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Refill {
+    pub base_amount: u64,
+    pub quote_amount: u64,
+}
+
+impl_discriminator!(instruction Refill);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SettleFunds {}
+
+impl_discriminator!(instruction SettleFunds);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SettleFundsExpired {}
+
+impl_discriminator!(instruction SettleFundsExpired);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SweepFees {}
+
+impl_discriminator!(instruction SweepFees);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SetDelegate {}
+
+impl_discriminator!(instruction SetDelegate);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SetMarketExpired {}
+
+impl_discriminator!(instruction SetMarketExpired);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PruneOrders {
+    pub limit: u8,
+}
+
+impl_discriminator!(instruction PruneOrders);
+
+/// Instruction accounts
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsumeEventsAccounts {
+    pub consume_events_admin: Pubkey,
+    pub market: Pubkey,
+    pub event_heap: Pubkey,
+}
+
+impl ConsumeEventsAccounts {
+    /// Reads the accounts of a `ConsumeEvents` or `ConsumeGivenEvents` instruction, the open
+    /// orders accounts of the makers come after them.
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        match accounts {
+            [consume_events_admin, market, event_heap, ..] => Some(Self {
+                consume_events_admin: *consume_events_admin,
+                market: *market,
+                event_heap: *event_heap,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Accounts
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventHeap {
+    pub header: EventHeapHeader,
+    pub nodes: Vec<EventNode>,
+}
+
+impl_discriminator!(account EventHeap);
+
+/// Zero copy in the program, with the nodes in a fixed size array followed by reserved bytes.
+impl BorshDeserialize for EventHeap {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let header = EventHeapHeader::deserialize_reader(reader)?;
+        let nodes = (0..MAX_NUM_EVENTS)
+            .map(|_| EventNode::deserialize_reader(reader))
+            .collect::<Result<_, _>>()?;
+        <[u8; 64]>::deserialize_reader(reader)?;
+        Ok(Self { header, nodes })
+    }
+}
+
+impl BorshSerialize for EventHeap {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        self.header.serialize(writer)?;
+        for node in &self.nodes {
+            node.serialize(writer)?;
+        }
+        [0u8; 64].serialize(writer)
+    }
+}
+
+impl EventHeap {
+    /// Events not yet consumed by the crank, oldest first, following the used list of the heap.
+    pub fn events(&self) -> impl Iterator<Item = &AnyEvent> {
+        let mut slot = self.header.used_head as usize;
+        (0..self.header.count).map_while(move |_| {
+            let node = self.nodes.get(slot)?;
+            slot = node.next as usize;
+            Some(&node.event)
+        })
+    }
+
+    pub fn fills(&self) -> impl Iterator<Item = &FillEvent> {
+        self.events().filter_map(|event| match event {
+            AnyEvent::Fill(fill) => Some(fill),
+            AnyEvent::Out(_) => None,
+        })
+    }
+}
+
+/// Types
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq)]
+pub enum Side {
+    Bid,
+    Ask,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq)]
+pub enum PlaceOrderType {
+    Limit,
+    ImmediateOrCancel,
+    PostOnly,
+    Market,
+    PostOnlySlide,
+    FillOrKill,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq)]
+pub enum SelfTradeBehavior {
+    DecrementTake,
+    CancelProvide,
+    AbortTransaction,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct OracleConfigParams {
+    pub conf_filter: f32,
+    pub max_staleness_slots: Option<u32>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceOrderArgs {
+    pub side: Side,
+    pub price_lots: i64,
+    pub max_base_lots: i64,
+    pub max_quote_lots_including_fees: i64,
+    pub client_order_id: u64,
+    pub order_type: PlaceOrderType,
+    pub expiry_timestamp: u64,
+    pub self_trade_behavior: SelfTradeBehavior,
+    pub limit: u8,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceOrderPeggedArgs {
+    pub side: Side,
+    pub price_offset_lots: i64,
+    pub peg_limit: i64,
+    pub max_base_lots: i64,
+    pub max_quote_lots_including_fees: i64,
+    pub client_order_id: u64,
+    pub order_type: PlaceOrderType,
+    pub expiry_timestamp: u64,
+    pub self_trade_behavior: SelfTradeBehavior,
+    pub limit: u8,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceTakeOrderArgs {
+    pub side: Side,
+    pub price_lots: i64,
+    pub max_base_lots: i64,
+    pub max_quote_lots_including_fees: i64,
+    pub order_type: PlaceOrderType,
+    pub limit: u8,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceMultipleOrdersArgs {
+    pub price_lots: i64,
+    pub quote_lots_including_fees: i64,
+    pub expiry_timestamp: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct EventHeapHeader {
+    pub free_head: u16,
+    pub used_head: u16,
+    pub count: u16,
+    pub padd: u16,
+    pub seq_num: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct EventNode {
+    pub next: u16,
+    pub prev: u16,
+    pub pad: [u8; 4],
+    pub event: AnyEvent,
+}
+
+/// The leading `event_type` byte of the 144 byte events is their borsh variant index.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum AnyEvent {
+    Fill(FillEvent),
+    Out(OutEvent),
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct FillEvent {
+    pub taker_side: u8,
+    pub maker_out: u8,
+    pub maker_slot: u8,
+    pub padding: [u8; 4],
+    pub timestamp: u64,
+    pub market_seq_num: u64,
+    pub maker: Pubkey,
+    pub maker_timestamp: u64,
+    pub taker: Pubkey,
+    pub taker_client_order_id: u64,
+    /// In quote lots per base lot.
+    pub price: i64,
+    pub peg_limit: i64,
+    /// In base lots.
+    pub quantity: i64,
+    pub maker_client_order_id: u64,
+    pub reserved: [u8; 8],
+}
+
+impl FillEvent {
+    pub fn taker_side(&self) -> Side {
+        match self.taker_side {
+            0 => Side::Bid,
+            _ => Side::Ask,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct OutEvent {
+    pub side: u8,
+    pub owner_slot: u8,
+    pub padding0: [u8; 5],
+    pub timestamp: u64,
+    pub seq_num: u64,
+    pub owner: Pubkey,
+    pub quantity: i64,
+    pub padding1: [u8; 80],
+}
+
+/// Events
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum OpenbookV2Event {
+    FillLog(FillLog),
+    TotalOrderFillEvent(TotalOrderFillEvent),
+    DepositLog(DepositLog),
+    SettleFundsLog(SettleFundsLog),
+}
+
+impl OpenbookV2Event {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (discriminator, rest) = input.split_at(8);
+        if let Ok(discriminator) = discriminator.try_into() {
+            return Ok(match discriminator {
+                FillLog::DISCRIMINATOR => Self::FillLog(borsh::from_slice(rest)?),
+                TotalOrderFillEvent::DISCRIMINATOR => {
+                    Self::TotalOrderFillEvent(borsh::from_slice(rest)?)
+                }
+                DepositLog::DISCRIMINATOR => Self::DepositLog(borsh::from_slice(rest)?),
+                SettleFundsLog::DISCRIMINATOR => Self::SettleFundsLog(borsh::from_slice(rest)?),
+                _ => return Err(ProgramError::InvalidInstructionData),
+            });
+        }
+
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+/// Logged for every maker an order is matched against, unlike the event heap it includes the
+/// fees.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct FillLog {
+    pub market: Pubkey,
+    pub taker_side: u8,
+    pub maker_slot: u8,
+    pub maker_out: bool,
+    pub timestamp: u64,
+    pub seq_num: u64,
+    pub maker: Pubkey,
+    pub maker_client_order_id: u64,
+    /// In native quote.
+    pub maker_fee: u64,
+    pub maker_timestamp: u64,
+    pub taker: Pubkey,
+    pub taker_client_order_id: u64,
+    /// In native quote.
+    pub taker_fee_ceil: u64,
+    pub price: i64,
+    pub quantity: i64,
+}
+
+impl_discriminator!(event FillLog);
+
+/// Logged once per taker order, with native amounts.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct TotalOrderFillEvent {
+    pub side: u8,
+    pub taker: Pubkey,
+    pub total_quantity_paid: u64,
+    pub total_quantity_received: u64,
+    pub fees: u64,
+}
+
+impl_discriminator!(event TotalOrderFillEvent);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DepositLog {
+    pub open_orders_account: Pubkey,
+    pub signer: Pubkey,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+}
+
+impl_discriminator!(event DepositLog);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SettleFundsLog {
+    pub open_orders_account: Pubkey,
+    pub base_native: u64,
+    pub quote_native: u64,
+    pub referrer_rebate: u64,
+    pub referrer: Option<Pubkey>,
+}
+
+impl_discriminator!(event SettleFundsLog);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unpack_account;
+
+    #[test]
+    fn unpack_place_take_order() {
+        let args = PlaceTakeOrderArgs {
+            side: Side::Ask,
+            price_lots: 1,
+            max_base_lots: 1_000,
+            max_quote_lots_including_fees: i64::MAX,
+            order_type: PlaceOrderType::Market,
+            limit: 50,
+        };
+        let mut data = PlaceTakeOrder::DISCRIMINATOR.to_vec();
+        args.serialize(&mut data).unwrap();
+
+        assert_eq!(
+            OpenbookV2Instruction::unpack(&data).unwrap(),
+            OpenbookV2Instruction::PlaceTakeOrder(PlaceTakeOrder { args })
+        );
+        assert_eq!(
+            OpenbookV2Instruction::unpack(&SettleFunds::DISCRIMINATOR).unwrap(),
+            OpenbookV2Instruction::SettleFunds
+        );
+    }
+
+    #[test]
+    fn unpack_fill_log() {
+        let log = FillLog {
+            market: Pubkey::new_unique(),
+            taker_side: 1,
+            maker_slot: 0,
+            maker_out: true,
+            timestamp: 1_700_000_000,
+            seq_num: 42,
+            maker: Pubkey::new_unique(),
+            maker_client_order_id: 1,
+            maker_fee: 0,
+            maker_timestamp: 1_699_999_000,
+            taker: Pubkey::new_unique(),
+            taker_client_order_id: 2,
+            taker_fee_ceil: 300,
+            price: 15_000,
+            quantity: 100,
+        };
+        let mut data = FillLog::DISCRIMINATOR.to_vec();
+        log.serialize(&mut data).unwrap();
+
+        assert_eq!(
+            OpenbookV2Event::unpack(&data).unwrap(),
+            OpenbookV2Event::FillLog(log)
+        );
+    }
+
+    fn fill(market_seq_num: u64) -> FillEvent {
+        FillEvent {
+            taker_side: 0,
+            maker_out: 0,
+            maker_slot: 0,
+            padding: [0; 4],
+            timestamp: 1_700_000_000,
+            market_seq_num,
+            maker: Pubkey::new_unique(),
+            maker_timestamp: 1_699_999_000,
+            taker: Pubkey::new_unique(),
+            taker_client_order_id: 0,
+            price: 15_000,
+            peg_limit: -1,
+            quantity: 10,
+            maker_client_order_id: 0,
+            reserved: [0; 8],
+        }
+    }
+
+    #[test]
+    fn event_heap_follows_used_list() {
+        let mut data = vec![0; 8 + 16 + MAX_NUM_EVENTS * 152 + 64];
+        data[..8].copy_from_slice(&EventHeap::DISCRIMINATOR);
+        let mut heap = unpack_account::<EventHeap>(&data).unwrap();
+        assert_eq!(heap.nodes.len(), MAX_NUM_EVENTS);
+        assert_eq!(borsh::to_vec(&heap).unwrap().len(), data.len() - 8);
+        assert_eq!(heap.events().count(), 0);
+
+        // Two fills in slots 5 then 2, with an out event left in a free slot.
+        heap.header.used_head = 5;
+        heap.header.count = 2;
+        heap.nodes[5].next = 2;
+        heap.nodes[5].event = AnyEvent::Fill(fill(7));
+        heap.nodes[2].event = AnyEvent::Fill(fill(8));
+        heap.nodes[3].event = AnyEvent::Out(OutEvent {
+            side: 1,
+            owner_slot: 0,
+            padding0: [0; 5],
+            timestamp: 0,
+            seq_num: 6,
+            owner: Pubkey::new_unique(),
+            quantity: 1,
+            padding1: [0; 80],
+        });
+        let mut data = EventHeap::DISCRIMINATOR.to_vec();
+        heap.serialize(&mut data).unwrap();
+
+        let heap = unpack_account::<EventHeap>(&data).unwrap();
+        let seq_nums = heap
+            .fills()
+            .map(|fill| fill.market_seq_num)
+            .collect::<Vec<_>>();
+        assert_eq!(seq_nums, vec![7, 8]);
+        assert_eq!(heap.fills().next().unwrap().taker_side(), Side::Bid);
+    }
+}
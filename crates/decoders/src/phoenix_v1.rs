@@ -0,0 +1,419 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+solana_program::declare_id!("PhoeNiXZ8ByJGLkxNfZRnkUfjvmuYqLR89jjFHGqdXY");
+
+/// Tag of the `Log` instruction Phoenix invokes itself with to record market events.
+pub const LOG_INSTRUCTION_TAG: u8 = 15;
+
+/// Instructions
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum PhoenixV1Instruction {
+    Swap(OrderPacket),
+    SwapWithFreeFunds(OrderPacket),
+    PlaceLimitOrder(OrderPacket),
+    PlaceLimitOrderWithFreeFunds(OrderPacket),
+    ReduceOrder(ReduceOrderParams),
+    ReduceOrderWithFreeFunds(ReduceOrderParams),
+    CancelAllOrders,
+    CancelAllOrdersWithFreeFunds,
+    CancelUpTo(CancelUpToParams),
+    CancelUpToWithFreeFunds(CancelUpToParams),
+    CancelMultipleOrdersById(CancelMultipleOrdersByIdParams),
+    CancelMultipleOrdersByIdWithFreeFunds(CancelMultipleOrdersByIdParams),
+    WithdrawFunds(WithdrawParams),
+    DepositFunds(DepositParams),
+    RequestSeat,
+    Log(PhoenixLog),
+    PlaceMultiplePostOnlyOrders(MultipleOrderPacket),
+    PlaceMultiplePostOnlyOrdersWithFreeFunds(MultipleOrderPacket),
+}
+
+impl PhoenixV1Instruction {
+    /// Instructions are tagged by their first byte, the variant index, followed by the borsh
+    /// encoded parameters. Market administration instructions are not decoded.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        Ok(borsh::from_slice(input)?)
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq)]
+pub enum Side {
+    Bid,
+    Ask,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq)]
+pub enum SelfTradeBehavior {
+    Abort,
+    CancelProvide,
+    DecrementTake,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum OrderPacket {
+    PostOnly {
+        side: Side,
+        price_in_ticks: u64,
+        num_base_lots: u64,
+        client_order_id: u128,
+        reject_post_only: bool,
+        use_only_deposited_funds: bool,
+        last_valid_slot: Option<u64>,
+        last_valid_unix_timestamp_in_seconds: Option<u64>,
+        fail_silently_on_insufficient_funds: bool,
+    },
+    Limit {
+        side: Side,
+        price_in_ticks: u64,
+        num_base_lots: u64,
+        self_trade_behavior: SelfTradeBehavior,
+        match_limit: Option<u64>,
+        client_order_id: u128,
+        use_only_deposited_funds: bool,
+        last_valid_slot: Option<u64>,
+        last_valid_unix_timestamp_in_seconds: Option<u64>,
+        fail_silently_on_insufficient_funds: bool,
+    },
+    ImmediateOrCancel {
+        side: Side,
+        price_in_ticks: Option<u64>,
+        num_base_lots: u64,
+        num_quote_lots: u64,
+        min_base_lots_to_fill: u64,
+        min_quote_lots_to_fill: u64,
+        self_trade_behavior: SelfTradeBehavior,
+        match_limit: Option<u64>,
+        client_order_id: u128,
+        use_only_deposited_funds: bool,
+        last_valid_slot: Option<u64>,
+        last_valid_unix_timestamp_in_seconds: Option<u64>,
+    },
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CancelOrderParams {
+    pub side: Side,
+    pub price_in_ticks: u64,
+    pub order_sequence_number: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ReduceOrderParams {
+    pub base_params: CancelOrderParams,
+    pub size: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CancelUpToParams {
+    pub side: Side,
+    pub tick_limit: Option<u64>,
+    pub num_orders_to_search: Option<u32>,
+    pub num_orders_to_cancel: Option<u32>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CancelMultipleOrdersByIdParams {
+    pub orders: Vec<CancelOrderParams>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct WithdrawParams {
+    pub quote_lots_to_withdraw: Option<u64>,
+    pub base_lots_to_withdraw: Option<u64>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DepositParams {
+    pub quote_lots_to_deposit: u64,
+    pub base_lots_to_deposit: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CondensedOrder {
+    pub price_in_ticks: u64,
+    pub size_in_base_lots: u64,
+    pub last_valid_slot: Option<u64>,
+    pub last_valid_unix_timestamp_in_seconds: Option<u64>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq)]
+pub enum FailedMultipleLimitOrderBehavior {
+    FailOnInsufficientFundsAndAmendOnCross,
+    FailOnInsufficientFundsAndFailOnCross,
+    SkipOnInsufficientFundsAndAmendOnCross,
+    SkipOnInsufficientFundsAndFailOnCross,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct MultipleOrderPacket {
+    pub bids: Vec<CondensedOrder>,
+    pub asks: Vec<CondensedOrder>,
+    pub client_order_id: Option<u128>,
+    pub failed_multiple_limit_order_behavior: FailedMultipleLimitOrderBehavior,
+}
+
+/// Instruction accounts
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SwapAccounts {
+    pub phoenix_program: Pubkey,
+    pub log_authority: Pubkey,
+    pub market: Pubkey,
+    pub trader: Pubkey,
+    pub base_account: Pubkey,
+    pub quote_account: Pubkey,
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// Events
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum PhoenixMarketEvent {
+    Uninitialized,
+    Header(AuditLogHeader),
+    Fill(FillEvent),
+    Place(PlaceEvent),
+    Reduce(ReduceEvent),
+    Evict(EvictEvent),
+    FillSummary(FillSummaryEvent),
+    Fee(FeeEvent),
+    TimeInForce(TimeInForceEvent),
+    ExpiredOrder(ExpiredOrderEvent),
+}
+
+/// Data of a `Log` instruction: a header followed by the events an instruction of `signer` on
+/// `market` produced.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhoenixLog {
+    pub header: AuditLogHeader,
+    pub events: Vec<PhoenixMarketEvent>,
+}
+
+impl BorshDeserialize for PhoenixLog {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        // The header is itself encoded as the `Header` event, followed by the other events as
+        // a borsh vector.
+        let header = match PhoenixMarketEvent::deserialize_reader(reader)? {
+            PhoenixMarketEvent::Header(header) => header,
+            _ => {
+                return Err(borsh::io::Error::new(
+                    borsh::io::ErrorKind::InvalidData,
+                    "Phoenix log doesn't start with a header",
+                ))
+            }
+        };
+
+        Ok(Self {
+            header,
+            events: Vec::deserialize_reader(reader)?,
+        })
+    }
+}
+
+impl BorshSerialize for PhoenixLog {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        PhoenixMarketEvent::Header(self.header.clone()).serialize(writer)?;
+        self.events.serialize(writer)
+    }
+}
+
+impl PhoenixLog {
+    /// Decodes the data of a `Log` instruction, tag included.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        match input.split_first() {
+            Some((&LOG_INSTRUCTION_TAG, rest)) => Ok(borsh::from_slice(rest)?),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    pub fn fills(&self) -> impl Iterator<Item = &FillEvent> {
+        self.events.iter().filter_map(|event| match event {
+            PhoenixMarketEvent::Fill(fill) => Some(fill),
+            _ => None,
+        })
+    }
+
+    pub fn fill_summary(&self) -> Option<&FillSummaryEvent> {
+        self.events.iter().find_map(|event| match event {
+            PhoenixMarketEvent::FillSummary(summary) => Some(summary),
+            _ => None,
+        })
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct AuditLogHeader {
+    /// Tag of the instruction that produced the events.
+    pub instruction: u8,
+    pub sequence_number: u64,
+    pub timestamp: i64,
+    pub slot: u64,
+    pub market: Pubkey,
+    pub signer: Pubkey,
+    pub total_events: u16,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct FillEvent {
+    pub index: u16,
+    pub maker_id: Pubkey,
+    pub order_sequence_number: u64,
+    pub price_in_ticks: u64,
+    pub base_lots_filled: u64,
+    pub base_lots_remaining: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PlaceEvent {
+    pub index: u16,
+    pub order_sequence_number: u64,
+    pub client_order_id: u128,
+    pub price_in_ticks: u64,
+    pub base_lots_placed: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ReduceEvent {
+    pub index: u16,
+    pub order_sequence_number: u64,
+    pub price_in_ticks: u64,
+    pub base_lots_removed: u64,
+    pub base_lots_remaining: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct EvictEvent {
+    pub index: u16,
+    pub maker_id: Pubkey,
+    pub order_sequence_number: u64,
+    pub price_in_ticks: u64,
+    pub base_lots_evicted: u64,
+}
+
+/// Totals of the fills a taker order got, in lots of the market.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct FillSummaryEvent {
+    pub index: u16,
+    pub client_order_id: u128,
+    pub total_base_lots_filled: u64,
+    pub total_quote_lots_filled: u64,
+    pub total_fee_in_quote_lots: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct FeeEvent {
+    pub index: u16,
+    pub fees_collected_in_quote_lots: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct TimeInForceEvent {
+    pub index: u16,
+    pub order_sequence_number: u64,
+    pub last_valid_slot: u64,
+    pub last_valid_unix_timestamp_in_seconds: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ExpiredOrderEvent {
+    pub index: u16,
+    pub maker_id: Pubkey,
+    pub order_sequence_number: u64,
+    pub price_in_ticks: u64,
+    pub base_lots_removed: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log() -> PhoenixLog {
+        let maker_id = Pubkey::new_unique();
+        PhoenixLog {
+            header: AuditLogHeader {
+                instruction: 0,
+                sequence_number: 42,
+                timestamp: 1_700_000_000,
+                slot: 250_000_000,
+                market: Pubkey::new_unique(),
+                signer: Pubkey::new_unique(),
+                total_events: 3,
+            },
+            events: vec![
+                PhoenixMarketEvent::Fill(FillEvent {
+                    index: 0,
+                    maker_id,
+                    order_sequence_number: 7,
+                    price_in_ticks: 150_000,
+                    base_lots_filled: 100,
+                    base_lots_remaining: 0,
+                }),
+                PhoenixMarketEvent::Fill(FillEvent {
+                    index: 1,
+                    maker_id,
+                    order_sequence_number: 8,
+                    price_in_ticks: 150_001,
+                    base_lots_filled: 50,
+                    base_lots_remaining: 25,
+                }),
+                PhoenixMarketEvent::FillSummary(FillSummaryEvent {
+                    index: 2,
+                    client_order_id: 0,
+                    total_base_lots_filled: 150,
+                    total_quote_lots_filled: 22_500_050,
+                    total_fee_in_quote_lots: 4_500,
+                }),
+            ],
+        }
+    }
+
+    #[test]
+    fn unpack_swap() {
+        let swap = PhoenixV1Instruction::Swap(OrderPacket::ImmediateOrCancel {
+            side: Side::Bid,
+            price_in_ticks: None,
+            num_base_lots: 0,
+            num_quote_lots: 1_000,
+            min_base_lots_to_fill: 6,
+            min_quote_lots_to_fill: 0,
+            self_trade_behavior: SelfTradeBehavior::Abort,
+            match_limit: None,
+            client_order_id: 0,
+            use_only_deposited_funds: false,
+            last_valid_slot: None,
+            last_valid_unix_timestamp_in_seconds: None,
+        });
+        let data = borsh::to_vec(&swap).unwrap();
+        assert_eq!(data[0], 0);
+
+        assert_eq!(PhoenixV1Instruction::unpack(&data), Ok(swap));
+    }
+
+    #[test]
+    fn unpack_log() {
+        let log = log();
+        let mut data = vec![LOG_INSTRUCTION_TAG];
+        data.extend(borsh::to_vec(&log).unwrap());
+
+        let decoded = PhoenixLog::unpack(&data).unwrap();
+        assert_eq!(decoded.fills().count(), 2);
+        assert_eq!(
+            decoded
+                .fill_summary()
+                .map(|summary| summary.total_base_lots_filled),
+            Some(150)
+        );
+        assert_eq!(decoded, log);
+        assert_eq!(
+            PhoenixV1Instruction::unpack(&data),
+            Ok(PhoenixV1Instruction::Log(log))
+        );
+
+        data[0] = 0;
+        assert!(PhoenixLog::unpack(&data).is_err());
+    }
+}
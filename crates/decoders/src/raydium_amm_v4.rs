@@ -0,0 +1,481 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+solana_program::declare_id!("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8");
+
+/// Prefix of the `Program log:` messages carrying a base64 encoded `RayLog`.
+pub const RAY_LOG_PREFIX: &str = "ray_log: ";
+
+/// Instructions
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RaydiumAmmV4Instruction {
+    Initialize(Initialize),
+    Initialize2(Initialize2),
+    MonitorStep(MonitorStep),
+    Deposit(Deposit),
+    Withdraw(Withdraw),
+    MigrateToOpenBook,
+    WithdrawPnl,
+    WithdrawSrm(WithdrawSrm),
+    SwapBaseIn(SwapBaseIn),
+    PreInitialize(PreInitialize),
+    SwapBaseOut(SwapBaseOut),
+    AdminCancelOrders(AdminCancelOrders),
+    CreateConfigAccount,
+    /// `SwapBaseIn` without the OpenBook market accounts.
+    SwapBaseInV2(SwapBaseIn),
+    /// `SwapBaseOut` without the OpenBook market accounts.
+    SwapBaseOutV2(SwapBaseOut),
+}
+
+impl RaydiumAmmV4Instruction {
+    /// Instructions are tagged by their first byte rather than an anchor discriminator. Like the
+    /// program, trailing bytes are ignored.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (tag, mut rest) = match input.split_first() {
+            Some((tag, rest)) => (*tag, rest),
+            None => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        Ok(match tag {
+            0 => Self::Initialize(BorshDeserialize::deserialize(&mut rest)?),
+            1 => Self::Initialize2(BorshDeserialize::deserialize(&mut rest)?),
+            2 => Self::MonitorStep(BorshDeserialize::deserialize(&mut rest)?),
+            3 => Self::Deposit(Deposit {
+                max_coin_amount: u64::deserialize(&mut rest)?,
+                max_pc_amount: u64::deserialize(&mut rest)?,
+                base_side: u64::deserialize(&mut rest)?,
+                other_amount_min: optional_u64(&mut rest)?,
+            }),
+            4 => {
+                let amount = u64::deserialize(&mut rest)?;
+                // The minimums were added as a pair, older clients send neither.
+                let (min_coin_amount, min_pc_amount) = match rest.len() >= 16 {
+                    true => (optional_u64(&mut rest)?, optional_u64(&mut rest)?),
+                    false => (None, None),
+                };
+                Self::Withdraw(Withdraw {
+                    amount,
+                    min_coin_amount,
+                    min_pc_amount,
+                })
+            }
+            5 => Self::MigrateToOpenBook,
+            7 => Self::WithdrawPnl,
+            8 => Self::WithdrawSrm(BorshDeserialize::deserialize(&mut rest)?),
+            9 => Self::SwapBaseIn(BorshDeserialize::deserialize(&mut rest)?),
+            10 => Self::PreInitialize(BorshDeserialize::deserialize(&mut rest)?),
+            11 => Self::SwapBaseOut(BorshDeserialize::deserialize(&mut rest)?),
+            13 => Self::AdminCancelOrders(BorshDeserialize::deserialize(&mut rest)?),
+            14 => Self::CreateConfigAccount,
+            16 => Self::SwapBaseInV2(BorshDeserialize::deserialize(&mut rest)?),
+            17 => Self::SwapBaseOutV2(BorshDeserialize::deserialize(&mut rest)?),
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+
+    /// Whether the instruction logs a `RayLog`, pairing the logs of a transaction with its
+    /// instructions in execution order.
+    pub fn writes_ray_log(&self) -> bool {
+        matches!(
+            self,
+            Self::Initialize2(_)
+                | Self::Deposit(_)
+                | Self::Withdraw(_)
+                | Self::SwapBaseIn(_)
+                | Self::SwapBaseOut(_)
+                | Self::SwapBaseInV2(_)
+                | Self::SwapBaseOutV2(_)
+        )
+    }
+}
+
+/// Reads a trailing `u64` the program treats as optional, present when 8 bytes are left.
+fn optional_u64(rest: &mut &[u8]) -> Result<Option<u64>, ProgramError> {
+    match rest.len() >= 8 {
+        true => Ok(Some(u64::deserialize(rest)?)),
+        false => Ok(None),
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Initialize {
+    pub nonce: u8,
+    pub open_time: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Initialize2 {
+    pub nonce: u8,
+    pub open_time: u64,
+    pub init_pc_amount: u64,
+    pub init_coin_amount: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct MonitorStep {
+    pub plan_order_limit: u16,
+    pub place_order_limit: u16,
+    pub cancel_order_limit: u16,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Deposit {
+    pub max_coin_amount: u64,
+    pub max_pc_amount: u64,
+    /// 0 when `max_coin_amount` is the fixed side, 1 when `max_pc_amount` is.
+    pub base_side: u64,
+    pub other_amount_min: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Withdraw {
+    pub amount: u64,
+    pub min_coin_amount: Option<u64>,
+    pub min_pc_amount: Option<u64>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct WithdrawSrm {
+    pub amount: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SwapBaseIn {
+    pub amount_in: u64,
+    pub minimum_amount_out: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PreInitialize {
+    pub nonce: u8,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SwapBaseOut {
+    pub max_amount_in: u64,
+    pub amount_out: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct AdminCancelOrders {
+    pub limit: u16,
+}
+
+/// Instruction accounts
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwapAccounts {
+    pub token_program: Pubkey,
+    pub amm: Pubkey,
+    pub amm_authority: Pubkey,
+    pub amm_open_orders: Pubkey,
+    /// Left out by newer clients, which pass 17 accounts instead of 18.
+    pub amm_target_orders: Option<Pubkey>,
+    pub pool_coin_token_account: Pubkey,
+    pub pool_pc_token_account: Pubkey,
+    pub serum_program: Pubkey,
+    pub serum_market: Pubkey,
+    pub serum_bids: Pubkey,
+    pub serum_asks: Pubkey,
+    pub serum_event_queue: Pubkey,
+    pub serum_coin_vault: Pubkey,
+    pub serum_pc_vault: Pubkey,
+    pub serum_vault_signer: Pubkey,
+    pub user_source_token_account: Pubkey,
+    pub user_destination_token_account: Pubkey,
+    pub user_source_owner: Pubkey,
+}
+
+impl SwapAccounts {
+    /// Reads the accounts of a `SwapBaseIn` or `SwapBaseOut` instruction.
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        let (amm_target_orders, rest) = match accounts.len() {
+            18 => (Some(accounts[4]), &accounts[5..]),
+            17 => (None, &accounts[4..]),
+            _ => return None,
+        };
+
+        Some(Self {
+            token_program: accounts[0],
+            amm: accounts[1],
+            amm_authority: accounts[2],
+            amm_open_orders: accounts[3],
+            amm_target_orders,
+            pool_coin_token_account: rest[0],
+            pool_pc_token_account: rest[1],
+            serum_program: rest[2],
+            serum_market: rest[3],
+            serum_bids: rest[4],
+            serum_asks: rest[5],
+            serum_event_queue: rest[6],
+            serum_coin_vault: rest[7],
+            serum_pc_vault: rest[8],
+            serum_vault_signer: rest[9],
+            user_source_token_account: rest[10],
+            user_destination_token_account: rest[11],
+            user_source_owner: rest[12],
+        })
+    }
+}
+
+/// Logs
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SwapDirection {
+    PcToCoin,
+    CoinToPc,
+}
+
+impl SwapDirection {
+    fn from_log(direction: u64) -> Option<Self> {
+        match direction {
+            1 => Some(Self::PcToCoin),
+            2 => Some(Self::CoinToPc),
+            _ => None,
+        }
+    }
+}
+
+/// What the program logs as `ray_log` for each initialization, deposit, withdrawal and swap,
+/// the only record of the amounts it computed.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum RayLog {
+    Init(InitLog),
+    Deposit(DepositLog),
+    Withdraw(WithdrawLog),
+    SwapBaseIn(SwapBaseInLog),
+    SwapBaseOut(SwapBaseOutLog),
+}
+
+impl RayLog {
+    /// Decodes the bytes of a `ray_log` message once base64 decoded.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        Ok(borsh::from_slice(input)?)
+    }
+
+    /// Amounts that actually moved in a swap, `None` for other logs.
+    pub fn swap(&self) -> Option<SwapAmounts> {
+        let (direction, amount_in, amount_out, pool_coin, pool_pc) = match self {
+            Self::SwapBaseIn(log) => (
+                log.direction,
+                log.amount_in,
+                log.out_amount,
+                log.pool_coin,
+                log.pool_pc,
+            ),
+            Self::SwapBaseOut(log) => (
+                log.direction,
+                log.deduct_in,
+                log.amount_out,
+                log.pool_coin,
+                log.pool_pc,
+            ),
+            _ => return None,
+        };
+
+        Some(SwapAmounts {
+            direction: SwapDirection::from_log(direction)?,
+            amount_in,
+            amount_out,
+            pool_coin,
+            pool_pc,
+        })
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitLog {
+    pub time: u64,
+    pub pc_decimals: u8,
+    pub coin_decimals: u8,
+    pub pc_lot_size: u64,
+    pub coin_lot_size: u64,
+    pub pc_amount: u64,
+    pub coin_amount: u64,
+    pub market: Pubkey,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DepositLog {
+    pub max_coin: u64,
+    pub max_pc: u64,
+    pub base: u64,
+    pub pool_coin: u64,
+    pub pool_pc: u64,
+    pub pool_lp: u64,
+    pub calc_pnl_x: u128,
+    pub calc_pnl_y: u128,
+    pub deduct_coin: u64,
+    pub deduct_pc: u64,
+    pub mint_lp: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct WithdrawLog {
+    pub withdraw_lp: u64,
+    pub user_lp: u64,
+    pub pool_coin: u64,
+    pub pool_pc: u64,
+    pub pool_lp: u64,
+    pub calc_pnl_x: u128,
+    pub calc_pnl_y: u128,
+    pub out_coin: u64,
+    pub out_pc: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SwapBaseInLog {
+    pub amount_in: u64,
+    pub minimum_out: u64,
+    pub direction: u64,
+    pub user_source: u64,
+    pub pool_coin: u64,
+    pub pool_pc: u64,
+    pub out_amount: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SwapBaseOutLog {
+    pub max_in: u64,
+    pub amount_out: u64,
+    pub direction: u64,
+    pub user_source: u64,
+    pub pool_coin: u64,
+    pub pool_pc: u64,
+    pub deduct_in: u64,
+}
+
+/// A swap as executed, with the pool reserves it was priced against.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SwapAmounts {
+    pub direction: SwapDirection,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub pool_coin: u64,
+    pub pool_pc: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unpack_swaps() {
+        let mut data = vec![9];
+        data.extend(1_000_000u64.to_le_bytes());
+        data.extend(990u64.to_le_bytes());
+        assert_eq!(
+            RaydiumAmmV4Instruction::unpack(&data),
+            Ok(RaydiumAmmV4Instruction::SwapBaseIn(SwapBaseIn {
+                amount_in: 1_000_000,
+                minimum_amount_out: 990,
+            }))
+        );
+
+        data[0] = 11;
+        assert_eq!(
+            RaydiumAmmV4Instruction::unpack(&data),
+            Ok(RaydiumAmmV4Instruction::SwapBaseOut(SwapBaseOut {
+                max_amount_in: 1_000_000,
+                amount_out: 990,
+            }))
+        );
+
+        assert!(RaydiumAmmV4Instruction::unpack(&data[..9]).is_err());
+        assert!(RaydiumAmmV4Instruction::unpack(&[]).is_err());
+    }
+
+    #[test]
+    fn unpack_optional_minimums() {
+        let mut data = vec![3];
+        data.extend([1u64, 2, 0].iter().flat_map(|amount| amount.to_le_bytes()));
+        let deposit = Deposit {
+            max_coin_amount: 1,
+            max_pc_amount: 2,
+            base_side: 0,
+            other_amount_min: None,
+        };
+        assert_eq!(
+            RaydiumAmmV4Instruction::unpack(&data),
+            Ok(RaydiumAmmV4Instruction::Deposit(deposit.clone()))
+        );
+
+        data.extend(3u64.to_le_bytes());
+        assert_eq!(
+            RaydiumAmmV4Instruction::unpack(&data),
+            Ok(RaydiumAmmV4Instruction::Deposit(Deposit {
+                other_amount_min: Some(3),
+                ..deposit
+            }))
+        );
+
+        let mut data = vec![4];
+        data.extend(5u64.to_le_bytes());
+        let withdraw = Withdraw {
+            amount: 5,
+            min_coin_amount: None,
+            min_pc_amount: None,
+        };
+        assert_eq!(
+            RaydiumAmmV4Instruction::unpack(&data),
+            Ok(RaydiumAmmV4Instruction::Withdraw(withdraw.clone()))
+        );
+
+        data.extend([6u64, 7].iter().flat_map(|amount| amount.to_le_bytes()));
+        assert_eq!(
+            RaydiumAmmV4Instruction::unpack(&data),
+            Ok(RaydiumAmmV4Instruction::Withdraw(Withdraw {
+                min_coin_amount: Some(6),
+                min_pc_amount: Some(7),
+                ..withdraw
+            }))
+        );
+    }
+
+    #[test]
+    fn ray_log_swap_amounts() {
+        let log = RayLog::SwapBaseOut(SwapBaseOutLog {
+            max_in: 1_010_000,
+            amount_out: 6_000,
+            direction: 2,
+            user_source: 5_000_000,
+            pool_coin: 900_000_000,
+            pool_pc: 5_400_000,
+            deduct_in: 1_003_000,
+        });
+        let data = borsh::to_vec(&log).unwrap();
+        // Log type, then 7 little endian u64s.
+        assert_eq!(data.len(), 57);
+        assert_eq!(data[0], 4);
+
+        let log = RayLog::unpack(&data).unwrap();
+        assert_eq!(
+            log.swap(),
+            Some(SwapAmounts {
+                direction: SwapDirection::CoinToPc,
+                amount_in: 1_003_000,
+                amount_out: 6_000,
+                pool_coin: 900_000_000,
+                pool_pc: 5_400_000,
+            })
+        );
+    }
+
+    #[test]
+    fn swap_accounts_with_and_without_target_orders() {
+        let accounts = (0..18).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+
+        let swap = SwapAccounts::from_accounts(&accounts).unwrap();
+        assert_eq!(swap.amm_target_orders, Some(accounts[4]));
+        assert_eq!(swap.user_source_owner, accounts[17]);
+
+        let swap = SwapAccounts::from_accounts(&accounts[1..]).unwrap();
+        assert_eq!(swap.amm_target_orders, None);
+        assert_eq!(swap.pool_coin_token_account, accounts[5]);
+        assert_eq!(swap.user_source_owner, accounts[17]);
+
+        assert!(SwapAccounts::from_accounts(&accounts[2..]).is_none());
+    }
+}
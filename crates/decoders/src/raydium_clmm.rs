@@ -0,0 +1,548 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    impl_discriminator,
+    math::{sqrt_price_x64_to_price, tick_to_price},
+    Discriminator,
+};
+
+solana_program::declare_id!("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK");
+
+/// Rewards a pool or position tracks.
+pub const REWARD_NUM: usize = 3;
+/// Ticks held by a `TickArrayState`.
+pub const TICK_ARRAY_SIZE: usize = 60;
+
+/// Instructions
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum RaydiumClmmInstruction {
+    CreatePool(CreatePool),
+    OpenPosition(OpenPosition),
+    OpenPositionV2(OpenPositionV2),
+    OpenPositionWithToken22Nft(OpenPositionWithToken22Nft),
+    ClosePosition,
+    IncreaseLiquidity(IncreaseLiquidity),
+    IncreaseLiquidityV2(IncreaseLiquidityV2),
+    DecreaseLiquidity(DecreaseLiquidity),
+    DecreaseLiquidityV2(DecreaseLiquidityV2),
+    Swap(Swap),
+    SwapV2(SwapV2),
+    CollectProtocolFee(CollectProtocolFee),
+    CollectFundFee(CollectFundFee),
+    CollectRemainingRewards(CollectRemainingRewards),
+}
+
+impl RaydiumClmmInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (discriminator, rest) = input.split_at(8);
+        if let Ok(discriminator) = discriminator.try_into() {
+            return Ok(match discriminator {
+                CreatePool::DISCRIMINATOR => Self::CreatePool(borsh::from_slice(rest)?),
+                OpenPosition::DISCRIMINATOR => Self::OpenPosition(borsh::from_slice(rest)?),
+                OpenPositionV2::DISCRIMINATOR => Self::OpenPositionV2(borsh::from_slice(rest)?),
+                OpenPositionWithToken22Nft::DISCRIMINATOR => {
+                    Self::OpenPositionWithToken22Nft(borsh::from_slice(rest)?)
+                }
+                ClosePosition::DISCRIMINATOR => Self::ClosePosition,
+                IncreaseLiquidity::DISCRIMINATOR => {
+                    Self::IncreaseLiquidity(borsh::from_slice(rest)?)
+                }
+                IncreaseLiquidityV2::DISCRIMINATOR => {
+                    Self::IncreaseLiquidityV2(borsh::from_slice(rest)?)
+                }
+                DecreaseLiquidity::DISCRIMINATOR => {
+                    Self::DecreaseLiquidity(borsh::from_slice(rest)?)
+                }
+                DecreaseLiquidityV2::DISCRIMINATOR => {
+                    Self::DecreaseLiquidityV2(borsh::from_slice(rest)?)
+                }
+                Swap::DISCRIMINATOR => Self::Swap(borsh::from_slice(rest)?),
+                SwapV2::DISCRIMINATOR => Self::SwapV2(borsh::from_slice(rest)?),
+                CollectProtocolFee::DISCRIMINATOR => {
+                    Self::CollectProtocolFee(borsh::from_slice(rest)?)
+                }
+                CollectFundFee::DISCRIMINATOR => Self::CollectFundFee(borsh::from_slice(rest)?),
+                CollectRemainingRewards::DISCRIMINATOR => {
+                    Self::CollectRemainingRewards(borsh::from_slice(rest)?)
+                }
+                _ => return Err(ProgramError::InvalidInstructionData),
+            });
+        }
+
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CreatePool {
+    pub sqrt_price_x64: u128,
+    pub open_time: u64,
+}
+
+impl_discriminator!(instruction CreatePool);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct OpenPosition {
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub tick_array_lower_start_index: i32,
+    pub tick_array_upper_start_index: i32,
+    pub liquidity: u128,
+    pub amount_0_max: u64,
+    pub amount_1_max: u64,
+}
+
+impl_discriminator!(instruction OpenPosition);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct OpenPositionV2 {
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub tick_array_lower_start_index: i32,
+    pub tick_array_upper_start_index: i32,
+    pub liquidity: u128,
+    pub amount_0_max: u64,
+    pub amount_1_max: u64,
+    pub with_metadata: bool,
+    pub base_flag: Option<bool>,
+}
+
+impl_discriminator!(instruction OpenPositionV2);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct OpenPositionWithToken22Nft {
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub tick_array_lower_start_index: i32,
+    pub tick_array_upper_start_index: i32,
+    pub liquidity: u128,
+    pub amount_0_max: u64,
+    pub amount_1_max: u64,
+    pub with_metadata: bool,
+    pub base_flag: Option<bool>,
+}
+
+impl_discriminator!(instruction OpenPositionWithToken22Nft);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ClosePosition {}
+
+impl_discriminator!(instruction ClosePosition);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct IncreaseLiquidity {
+    pub liquidity: u128,
+    pub amount_0_max: u64,
+    pub amount_1_max: u64,
+}
+
+impl_discriminator!(instruction IncreaseLiquidity);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct IncreaseLiquidityV2 {
+    pub liquidity: u128,
+    pub amount_0_max: u64,
+    pub amount_1_max: u64,
+    pub base_flag: Option<bool>,
+}
+
+impl_discriminator!(instruction IncreaseLiquidityV2);
+
+/// Also collects the fees and rewards owed to the position, there is no separate collect
+/// instruction for positions.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DecreaseLiquidity {
+    pub liquidity: u128,
+    pub amount_0_min: u64,
+    pub amount_1_min: u64,
+}
+
+impl_discriminator!(instruction DecreaseLiquidity);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DecreaseLiquidityV2 {
+    pub liquidity: u128,
+    pub amount_0_min: u64,
+    pub amount_1_min: u64,
+}
+
+impl_discriminator!(instruction DecreaseLiquidityV2);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Swap {
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub sqrt_price_limit_x64: u128,
+    pub is_base_input: bool,
+}
+
+impl_discriminator!(instruction Swap);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SwapV2 {
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub sqrt_price_limit_x64: u128,
+    pub is_base_input: bool,
+}
+
+impl_discriminator!(instruction SwapV2);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CollectProtocolFee {
+    pub amount_0_requested: u64,
+    pub amount_1_requested: u64,
+}
+
+impl_discriminator!(instruction CollectProtocolFee);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CollectFundFee {
+    pub amount_0_requested: u64,
+    pub amount_1_requested: u64,
+}
+
+impl_discriminator!(instruction CollectFundFee);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CollectRemainingRewards {
+    pub reward_index: u8,
+}
+
+impl_discriminator!(instruction CollectRemainingRewards);
+
+/// Instruction accounts
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SwapAccounts {
+    pub payer: Pubkey,
+    pub amm_config: Pubkey,
+    pub pool_state: Pubkey,
+    pub input_token_account: Pubkey,
+    pub output_token_account: Pubkey,
+    pub input_vault: Pubkey,
+    pub output_vault: Pubkey,
+    pub observation_state: Pubkey,
+    pub token_program: Pubkey,
+    pub tick_array: Pubkey,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SwapV2Accounts {
+    pub payer: Pubkey,
+    pub amm_config: Pubkey,
+    pub pool_state: Pubkey,
+    pub input_token_account: Pubkey,
+    pub output_token_account: Pubkey,
+    pub input_vault: Pubkey,
+    pub output_vault: Pubkey,
+    pub observation_state: Pubkey,
+    pub token_program: Pubkey,
+    pub token_program_2022: Pubkey,
+    pub memo_program: Pubkey,
+    pub input_vault_mint: Pubkey,
+    pub output_vault_mint: Pubkey,
+}
+
+/// Accounts
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PersonalPositionState {
+    pub bump: [u8; 1],
+    pub nft_mint: Pubkey,
+    pub pool_id: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    pub fee_growth_inside_0_last_x64: u128,
+    pub fee_growth_inside_1_last_x64: u128,
+    pub token_fees_owed_0: u64,
+    pub token_fees_owed_1: u64,
+    pub reward_infos: [PositionRewardInfo; REWARD_NUM],
+    pub recent_epoch: u64,
+    pub padding: [u64; 7],
+}
+
+impl_discriminator!(account PersonalPositionState);
+
+impl PersonalPositionState {
+    /// Prices of token 0 in token 1 at the lower and upper ticks of the position.
+    pub fn price_range(&self, decimals_0: u8, decimals_1: u8) -> (f64, f64) {
+        (
+            tick_to_price(self.tick_lower_index, decimals_0, decimals_1),
+            tick_to_price(self.tick_upper_index, decimals_0, decimals_1),
+        )
+    }
+}
+
+/// Zero copy in the program like `TickArrayState`.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PoolState {
+    pub bump: [u8; 1],
+    pub amm_config: Pubkey,
+    pub owner: Pubkey,
+    pub token_mint_0: Pubkey,
+    pub token_mint_1: Pubkey,
+    pub token_vault_0: Pubkey,
+    pub token_vault_1: Pubkey,
+    pub observation_key: Pubkey,
+    pub mint_decimals_0: u8,
+    pub mint_decimals_1: u8,
+    pub tick_spacing: u16,
+    pub liquidity: u128,
+    pub sqrt_price_x64: u128,
+    pub tick_current: i32,
+    pub padding3: u16,
+    pub padding4: u16,
+    pub fee_growth_global_0_x64: u128,
+    pub fee_growth_global_1_x64: u128,
+    pub protocol_fees_token_0: u64,
+    pub protocol_fees_token_1: u64,
+    pub swap_in_amount_token_0: u128,
+    pub swap_out_amount_token_1: u128,
+    pub swap_in_amount_token_1: u128,
+    pub swap_out_amount_token_0: u128,
+    pub status: u8,
+    pub padding: [u8; 7],
+    pub reward_infos: [RewardInfo; REWARD_NUM],
+    pub tick_array_bitmap: [u64; 16],
+    pub total_fees_token_0: u64,
+    pub total_fees_claimed_token_0: u64,
+    pub total_fees_token_1: u64,
+    pub total_fees_claimed_token_1: u64,
+    pub fund_fees_token_0: u64,
+    pub fund_fees_token_1: u64,
+    pub open_time: u64,
+    pub recent_epoch: u64,
+    pub padding1: [u64; 24],
+    pub padding2: [u64; 32],
+}
+
+impl_discriminator!(account PoolState);
+
+impl PoolState {
+    /// Price of token 0 in token 1.
+    pub fn price(&self) -> f64 {
+        sqrt_price_x64_to_price(
+            self.sqrt_price_x64,
+            self.mint_decimals_0,
+            self.mint_decimals_1,
+        )
+    }
+}
+
+/// Zero copy in the program, its packed layout is the borsh encoding of the fields.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct TickArrayState {
+    pub pool_id: Pubkey,
+    pub start_tick_index: i32,
+    pub ticks: [TickState; TICK_ARRAY_SIZE],
+    pub initialized_tick_count: u8,
+    pub recent_epoch: u64,
+    pub padding: [u8; 107],
+}
+
+impl_discriminator!(account TickArrayState);
+
+/// Types
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RewardInfo {
+    pub reward_state: u8,
+    pub open_time: u64,
+    pub end_time: u64,
+    pub last_update_time: u64,
+    pub emissions_per_second_x64: u128,
+    pub reward_total_emissioned: u64,
+    pub reward_claimed: u64,
+    pub token_mint: Pubkey,
+    pub token_vault: Pubkey,
+    pub authority: Pubkey,
+    pub reward_growth_global_x64: u128,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PositionRewardInfo {
+    pub growth_inside_last_x64: u128,
+    pub reward_amount_owed: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct TickState {
+    pub tick: i32,
+    pub liquidity_net: i128,
+    pub liquidity_gross: u128,
+    pub fee_growth_outside_0_x64: u128,
+    pub fee_growth_outside_1_x64: u128,
+    pub reward_growths_outside_x64: [u128; REWARD_NUM],
+    pub padding: [u32; 13],
+}
+
+/// Events
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum RaydiumClmmEvent {
+    SwapEvent(SwapEvent),
+    LiquidityChangeEvent(LiquidityChangeEvent),
+    IncreaseLiquidityEvent(IncreaseLiquidityEvent),
+    DecreaseLiquidityEvent(DecreaseLiquidityEvent),
+    CollectPersonalFeeEvent(CollectPersonalFeeEvent),
+}
+
+impl RaydiumClmmEvent {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (discriminator, rest) = input.split_at(8);
+        if let Ok(discriminator) = discriminator.try_into() {
+            return Ok(match discriminator {
+                SwapEvent::DISCRIMINATOR => Self::SwapEvent(borsh::from_slice(rest)?),
+                LiquidityChangeEvent::DISCRIMINATOR => {
+                    Self::LiquidityChangeEvent(borsh::from_slice(rest)?)
+                }
+                IncreaseLiquidityEvent::DISCRIMINATOR => {
+                    Self::IncreaseLiquidityEvent(borsh::from_slice(rest)?)
+                }
+                DecreaseLiquidityEvent::DISCRIMINATOR => {
+                    Self::DecreaseLiquidityEvent(borsh::from_slice(rest)?)
+                }
+                CollectPersonalFeeEvent::DISCRIMINATOR => {
+                    Self::CollectPersonalFeeEvent(borsh::from_slice(rest)?)
+                }
+                _ => return Err(ProgramError::InvalidInstructionData),
+            });
+        }
+
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+/// The pool price, liquidity and tick are the ones after the swap.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SwapEvent {
+    pub pool_state: Pubkey,
+    pub sender: Pubkey,
+    pub token_account_0: Pubkey,
+    pub token_account_1: Pubkey,
+    pub amount_0: u64,
+    pub transfer_fee_0: u64,
+    pub amount_1: u64,
+    pub transfer_fee_1: u64,
+    pub zero_for_one: bool,
+    pub sqrt_price_x64: u128,
+    pub liquidity: u128,
+    pub tick: i32,
+}
+
+impl_discriminator!(event SwapEvent);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct LiquidityChangeEvent {
+    pub pool_state: Pubkey,
+    pub tick: i32,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity_before: u128,
+    pub liquidity_after: u128,
+}
+
+impl_discriminator!(event LiquidityChangeEvent);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct IncreaseLiquidityEvent {
+    pub position_nft_mint: Pubkey,
+    pub liquidity: u128,
+    pub amount_0: u64,
+    pub amount_1: u64,
+    pub amount_0_transfer_fee: u64,
+    pub amount_1_transfer_fee: u64,
+}
+
+impl_discriminator!(event IncreaseLiquidityEvent);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DecreaseLiquidityEvent {
+    pub position_nft_mint: Pubkey,
+    pub liquidity: u128,
+    pub decrease_amount_0: u64,
+    pub decrease_amount_1: u64,
+    pub fee_amount_0: u64,
+    pub fee_amount_1: u64,
+    pub reward_amounts: [u64; REWARD_NUM],
+    pub transfer_fee_0: u64,
+    pub transfer_fee_1: u64,
+}
+
+impl_discriminator!(event DecreaseLiquidityEvent);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CollectPersonalFeeEvent {
+    pub position_nft_mint: Pubkey,
+    pub recipient_token_account_0: Pubkey,
+    pub recipient_token_account_1: Pubkey,
+    pub amount_0: u64,
+    pub amount_1: u64,
+}
+
+impl_discriminator!(event CollectPersonalFeeEvent);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zeroed_account;
+
+    #[test]
+    fn discriminators_match_program() {
+        assert_eq!(
+            Swap::DISCRIMINATOR,
+            [248, 198, 158, 145, 225, 117, 135, 200]
+        );
+        assert_eq!(SwapV2::DISCRIMINATOR, [43, 4, 237, 11, 26, 201, 30, 98]);
+        assert_eq!(
+            PoolState::DISCRIMINATOR,
+            [247, 237, 227, 245, 215, 195, 222, 70]
+        );
+    }
+
+    #[test]
+    fn accounts_have_program_sizes() {
+        zeroed_account::<PoolState>(1544);
+
+        zeroed_account::<PersonalPositionState>(281);
+
+        zeroed_account::<TickArrayState>(10240);
+    }
+
+    #[test]
+    fn unpack_swap_event() {
+        let event = SwapEvent {
+            pool_state: Pubkey::new_unique(),
+            sender: Pubkey::new_unique(),
+            token_account_0: Pubkey::new_unique(),
+            token_account_1: Pubkey::new_unique(),
+            amount_0: 1_000_000_000,
+            transfer_fee_0: 0,
+            amount_1: 150_000_000,
+            transfer_fee_1: 0,
+            zero_for_one: true,
+            sqrt_price_x64: 7_144_424_704_000_000_000,
+            liquidity: 1 << 40,
+            tick: -18_971,
+        };
+        let mut data = SwapEvent::DISCRIMINATOR.to_vec();
+        data.extend(borsh::to_vec(&event).unwrap());
+
+        assert_eq!(
+            RaydiumClmmEvent::unpack(&data),
+            Ok(RaydiumClmmEvent::SwapEvent(event))
+        );
+    }
+}
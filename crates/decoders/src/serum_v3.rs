@@ -0,0 +1,658 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+solana_program::declare_id!("9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin");
+
+/// The OpenBook v1 fork of the program, same instructions and account layouts.
+pub const OPENBOOK_V1_ID: Pubkey =
+    solana_program::pubkey!("srmqPvymJeFKQ4zGQed1GFppgkRHL9kaELCbyksJtPX");
+
+/// Padding the program writes before the data of its accounts.
+pub const ACCOUNT_HEAD_PADDING: &[u8; 5] = b"serum";
+/// Padding the program writes after the data of its accounts.
+pub const ACCOUNT_TAIL_PADDING: &[u8; 7] = b"padding";
+
+/// Packed size of an `Event` in the event queue.
+pub const EVENT_LEN: usize = 88;
+/// Packed size of a `Request` in the request queue.
+pub const REQUEST_LEN: usize = 80;
+
+/// Set in the `account_flags` every account of the program starts with.
+pub mod account_flag {
+    pub const INITIALIZED: u64 = 1 << 0;
+    pub const MARKET: u64 = 1 << 1;
+    pub const OPEN_ORDERS: u64 = 1 << 2;
+    pub const REQUEST_QUEUE: u64 = 1 << 3;
+    pub const EVENT_QUEUE: u64 = 1 << 4;
+    pub const BIDS: u64 = 1 << 5;
+    pub const ASKS: u64 = 1 << 6;
+    pub const DISABLED: u64 = 1 << 7;
+    pub const CLOSED: u64 = 1 << 8;
+    pub const PERMISSIONED: u64 = 1 << 9;
+    pub const CRANK_AUTHORITY_REQUIRED: u64 = 1 << 10;
+}
+
+/// Set in the `event_flags` of an `Event`.
+pub mod event_flag {
+    pub const FILL: u8 = 1 << 0;
+    pub const OUT: u8 = 1 << 1;
+    pub const BID: u8 = 1 << 2;
+    pub const MAKER: u8 = 1 << 3;
+    pub const RELEASE_FUNDS: u8 = 1 << 4;
+}
+
+/// Set in the `request_flags` of a `Request`.
+pub mod request_flag {
+    pub const NEW_ORDER: u8 = 1 << 0;
+    pub const CANCEL_ORDER: u8 = 1 << 1;
+    pub const BID: u8 = 1 << 2;
+    pub const POST_ONLY: u8 = 1 << 3;
+    pub const IMMEDIATE_OR_CANCEL: u8 = 1 << 4;
+    pub const DECREMENT_TAKE_ON_SELF_TRADE: u8 = 1 << 5;
+}
+
+/// Instructions
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SerumV3Instruction {
+    InitializeMarket(InitializeMarket),
+    NewOrder(NewOrderV1),
+    MatchOrders(u16),
+    ConsumeEvents(u16),
+    CancelOrder(CancelOrderV1),
+    SettleFunds,
+    CancelOrderByClientId(u64),
+    DisableMarket,
+    SweepFees,
+    NewOrderV2(NewOrderV2),
+    NewOrderV3(NewOrderV3),
+    CancelOrderV2(CancelOrderV2),
+    CancelOrderByClientIdV2(u64),
+    SendTake(SendTake),
+    CloseOpenOrders,
+    InitOpenOrders,
+    Prune(u16),
+    ConsumeEventsPermissioned(u16),
+    CancelOrdersByClientIds([u64; 8]),
+    ReplaceOrderByClientId(NewOrderV3),
+    ReplaceOrdersByClientIds(Vec<NewOrderV3>),
+}
+
+impl SerumV3Instruction {
+    /// Instructions are bincode encoded after a version byte, tagged by a `u32` and packed without
+    /// padding. Like the program, trailing bytes are ignored.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let mut rest = match input.split_first() {
+            Some((0, rest)) => rest,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+        let tag = u32::deserialize(&mut rest)?;
+
+        Ok(match tag {
+            0 => Self::InitializeMarket(BorshDeserialize::deserialize(&mut rest)?),
+            1 => Self::NewOrder(BorshDeserialize::deserialize(&mut rest)?),
+            2 => Self::MatchOrders(u16::deserialize(&mut rest)?),
+            3 => Self::ConsumeEvents(u16::deserialize(&mut rest)?),
+            4 => Self::CancelOrder(BorshDeserialize::deserialize(&mut rest)?),
+            5 => Self::SettleFunds,
+            6 => Self::CancelOrderByClientId(u64::deserialize(&mut rest)?),
+            7 => Self::DisableMarket,
+            8 => Self::SweepFees,
+            9 => Self::NewOrderV2(BorshDeserialize::deserialize(&mut rest)?),
+            10 => Self::NewOrderV3(NewOrderV3::unpack(&mut rest)?),
+            11 => Self::CancelOrderV2(BorshDeserialize::deserialize(&mut rest)?),
+            12 => Self::CancelOrderByClientIdV2(u64::deserialize(&mut rest)?),
+            13 => Self::SendTake(BorshDeserialize::deserialize(&mut rest)?),
+            14 => Self::CloseOpenOrders,
+            15 => Self::InitOpenOrders,
+            16 => Self::Prune(u16::deserialize(&mut rest)?),
+            17 => Self::ConsumeEventsPermissioned(u16::deserialize(&mut rest)?),
+            18 => Self::CancelOrdersByClientIds(BorshDeserialize::deserialize(&mut rest)?),
+            19 => Self::ReplaceOrderByClientId(NewOrderV3::unpack(&mut rest)?),
+            20 => {
+                // bincode prefixes vectors with a `u64` length.
+                let len = u64::deserialize(&mut rest)?;
+                let orders = (0..len)
+                    .map(|_| NewOrderV3::unpack(&mut rest))
+                    .collect::<Result<_, _>>()?;
+                Self::ReplaceOrdersByClientIds(orders)
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializeMarket {
+    pub coin_lot_size: u64,
+    pub pc_lot_size: u64,
+    pub fee_rate_bps: u16,
+    pub vault_signer_nonce: u64,
+    pub pc_dust_threshold: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct NewOrderV1 {
+    pub side: Side,
+    pub limit_price: u64,
+    pub max_qty: u64,
+    pub order_type: OrderType,
+    pub client_id: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct NewOrderV2 {
+    pub side: Side,
+    pub limit_price: u64,
+    pub max_qty: u64,
+    pub order_type: OrderType,
+    pub client_id: u64,
+    pub self_trade_behavior: SelfTradeBehavior,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewOrderV3 {
+    pub side: Side,
+    /// In pc lots per coin lot.
+    pub limit_price: u64,
+    pub max_coin_qty: u64,
+    pub max_native_pc_qty_including_fees: u64,
+    pub self_trade_behavior: SelfTradeBehavior,
+    pub order_type: OrderType,
+    pub client_order_id: u64,
+    pub limit: u16,
+    /// Added by OpenBook v1, older clients leave it out.
+    pub max_ts: Option<i64>,
+}
+
+impl NewOrderV3 {
+    fn unpack(rest: &mut &[u8]) -> Result<Self, ProgramError> {
+        Ok(Self {
+            side: Side::deserialize(rest)?,
+            limit_price: u64::deserialize(rest)?,
+            max_coin_qty: u64::deserialize(rest)?,
+            max_native_pc_qty_including_fees: u64::deserialize(rest)?,
+            self_trade_behavior: SelfTradeBehavior::deserialize(rest)?,
+            order_type: OrderType::deserialize(rest)?,
+            client_order_id: u64::deserialize(rest)?,
+            limit: u16::deserialize(rest)?,
+            max_ts: match rest.len() >= 8 {
+                true => Some(i64::deserialize(rest)?),
+                false => None,
+            },
+        })
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CancelOrderV1 {
+    pub side: Side,
+    pub order_id: u128,
+    pub owner: Pubkey,
+    pub owner_slot: u8,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CancelOrderV2 {
+    pub side: Side,
+    pub order_id: u128,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SendTake {
+    pub side: Side,
+    pub limit_price: u64,
+    pub max_coin_qty: u64,
+    pub max_native_pc_qty_including_fees: u64,
+    pub min_coin_qty: u64,
+    pub min_native_pc_qty: u64,
+    pub limit: u16,
+}
+
+/// Instruction accounts
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewOrderV3Accounts {
+    pub market: Pubkey,
+    pub open_orders: Pubkey,
+    pub request_queue: Pubkey,
+    pub event_queue: Pubkey,
+    pub bids: Pubkey,
+    pub asks: Pubkey,
+    pub payer: Pubkey,
+    pub open_orders_owner: Pubkey,
+    pub coin_vault: Pubkey,
+    pub pc_vault: Pubkey,
+    pub token_program: Pubkey,
+    pub rent: Pubkey,
+    pub fee_discount: Option<Pubkey>,
+}
+
+impl NewOrderV3Accounts {
+    /// Reads the accounts of a `NewOrderV3` or `ReplaceOrderByClientId` instruction.
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(Self {
+            market: accounts[0],
+            open_orders: accounts[1],
+            request_queue: accounts[2],
+            event_queue: accounts[3],
+            bids: accounts[4],
+            asks: accounts[5],
+            payer: accounts[6],
+            open_orders_owner: accounts[7],
+            coin_vault: accounts[8],
+            pc_vault: accounts[9],
+            token_program: accounts[10],
+            rent: accounts[11],
+            fee_discount: accounts.get(12).copied(),
+        })
+    }
+}
+
+/// Accounts
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct QueueHeader {
+    pub account_flags: u64,
+    /// Slot of the oldest item in the ring buffer.
+    pub head: u64,
+    pub count: u64,
+    /// Sequence number of the next item pushed.
+    pub seq_num: u64,
+}
+
+/// Events not yet consumed by the crank, oldest first.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventQueue {
+    pub header: QueueHeader,
+    pub events: Vec<Event>,
+}
+
+impl EventQueue {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (header, events) = unpack_queue(input, account_flag::EVENT_QUEUE, EVENT_LEN)?;
+        Ok(Self { header, events })
+    }
+
+    /// Sequence number of the first event of the queue, so that events can be told apart across
+    /// snapshots of the account.
+    pub fn first_seq_num(&self) -> u64 {
+        self.header.seq_num.wrapping_sub(self.header.count)
+    }
+
+    pub fn fills(&self) -> impl Iterator<Item = &Event> {
+        self.events.iter().filter(|event| event.is_fill())
+    }
+}
+
+/// Orders waiting to be matched, oldest first.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestQueue {
+    pub header: QueueHeader,
+    pub requests: Vec<Request>,
+}
+
+impl RequestQueue {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (header, requests) = unpack_queue(input, account_flag::REQUEST_QUEUE, REQUEST_LEN)?;
+        Ok(Self { header, requests })
+    }
+}
+
+/// Reads the header of a queue account and the items of its ring buffer, starting at its head.
+fn unpack_queue<T: BorshDeserialize>(
+    input: &[u8],
+    flag: u64,
+    item_len: usize,
+) -> Result<(QueueHeader, Vec<T>), ProgramError> {
+    let data = input
+        .strip_prefix(ACCOUNT_HEAD_PADDING)
+        .and_then(|data| data.strip_suffix(ACCOUNT_TAIL_PADDING))
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let mut rest = data;
+    let header = QueueHeader::deserialize(&mut rest)?;
+    if header.account_flags & (account_flag::INITIALIZED | flag) != account_flag::INITIALIZED | flag
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let capacity = rest.len() / item_len;
+    if header.count as usize > capacity || (capacity > 0 && header.head as usize >= capacity) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let items = (0..header.count as usize)
+        .map(|i| {
+            let offset = (header.head as usize + i) % capacity * item_len;
+            T::deserialize(&mut &rest[offset..offset + item_len])
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((header, items))
+}
+
+/// Types
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Event {
+    pub event_flags: u8,
+    pub owner_slot: u8,
+    pub fee_tier: u8,
+    pub padding: [u8; 5],
+    /// Coin for a bid, pc for an ask, after fees and rebates.
+    pub native_qty_released: u64,
+    /// Pc for a bid, coin for an ask, after fees and rebates.
+    pub native_qty_paid: u64,
+    /// Fee paid by a taker, rebate earned by a maker.
+    pub native_fee_or_rebate: u64,
+    pub order_id: u128,
+    pub owner: Pubkey,
+    pub client_order_id: u64,
+}
+
+impl Event {
+    pub fn is_fill(&self) -> bool {
+        self.event_flags & event_flag::FILL != 0
+    }
+
+    pub fn is_out(&self) -> bool {
+        self.event_flags & event_flag::OUT != 0
+    }
+
+    pub fn is_maker(&self) -> bool {
+        self.event_flags & event_flag::MAKER != 0
+    }
+
+    pub fn side(&self) -> Side {
+        match self.event_flags & event_flag::BID != 0 {
+            true => Side::Bid,
+            false => Side::Ask,
+        }
+    }
+
+    /// Native coin and pc quantities exchanged by a fill, the pc side excluding fees and rebates.
+    pub fn native_quantities(&self) -> (u64, u64) {
+        match (self.side(), self.is_maker()) {
+            (Side::Bid, false) => (
+                self.native_qty_released,
+                self.native_qty_paid
+                    .saturating_sub(self.native_fee_or_rebate),
+            ),
+            (Side::Bid, true) => (
+                self.native_qty_released,
+                self.native_qty_paid + self.native_fee_or_rebate,
+            ),
+            (Side::Ask, false) => (
+                self.native_qty_paid,
+                self.native_qty_released + self.native_fee_or_rebate,
+            ),
+            (Side::Ask, true) => (
+                self.native_qty_paid,
+                self.native_qty_released
+                    .saturating_sub(self.native_fee_or_rebate),
+            ),
+        }
+    }
+
+    /// Limit price of the order, in pc lots per coin lot, which order ids start with.
+    pub fn price_lots(&self) -> u64 {
+        (self.order_id >> 64) as u64
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Request {
+    pub request_flags: u8,
+    pub owner_slot: u8,
+    pub fee_tier: u8,
+    pub self_trade_behavior: u8,
+    pub padding: [u8; 4],
+    pub max_coin_qty_or_cancel_id: u64,
+    pub native_pc_qty_locked: u64,
+    pub order_id: u128,
+    pub owner: Pubkey,
+    pub client_order_id: u64,
+}
+
+impl Request {
+    pub fn is_new_order(&self) -> bool {
+        self.request_flags & request_flag::NEW_ORDER != 0
+    }
+
+    pub fn is_cancel_order(&self) -> bool {
+        self.request_flags & request_flag::CANCEL_ORDER != 0
+    }
+
+    pub fn side(&self) -> Side {
+        match self.request_flags & request_flag::BID != 0 {
+            true => Side::Bid,
+            false => Side::Ask,
+        }
+    }
+}
+
+/// Declares an enum bincode encodes as a `u32`.
+macro_rules! u32_enum {
+    ($name:ident { $($variant:ident = $value:literal),* $(,)? }) => {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub enum $name {
+            $($variant = $value),*
+        }
+
+        impl BorshDeserialize for $name {
+            fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+                match u32::deserialize_reader(reader)? {
+                    $($value => Ok(Self::$variant),)*
+                    value => Err(borsh::io::Error::new(
+                        borsh::io::ErrorKind::InvalidData,
+                        format!("invalid {} {}", stringify!($name), value),
+                    )),
+                }
+            }
+        }
+
+        impl BorshSerialize for $name {
+            fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+                (*self as u32).serialize(writer)
+            }
+        }
+    };
+}
+
+u32_enum!(Side { Bid = 0, Ask = 1 });
+
+u32_enum!(OrderType {
+    Limit = 0,
+    ImmediateOrCancel = 1,
+    PostOnly = 2,
+});
+
+u32_enum!(SelfTradeBehavior {
+    DecrementTake = 0,
+    CancelProvide = 1,
+    AbortTransaction = 2,
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_order() -> NewOrderV3 {
+        NewOrderV3 {
+            side: Side::Bid,
+            limit_price: 150_000,
+            max_coin_qty: 10,
+            max_native_pc_qty_including_fees: 1_500_450_000,
+            self_trade_behavior: SelfTradeBehavior::AbortTransaction,
+            order_type: OrderType::ImmediateOrCancel,
+            client_order_id: 7,
+            limit: 10,
+            max_ts: None,
+        }
+    }
+
+    fn pack_new_order(order: &NewOrderV3, data: &mut Vec<u8>) {
+        order.side.serialize(data).unwrap();
+        order.limit_price.serialize(data).unwrap();
+        order.max_coin_qty.serialize(data).unwrap();
+        order
+            .max_native_pc_qty_including_fees
+            .serialize(data)
+            .unwrap();
+        order.self_trade_behavior.serialize(data).unwrap();
+        order.order_type.serialize(data).unwrap();
+        order.client_order_id.serialize(data).unwrap();
+        order.limit.serialize(data).unwrap();
+        if let Some(max_ts) = order.max_ts {
+            max_ts.serialize(data).unwrap();
+        }
+    }
+
+    #[test]
+    fn unpack_new_order_v3() {
+        let order = new_order();
+        let mut data = vec![0, 10, 0, 0, 0];
+        pack_new_order(&order, &mut data);
+        assert_eq!(data.len(), 5 + 46);
+        assert_eq!(
+            SerumV3Instruction::unpack(&data).unwrap(),
+            SerumV3Instruction::NewOrderV3(order.clone())
+        );
+
+        let order = NewOrderV3 {
+            max_ts: Some(1_700_000_000),
+            ..order
+        };
+        let mut data = vec![0, 10, 0, 0, 0];
+        pack_new_order(&order, &mut data);
+        assert_eq!(
+            SerumV3Instruction::unpack(&data).unwrap(),
+            SerumV3Instruction::NewOrderV3(order)
+        );
+    }
+
+    #[test]
+    fn unpack_replace_orders() {
+        let order = NewOrderV3 {
+            max_ts: Some(i64::MAX),
+            ..new_order()
+        };
+        let mut data = vec![0, 20, 0, 0, 0];
+        2u64.serialize(&mut data).unwrap();
+        pack_new_order(&order, &mut data);
+        pack_new_order(&order, &mut data);
+        assert_eq!(
+            SerumV3Instruction::unpack(&data).unwrap(),
+            SerumV3Instruction::ReplaceOrdersByClientIds(vec![order.clone(), order])
+        );
+    }
+
+    #[test]
+    fn unpack_rejects_unknown_version() {
+        assert!(SerumV3Instruction::unpack(&[1, 5, 0, 0, 0]).is_err());
+        assert_eq!(
+            SerumV3Instruction::unpack(&[0, 5, 0, 0, 0]).unwrap(),
+            SerumV3Instruction::SettleFunds
+        );
+    }
+
+    fn fill(event_flags: u8, released: u64, paid: u64, fee: u64) -> Event {
+        Event {
+            event_flags,
+            owner_slot: 0,
+            fee_tier: 0,
+            padding: [0; 5],
+            native_qty_released: released,
+            native_qty_paid: paid,
+            native_fee_or_rebate: fee,
+            order_id: 150_000u128 << 64 | 1,
+            owner: Pubkey::new_unique(),
+            client_order_id: 0,
+        }
+    }
+
+    fn event_queue(header: QueueHeader, slots: &[Event]) -> Vec<u8> {
+        let mut data = ACCOUNT_HEAD_PADDING.to_vec();
+        header.serialize(&mut data).unwrap();
+        for event in slots {
+            event.serialize(&mut data).unwrap();
+        }
+        data.extend_from_slice(ACCOUNT_TAIL_PADDING);
+        data
+    }
+
+    #[test]
+    fn unpack_event_queue_wraps_around() {
+        let taker = fill(event_flag::FILL | event_flag::BID, 10, 1_500_450, 450);
+        let maker = fill(event_flag::FILL | event_flag::MAKER, 1_500_300, 10, 300);
+        let stale = fill(event_flag::OUT, 0, 0, 0);
+        assert_eq!(borsh::to_vec(&taker).unwrap().len(), EVENT_LEN);
+
+        let header = QueueHeader {
+            account_flags: account_flag::INITIALIZED | account_flag::EVENT_QUEUE,
+            head: 2,
+            count: 2,
+            seq_num: 12,
+        };
+        let data = event_queue(header, &[maker.clone(), stale, taker.clone()]);
+        let queue = EventQueue::unpack(&data).unwrap();
+
+        assert_eq!(queue.events, vec![taker.clone(), maker.clone()]);
+        assert_eq!(queue.first_seq_num(), 10);
+        assert_eq!(queue.fills().count(), 2);
+        assert_eq!(taker.side(), Side::Bid);
+        assert_eq!(taker.native_quantities(), (10, 1_500_000));
+        assert_eq!(maker.native_quantities(), (10, 1_500_000));
+        assert_eq!(taker.price_lots(), 150_000);
+    }
+
+    #[test]
+    fn unpack_queue_checks_flags() {
+        let header = QueueHeader {
+            account_flags: account_flag::INITIALIZED | account_flag::EVENT_QUEUE,
+            head: 0,
+            count: 0,
+            seq_num: 0,
+        };
+        let data = event_queue(header, &[]);
+        assert!(EventQueue::unpack(&data).is_ok());
+        assert!(RequestQueue::unpack(&data).is_err());
+    }
+
+    #[test]
+    fn unpack_request_queue() {
+        let request = Request {
+            request_flags: request_flag::NEW_ORDER | request_flag::BID,
+            owner_slot: 1,
+            fee_tier: 0,
+            self_trade_behavior: 2,
+            padding: [0; 4],
+            max_coin_qty_or_cancel_id: 10,
+            native_pc_qty_locked: 1_500_450,
+            order_id: 150_000u128 << 64 | 3,
+            owner: Pubkey::new_unique(),
+            client_order_id: 7,
+        };
+        assert_eq!(borsh::to_vec(&request).unwrap().len(), REQUEST_LEN);
+
+        let mut data = ACCOUNT_HEAD_PADDING.to_vec();
+        QueueHeader {
+            account_flags: account_flag::INITIALIZED | account_flag::REQUEST_QUEUE,
+            head: 0,
+            count: 1,
+            seq_num: 1,
+        }
+        .serialize(&mut data)
+        .unwrap();
+        request.serialize(&mut data).unwrap();
+        data.extend_from_slice(ACCOUNT_TAIL_PADDING);
+
+        let queue = RequestQueue::unpack(&data).unwrap();
+        assert_eq!(queue.requests, vec![request]);
+        assert!(queue.requests[0].is_new_order());
+        assert_eq!(queue.requests[0].side(), Side::Bid);
+    }
+}
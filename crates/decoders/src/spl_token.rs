@@ -0,0 +1,595 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+solana_program::declare_id!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// Token-2022 shares the instructions of the token program, extensions come after them.
+pub const TOKEN_2022_ID: Pubkey =
+    solana_program::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Instructions
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SplTokenInstruction {
+    InitializeMint(InitializeMint),
+    InitializeAccount,
+    InitializeMultisig {
+        m: u8,
+    },
+    Transfer {
+        amount: u64,
+    },
+    Approve {
+        amount: u64,
+    },
+    Revoke,
+    SetAuthority(SetAuthority),
+    MintTo {
+        amount: u64,
+    },
+    Burn {
+        amount: u64,
+    },
+    CloseAccount,
+    FreezeAccount,
+    ThawAccount,
+    TransferChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    ApproveChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    MintToChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    BurnChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    InitializeAccount2 {
+        owner: Pubkey,
+    },
+    SyncNative,
+    InitializeAccount3 {
+        owner: Pubkey,
+    },
+    InitializeMultisig2 {
+        m: u8,
+    },
+    InitializeMint2(InitializeMint),
+    /// Token-2022 lists the extensions the account needs room for.
+    GetAccountDataSize {
+        extension_types: Vec<u16>,
+    },
+    InitializeImmutableOwner,
+    AmountToUiAmount {
+        amount: u64,
+    },
+    UiAmountToAmount {
+        ui_amount: String,
+    },
+    InitializeMintCloseAuthority {
+        close_authority: Option<Pubkey>,
+    },
+    TransferFeeExtension(TransferFeeInstruction),
+    Reallocate {
+        extension_types: Vec<u16>,
+    },
+    MemoTransferExtension(ToggleInstruction),
+    CreateNativeMint,
+    InitializeNonTransferableMint,
+    CpiGuardExtension(ToggleInstruction),
+    InitializePermanentDelegate {
+        delegate: Pubkey,
+    },
+    TransferHookExtension(TransferHookInstruction),
+    WithdrawExcessLamports,
+}
+
+impl SplTokenInstruction {
+    /// Instructions are tagged by their first byte and packed without padding, Token-2022
+    /// extensions by a second byte. Optional keys are a byte flag followed by the key, like borsh.
+    /// Confidential transfer and pointer extensions aren't decoded.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (tag, mut rest) = match input.split_first() {
+            Some((tag, rest)) => (*tag, rest),
+            None => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        Ok(match tag {
+            0 => Self::InitializeMint(BorshDeserialize::deserialize(&mut rest)?),
+            1 => Self::InitializeAccount,
+            2 => Self::InitializeMultisig {
+                m: u8::deserialize(&mut rest)?,
+            },
+            3 => Self::Transfer {
+                amount: u64::deserialize(&mut rest)?,
+            },
+            4 => Self::Approve {
+                amount: u64::deserialize(&mut rest)?,
+            },
+            5 => Self::Revoke,
+            6 => Self::SetAuthority(BorshDeserialize::deserialize(&mut rest)?),
+            7 => Self::MintTo {
+                amount: u64::deserialize(&mut rest)?,
+            },
+            8 => Self::Burn {
+                amount: u64::deserialize(&mut rest)?,
+            },
+            9 => Self::CloseAccount,
+            10 => Self::FreezeAccount,
+            11 => Self::ThawAccount,
+            12 => Self::TransferChecked {
+                amount: u64::deserialize(&mut rest)?,
+                decimals: u8::deserialize(&mut rest)?,
+            },
+            13 => Self::ApproveChecked {
+                amount: u64::deserialize(&mut rest)?,
+                decimals: u8::deserialize(&mut rest)?,
+            },
+            14 => Self::MintToChecked {
+                amount: u64::deserialize(&mut rest)?,
+                decimals: u8::deserialize(&mut rest)?,
+            },
+            15 => Self::BurnChecked {
+                amount: u64::deserialize(&mut rest)?,
+                decimals: u8::deserialize(&mut rest)?,
+            },
+            16 => Self::InitializeAccount2 {
+                owner: Pubkey::deserialize(&mut rest)?,
+            },
+            17 => Self::SyncNative,
+            18 => Self::InitializeAccount3 {
+                owner: Pubkey::deserialize(&mut rest)?,
+            },
+            19 => Self::InitializeMultisig2 {
+                m: u8::deserialize(&mut rest)?,
+            },
+            20 => Self::InitializeMint2(BorshDeserialize::deserialize(&mut rest)?),
+            21 => Self::GetAccountDataSize {
+                extension_types: extension_types(rest)?,
+            },
+            22 => Self::InitializeImmutableOwner,
+            23 => Self::AmountToUiAmount {
+                amount: u64::deserialize(&mut rest)?,
+            },
+            24 => Self::UiAmountToAmount {
+                ui_amount: std::str::from_utf8(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?
+                    .to_string(),
+            },
+            25 => Self::InitializeMintCloseAuthority {
+                close_authority: Option::deserialize(&mut rest)?,
+            },
+            26 => Self::TransferFeeExtension(TransferFeeInstruction::unpack(rest)?),
+            29 => Self::Reallocate {
+                extension_types: extension_types(rest)?,
+            },
+            30 => Self::MemoTransferExtension(ToggleInstruction::unpack(rest)?),
+            31 => Self::CreateNativeMint,
+            32 => Self::InitializeNonTransferableMint,
+            34 => Self::CpiGuardExtension(ToggleInstruction::unpack(rest)?),
+            35 => Self::InitializePermanentDelegate {
+                delegate: Pubkey::deserialize(&mut rest)?,
+            },
+            36 => Self::TransferHookExtension(TransferHookInstruction::unpack(rest)?),
+            38 => Self::WithdrawExcessLamports,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+
+    /// Whether the instruction moves tokens between two accounts, fee included for Token-2022
+    /// transfers with a fee.
+    pub fn is_transfer(&self) -> bool {
+        matches!(
+            self,
+            Self::Transfer { .. }
+                | Self::TransferChecked { .. }
+                | Self::TransferFeeExtension(TransferFeeInstruction::TransferCheckedWithFee { .. })
+        )
+    }
+
+    /// Amount of tokens moved, minted or burned, in base units.
+    pub fn amount(&self) -> Option<u64> {
+        match self {
+            Self::Transfer { amount }
+            | Self::MintTo { amount }
+            | Self::Burn { amount }
+            | Self::TransferChecked { amount, .. }
+            | Self::MintToChecked { amount, .. }
+            | Self::BurnChecked { amount, .. } => Some(*amount),
+            Self::TransferFeeExtension(TransferFeeInstruction::TransferCheckedWithFee {
+                amount,
+                ..
+            }) => Some(*amount),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the `u16` extension types packed until the end of the instruction.
+fn extension_types(rest: &[u8]) -> Result<Vec<u16>, ProgramError> {
+    if !rest.len().is_multiple_of(2) {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok(rest
+        .chunks_exact(2)
+        .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+        .collect())
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializeMint {
+    pub decimals: u8,
+    pub mint_authority: Pubkey,
+    pub freeze_authority: Option<Pubkey>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SetAuthority {
+    pub authority_type: AuthorityType,
+    pub new_authority: Option<Pubkey>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransferFeeInstruction {
+    InitializeTransferFeeConfig {
+        transfer_fee_config_authority: Option<Pubkey>,
+        withdraw_withheld_authority: Option<Pubkey>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    },
+    /// `TransferChecked` with the fee the sender expects to be withheld from the amount.
+    TransferCheckedWithFee {
+        amount: u64,
+        decimals: u8,
+        fee: u64,
+    },
+    WithdrawWithheldTokensFromMint,
+    WithdrawWithheldTokensFromAccounts {
+        num_token_accounts: u8,
+    },
+    HarvestWithheldTokensToMint,
+    SetTransferFee {
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    },
+}
+
+impl TransferFeeInstruction {
+    fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (tag, mut rest) = match input.split_first() {
+            Some((tag, rest)) => (*tag, rest),
+            None => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        Ok(match tag {
+            0 => Self::InitializeTransferFeeConfig {
+                transfer_fee_config_authority: Option::deserialize(&mut rest)?,
+                withdraw_withheld_authority: Option::deserialize(&mut rest)?,
+                transfer_fee_basis_points: u16::deserialize(&mut rest)?,
+                maximum_fee: u64::deserialize(&mut rest)?,
+            },
+            1 => Self::TransferCheckedWithFee {
+                amount: u64::deserialize(&mut rest)?,
+                decimals: u8::deserialize(&mut rest)?,
+                fee: u64::deserialize(&mut rest)?,
+            },
+            2 => Self::WithdrawWithheldTokensFromMint,
+            3 => Self::WithdrawWithheldTokensFromAccounts {
+                num_token_accounts: u8::deserialize(&mut rest)?,
+            },
+            4 => Self::HarvestWithheldTokensToMint,
+            5 => Self::SetTransferFee {
+                transfer_fee_basis_points: u16::deserialize(&mut rest)?,
+                maximum_fee: u64::deserialize(&mut rest)?,
+            },
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
+
+/// Extensions that are only switched on and off for an account, like memo transfers and the CPI
+/// guard.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ToggleInstruction {
+    Enable,
+    Disable,
+}
+
+impl ToggleInstruction {
+    fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        match input.first() {
+            Some(0) => Ok(Self::Enable),
+            Some(1) => Ok(Self::Disable),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransferHookInstruction {
+    Initialize {
+        authority: Option<Pubkey>,
+        program_id: Option<Pubkey>,
+    },
+    Update {
+        program_id: Option<Pubkey>,
+    },
+}
+
+impl TransferHookInstruction {
+    fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (tag, mut rest) = match input.split_first() {
+            Some((tag, rest)) => (*tag, rest),
+            None => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        Ok(match tag {
+            0 => Self::Initialize {
+                authority: non_zero_pubkey(&mut rest)?,
+                program_id: non_zero_pubkey(&mut rest)?,
+            },
+            1 => Self::Update {
+                program_id: non_zero_pubkey(&mut rest)?,
+            },
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
+
+/// Reads a key where the default key stands for `None`, as extensions pack them.
+fn non_zero_pubkey(rest: &mut &[u8]) -> Result<Option<Pubkey>, ProgramError> {
+    let key = Pubkey::deserialize(rest)?;
+    Ok((key != Pubkey::default()).then_some(key))
+}
+
+/// Instruction accounts
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferAccounts {
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    /// The owner or delegate, or the multisig whose signers follow.
+    pub authority: Pubkey,
+}
+
+impl TransferAccounts {
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        match accounts {
+            [source, destination, authority, ..] => Some(Self {
+                source: *source,
+                destination: *destination,
+                authority: *authority,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferCheckedAccounts {
+    pub source: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl TransferCheckedAccounts {
+    /// Reads the accounts of a `TransferChecked` or `TransferCheckedWithFee` instruction.
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        match accounts {
+            [source, mint, destination, authority, ..] => Some(Self {
+                source: *source,
+                mint: *mint,
+                destination: *destination,
+                authority: *authority,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MintToAccounts {
+    pub mint: Pubkey,
+    pub account: Pubkey,
+    pub mint_authority: Pubkey,
+}
+
+impl MintToAccounts {
+    /// Reads the accounts of a `MintTo` or `MintToChecked` instruction.
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        match accounts {
+            [mint, account, mint_authority, ..] => Some(Self {
+                mint: *mint,
+                account: *account,
+                mint_authority: *mint_authority,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BurnAccounts {
+    pub account: Pubkey,
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl BurnAccounts {
+    /// Reads the accounts of a `Burn` or `BurnChecked` instruction.
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        match accounts {
+            [account, mint, authority, ..] => Some(Self {
+                account: *account,
+                mint: *mint,
+                authority: *authority,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CloseAccountAccounts {
+    pub account: Pubkey,
+    /// Receives the lamports of the account.
+    pub destination: Pubkey,
+    pub owner: Pubkey,
+}
+
+impl CloseAccountAccounts {
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        match accounts {
+            [account, destination, owner, ..] => Some(Self {
+                account: *account,
+                destination: *destination,
+                owner: *owner,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Types
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq)]
+pub enum AuthorityType {
+    MintTokens,
+    FreezeAccount,
+    AccountOwner,
+    CloseAccount,
+    TransferFeeConfig,
+    WithheldWithdraw,
+    CloseMint,
+    InterestRate,
+    PermanentDelegate,
+    ConfidentialTransferMint,
+    TransferHookProgramId,
+    ConfidentialTransferFeeConfig,
+    MetadataPointer,
+    GroupPointer,
+    GroupMemberPointer,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unpack_transfers() {
+        let mut data = vec![3];
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        let transfer = SplTokenInstruction::unpack(&data).unwrap();
+        assert_eq!(transfer, SplTokenInstruction::Transfer { amount: 1_000 });
+        assert_eq!(transfer.amount(), Some(1_000));
+
+        let mut data = vec![12];
+        data.extend_from_slice(&2_500u64.to_le_bytes());
+        data.push(6);
+        assert_eq!(
+            SplTokenInstruction::unpack(&data).unwrap(),
+            SplTokenInstruction::TransferChecked {
+                amount: 2_500,
+                decimals: 6
+            }
+        );
+
+        assert_eq!(
+            SplTokenInstruction::unpack(&[17]).unwrap(),
+            SplTokenInstruction::SyncNative
+        );
+        assert!(SplTokenInstruction::unpack(&[3, 1]).is_err());
+    }
+
+    #[test]
+    fn unpack_set_authority() {
+        let authority = Pubkey::new_unique();
+        let mut data = vec![6, 2, 1];
+        data.extend_from_slice(authority.as_ref());
+        assert_eq!(
+            SplTokenInstruction::unpack(&data).unwrap(),
+            SplTokenInstruction::SetAuthority(SetAuthority {
+                authority_type: AuthorityType::AccountOwner,
+                new_authority: Some(authority),
+            })
+        );
+        assert_eq!(
+            SplTokenInstruction::unpack(&[6, 3, 0]).unwrap(),
+            SplTokenInstruction::SetAuthority(SetAuthority {
+                authority_type: AuthorityType::CloseAccount,
+                new_authority: None,
+            })
+        );
+    }
+
+    #[test]
+    fn unpack_transfer_fee_extension() {
+        let mut data = vec![26, 1];
+        data.extend_from_slice(&10_000u64.to_le_bytes());
+        data.push(9);
+        data.extend_from_slice(&50u64.to_le_bytes());
+        let transfer = SplTokenInstruction::unpack(&data).unwrap();
+        assert_eq!(
+            transfer,
+            SplTokenInstruction::TransferFeeExtension(
+                TransferFeeInstruction::TransferCheckedWithFee {
+                    amount: 10_000,
+                    decimals: 9,
+                    fee: 50,
+                }
+            )
+        );
+        assert_eq!(transfer.amount(), Some(10_000));
+
+        let authority = Pubkey::new_unique();
+        let mut data = vec![26, 0, 1];
+        data.extend_from_slice(authority.as_ref());
+        data.push(0);
+        data.extend_from_slice(&25u16.to_le_bytes());
+        data.extend_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(
+            SplTokenInstruction::unpack(&data).unwrap(),
+            SplTokenInstruction::TransferFeeExtension(
+                TransferFeeInstruction::InitializeTransferFeeConfig {
+                    transfer_fee_config_authority: Some(authority),
+                    withdraw_withheld_authority: None,
+                    transfer_fee_basis_points: 25,
+                    maximum_fee: u64::MAX,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn unpack_transfer_hook_extension() {
+        let program_id = Pubkey::new_unique();
+        let mut data = vec![36, 0];
+        data.extend_from_slice(Pubkey::default().as_ref());
+        data.extend_from_slice(program_id.as_ref());
+        assert_eq!(
+            SplTokenInstruction::unpack(&data).unwrap(),
+            SplTokenInstruction::TransferHookExtension(TransferHookInstruction::Initialize {
+                authority: None,
+                program_id: Some(program_id),
+            })
+        );
+    }
+
+    #[test]
+    fn unpack_extension_types() {
+        assert_eq!(
+            SplTokenInstruction::unpack(&[29, 1, 0, 14, 0]).unwrap(),
+            SplTokenInstruction::Reallocate {
+                extension_types: vec![1, 14]
+            }
+        );
+        assert!(SplTokenInstruction::unpack(&[21, 1]).is_err());
+    }
+}
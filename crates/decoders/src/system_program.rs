@@ -0,0 +1,249 @@
+use borsh::BorshDeserialize;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+solana_program::declare_id!("11111111111111111111111111111111");
+
+/// Instructions
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SystemInstruction {
+    CreateAccount(CreateAccount),
+    Assign {
+        owner: Pubkey,
+    },
+    Transfer {
+        lamports: u64,
+    },
+    CreateAccountWithSeed(CreateAccountWithSeed),
+    AdvanceNonceAccount,
+    WithdrawNonceAccount {
+        lamports: u64,
+    },
+    InitializeNonceAccount {
+        authority: Pubkey,
+    },
+    AuthorizeNonceAccount {
+        authority: Pubkey,
+    },
+    Allocate {
+        space: u64,
+    },
+    AllocateWithSeed {
+        base: Pubkey,
+        seed: String,
+        space: u64,
+        owner: Pubkey,
+    },
+    AssignWithSeed {
+        base: Pubkey,
+        seed: String,
+        owner: Pubkey,
+    },
+    TransferWithSeed {
+        lamports: u64,
+        from_seed: String,
+        from_owner: Pubkey,
+    },
+    UpgradeNonceAccount,
+}
+
+impl SystemInstruction {
+    /// Instructions are bincode encoded, tagged by a `u32` with strings prefixed by a `u64`
+    /// length. Like the program, trailing bytes are ignored.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let mut rest = input;
+        let tag = u32::deserialize(&mut rest)?;
+
+        Ok(match tag {
+            0 => Self::CreateAccount(CreateAccount {
+                lamports: u64::deserialize(&mut rest)?,
+                space: u64::deserialize(&mut rest)?,
+                owner: Pubkey::deserialize(&mut rest)?,
+            }),
+            1 => Self::Assign {
+                owner: Pubkey::deserialize(&mut rest)?,
+            },
+            2 => Self::Transfer {
+                lamports: u64::deserialize(&mut rest)?,
+            },
+            3 => Self::CreateAccountWithSeed(CreateAccountWithSeed {
+                base: Pubkey::deserialize(&mut rest)?,
+                seed: seed(&mut rest)?,
+                lamports: u64::deserialize(&mut rest)?,
+                space: u64::deserialize(&mut rest)?,
+                owner: Pubkey::deserialize(&mut rest)?,
+            }),
+            4 => Self::AdvanceNonceAccount,
+            5 => Self::WithdrawNonceAccount {
+                lamports: u64::deserialize(&mut rest)?,
+            },
+            6 => Self::InitializeNonceAccount {
+                authority: Pubkey::deserialize(&mut rest)?,
+            },
+            7 => Self::AuthorizeNonceAccount {
+                authority: Pubkey::deserialize(&mut rest)?,
+            },
+            8 => Self::Allocate {
+                space: u64::deserialize(&mut rest)?,
+            },
+            9 => Self::AllocateWithSeed {
+                base: Pubkey::deserialize(&mut rest)?,
+                seed: seed(&mut rest)?,
+                space: u64::deserialize(&mut rest)?,
+                owner: Pubkey::deserialize(&mut rest)?,
+            },
+            10 => Self::AssignWithSeed {
+                base: Pubkey::deserialize(&mut rest)?,
+                seed: seed(&mut rest)?,
+                owner: Pubkey::deserialize(&mut rest)?,
+            },
+            11 => Self::TransferWithSeed {
+                lamports: u64::deserialize(&mut rest)?,
+                from_seed: seed(&mut rest)?,
+                from_owner: Pubkey::deserialize(&mut rest)?,
+            },
+            12 => Self::UpgradeNonceAccount,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+
+    /// Lamports moved out of the funding account, rent of created accounts included.
+    pub fn lamports(&self) -> Option<u64> {
+        match self {
+            Self::CreateAccount(CreateAccount { lamports, .. })
+            | Self::CreateAccountWithSeed(CreateAccountWithSeed { lamports, .. })
+            | Self::Transfer { lamports }
+            | Self::TransferWithSeed { lamports, .. }
+            | Self::WithdrawNonceAccount { lamports } => Some(*lamports),
+            _ => None,
+        }
+    }
+}
+
+/// Reads a bincode string, a `u64` length followed by its utf-8 bytes.
+fn seed(rest: &mut &[u8]) -> Result<String, ProgramError> {
+    let len = u64::deserialize(rest)? as usize;
+    if rest.len() < len {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let (seed, remaining) = rest.split_at(len);
+    *rest = remaining;
+    String::from_utf8(seed.to_vec()).map_err(|_| ProgramError::InvalidInstructionData)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateAccount {
+    /// Rent exemption of the new account, paid by the funding account.
+    pub lamports: u64,
+    pub space: u64,
+    pub owner: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateAccountWithSeed {
+    pub base: Pubkey,
+    pub seed: String,
+    pub lamports: u64,
+    pub space: u64,
+    pub owner: Pubkey,
+}
+
+/// Instruction accounts
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateAccountAccounts {
+    pub funding_account: Pubkey,
+    pub new_account: Pubkey,
+}
+
+impl CreateAccountAccounts {
+    /// Reads the accounts of a `CreateAccount` or `CreateAccountWithSeed` instruction.
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        match accounts {
+            [funding_account, new_account, ..] => Some(Self {
+                funding_account: *funding_account,
+                new_account: *new_account,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferAccounts {
+    pub from: Pubkey,
+    pub to: Pubkey,
+}
+
+impl TransferAccounts {
+    /// Reads the accounts of a `Transfer` instruction. `TransferWithSeed` puts the base account
+    /// between them.
+    pub fn from_accounts(accounts: &[Pubkey]) -> Option<Self> {
+        match accounts {
+            [from, to, ..] => Some(Self {
+                from: *from,
+                to: *to,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unpack_create_account() {
+        let owner = Pubkey::new_unique();
+        let mut data = 0u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&35_144_640u64.to_le_bytes());
+        data.extend_from_slice(&4_376u64.to_le_bytes());
+        data.extend_from_slice(owner.as_ref());
+
+        let instruction = SystemInstruction::unpack(&data).unwrap();
+        assert_eq!(
+            instruction,
+            SystemInstruction::CreateAccount(CreateAccount {
+                lamports: 35_144_640,
+                space: 4_376,
+                owner,
+            })
+        );
+        assert_eq!(instruction.lamports(), Some(35_144_640));
+    }
+
+    #[test]
+    fn unpack_seeded_transfer() {
+        let from_owner = Pubkey::new_unique();
+        let mut data = 11u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&4u64.to_le_bytes());
+        data.extend_from_slice(b"seed");
+        data.extend_from_slice(from_owner.as_ref());
+
+        assert_eq!(
+            SystemInstruction::unpack(&data).unwrap(),
+            SystemInstruction::TransferWithSeed {
+                lamports: 1_000,
+                from_seed: "seed".to_string(),
+                from_owner,
+            }
+        );
+
+        let mut data = 11u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&64u64.to_le_bytes());
+        assert!(SystemInstruction::unpack(&data).is_err());
+    }
+
+    #[test]
+    fn unpack_nonce_ops() {
+        assert_eq!(
+            SystemInstruction::unpack(&4u32.to_le_bytes()).unwrap(),
+            SystemInstruction::AdvanceNonceAccount
+        );
+        assert!(SystemInstruction::unpack(&13u32.to_le_bytes()).is_err());
+    }
+}
@@ -0,0 +1,537 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    impl_discriminator,
+    math::{sqrt_price_x64_to_price, tick_to_price},
+    Discriminator,
+};
+
+solana_program::declare_id!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+
+/// Rewards a whirlpool or position tracks.
+pub const NUM_REWARDS: usize = 3;
+/// Ticks held by a `TickArray`.
+pub const TICK_ARRAY_SIZE: usize = 88;
+
+/// Instructions
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum WhirlpoolInstruction {
+    Swap(Swap),
+    SwapV2(SwapV2),
+    TwoHopSwap(TwoHopSwap),
+    TwoHopSwapV2(TwoHopSwapV2),
+    OpenPosition(OpenPosition),
+    OpenPositionWithMetadata(OpenPositionWithMetadata),
+    OpenPositionWithTokenExtensions(OpenPositionWithTokenExtensions),
+    ClosePosition,
+    ClosePositionWithTokenExtensions,
+    IncreaseLiquidity(IncreaseLiquidity),
+    IncreaseLiquidityV2(IncreaseLiquidityV2),
+    DecreaseLiquidity(DecreaseLiquidity),
+    DecreaseLiquidityV2(DecreaseLiquidityV2),
+    UpdateFeesAndRewards,
+    CollectFees,
+    CollectFeesV2(CollectFeesV2),
+    CollectReward(CollectReward),
+    CollectRewardV2(CollectRewardV2),
+}
+
+impl WhirlpoolInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (discriminator, rest) = input.split_at(8);
+        if let Ok(discriminator) = discriminator.try_into() {
+            return Ok(match discriminator {
+                Swap::DISCRIMINATOR => Self::Swap(borsh::from_slice(rest)?),
+                SwapV2::DISCRIMINATOR => Self::SwapV2(borsh::from_slice(rest)?),
+                TwoHopSwap::DISCRIMINATOR => Self::TwoHopSwap(borsh::from_slice(rest)?),
+                TwoHopSwapV2::DISCRIMINATOR => Self::TwoHopSwapV2(borsh::from_slice(rest)?),
+                OpenPosition::DISCRIMINATOR => Self::OpenPosition(borsh::from_slice(rest)?),
+                OpenPositionWithMetadata::DISCRIMINATOR => {
+                    Self::OpenPositionWithMetadata(borsh::from_slice(rest)?)
+                }
+                OpenPositionWithTokenExtensions::DISCRIMINATOR => {
+                    Self::OpenPositionWithTokenExtensions(borsh::from_slice(rest)?)
+                }
+                ClosePosition::DISCRIMINATOR => Self::ClosePosition,
+                ClosePositionWithTokenExtensions::DISCRIMINATOR => {
+                    Self::ClosePositionWithTokenExtensions
+                }
+                IncreaseLiquidity::DISCRIMINATOR => {
+                    Self::IncreaseLiquidity(borsh::from_slice(rest)?)
+                }
+                IncreaseLiquidityV2::DISCRIMINATOR => {
+                    Self::IncreaseLiquidityV2(borsh::from_slice(rest)?)
+                }
+                DecreaseLiquidity::DISCRIMINATOR => {
+                    Self::DecreaseLiquidity(borsh::from_slice(rest)?)
+                }
+                DecreaseLiquidityV2::DISCRIMINATOR => {
+                    Self::DecreaseLiquidityV2(borsh::from_slice(rest)?)
+                }
+                UpdateFeesAndRewards::DISCRIMINATOR => Self::UpdateFeesAndRewards,
+                CollectFees::DISCRIMINATOR => Self::CollectFees,
+                CollectFeesV2::DISCRIMINATOR => Self::CollectFeesV2(borsh::from_slice(rest)?),
+                CollectReward::DISCRIMINATOR => Self::CollectReward(borsh::from_slice(rest)?),
+                CollectRewardV2::DISCRIMINATOR => Self::CollectRewardV2(borsh::from_slice(rest)?),
+                _ => return Err(ProgramError::InvalidInstructionData),
+            });
+        }
+
+        Err(ProgramError::InvalidInstructionData)
+    }
+
+    /// Whether the instruction swaps, its token transfers then give `SwapAmounts`.
+    pub fn is_swap(&self) -> bool {
+        matches!(
+            self,
+            Self::Swap(_) | Self::SwapV2(_) | Self::TwoHopSwap(_) | Self::TwoHopSwapV2(_)
+        )
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Swap {
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub sqrt_price_limit: u128,
+    pub amount_specified_is_input: bool,
+    pub a_to_b: bool,
+}
+
+impl_discriminator!(instruction Swap);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SwapV2 {
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub sqrt_price_limit: u128,
+    pub amount_specified_is_input: bool,
+    pub a_to_b: bool,
+    pub remaining_accounts_info: Option<RemainingAccountsInfo>,
+}
+
+impl_discriminator!(instruction SwapV2);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct TwoHopSwap {
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub amount_specified_is_input: bool,
+    pub a_to_b_one: bool,
+    pub a_to_b_two: bool,
+    pub sqrt_price_limit_one: u128,
+    pub sqrt_price_limit_two: u128,
+}
+
+impl_discriminator!(instruction TwoHopSwap);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct TwoHopSwapV2 {
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub amount_specified_is_input: bool,
+    pub a_to_b_one: bool,
+    pub a_to_b_two: bool,
+    pub sqrt_price_limit_one: u128,
+    pub sqrt_price_limit_two: u128,
+    pub remaining_accounts_info: Option<RemainingAccountsInfo>,
+}
+
+impl_discriminator!(instruction TwoHopSwapV2);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct OpenPosition {
+    pub bumps: OpenPositionBumps,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+}
+
+impl_discriminator!(instruction OpenPosition);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct OpenPositionWithMetadata {
+    pub bumps: OpenPositionWithMetadataBumps,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+}
+
+impl_discriminator!(instruction OpenPositionWithMetadata);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct OpenPositionWithTokenExtensions {
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub with_token_metadata_extension: bool,
+}
+
+impl_discriminator!(instruction OpenPositionWithTokenExtensions);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ClosePosition {}
+
+impl_discriminator!(instruction ClosePosition);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ClosePositionWithTokenExtensions {}
+
+impl_discriminator!(instruction ClosePositionWithTokenExtensions);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct IncreaseLiquidity {
+    pub liquidity_amount: u128,
+    pub token_max_a: u64,
+    pub token_max_b: u64,
+}
+
+impl_discriminator!(instruction IncreaseLiquidity);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct IncreaseLiquidityV2 {
+    pub liquidity_amount: u128,
+    pub token_max_a: u64,
+    pub token_max_b: u64,
+    pub remaining_accounts_info: Option<RemainingAccountsInfo>,
+}
+
+impl_discriminator!(instruction IncreaseLiquidityV2);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DecreaseLiquidity {
+    pub liquidity_amount: u128,
+    pub token_min_a: u64,
+    pub token_min_b: u64,
+}
+
+impl_discriminator!(instruction DecreaseLiquidity);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct DecreaseLiquidityV2 {
+    pub liquidity_amount: u128,
+    pub token_min_a: u64,
+    pub token_min_b: u64,
+    pub remaining_accounts_info: Option<RemainingAccountsInfo>,
+}
+
+impl_discriminator!(instruction DecreaseLiquidityV2);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateFeesAndRewards {}
+
+impl_discriminator!(instruction UpdateFeesAndRewards);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CollectFees {}
+
+impl_discriminator!(instruction CollectFees);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CollectFeesV2 {
+    pub remaining_accounts_info: Option<RemainingAccountsInfo>,
+}
+
+impl_discriminator!(instruction CollectFeesV2);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CollectReward {
+    pub reward_index: u8,
+}
+
+impl_discriminator!(instruction CollectReward);
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct CollectRewardV2 {
+    pub reward_index: u8,
+    pub remaining_accounts_info: Option<RemainingAccountsInfo>,
+}
+
+impl_discriminator!(instruction CollectRewardV2);
+
+/// Instruction accounts
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SwapAccounts {
+    pub token_program: Pubkey,
+    pub token_authority: Pubkey,
+    pub whirlpool: Pubkey,
+    pub token_owner_account_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub token_owner_account_b: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub tick_array_0: Pubkey,
+    pub tick_array_1: Pubkey,
+    pub tick_array_2: Pubkey,
+    pub oracle: Pubkey,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SwapV2Accounts {
+    pub token_program_a: Pubkey,
+    pub token_program_b: Pubkey,
+    pub memo_program: Pubkey,
+    pub token_authority: Pubkey,
+    pub whirlpool: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub token_owner_account_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub token_owner_account_b: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub tick_array_0: Pubkey,
+    pub tick_array_1: Pubkey,
+    pub tick_array_2: Pubkey,
+    pub oracle: Pubkey,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ModifyLiquidityAccounts {
+    pub whirlpool: Pubkey,
+    pub token_program: Pubkey,
+    pub position_authority: Pubkey,
+    pub position: Pubkey,
+    pub position_token_account: Pubkey,
+    pub token_owner_account_a: Pubkey,
+    pub token_owner_account_b: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+}
+
+/// Accounts
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Whirlpool {
+    pub whirlpools_config: Pubkey,
+    pub whirlpool_bump: [u8; 1],
+    pub tick_spacing: u16,
+    pub tick_spacing_seed: [u8; 2],
+    pub fee_rate: u16,
+    pub protocol_fee_rate: u16,
+    pub liquidity: u128,
+    pub sqrt_price: u128,
+    pub tick_current_index: i32,
+    pub protocol_fee_owed_a: u64,
+    pub protocol_fee_owed_b: u64,
+    pub token_mint_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub fee_growth_global_a: u128,
+    pub token_mint_b: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub fee_growth_global_b: u128,
+    pub reward_last_updated_timestamp: u64,
+    pub reward_infos: [WhirlpoolRewardInfo; NUM_REWARDS],
+}
+
+impl_discriminator!(account Whirlpool);
+
+impl Whirlpool {
+    /// Price of token A in token B, the pool doesn't store the decimals of its mints.
+    pub fn price(&self, decimals_a: u8, decimals_b: u8) -> f64 {
+        sqrt_price_x64_to_price(self.sqrt_price, decimals_a, decimals_b)
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Position {
+    pub whirlpool: Pubkey,
+    pub position_mint: Pubkey,
+    pub liquidity: u128,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub fee_growth_checkpoint_a: u128,
+    pub fee_owed_a: u64,
+    pub fee_growth_checkpoint_b: u128,
+    pub fee_owed_b: u64,
+    pub reward_infos: [PositionRewardInfo; NUM_REWARDS],
+}
+
+impl_discriminator!(account Position);
+
+impl Position {
+    /// Prices of token A in token B at the lower and upper ticks of the position.
+    pub fn price_range(&self, decimals_a: u8, decimals_b: u8) -> (f64, f64) {
+        (
+            tick_to_price(self.tick_lower_index, decimals_a, decimals_b),
+            tick_to_price(self.tick_upper_index, decimals_a, decimals_b),
+        )
+    }
+}
+
+/// Zero copy in the program, its packed layout is the borsh encoding of the fields.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct TickArray {
+    pub start_tick_index: i32,
+    pub ticks: [Tick; TICK_ARRAY_SIZE],
+    pub whirlpool: Pubkey,
+}
+
+impl_discriminator!(account TickArray);
+
+/// Types
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct OpenPositionBumps {
+    pub position_bump: u8,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct OpenPositionWithMetadataBumps {
+    pub position_bump: u8,
+    pub metadata_bump: u8,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RemainingAccountsInfo {
+    pub slices: Vec<RemainingAccountsSlice>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct RemainingAccountsSlice {
+    pub accounts_type: AccountsType,
+    pub length: u8,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq)]
+pub enum AccountsType {
+    TransferHookA,
+    TransferHookB,
+    TransferHookReward,
+    TransferHookInput,
+    TransferHookIntermediate,
+    TransferHookOutput,
+    SupplementalTickArrays,
+    SupplementalTickArraysOne,
+    SupplementalTickArraysTwo,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct WhirlpoolRewardInfo {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub emissions_per_second_x64: u128,
+    pub growth_global_x64: u128,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct PositionRewardInfo {
+    pub growth_inside_checkpoint: u128,
+    pub amount_owed: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Tick {
+    pub initialized: bool,
+    pub liquidity_net: i128,
+    pub liquidity_gross: u128,
+    pub fee_growth_outside_a: u128,
+    pub fee_growth_outside_b: u128,
+    pub reward_growths_outside: [u128; NUM_REWARDS],
+}
+
+/// Whirlpool emits no events, what a swap moved is read from the token transfers it made.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SwapAmounts {
+    pub amount_in: u64,
+    pub amount_out: u64,
+}
+
+impl SwapAmounts {
+    /// Reads the amounts from the token transfers a swap made, in order. A swap pays the input
+    /// first and the output last: one transfer each for `Swap` and `SwapV2`, while two hop swaps
+    /// move the intermediate token in between.
+    pub fn from_transfers(transfers: &[u64]) -> Option<Self> {
+        match transfers {
+            [amount_in, .., amount_out] => Some(Self {
+                amount_in: *amount_in,
+                amount_out: *amount_out,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zeroed_account;
+
+    #[test]
+    fn discriminators_match_program() {
+        assert_eq!(
+            TwoHopSwap::DISCRIMINATOR,
+            [195, 96, 237, 108, 68, 162, 219, 230]
+        );
+        assert_eq!(
+            Whirlpool::DISCRIMINATOR,
+            [63, 149, 209, 12, 225, 128, 99, 9]
+        );
+        assert_eq!(
+            Position::DISCRIMINATOR,
+            [170, 188, 143, 228, 122, 64, 247, 208]
+        );
+        assert_eq!(
+            TickArray::DISCRIMINATOR,
+            [69, 97, 189, 190, 110, 7, 66, 187]
+        );
+    }
+
+    #[test]
+    fn accounts_have_program_sizes() {
+        zeroed_account::<Whirlpool>(653);
+
+        zeroed_account::<Position>(216);
+
+        zeroed_account::<TickArray>(9988);
+    }
+
+    #[test]
+    fn unpack_two_hop_swap_v2() {
+        let swap = TwoHopSwapV2 {
+            amount: 1_000_000,
+            other_amount_threshold: 990,
+            amount_specified_is_input: true,
+            a_to_b_one: true,
+            a_to_b_two: false,
+            sqrt_price_limit_one: 4_295_048_016,
+            sqrt_price_limit_two: 79_226_673_515_401_279_992_447_579_055,
+            remaining_accounts_info: Some(RemainingAccountsInfo {
+                slices: vec![RemainingAccountsSlice {
+                    accounts_type: AccountsType::SupplementalTickArraysOne,
+                    length: 1,
+                }],
+            }),
+        };
+        let mut data = TwoHopSwapV2::DISCRIMINATOR.to_vec();
+        data.extend(borsh::to_vec(&swap).unwrap());
+
+        let instruction = WhirlpoolInstruction::unpack(&data).unwrap();
+        assert!(instruction.is_swap());
+        assert_eq!(instruction, WhirlpoolInstruction::TwoHopSwapV2(swap));
+    }
+
+    #[test]
+    fn swap_amounts_from_transfers() {
+        assert_eq!(
+            SwapAmounts::from_transfers(&[1_000, 6]),
+            Some(SwapAmounts {
+                amount_in: 1_000,
+                amount_out: 6,
+            })
+        );
+        assert_eq!(
+            SwapAmounts::from_transfers(&[1_000, 6, 6, 150]),
+            Some(SwapAmounts {
+                amount_in: 1_000,
+                amount_out: 150,
+            })
+        );
+        assert_eq!(SwapAmounts::from_transfers(&[1_000]), None);
+    }
+}
@@ -1,15 +1,17 @@
-use std::{borrow::BorrowMut, str::FromStr};
+use std::str::FromStr;
 
 use models::{ReadOnlyInstruction, ReadOnlyTransaction};
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{address_lookup_table::state::AddressLookupTable, pubkey::Pubkey};
 use solana_transaction_status::{
     option_serializer::OptionSerializer, EncodedTransaction, EncodedTransactionWithStatusMeta,
-    UiInstruction, UiMessage, UiParsedInstruction, UiParsedMessage, UiRawMessage,
+    UiCompiledInstruction, UiInstruction, UiMessage, UiParsedInstruction, UiParsedMessage,
+    UiRawMessage,
 };
 
 pub struct EncodedTransactionExtractor {
     rpc_client: RpcClient,
+    slot: u64,
     block_time: i64,
     encoded_transaction: EncodedTransactionWithStatusMeta,
 }
@@ -17,6 +19,7 @@ pub struct EncodedTransactionExtractor {
 impl EncodedTransactionExtractor {
     pub fn new(
         rpc_url: &str,
+        slot: u64,
         block_time: i64,
         encoded_transaction: &EncodedTransactionWithStatusMeta,
     ) -> Self {
@@ -24,6 +27,7 @@ impl EncodedTransactionExtractor {
 
         Self {
             rpc_client,
+            slot,
             block_time,
             encoded_transaction: encoded_transaction.clone(),
         }
@@ -43,6 +47,8 @@ impl EncodedTransactionExtractor {
 
             return Some(ReadOnlyTransaction {
                 signature,
+                slot: self.slot,
+                block_time: self.block_time,
                 log_messages: self.extract_raw_logs(),
                 accounts,
                 instructions,
@@ -66,8 +72,21 @@ impl EncodedTransactionExtractor {
         let mut accounts = Vec::new();
         let mut writable = Vec::new();
         let mut readonly = Vec::new();
-        let meta = &self.encoded_transaction.clone().meta.unwrap();
+        let meta = &self.encoded_transaction.meta;
+        // Nodes resolve lookup tables themselves and return the addresses they loaded, tables are
+        // only fetched when those are missing.
+        let loaded = meta
+            .as_ref()
+            .is_some_and(|meta| matches!(meta.loaded_addresses, OptionSerializer::Some(_)));
         if let EncodedTransaction::Json(transaction) = &self.encoded_transaction.transaction {
+            // Parsed messages already list the addresses loaded from lookup tables.
+            if let UiMessage::Parsed(UiParsedMessage { account_keys, .. }) = &transaction.message {
+                return account_keys
+                    .iter()
+                    .map(|account_key| account_key.pubkey.clone())
+                    .collect();
+            }
+
             if let UiMessage::Raw(UiRawMessage {
                 account_keys,
                 address_table_lookups,
@@ -77,14 +96,18 @@ impl EncodedTransactionExtractor {
                 account_keys
                     .iter()
                     .for_each(|account_key| accounts.push(account_key.to_string()));
-                if let Some(address_table_lookups) = address_table_lookups {
+                if let (Some(address_table_lookups), false) = (address_table_lookups, loaded) {
                     for lookup in address_table_lookups {
-                        let account_key = lookup.account_key.clone();
-                        let account = self
-                            .rpc_client
-                            .get_account(&Pubkey::from_str(&account_key).unwrap())
-                            .await
-                            .unwrap();
+                        // A table that can't be fetched leaves its addresses out, instructions
+                        // referencing them are skipped.
+                        let account_key = match Pubkey::from_str(&lookup.account_key) {
+                            Ok(account_key) => account_key,
+                            Err(_) => continue,
+                        };
+                        let account = match self.rpc_client.get_account(&account_key).await {
+                            Ok(account) => account,
+                            Err(_) => continue,
+                        };
                         if let Ok(atl) = AddressLookupTable::deserialize(&account.data) {
                             let addresses = atl
                                 .addresses
@@ -94,13 +117,13 @@ impl EncodedTransactionExtractor {
                             let lookup_writable = lookup
                                 .writable_indexes
                                 .iter()
-                                .map(|index| addresses.get(*index as usize).unwrap().to_string())
+                                .filter_map(|index| addresses.get(*index as usize).cloned())
                                 .collect::<Vec<_>>();
                             writable.extend(lookup_writable);
                             let lookup_readonly = lookup
                                 .readonly_indexes
                                 .iter()
-                                .map(|index| addresses.get(*index as usize).unwrap().to_string())
+                                .filter_map(|index| addresses.get(*index as usize).cloned())
                                 .collect::<Vec<_>>();
                             readonly.extend(lookup_readonly);
                         }
@@ -109,15 +132,17 @@ impl EncodedTransactionExtractor {
             }
         }
 
-        if let OptionSerializer::Some(loaded_addresses) = &meta.loaded_addresses {
-            loaded_addresses
-                .writable
-                .iter()
-                .for_each(|address| accounts.push(address.to_string()));
-            loaded_addresses
-                .readonly
-                .iter()
-                .for_each(|address| accounts.push(address.to_string()));
+        if let Some(meta) = meta {
+            if let OptionSerializer::Some(loaded_addresses) = &meta.loaded_addresses {
+                loaded_addresses
+                    .writable
+                    .iter()
+                    .for_each(|address| accounts.push(address.to_string()));
+                loaded_addresses
+                    .readonly
+                    .iter()
+                    .for_each(|address| accounts.push(address.to_string()));
+            }
         }
 
         accounts.extend(writable);
@@ -128,6 +153,8 @@ impl EncodedTransactionExtractor {
 
     pub async fn extract_readonly_instructions(&self) -> Vec<ReadOnlyInstruction> {
         let accounts = self.extract_accounts().await;
+        // Instructions that can't be resolved keep their slot as `None`, so inner instructions
+        // still find their parent by index.
         let mut tx_instructions = Vec::new();
         if let EncodedTransaction::Json(transaction) = self.encoded_transaction.transaction.clone()
         {
@@ -135,47 +162,16 @@ impl EncodedTransactionExtractor {
                 UiMessage::Parsed(UiParsedMessage { instructions, .. }) => {
                     for instruction in instructions {
                         match instruction {
-                            UiInstruction::Compiled(_) => {}
-                            UiInstruction::Parsed(parsed) => match parsed {
-                                UiParsedInstruction::PartiallyDecoded(decoded) => {
-                                    tx_instructions.push(ReadOnlyInstruction {
-                                        program_id: decoded.program_id,
-                                        data: decoded.data,
-                                        accounts: decoded.accounts,
-                                        inner_instructions: Vec::new(),
-                                    });
-                                }
-                                UiParsedInstruction::Parsed(parsed) => {
-                                    tx_instructions.push(ReadOnlyInstruction {
-                                        program_id: parsed.program_id,
-                                        data: parsed.parsed.to_string(),
-                                        accounts: Vec::new(),
-                                        inner_instructions: Vec::new(),
-                                    });
-                                }
-                            },
+                            UiInstruction::Compiled(_) => tx_instructions.push(None),
+                            UiInstruction::Parsed(parsed) => {
+                                tx_instructions.push(Some(parsed_instruction(parsed).0));
+                            }
                         }
                     }
                 }
                 UiMessage::Raw(UiRawMessage { instructions, .. }) => {
                     for instruction in instructions {
-                        let program_id = accounts
-                            .get(instruction.program_id_index as usize)
-                            .unwrap()
-                            .to_string();
-                        let instruction_accounts = instruction
-                            .accounts
-                            .iter()
-                            .map(|account_index| {
-                                accounts.get(*account_index as usize).unwrap().to_string()
-                            })
-                            .collect::<Vec<_>>();
-                        tx_instructions.push(ReadOnlyInstruction {
-                            program_id,
-                            data: instruction.data,
-                            accounts: instruction_accounts,
-                            inner_instructions: Vec::new(),
-                        });
+                        tx_instructions.push(compiled_instruction(&instruction, &accounts));
                     }
                 }
             }
@@ -184,63 +180,103 @@ impl EncodedTransactionExtractor {
         if let Some(meta) = self.encoded_transaction.meta.clone() {
             if let OptionSerializer::Some(inner_instructions) = meta.inner_instructions {
                 inner_instructions.iter().for_each(|inner_instruction| {
-                    let parent_instruction = tx_instructions
-                        .get_mut(inner_instruction.index as usize)
-                        .unwrap();
+                    let parent_instruction =
+                        match tx_instructions.get_mut(inner_instruction.index as usize) {
+                            Some(Some(parent_instruction)) => parent_instruction,
+                            _ => return,
+                        };
 
                     inner_instruction
                         .instructions
                         .iter()
                         .for_each(|instruction| {
-                            if let UiInstruction::Compiled(instruction) = instruction {
-                                let program_id = accounts
-                                    .get(instruction.program_id_index as usize)
-                                    .unwrap()
-                                    .to_string();
-                                let instruction_accounts = instruction
-                                    .accounts
-                                    .iter()
-                                    .map(|account_index| {
-                                        accounts.get(*account_index as usize).unwrap().to_string()
-                                    })
-                                    .collect::<Vec<_>>();
-                                let inner_readonly_instruction = ReadOnlyInstruction {
-                                    program_id,
-                                    data: instruction.data.clone(),
-                                    accounts: instruction_accounts,
-                                    inner_instructions: Vec::new(),
-                                };
-
-                                if let Some(stack_height) = instruction.stack_height {
-                                    if stack_height.eq(&2) {
-                                        parent_instruction
-                                            .inner_instructions
-                                            .push(inner_readonly_instruction);
-                                    } else {
-                                        let mut target = parent_instruction.borrow_mut();
-                                        for _ in 2..stack_height {
-                                            target = target.inner_instructions.last_mut().unwrap();
-                                        }
-                                        target.inner_instructions.push(inner_readonly_instruction);
+                            let (inner_readonly_instruction, stack_height) = match instruction {
+                                UiInstruction::Compiled(compiled) => {
+                                    match compiled_instruction(compiled, &accounts) {
+                                        Some(inner) => (inner, compiled.stack_height),
+                                        None => return,
                                     }
                                 }
+                                UiInstruction::Parsed(parsed) => parsed_instruction(parsed.clone()),
+                            };
+
+                            if let Some(stack_height) = stack_height {
+                                // Descends one level per stack frame below the top-level
+                                // instruction, stopping early if the frames above are missing.
+                                let mut target = &mut *parent_instruction;
+                                for _ in 2..stack_height {
+                                    if target.inner_instructions.is_empty() {
+                                        break;
+                                    }
+                                    let last = target.inner_instructions.len() - 1;
+                                    target = &mut target.inner_instructions[last];
+                                }
+                                target.inner_instructions.push(inner_readonly_instruction);
                             }
                         });
                 });
             }
         }
 
-        tx_instructions
+        tx_instructions.into_iter().flatten().collect()
     }
 
     pub fn extract_raw_logs(&self) -> Vec<String> {
         let mut logs = Vec::new();
-        let meta = &self.encoded_transaction.clone().meta.unwrap();
 
-        if let OptionSerializer::Some(log_messages) = &meta.log_messages {
-            log_messages.iter().for_each(|log| logs.push(log.clone()));
+        if let Some(meta) = &self.encoded_transaction.meta {
+            if let OptionSerializer::Some(log_messages) = &meta.log_messages {
+                log_messages.iter().for_each(|log| logs.push(log.clone()));
+            }
         }
 
         logs
     }
 }
+
+/// Resolves the account indexes of a compiled instruction, `None` when one is out of range.
+fn compiled_instruction(
+    instruction: &UiCompiledInstruction,
+    accounts: &[String],
+) -> Option<ReadOnlyInstruction> {
+    let program_id = accounts
+        .get(instruction.program_id_index as usize)?
+        .to_string();
+    let instruction_accounts = instruction
+        .accounts
+        .iter()
+        .map(|account_index| accounts.get(*account_index as usize).cloned())
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(ReadOnlyInstruction {
+        program_id,
+        data: instruction.data.clone(),
+        accounts: instruction_accounts,
+        inner_instructions: Vec::new(),
+    })
+}
+
+/// Converts an instruction of a `jsonParsed` transaction, returning its stack height along with
+/// it. Instructions of programs the node knows how to parse keep the parsed JSON as their data.
+fn parsed_instruction(instruction: UiParsedInstruction) -> (ReadOnlyInstruction, Option<u32>) {
+    match instruction {
+        UiParsedInstruction::PartiallyDecoded(decoded) => (
+            ReadOnlyInstruction {
+                program_id: decoded.program_id,
+                data: decoded.data,
+                accounts: decoded.accounts,
+                inner_instructions: Vec::new(),
+            },
+            decoded.stack_height,
+        ),
+        UiParsedInstruction::Parsed(parsed) => (
+            ReadOnlyInstruction {
+                program_id: parsed.program_id,
+                data: parsed.parsed.to_string(),
+                accounts: Vec::new(),
+                inner_instructions: Vec::new(),
+            },
+            parsed.stack_height,
+        ),
+    }
+}
@@ -0,0 +1,113 @@
+//! Snapshot tests over the transactions in `tests/fixtures`, pinning what the extractor returns
+//! for them and how the Drift, Jupiter and Whirlpool decoders read its output. Review changes
+//! with `cargo insta review`.
+
+use std::fs;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use decoders::{
+    drift_v2::{DriftV2Event, DriftV2Instruction},
+    jupiter_v6::{JupiterV6Event, JupiterV6Instruction},
+    whirlpool::{SwapAmounts, WhirlpoolInstruction},
+};
+use extractors::EncodedTransactionExtractor;
+use models::ReadOnlyTransaction;
+use solana_sdk::program_error::ProgramError;
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+use utils::{
+    find_all_instructions_by_program_id, find_all_program_data_by_program_id,
+    find_all_token_transfer_amounts,
+};
+
+const DRIFT_V2: &str = "dRiftyHA39MWEi3m9aunc5MzRF1JYuBsbn6VPcn33UH";
+const JUPITER_V6: &str = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4";
+const WHIRLPOOL: &str = "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc";
+
+/// Never contacted, the fixtures carry the addresses their lookup tables loaded.
+const RPC_URL: &str = "http://127.0.0.1:8899";
+
+// Fields are only read through `Debug`.
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Snapshot {
+    transaction: Option<ReadOnlyTransaction>,
+    instructions: Vec<Result<DriftV2Instruction, ProgramError>>,
+    events: Vec<Result<DriftV2Event, ProgramError>>,
+    jupiter: Vec<JupiterRoute>,
+    whirlpool: Vec<(
+        Result<WhirlpoolInstruction, ProgramError>,
+        Option<SwapAmounts>,
+    )>,
+}
+
+/// A Jupiter instruction with the events it emitted through self-CPI.
+type JupiterRoute = (
+    Result<JupiterV6Instruction, ProgramError>,
+    Vec<Result<JupiterV6Event, ProgramError>>,
+);
+
+async fn snapshot(fixture: &EncodedConfirmedTransactionWithStatusMeta) -> Snapshot {
+    let mut extractor = EncodedTransactionExtractor::new(
+        RPC_URL,
+        fixture.slot,
+        fixture.block_time.unwrap_or_default(),
+        &fixture.transaction,
+    );
+
+    // Failed transactions don't make it through `parse_readonly_transaction`, their instructions
+    // and logs are still decoded to pin what the extractor sees.
+    let transaction = extractor.parse_readonly_transaction().await;
+    let readonly_instructions = extractor.extract_readonly_instructions().await;
+    let instructions = find_all_instructions_by_program_id(readonly_instructions.clone(), DRIFT_V2)
+        .into_iter()
+        .map(|instruction| {
+            let data = bs58::decode(&instruction.data).into_vec().unwrap();
+            DriftV2Instruction::unpack(&data)
+        })
+        .collect();
+    let events = find_all_program_data_by_program_id(&extractor.extract_raw_logs(), DRIFT_V2)
+        .into_iter()
+        .map(|event| DriftV2Event::unpack(&STANDARD.decode(event).unwrap()))
+        .collect();
+    let jupiter = find_all_instructions_by_program_id(readonly_instructions.clone(), JUPITER_V6)
+        .into_iter()
+        .map(|instruction| {
+            let data = bs58::decode(&instruction.data).into_vec().unwrap();
+            let events =
+                find_all_instructions_by_program_id(instruction.inner_instructions, JUPITER_V6)
+                    .into_iter()
+                    .map(|event| {
+                        JupiterV6Event::unpack_cpi(&bs58::decode(&event.data).into_vec().unwrap())
+                    })
+                    .collect();
+            (JupiterV6Instruction::unpack(&data), events)
+        })
+        .collect();
+    let whirlpool = find_all_instructions_by_program_id(readonly_instructions, WHIRLPOOL)
+        .into_iter()
+        .map(|instruction| {
+            let data = bs58::decode(&instruction.data).into_vec().unwrap();
+            let amounts =
+                SwapAmounts::from_transfers(&find_all_token_transfer_amounts(&instruction));
+            (WhirlpoolInstruction::unpack(&data), amounts)
+        })
+        .collect();
+
+    Snapshot {
+        transaction,
+        instructions,
+        events,
+        jupiter,
+        whirlpool,
+    }
+}
+
+#[test]
+fn fixtures() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    insta::glob!("fixtures/*.json", |path| {
+        let fixture = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        insta::assert_debug_snapshot!(runtime.block_on(snapshot(&fixture)));
+    });
+}
@@ -0,0 +1,456 @@
+use std::{collections::HashMap, path::Path};
+
+use anyhow::{anyhow, bail, Result};
+use models::ReadOnlyInstruction;
+use serde_json::{json, Map, Number, Value};
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    account_discriminator, event_discriminator, instruction_discriminator, Idl, IdlEnumFields,
+    IdlField, IdlType, IdlTypeDefinitionTy,
+};
+
+/// Nested `defined` types deeper than this are rejected, which stops recursive types from
+/// looping forever on crafted data.
+const MAX_DEPTH: usize = 64;
+
+/// Decodes instructions, events and accounts of any Anchor program by walking its IDL.
+///
+/// Integers up to 64 bits become JSON numbers, 128 bit integers become strings so they keep their
+/// precision. Public keys are base58 strings, unit enum variants are their name and variants with
+/// fields are `{ "Variant": fields }`.
+pub struct IdlDecoder {
+    program_id: String,
+    idl: Idl,
+    instructions: HashMap<[u8; 8], usize>,
+    events: HashMap<[u8; 8], usize>,
+    accounts: HashMap<[u8; 8], usize>,
+}
+
+impl IdlDecoder {
+    pub fn new(program_id: &str, idl: Idl) -> Self {
+        let instructions = idl
+            .instructions
+            .iter()
+            .enumerate()
+            .map(|(i, instruction)| (instruction_discriminator(&instruction.name), i))
+            .collect();
+        let events = idl
+            .events
+            .iter()
+            .enumerate()
+            .map(|(i, event)| (event_discriminator(&event.name), i))
+            .collect();
+        let accounts = idl
+            .accounts
+            .iter()
+            .enumerate()
+            .map(|(i, account)| (account_discriminator(&account.name), i))
+            .collect();
+
+        Self {
+            program_id: program_id.to_string(),
+            idl,
+            instructions,
+            events,
+            accounts,
+        }
+    }
+
+    pub fn from_file(program_id: &str, path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self::new(program_id, Idl::from_file(path)?))
+    }
+
+    pub fn program_id(&self) -> &str {
+        &self.program_id
+    }
+
+    pub fn idl(&self) -> &Idl {
+        &self.idl
+    }
+
+    /// Decodes the data of an instruction and names its accounts. Accounts past the ones the IDL
+    /// declares are listed under `remaining_accounts`.
+    pub fn decode_instruction(&self, instruction: &ReadOnlyInstruction) -> Result<Value> {
+        let data = bs58::decode(&instruction.data).into_vec()?;
+        let (name, args) = self.decode_instruction_data(&data)?;
+        let declared = self
+            .idl
+            .instructions
+            .iter()
+            .find(|candidate| candidate.name == name)
+            .map(|candidate| candidate.flat_accounts())
+            .unwrap_or_default();
+
+        let mut accounts = Map::new();
+        for (account, address) in declared.iter().zip(instruction.accounts.iter()) {
+            accounts.insert(account.name.clone(), Value::String(address.clone()));
+        }
+        let remaining_accounts = instruction
+            .accounts
+            .iter()
+            .skip(declared.len())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        Ok(json!({
+            "program_id": instruction.program_id,
+            "name": name,
+            "args": args,
+            "accounts": accounts,
+            "remaining_accounts": remaining_accounts,
+        }))
+    }
+
+    /// Returns the instruction name and its decoded arguments.
+    pub fn decode_instruction_data(&self, data: &[u8]) -> Result<(String, Value)> {
+        let (discriminator, rest) = split_discriminator(data)?;
+        let instruction = match self.instructions.get(&discriminator) {
+            Some(i) => &self.idl.instructions[*i],
+            None => bail!("Unknown instruction discriminator: {:?}", discriminator),
+        };
+
+        let mut reader = Reader::new(rest);
+        let args = self.read_fields(&mut reader, &instruction.args, 0)?;
+        Ok((instruction.name.clone(), args))
+    }
+
+    /// Decodes a `Program data:` payload into `{ "name": .., "data": .. }`.
+    pub fn decode_event(&self, data: &[u8]) -> Result<Value> {
+        let (discriminator, rest) = split_discriminator(data)?;
+        let event = match self.events.get(&discriminator) {
+            Some(i) => &self.idl.events[*i],
+            None => bail!("Unknown event discriminator: {:?}", discriminator),
+        };
+
+        let fields = event
+            .fields
+            .iter()
+            .map(|field| IdlField {
+                name: field.name.clone(),
+                docs: Vec::new(),
+                ty: field.ty.clone(),
+            })
+            .collect::<Vec<_>>();
+        let mut reader = Reader::new(rest);
+        let data = self.read_fields(&mut reader, &fields, 0)?;
+
+        Ok(json!({ "name": event.name, "data": data }))
+    }
+
+    /// Decodes account data into `{ "name": .., "data": .. }`. Trailing bytes are ignored.
+    pub fn decode_account(&self, data: &[u8]) -> Result<Value> {
+        let (discriminator, rest) = split_discriminator(data)?;
+        let account = match self.accounts.get(&discriminator) {
+            Some(i) => &self.idl.accounts[*i],
+            None => bail!("Unknown account discriminator: {:?}", discriminator),
+        };
+
+        let mut reader = Reader::new(rest);
+        let data = self.read(&mut reader, &IdlType::Defined(account.name.clone()), 0)?;
+
+        Ok(json!({ "name": account.name, "data": data }))
+    }
+
+    fn read_fields(&self, reader: &mut Reader, fields: &[IdlField], depth: usize) -> Result<Value> {
+        let mut object = Map::new();
+        for field in fields {
+            object.insert(field.name.clone(), self.read(reader, &field.ty, depth)?);
+        }
+        Ok(Value::Object(object))
+    }
+
+    fn read(&self, reader: &mut Reader, ty: &IdlType, depth: usize) -> Result<Value> {
+        Ok(match ty {
+            IdlType::Bool => Value::Bool(reader.take(1)?[0] != 0),
+            IdlType::U8 => Value::from(reader.take(1)?[0]),
+            IdlType::I8 => Value::from(reader.take(1)?[0] as i8),
+            IdlType::U16 => Value::from(u16::from_le_bytes(reader.array()?)),
+            IdlType::I16 => Value::from(i16::from_le_bytes(reader.array()?)),
+            IdlType::U32 => Value::from(u32::from_le_bytes(reader.array()?)),
+            IdlType::I32 => Value::from(i32::from_le_bytes(reader.array()?)),
+            IdlType::F32 => float(f32::from_le_bytes(reader.array()?) as f64),
+            IdlType::U64 => Value::from(u64::from_le_bytes(reader.array()?)),
+            IdlType::I64 => Value::from(i64::from_le_bytes(reader.array()?)),
+            IdlType::F64 => float(f64::from_le_bytes(reader.array()?)),
+            IdlType::U128 => Value::String(u128::from_le_bytes(reader.array()?).to_string()),
+            IdlType::I128 => Value::String(i128::from_le_bytes(reader.array()?).to_string()),
+            IdlType::U256 | IdlType::I256 => bail!("256 bit integers are not supported"),
+            IdlType::Bytes => {
+                let len = reader.len()?;
+                Value::from(reader.take(len)?.to_vec())
+            }
+            IdlType::String => {
+                let len = reader.len()?;
+                Value::String(String::from_utf8(reader.take(len)?.to_vec())?)
+            }
+            IdlType::PublicKey => {
+                Value::String(Pubkey::new_from_array(reader.array()?).to_string())
+            }
+            IdlType::Option(inner) => match reader.take(1)?[0] {
+                0 => Value::Null,
+                1 => self.read(reader, inner, depth)?,
+                tag => bail!("Invalid option tag: {}", tag),
+            },
+            IdlType::Vec(inner) => {
+                let len = reader.len()?;
+                self.read_many(reader, inner, len, depth)?
+            }
+            IdlType::Array(inner, len) => self.read_many(reader, inner, *len, depth)?,
+            IdlType::Defined(name) => self.read_defined(reader, name, depth + 1)?,
+        })
+    }
+
+    fn read_many(
+        &self,
+        reader: &mut Reader,
+        ty: &IdlType,
+        len: usize,
+        depth: usize,
+    ) -> Result<Value> {
+        let mut values = Vec::new();
+        for _ in 0..len {
+            let remaining = reader.data.len();
+            values.push(self.read(reader, ty, depth)?);
+            // Zero sized elements would let a crafted length spin for billions of iterations.
+            if reader.data.len() == remaining {
+                bail!("Elements of {:?} take no space", ty);
+            }
+        }
+        Ok(Value::Array(values))
+    }
+
+    fn read_defined(&self, reader: &mut Reader, name: &str, depth: usize) -> Result<Value> {
+        if depth > MAX_DEPTH {
+            bail!("Type {} is nested too deep", name);
+        }
+
+        let definition = match self.idl.type_definition(name) {
+            Some(definition) => definition,
+            None => bail!("Unknown type: {}", name),
+        };
+
+        match &definition.ty {
+            IdlTypeDefinitionTy::Struct { fields } => self.read_fields(reader, fields, depth),
+            IdlTypeDefinitionTy::Enum { variants } => {
+                let index = reader.take(1)?[0] as usize;
+                let variant = match variants.get(index) {
+                    Some(variant) => variant,
+                    None => bail!("Invalid variant {} of {}", index, name),
+                };
+
+                let fields = match &variant.fields {
+                    None => return Ok(Value::String(variant.name.clone())),
+                    Some(IdlEnumFields::Named(fields)) => {
+                        self.read_fields(reader, fields, depth)?
+                    }
+                    Some(IdlEnumFields::Tuple(types)) => {
+                        let mut values = Vec::new();
+                        for ty in types {
+                            values.push(self.read(reader, ty, depth)?);
+                        }
+                        Value::Array(values)
+                    }
+                };
+
+                let mut object = Map::new();
+                object.insert(variant.name.clone(), fields);
+                Ok(Value::Object(object))
+            }
+        }
+    }
+}
+
+fn split_discriminator(data: &[u8]) -> Result<([u8; 8], &[u8])> {
+    if data.len() < 8 {
+        bail!("Data is shorter than a discriminator");
+    }
+
+    let (discriminator, rest) = data.split_at(8);
+    Ok((discriminator.try_into()?, rest))
+}
+
+/// NaN and infinities have no JSON representation.
+fn float(value: f64) -> Value {
+    match Number::from_f64(value) {
+        Some(number) => Value::Number(number),
+        None => Value::Null,
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.data.len() < len {
+            return Err(anyhow!("Unexpected end of data"));
+        }
+
+        let (taken, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        Ok(self.take(N)?.try_into()?)
+    }
+
+    /// Borsh length prefix of vectors, strings and bytes.
+    fn len(&mut self) -> Result<usize> {
+        Ok(u32::from_le_bytes(self.array()?) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDL: &str = r#"{
+        "version": "0.1.0",
+        "name": "example",
+        "instructions": [
+            {
+                "name": "placeOrder",
+                "accounts": [{ "name": "user", "isMut": true, "isSigner": false }],
+                "args": [
+                    { "name": "side", "type": { "defined": "Side" } },
+                    { "name": "price", "type": "u64" },
+                    { "name": "size", "type": "i128" },
+                    { "name": "expiry", "type": { "option": "i64" } },
+                    { "name": "tags", "type": { "vec": "u8" } }
+                ]
+            },
+            {
+                "name": "batch",
+                "accounts": [],
+                "args": [{ "name": "items", "type": { "vec": { "defined": "Empty" } } }]
+            }
+        ],
+        "accounts": [
+            {
+                "name": "Market",
+                "type": { "kind": "struct", "fields": [{ "name": "authority", "type": "publicKey" }] }
+            }
+        ],
+        "types": [
+            {
+                "name": "Side",
+                "type": {
+                    "kind": "enum",
+                    "variants": [{ "name": "Bid" }, { "name": "Ask", "fields": ["u8"] }]
+                }
+            },
+            { "name": "Empty", "type": { "kind": "struct", "fields": [] } }
+        ],
+        "events": [
+            { "name": "Filled", "fields": [{ "name": "amount", "type": "u32", "index": false }] }
+        ]
+    }"#;
+
+    fn decoder() -> IdlDecoder {
+        IdlDecoder::new("Example", Idl::from_json(IDL).unwrap())
+    }
+
+    fn with_discriminator(discriminator: [u8; 8], data: &[u8]) -> Vec<u8> {
+        [&discriminator[..], data].concat()
+    }
+
+    #[test]
+    fn decodes_instruction_args() {
+        let mut data = vec![1, 7];
+        data.extend(5u64.to_le_bytes());
+        data.extend((-3i128).to_le_bytes());
+        data.extend([1]);
+        data.extend(9i64.to_le_bytes());
+        data.extend([2, 0, 0, 0, 4, 5]);
+
+        let (name, args) = decoder()
+            .decode_instruction_data(&with_discriminator(
+                instruction_discriminator("placeOrder"),
+                &data,
+            ))
+            .unwrap();
+
+        assert_eq!(name, "placeOrder");
+        assert_eq!(
+            args,
+            json!({
+                "side": { "Ask": [7] },
+                "price": 5,
+                "size": "-3",
+                "expiry": 9,
+                "tags": [4, 5],
+            })
+        );
+    }
+
+    #[test]
+    fn names_accounts_and_lists_remaining_ones() {
+        let mut data = with_discriminator(instruction_discriminator("placeOrder"), &[0]);
+        data.extend(5u64.to_le_bytes());
+        data.extend(0i128.to_le_bytes());
+        data.extend([0, 0, 0, 0, 0]);
+        let instruction = ReadOnlyInstruction {
+            program_id: "Example".to_string(),
+            accounts: vec!["User".to_string(), "Oracle".to_string()],
+            data: bs58::encode(data).into_string(),
+            inner_instructions: Vec::new(),
+        };
+
+        let decoded = decoder().decode_instruction(&instruction).unwrap();
+
+        assert_eq!(decoded["accounts"], json!({ "user": "User" }));
+        assert_eq!(decoded["remaining_accounts"], json!(["Oracle"]));
+        assert_eq!(decoded["args"]["side"], json!("Bid"));
+        assert_eq!(decoded["args"]["expiry"], Value::Null);
+    }
+
+    #[test]
+    fn decodes_events_and_accounts() {
+        let event = with_discriminator(event_discriminator("Filled"), &3u32.to_le_bytes());
+        assert_eq!(
+            decoder().decode_event(&event).unwrap(),
+            json!({ "name": "Filled", "data": { "amount": 3 } })
+        );
+
+        let authority = Pubkey::new_unique();
+        let mut account = with_discriminator(account_discriminator("Market"), authority.as_ref());
+        account.extend([0; 16]);
+        assert_eq!(
+            decoder().decode_account(&account).unwrap(),
+            json!({ "name": "Market", "data": { "authority": authority.to_string() } })
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_and_unknown_data() {
+        let decoder = decoder();
+
+        assert!(decoder.decode_instruction_data(&[0; 4]).is_err());
+        assert!(decoder.decode_instruction_data(&[0; 8]).is_err());
+        assert!(decoder
+            .decode_instruction_data(&with_discriminator(
+                instruction_discriminator("placeOrder"),
+                &[2]
+            ))
+            .is_err());
+        assert!(decoder
+            .decode_instruction_data(&with_discriminator(
+                instruction_discriminator("placeOrder"),
+                &[0, 1]
+            ))
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_long_vectors_of_empty_elements() {
+        let data = with_discriminator(instruction_discriminator("batch"), &u32::MAX.to_le_bytes());
+        assert!(decoder().decode_instruction_data(&data).is_err());
+    }
+}
@@ -0,0 +1,221 @@
+pub mod decoder;
+
+use std::{fs, path::Path};
+
+use anyhow::Result;
+use decoders::discriminator;
+pub use decoders::discriminator::to_snake_case;
+use serde::Deserialize;
+
+/// Anchor IDL, as emitted by `anchor build` up to anchor 0.29.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Idl {
+    pub version: String,
+    pub name: String,
+    #[serde(default)]
+    pub instructions: Vec<IdlInstruction>,
+    #[serde(default)]
+    pub accounts: Vec<IdlTypeDefinition>,
+    #[serde(default)]
+    pub types: Vec<IdlTypeDefinition>,
+    #[serde(default)]
+    pub events: Vec<IdlEvent>,
+    #[serde(default)]
+    pub errors: Vec<IdlError>,
+    #[serde(default)]
+    pub metadata: Option<IdlMetadata>,
+}
+
+impl Idl {
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Looks a type up among the account and type definitions.
+    pub fn type_definition(&self, name: &str) -> Option<&IdlTypeDefinition> {
+        self.types
+            .iter()
+            .chain(self.accounts.iter())
+            .find(|definition| definition.name == name)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlMetadata {
+    /// Program id, added by `anchor deploy` and `anchor idl fetch`.
+    pub address: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlInstruction {
+    pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    pub accounts: Vec<IdlAccountItem>,
+    pub args: Vec<IdlField>,
+}
+
+impl IdlInstruction {
+    /// Accounts in the order the instruction expects them, with composite accounts flattened.
+    pub fn flat_accounts(&self) -> Vec<&IdlAccount> {
+        let mut accounts = Vec::new();
+        for item in &self.accounts {
+            item.flatten_into(&mut accounts);
+        }
+        accounts
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum IdlAccountItem {
+    Account(IdlAccount),
+    Accounts(IdlAccounts),
+}
+
+impl IdlAccountItem {
+    fn flatten_into<'a>(&'a self, accounts: &mut Vec<&'a IdlAccount>) {
+        match self {
+            IdlAccountItem::Account(account) => accounts.push(account),
+            IdlAccountItem::Accounts(composite) => {
+                for item in &composite.accounts {
+                    item.flatten_into(accounts);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdlAccount {
+    pub name: String,
+    #[serde(default)]
+    pub is_mut: bool,
+    #[serde(default)]
+    pub is_signer: bool,
+    #[serde(default)]
+    pub is_optional: bool,
+    #[serde(default)]
+    pub docs: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlAccounts {
+    pub name: String,
+    pub accounts: Vec<IdlAccountItem>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlField {
+    pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    #[serde(rename = "type")]
+    pub ty: IdlType,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlEvent {
+    pub name: String,
+    pub fields: Vec<IdlEventField>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlEventField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlType,
+    #[serde(default)]
+    pub index: bool,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlError {
+    pub code: u32,
+    pub name: String,
+    pub msg: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlTypeDefinition {
+    pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    #[serde(rename = "type")]
+    pub ty: IdlTypeDefinitionTy,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum IdlTypeDefinitionTy {
+    Struct { fields: Vec<IdlField> },
+    Enum { variants: Vec<IdlEnumVariant> },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlEnumVariant {
+    pub name: String,
+    pub fields: Option<IdlEnumFields>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum IdlEnumFields {
+    Named(Vec<IdlField>),
+    Tuple(Vec<IdlType>),
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum IdlType {
+    Bool,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    F32,
+    U64,
+    I64,
+    F64,
+    U128,
+    I128,
+    U256,
+    I256,
+    Bytes,
+    String,
+    #[serde(alias = "pubkey")]
+    PublicKey,
+    Defined(String),
+    Option(Box<IdlType>),
+    Vec(Box<IdlType>),
+    Array(Box<IdlType>, usize),
+}
+
+/// Discriminator of an instruction, from its camelCase IDL name.
+pub fn instruction_discriminator(name: &str) -> [u8; 8] {
+    discriminator::discriminator("global", to_snake_case(name).as_bytes())
+}
+
+pub fn account_discriminator(name: &str) -> [u8; 8] {
+    discriminator::account_discriminator(name)
+}
+
+pub fn event_discriminator(name: &str) -> [u8; 8] {
+    discriminator::event_discriminator(name)
+}
+
+/// `placePerpOrder` -> `PlacePerpOrder`.
+pub fn to_pascal_case(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -11,7 +11,157 @@ pub struct ReadOnlyInstruction {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ReadOnlyTransaction {
     pub signature: String,
+    pub slot: u64,
+    pub block_time: i64,
     pub log_messages: Vec<String>,
     pub accounts: Vec<String>,
     pub instructions: Vec<ReadOnlyInstruction>,
 }
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarketKind {
+    Perp,
+    Spot,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Resolution {
+    #[serde(rename = "1m")]
+    OneMinute,
+    #[serde(rename = "5m")]
+    FiveMinutes,
+    #[serde(rename = "1h")]
+    OneHour,
+    #[serde(rename = "1d")]
+    OneDay,
+}
+
+impl Resolution {
+    pub fn seconds(&self) -> i64 {
+        match self {
+            Resolution::OneMinute => 60,
+            Resolution::FiveMinutes => 5 * 60,
+            Resolution::OneHour => 60 * 60,
+            Resolution::OneDay => 24 * 60 * 60,
+        }
+    }
+
+    /// Start of the bar containing `ts`.
+    pub fn open_time(&self, ts: i64) -> i64 {
+        ts - ts.rem_euclid(self.seconds())
+    }
+}
+
+/// Human readable identity of a market, resolved from its index.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MarketMetadata {
+    /// e.g. `SOL-PERP` or `USDC`
+    pub symbol: String,
+    /// Decimals of the base amounts of the market: 9 for perps, the token mint decimals for spot
+    pub decimals: u32,
+}
+
+/// OHLCV bar for a single market. Prices are in PRICE_PRECISION, base volume in the market's
+/// base precision and quote volume in QUOTE_PRECISION.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Candle {
+    pub market_kind: MarketKind,
+    pub market_index: u16,
+    #[serde(flatten)]
+    pub market: Option<MarketMetadata>,
+    pub resolution: Resolution,
+    pub open_time: i64,
+    pub close_time: i64,
+    pub open: u64,
+    pub high: u64,
+    pub low: u64,
+    pub close: u64,
+    pub volume_base: u128,
+    pub volume_quote: u128,
+    pub trade_count: u64,
+    pub vwap: u64,
+    pub first_slot: u64,
+    pub last_slot: u64,
+}
+
+/// One point of the hourly funding series of a perp market. Rates are in FUNDING_RATE_PRECISION,
+/// twaps in PRICE_PRECISION and revenue in QUOTE_PRECISION.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct FundingRate {
+    pub market_index: u16,
+    #[serde(flatten)]
+    pub market: Option<MarketMetadata>,
+    pub hour: i64,
+    pub ts: i64,
+    pub record_id: u64,
+    pub funding_rate: i64,
+    pub funding_rate_long: i128,
+    pub funding_rate_short: i128,
+    pub cumulative_funding_rate_long: i128,
+    pub cumulative_funding_rate_short: i128,
+    pub oracle_price_twap: i64,
+    pub mark_price_twap: u64,
+    /// mark twap minus oracle twap, the premium funding is paid on
+    pub mark_oracle_spread: i64,
+    pub period_revenue: i64,
+}
+
+/// Running total of the funding a user has paid or received in a perp market.
+/// Payments are in QUOTE_PRECISION, positive when the user received funding.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct FundingPayments {
+    pub user: String,
+    pub user_authority: String,
+    pub market_index: u16,
+    #[serde(flatten)]
+    pub market: Option<MarketMetadata>,
+    pub total_funding_payment: i128,
+    pub payment_count: u64,
+    pub first_ts: i64,
+    pub last_ts: i64,
+}
+
+/// A Drift order from placement to the fill, cancel or expiry that closed it. Base amounts are in
+/// the market's base precision, quote amounts in QUOTE_PRECISION and prices in PRICE_PRECISION.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ClosedOrder {
+    pub user: String,
+    pub order_id: u32,
+    pub user_order_id: u8,
+    pub market_kind: MarketKind,
+    pub market_index: u16,
+    #[serde(flatten)]
+    pub market: Option<MarketMetadata>,
+    /// e.g. `Limit` or `TriggerMarket`
+    pub order_type: String,
+    /// `Long` or `Short`
+    pub direction: String,
+    pub base_asset_amount: u64,
+    pub price: u64,
+    pub placed_slot: u64,
+    pub placed_ts: i64,
+    pub triggered_ts: Option<i64>,
+    pub first_fill_ts: Option<i64>,
+    pub base_asset_amount_filled: u64,
+    pub quote_asset_amount_filled: u64,
+    pub fill_count: u32,
+    pub modify_count: u32,
+    pub cancel_requested: bool,
+    /// `Filled`, `Canceled` or `Expired`
+    pub status: String,
+    pub cancel_reason: Option<String>,
+    /// e.g. `FilledDuringAuction`
+    pub auction_outcome: String,
+    pub closed_ts: Option<i64>,
+}
+
+/// Everything the scraper can hand over to a sink.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SinkRecord {
+    Candle(Candle),
+    FundingRate(FundingRate),
+    FundingPayments(FundingPayments),
+    ClosedOrder(ClosedOrder),
+}
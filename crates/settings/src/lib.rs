@@ -1,6 +1,7 @@
 use std::{env, path::Path};
 
 use config::{Config, ConfigError, Environment, File};
+use models::Resolution;
 use serde::Deserialize;
 use tracing::level_filters::LevelFilter;
 
@@ -8,6 +9,10 @@ use tracing::level_filters::LevelFilter;
 pub struct Settings {
     pub tracing: Tracing,
     pub solana: Solana,
+    pub sink: Sink,
+    pub candles: Candles,
+    pub drift: Drift,
+    pub idl: Idl,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -37,6 +42,44 @@ pub struct Solana {
     pub rpc: String,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct Sink {
+    /// Where records go: "log" writes them to the tracing output, "jsonl" appends them to `path`
+    pub kind: String,
+    pub path: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Candles {
+    pub resolutions: Vec<Resolution>,
+    /// How long, in seconds of block time, a bar stays open for late fills after it closes
+    pub allowed_lateness: i64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Drift {
+    pub upgrades: Vec<Upgrade>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Upgrade {
+    pub slot: u64,
+    /// IDL revision live from `slot` on, e.g. "v1" or "v2"
+    pub revision: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Idl {
+    pub programs: Vec<IdlProgram>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlProgram {
+    pub program_id: String,
+    /// Anchor IDL JSON, relative to the working directory
+    pub path: String,
+}
+
 impl Settings {
     pub fn new() -> Result<Self, ConfigError> {
         let root_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
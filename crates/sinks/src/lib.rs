@@ -0,0 +1,70 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use anyhow::{bail, Result};
+use models::SinkRecord;
+use tracing::info;
+
+/// Destination for records produced by the scraper.
+pub trait Sink {
+    fn write(&mut self, record: &SinkRecord) -> Result<()>;
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+pub fn from_settings(settings: &settings::Sink) -> Result<Box<dyn Sink>> {
+    match settings.kind.as_str() {
+        "log" => Ok(Box::new(LogSink)),
+        "jsonl" => Ok(Box::new(JsonLinesSink::new(&settings.path)?)),
+        kind => bail!("Unknown sink kind: {}", kind),
+    }
+}
+
+/// Writes every record to the tracing output.
+pub struct LogSink;
+
+impl Sink for LogSink {
+    fn write(&mut self, record: &SinkRecord) -> Result<()> {
+        info!("{}", serde_json::to_string(record)?);
+
+        Ok(())
+    }
+}
+
+/// Appends every record as a single JSON line to a file.
+pub struct JsonLinesSink {
+    writer: BufWriter<File>,
+}
+
+impl JsonLinesSink {
+    pub fn new(path: &str) -> Result<Self> {
+        if let Some(parent) = Path::new(path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        Ok(Self {
+            writer: BufWriter::new(file),
+        })
+    }
+}
+
+impl Sink for JsonLinesSink {
+    fn write(&mut self, record: &SinkRecord) -> Result<()> {
+        serde_json::to_writer(&mut self.writer, record)?;
+        self.writer.write_all(b"\n")?;
+
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
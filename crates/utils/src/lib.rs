@@ -1,7 +1,16 @@
-use std::ops::Not;
-
+use decoders::spl_token::SplTokenInstruction;
 use models::ReadOnlyInstruction;
+use serde_json::Value;
+
+pub const TOKEN_PROGRAM: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+pub const TOKEN_2022_PROGRAM: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+
+const PROGRAM_LOG_PREFIX: &str = "Program ";
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+const PROGRAM_MESSAGE_PREFIX: &str = "Program log: ";
 
+/// Collects the instructions of `program_id`, whether called at the top level or through CPI.
+/// Matches keep their inner instructions, which are not searched further.
 pub fn find_all_instructions_by_program_id(
     instructions: Vec<ReadOnlyInstruction>,
     program_id: &str,
@@ -9,28 +18,157 @@ pub fn find_all_instructions_by_program_id(
     let mut result = Vec::new();
 
     for instruction in instructions {
-        let found = search(instruction, program_id);
-        if let Some(found) = found {
-            result.push(found);
+        search(instruction, program_id, &mut result);
+    }
+
+    result
+}
+
+/// Collects the base64 payloads of every `Program data:` log emitted while `program_id` is the
+/// program currently executing, following the invoke stack so CPI logs are attributed correctly.
+pub fn find_all_program_data_by_program_id(
+    log_messages: &[String],
+    program_id: &str,
+) -> Vec<String> {
+    find_all_logs_by_program_id(log_messages, program_id, PROGRAM_DATA_PREFIX)
+}
+
+/// Collects the text of every `Program log:` message written while `program_id` is the program
+/// currently executing, like `find_all_program_data_by_program_id` does for `Program data:`.
+pub fn find_all_program_logs_by_program_id(
+    log_messages: &[String],
+    program_id: &str,
+) -> Vec<String> {
+    find_all_logs_by_program_id(log_messages, program_id, PROGRAM_MESSAGE_PREFIX)
+}
+
+fn find_all_logs_by_program_id(
+    log_messages: &[String],
+    program_id: &str,
+    prefix: &str,
+) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut invoke_stack: Vec<&str> = Vec::new();
+
+    for log in log_messages {
+        if let Some(data) = log.strip_prefix(prefix) {
+            if invoke_stack
+                .last()
+                .is_some_and(|current| current.eq(&program_id))
+            {
+                result.push(data.to_string());
+            }
+            continue;
+        }
+
+        if let Some(rest) = log.strip_prefix(PROGRAM_LOG_PREFIX) {
+            let mut parts = rest.split_whitespace();
+            if let (Some(id), Some(status)) = (parts.next(), parts.next()) {
+                match status {
+                    "invoke" => invoke_stack.push(id),
+                    "success" | "failed:" => {
+                        invoke_stack.pop();
+                    }
+                    _ => {}
+                }
+            }
         }
     }
 
     result
 }
 
-fn search(instruction: ReadOnlyInstruction, program_id: &str) -> Option<ReadOnlyInstruction> {
+/// Amounts of the token transfers `instruction` made directly, in order. Reads the raw data of
+/// `Transfer`, `TransferChecked` and `TransferCheckedWithFee` as well as the JSON of `jsonParsed`
+/// transactions.
+pub fn find_all_token_transfer_amounts(instruction: &ReadOnlyInstruction) -> Vec<u64> {
+    instruction
+        .inner_instructions
+        .iter()
+        .filter(|inner| {
+            inner.program_id.eq(TOKEN_PROGRAM) || inner.program_id.eq(TOKEN_2022_PROGRAM)
+        })
+        .filter_map(|inner| token_transfer_amount(&inner.data))
+        .collect()
+}
+
+fn token_transfer_amount(data: &str) -> Option<u64> {
+    if let Ok(parsed) = serde_json::from_str::<Value>(data) {
+        let info = &parsed["info"];
+        return match parsed["type"].as_str() {
+            Some("transfer") => info["amount"].as_str()?.parse().ok(),
+            Some("transferChecked" | "transferCheckedWithFee") => {
+                info["tokenAmount"]["amount"].as_str()?.parse().ok()
+            }
+            _ => None,
+        };
+    }
+
+    let data = bs58::decode(data).into_vec().ok()?;
+    SplTokenInstruction::unpack(&data)
+        .ok()
+        .filter(SplTokenInstruction::is_transfer)?
+        .amount()
+}
+
+fn search(
+    instruction: ReadOnlyInstruction,
+    program_id: &str,
+    result: &mut Vec<ReadOnlyInstruction>,
+) {
     if instruction.program_id.eq(program_id) {
-        return Some(instruction);
+        result.push(instruction);
+        return;
+    }
+
+    for inner_instruction in instruction.inner_instructions {
+        search(inner_instruction, program_id, result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(parts: &[&[u8]]) -> String {
+        bs58::encode(parts.concat()).into_string()
     }
 
-    if instruction.inner_instructions.is_empty().not() {
-        instruction
-            .inner_instructions
-            .iter()
-            .for_each(|inner_instruction| {
-                search(inner_instruction.clone(), program_id);
-            });
+    #[test]
+    fn token_transfer_amount_reads_raw_transfers() {
+        let amount = 1_500_000u64.to_le_bytes();
+        let fee = 1_500u64.to_le_bytes();
+        assert_eq!(
+            token_transfer_amount(&encode(&[&[3], &amount])),
+            Some(1_500_000)
+        );
+        assert_eq!(
+            token_transfer_amount(&encode(&[&[12], &amount, &[6]])),
+            Some(1_500_000)
+        );
+        assert_eq!(
+            token_transfer_amount(&encode(&[&[26, 1], &amount, &[6], &fee])),
+            Some(1_500_000)
+        );
+        // `MintTo` carries an amount but moves no tokens between accounts.
+        assert_eq!(token_transfer_amount(&encode(&[&[7], &amount])), None);
     }
 
-    None
+    #[test]
+    fn token_transfer_amount_reads_parsed_transfers() {
+        assert_eq!(
+            token_transfer_amount(r#"{"type":"transfer","info":{"amount":"42"}}"#),
+            Some(42)
+        );
+        assert_eq!(
+            token_transfer_amount(
+                r#"{"type":"transferCheckedWithFee","info":{"tokenAmount":{"amount":"42"}}}"#
+            ),
+            Some(42)
+        );
+        assert_eq!(
+            token_transfer_amount(r#"{"type":"mintTo","info":{"amount":"42"}}"#),
+            None
+        );
+    }
 }
@@ -0,0 +1,25 @@
+#![no_main]
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use decoders::drift_v2::{self, DriftV2Event};
+use fuzz::assert_bounded_allocation;
+use libfuzzer_sys::fuzz_target;
+use utils::find_all_program_data_by_program_id;
+
+fuzz_target!(|data: &[u8]| {
+    assert_bounded_allocation(data.len(), || {
+        let _ = DriftV2Event::unpack(data);
+
+        // The same bytes read as the log messages of a transaction, the way the scraper finds
+        // events.
+        let logs = String::from_utf8_lossy(data)
+            .lines()
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        for event in find_all_program_data_by_program_id(&logs, &drift_v2::ID.to_string()) {
+            if let Ok(event) = STANDARD.decode(event) {
+                let _ = DriftV2Event::unpack(&event);
+            }
+        }
+    });
+});
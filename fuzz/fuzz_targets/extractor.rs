@@ -0,0 +1,40 @@
+#![no_main]
+
+use std::sync::OnceLock;
+
+use extractors::EncodedTransactionExtractor;
+use fuzz::assert_bounded_allocation;
+use libfuzzer_sys::fuzz_target;
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+use tokio::runtime::{Builder, Runtime};
+
+/// Nothing listens there, lookup tables that have to be fetched fail right away.
+const RPC_URL: &str = "http://127.0.0.1:1";
+
+fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| Builder::new_current_thread().enable_all().build().unwrap())
+}
+
+fuzz_target!(|data: &[u8]| {
+    let transaction =
+        match serde_json::from_slice::<EncodedConfirmedTransactionWithStatusMeta>(data) {
+            Ok(transaction) => transaction,
+            Err(_) => return,
+        };
+
+    let mut extractor = EncodedTransactionExtractor::new(
+        RPC_URL,
+        transaction.slot,
+        transaction.block_time.unwrap_or_default(),
+        &transaction.transaction,
+    );
+    assert_bounded_allocation(data.len(), || {
+        runtime().block_on(async {
+            extractor.extract_signature();
+            extractor.extract_raw_logs();
+            extractor.parse_readonly_transaction().await;
+            extractor.extract_readonly_instructions().await;
+        })
+    });
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use decoders::drift_v2::DriftV2Instruction;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|instruction: DriftV2Instruction| {
+    let data = instruction.pack().unwrap();
+    assert_eq!(DriftV2Instruction::unpack(&data), Ok(instruction));
+});
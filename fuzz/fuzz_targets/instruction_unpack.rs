@@ -0,0 +1,22 @@
+#![no_main]
+
+use decoders::drift_v2::{
+    versions::{self, IdlRevision},
+    DriftV2Instruction,
+};
+use fuzz::assert_bounded_allocation;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    assert_bounded_allocation(data.len(), || {
+        // Borsh encodings are canonical, whatever decodes packs back into the same bytes. Only
+        // instructions without arguments ignore trailing data, like anchor does.
+        if let Ok(instruction) = DriftV2Instruction::unpack(data) {
+            let packed = instruction.pack().unwrap();
+            assert!(data.starts_with(&packed));
+            assert_eq!(DriftV2Instruction::unpack(&packed), Ok(instruction));
+        }
+
+        let _ = versions::unpack_instruction(IdlRevision::V1, data);
+    });
+});
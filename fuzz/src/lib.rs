@@ -0,0 +1,77 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Bytes a target may allocate per input byte on top of `BASE_ALLOCATION`. Decoded values are
+/// larger than their encoding, but nowhere near what a forged length prefix asks for.
+pub const ALLOCATION_PER_BYTE: usize = 64;
+pub const BASE_ALLOCATION: usize = 1 << 20;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// System allocator keeping track of the bytes in use and their peak.
+struct PeakAllocator;
+
+impl PeakAllocator {
+    fn grow(size: usize) {
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn shrink(size: usize) {
+        CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            if new_size > layout.size() {
+                Self::grow(new_size - layout.size());
+            } else {
+                Self::shrink(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: PeakAllocator = PeakAllocator;
+
+/// Runs `f` and panics if the memory it held at any point exceeds the bound for an input of `len`
+/// bytes.
+pub fn assert_bounded_allocation<T>(len: usize, f: impl FnOnce() -> T) -> T {
+    let start = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(start, Ordering::Relaxed);
+
+    let value = f();
+
+    let peak = PEAK.load(Ordering::Relaxed).saturating_sub(start);
+    let bound = BASE_ALLOCATION + len * ALLOCATION_PER_BYTE;
+    assert!(
+        peak <= bound,
+        "Allocated {} bytes for a {} byte input, the bound is {}",
+        peak,
+        len,
+        bound
+    );
+
+    value
+}